@@ -0,0 +1,47 @@
+use criterion::*;
+use genetic_algorithm::fitness::placeholders::CountTrue;
+use genetic_algorithm::strategy::hill_climb::prelude::*;
+use std::time::Duration;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let genes_size = 10_000;
+    let max_stale_generations = 100;
+
+    let mut group = c.benchmark_group("hill_climb");
+    group.warm_up_time(Duration::from_secs(5));
+    group.measurement_time(Duration::from_secs(10));
+
+    let variants = [
+        HillClimbVariant::Stochastic,
+        HillClimbVariant::SteepestAscent,
+    ];
+    for variant in variants {
+        group.bench_function(
+            format!(
+                "binary-{}-gen{}-{:?}",
+                genes_size, max_stale_generations, variant
+            ),
+            |b| {
+                let genotype = BinaryGenotype::builder()
+                    .with_genes_size(genes_size)
+                    .build()
+                    .unwrap();
+
+                let hill_climb_builder = HillClimb::builder()
+                    .with_genotype(genotype)
+                    .with_variant(variant)
+                    .with_max_stale_generations(max_stale_generations)
+                    .with_fitness(CountTrue);
+
+                b.iter_batched(
+                    || hill_climb_builder.clone().build().unwrap(),
+                    |mut h| h.call(),
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
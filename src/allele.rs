@@ -53,10 +53,16 @@ impl Allele for Tuple {
     }
 }
 
+mod sealed {
+    pub trait Sealed {}
+}
+
 /// Special Allele subtrait, used for [crate::genotype::RangeGenotype],
-/// [crate::genotype::MultiRangeGenotype]
+/// [crate::genotype::MultiRangeGenotype]. The trait is sealed (implemented for f32, f64, i8, i16,
+/// i32, u8, u16 and u32), so methods can be added without breaking downstream code
 pub trait RangeAllele:
-    Allele
+    sealed::Sealed
+    + Allele
     + Add<Output = Self>
     + Sub<Output = Self>
     + AddAssign
@@ -80,6 +86,25 @@ pub trait RangeAllele:
     /// Floors to nearest integer (identity for integer types)
     fn floor(&self) -> Self;
 
+    /// Multiplies by a (non-negative) factor, rounded for integer types. Used for the Gaussian
+    /// mutation delta of [MutationType::Gaussian](crate::genotype::MutationType::Gaussian)
+    fn scale_by(&self, factor: f64) -> Self;
+
+    /// The number of whole steps from the start to the value (floored, zero below the start).
+    /// Used for the allele_step grid of
+    /// [with_allele_step](crate::genotype::GenotypeBuilder::with_allele_step)
+    fn steps_from(&self, start: Self, step: Self) -> usize;
+
+    /// The value the number of steps from the start, the inverse of `steps_from`
+    fn add_steps(start: Self, step: Self, number_of_steps: usize) -> Self;
+
+    /// Rounds to the given number of decimals (identity for integer types), with a single
+    /// representation for zero and NaN. Used for the quantized genes_hash of
+    /// `with_genes_key_precision`
+    fn round_to_decimals(&self, _decimals: u32) -> Self {
+        *self
+    }
+
     /// Needed as f32 and f64 don't implement saturating_sub and saturating_add
     fn clamped_add(current_value: Self, delta: Self, max_value: Self) -> Self;
     fn clamped_sub(current_value: Self, delta: Self, min_value: Self) -> Self;
@@ -88,6 +113,15 @@ pub trait RangeAllele:
     }
 }
 
+impl sealed::Sealed for f32 {}
+impl sealed::Sealed for f64 {}
+impl sealed::Sealed for i8 {}
+impl sealed::Sealed for i16 {}
+impl sealed::Sealed for i32 {}
+impl sealed::Sealed for u8 {}
+impl sealed::Sealed for u16 {}
+impl sealed::Sealed for u32 {}
+
 impl RangeAllele for f32 {
     fn smallest_increment() -> Self {
         f32::EPSILON
@@ -101,6 +135,33 @@ impl RangeAllele for f32 {
     fn floor(&self) -> Self {
         f32::floor(*self)
     }
+    fn scale_by(&self, factor: f64) -> Self {
+        (*self as f64 * factor) as f32
+    }
+    fn steps_from(&self, start: Self, step: Self) -> usize {
+        let steps = (*self - start) / step;
+        let rounded_steps = steps.round();
+        // tolerate float rounding just below a whole step, the cast saturates below zero
+        if (steps - rounded_steps).abs() < 1e-4 {
+            rounded_steps as usize
+        } else {
+            steps.floor() as usize
+        }
+    }
+    fn add_steps(start: Self, step: Self, number_of_steps: usize) -> Self {
+        start + step * number_of_steps as f32
+    }
+    fn round_to_decimals(&self, decimals: u32) -> Self {
+        let factor = 10.0_f32.powi(decimals as i32);
+        let value = (*self * factor).round() / factor;
+        if value.is_nan() {
+            f32::NAN
+        } else if value == 0.0 {
+            0.0
+        } else {
+            value
+        }
+    }
     // ignore f32::MAX, not realistic for use case
     fn clamped_add(current_value: Self, delta: Self, max_value: Self) -> Self {
         let new_value = current_value + delta;
@@ -133,168 +194,45 @@ impl RangeAllele for f64 {
     fn floor(&self) -> Self {
         f64::floor(*self)
     }
-    // ignore f64::MAX, not realistic for use case
-    fn clamped_add(current_value: Self, delta: Self, max_value: Self) -> Self {
-        let new_value = current_value + delta;
-        if new_value > max_value {
-            max_value
-        } else {
-            new_value
-        }
+    fn scale_by(&self, factor: f64) -> Self {
+        *self * factor
     }
-    // ignore f64::MIN, not realistic for use case
-    fn clamped_sub(current_value: Self, delta: Self, min_value: Self) -> Self {
-        let new_value = current_value - delta;
-        if new_value < min_value {
-            min_value
+    fn steps_from(&self, start: Self, step: Self) -> usize {
+        let steps = (*self - start) / step;
+        let rounded_steps = steps.round();
+        // tolerate float rounding just below a whole step, the cast saturates below zero
+        if (steps - rounded_steps).abs() < 1e-4 {
+            rounded_steps as usize
         } else {
-            new_value
+            steps.floor() as usize
         }
     }
-}
-impl RangeAllele for i8 {
-    fn smallest_increment() -> Self {
-        1
-    }
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
+    fn add_steps(start: Self, step: Self, number_of_steps: usize) -> Self {
+        start + step * number_of_steps as f64
     }
-    fn floor(&self) -> Self {
-        *self
-    }
-    fn clamped_add(current_value: Self, delta: Self, max_value: Self) -> Self {
-        let new_value = current_value.saturating_add(delta);
-        if new_value > max_value {
-            max_value
+    fn round_to_decimals(&self, decimals: u32) -> Self {
+        let factor = 10.0_f64.powi(decimals as i32);
+        let value = (*self * factor).round() / factor;
+        if value.is_nan() {
+            f64::NAN
+        } else if value == 0.0 {
+            0.0
         } else {
-            new_value
+            value
         }
     }
-    fn clamped_sub(current_value: Self, delta: Self, min_value: Self) -> Self {
-        let new_value = current_value.saturating_sub(delta);
-        if new_value < min_value {
-            min_value
-        } else {
-            new_value
-        }
-    }
-}
-impl RangeAllele for i16 {
-    fn smallest_increment() -> Self {
-        1
-    }
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-    fn floor(&self) -> Self {
-        *self
-    }
-    fn clamped_add(current_value: Self, delta: Self, max_value: Self) -> Self {
-        let new_value = current_value.saturating_add(delta);
-        if new_value > max_value {
-            max_value
-        } else {
-            new_value
-        }
-    }
-    fn clamped_sub(current_value: Self, delta: Self, min_value: Self) -> Self {
-        let new_value = current_value.saturating_sub(delta);
-        if new_value < min_value {
-            min_value
-        } else {
-            new_value
-        }
-    }
-}
-impl RangeAllele for i32 {
-    fn smallest_increment() -> Self {
-        1
-    }
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-    fn floor(&self) -> Self {
-        *self
-    }
-    fn clamped_add(current_value: Self, delta: Self, max_value: Self) -> Self {
-        let new_value = current_value.saturating_add(delta);
-        if new_value > max_value {
-            max_value
-        } else {
-            new_value
-        }
-    }
-    fn clamped_sub(current_value: Self, delta: Self, min_value: Self) -> Self {
-        let new_value = current_value.saturating_sub(delta);
-        if new_value < min_value {
-            min_value
-        } else {
-            new_value
-        }
-    }
-}
-impl RangeAllele for u8 {
-    fn smallest_increment() -> Self {
-        1
-    }
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-    fn floor(&self) -> Self {
-        *self
-    }
-    fn clamped_add(current_value: Self, delta: Self, max_value: Self) -> Self {
-        let new_value = current_value.saturating_add(delta);
-        if new_value > max_value {
-            max_value
-        } else {
-            new_value
-        }
-    }
-    fn clamped_sub(current_value: Self, delta: Self, min_value: Self) -> Self {
-        let new_value = current_value.saturating_sub(delta);
-        if new_value < min_value {
-            min_value
-        } else {
-            new_value
-        }
-    }
-}
-impl RangeAllele for u16 {
-    fn smallest_increment() -> Self {
-        1
-    }
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-    fn floor(&self) -> Self {
-        *self
-    }
+    // ignore f64::MAX, not realistic for use case
     fn clamped_add(current_value: Self, delta: Self, max_value: Self) -> Self {
-        let new_value = current_value.saturating_add(delta);
+        let new_value = current_value + delta;
         if new_value > max_value {
             max_value
         } else {
             new_value
         }
     }
+    // ignore f64::MIN, not realistic for use case
     fn clamped_sub(current_value: Self, delta: Self, min_value: Self) -> Self {
-        let new_value = current_value.saturating_sub(delta);
+        let new_value = current_value - delta;
         if new_value < min_value {
             min_value
         } else {
@@ -302,33 +240,54 @@ impl RangeAllele for u16 {
         }
     }
 }
-impl RangeAllele for u32 {
-    fn smallest_increment() -> Self {
-        1
-    }
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-    fn floor(&self) -> Self {
-        *self
-    }
-    fn clamped_add(current_value: Self, delta: Self, max_value: Self) -> Self {
-        let new_value = current_value.saturating_add(delta);
-        if new_value > max_value {
-            max_value
-        } else {
-            new_value
-        }
-    }
-    fn clamped_sub(current_value: Self, delta: Self, min_value: Self) -> Self {
-        let new_value = current_value.saturating_sub(delta);
-        if new_value < min_value {
-            min_value
-        } else {
-            new_value
-        }
+macro_rules! impl_range_allele_integer {
+    ($($t:ty),*) => {
+        $(
+            impl RangeAllele for $t {
+                fn smallest_increment() -> Self {
+                    1
+                }
+                fn zero() -> Self {
+                    0
+                }
+                fn one() -> Self {
+                    1
+                }
+                fn floor(&self) -> Self {
+                    *self
+                }
+                fn scale_by(&self, factor: f64) -> Self {
+                    (*self as f64 * factor).round() as $t
+                }
+                fn steps_from(&self, start: Self, step: Self) -> usize {
+                    if *self <= start {
+                        0
+                    } else {
+                        ((*self as i64 - start as i64) / step as i64) as usize
+                    }
+                }
+                fn add_steps(start: Self, step: Self, number_of_steps: usize) -> Self {
+                    (start as i64 + step as i64 * number_of_steps as i64) as $t
+                }
+                fn clamped_add(current_value: Self, delta: Self, max_value: Self) -> Self {
+                    let new_value = current_value.saturating_add(delta);
+                    if new_value > max_value {
+                        max_value
+                    } else {
+                        new_value
+                    }
+                }
+                fn clamped_sub(current_value: Self, delta: Self, min_value: Self) -> Self {
+                    let new_value = current_value.saturating_sub(delta);
+                    if new_value < min_value {
+                        min_value
+                    } else {
+                        new_value
+                    }
+                }
+            }
+        )*
     }
 }
+
+impl_range_allele_integer!(i8, i16, i32, u8, u16, u32);
@@ -22,12 +22,39 @@ pub type Genes<T> = Vec<T>;
 /// [HillClimb::best_chromosome()](crate::strategy::hill_climb::HillClimb::best_chromosome) and
 /// [Permutate::best_chromosome()](crate::strategy::permutate::Permutate::best_chromosome)
 /// to access the best chromosome directly.
-#[derive(Clone, Debug)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chromosome<T: Allele> {
     pub genes: Genes<T>,
     pub fitness_score: Option<FitnessValue>,
     pub genes_hash: Option<GenesHash>,
     pub age: usize,
+    /// The number of generations since the genetic material was randomly introduced. Unlike the
+    /// age, it is inherited by the offspring from the cloned parent and not reset by crossover.
+    /// Used for the age layers of [Evolve](crate::strategy::evolve::EvolveBuilder::with_age_layers)
+    pub lineage_age: usize,
+    /// The objective values of a [FitnessMultiObjective](crate::fitness::FitnessMultiObjective),
+    /// empty otherwise
+    pub objectives: Vec<FitnessValue>,
+}
+
+/// Manual implementation, so `clone_from` reuses the genes allocation (see
+/// [copy_from](Chromosome::copy_from)). Used for repeatedly copying the best chromosome in the
+/// strategies without reallocating
+impl<T: Allele> Clone for Chromosome<T> {
+    fn clone(&self) -> Self {
+        Self {
+            genes: self.genes.clone(),
+            fitness_score: self.fitness_score,
+            genes_hash: self.genes_hash,
+            age: self.age,
+            lineage_age: self.lineage_age,
+            objectives: self.objectives.clone(),
+        }
+    }
+    fn clone_from(&mut self, source: &Self) {
+        self.copy_from(source);
+    }
 }
 
 impl<T: Allele> Chromosome<T> {
@@ -37,6 +64,8 @@ impl<T: Allele> Chromosome<T> {
             fitness_score: None,
             genes_hash: None,
             age: 0,
+            lineage_age: 0,
+            objectives: Vec::new(),
         }
     }
 
@@ -46,6 +75,8 @@ impl<T: Allele> Chromosome<T> {
             fitness_score: None,
             genes_hash: None,
             age: 0,
+            lineage_age: 0,
+            objectives: Vec::new(),
         }
     }
 
@@ -58,13 +89,18 @@ impl<T: Allele> Chromosome<T> {
     }
 
     pub fn increment_age(&mut self) {
-        self.age += 1
+        self.age += 1;
+        self.lineage_age += 1;
     }
 
     pub fn set_age(&mut self, age: usize) {
         self.age = age
     }
 
+    pub fn lineage_age(&self) -> usize {
+        self.lineage_age
+    }
+
     pub fn is_offspring(&self) -> bool {
         self.age == 0
     }
@@ -85,6 +121,10 @@ impl<T: Allele> Chromosome<T> {
         self.genes_hash = genes_hash
     }
 
+    pub fn objectives(&self) -> &[FitnessValue] {
+        &self.objectives
+    }
+
     pub fn genes(&self) -> &Genes<T> {
         &self.genes
     }
@@ -92,6 +132,7 @@ impl<T: Allele> Chromosome<T> {
     pub fn reset_metadata(&mut self, genes_hashing: bool) {
         self.age = 0;
         self.fitness_score = None;
+        self.objectives.clear();
         if genes_hashing {
             self.genes_hash = Some(self.calculate_hash())
         }
@@ -99,8 +140,10 @@ impl<T: Allele> Chromosome<T> {
 
     pub fn copy_metadata(&mut self, other: &Self) {
         self.age = other.age;
+        self.lineage_age = other.lineage_age;
         self.fitness_score = other.fitness_score;
         self.genes_hash = other.genes_hash;
+        self.objectives.clone_from(&other.objectives);
     }
 
     pub fn copy_from(&mut self, source: &Self) {
@@ -17,8 +17,15 @@
 //! Normally the crossover adds children to the popluation, thus increasing the population_size
 //! above the target_population_size. Selection will reduce this again in the next generation
 mod clone;
+mod cycle;
+mod group_point;
+mod masked;
 mod multi_gene;
 mod multi_point;
+mod multi_unique_order;
+mod noop;
+mod ordered;
+mod partially_mapped;
 mod rejuvenate;
 mod single_gene;
 mod single_point;
@@ -26,8 +33,15 @@ mod uniform;
 mod wrapper;
 
 pub use self::clone::Clone as CrossoverClone;
+pub use self::cycle::Cycle as CrossoverCycle;
+pub use self::group_point::GroupPoint as CrossoverGroupPoint;
+pub use self::masked::Masked as CrossoverMasked;
 pub use self::multi_gene::MultiGene as CrossoverMultiGene;
 pub use self::multi_point::MultiPoint as CrossoverMultiPoint;
+pub use self::multi_unique_order::MultiUniqueOrder as CrossoverMultiUniqueOrder;
+pub use self::noop::Noop as CrossoverNoop;
+pub use self::ordered::Ordered as CrossoverOrdered;
+pub use self::partially_mapped::PartiallyMapped as CrossoverPartiallyMapped;
 pub use self::rejuvenate::Rejuvenate as CrossoverRejuvenate;
 pub use self::single_gene::SingleGene as CrossoverSingleGene;
 pub use self::single_point::SinglePoint as CrossoverSinglePoint;
@@ -137,6 +151,32 @@ pub trait Crossover: Clone + Send + Sync + std::fmt::Debug {
     fn require_crossover_points(&self) -> bool {
         false
     }
+    /// to guard against invalid Crossover strategies, the ordered crossovers require a genotype
+    /// where the genes are a permutation
+    fn require_crossover_ordered(&self) -> bool {
+        false
+    }
+    /// to guard against invalid Crossover strategies, which swap genes between the parents
+    /// directly instead of through the genotype, breaking genotypes with constraints between the
+    /// genes
+    fn require_raw_gene_swap(&self) -> bool {
+        false
+    }
+    /// the configured number of crossovers per pair, which is clamped to the genes_size at
+    /// runtime. Used to warn about over-large counts in the builder's validate()
+    fn number_of_crossovers(&self) -> Option<usize> {
+        None
+    }
+    /// the size of the configured per gene mask, which must equal the genes_size. Validated in
+    /// the builder
+    fn mask_size(&self) -> Option<usize> {
+        None
+    }
+    /// the configured selection_rate, if any. Used to warn about a zero selection_rate (no
+    /// offspring) in the builder's validate()
+    fn selection_rate(&self) -> Option<f32> {
+        None
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -20,7 +20,7 @@ impl<G: EvolveGenotype> Crossover for Clone<G> {
         &mut self,
         _genotype: &G,
         state: &mut EvolveState<G>,
-        _config: &EvolveConfig,
+        config: &EvolveConfig,
         _reporter: &mut SR,
         _rng: &mut R,
     ) {
@@ -28,9 +28,10 @@ impl<G: EvolveGenotype> Crossover for Clone<G> {
         let existing_population_size = state.population.chromosomes.len();
         let selected_population_size =
             (existing_population_size as f32 * self.selection_rate).ceil() as usize;
+        let offspring_size = config.offspring_size(selected_population_size);
         state
             .population
-            .extend_from_within(selected_population_size);
+            .extend_from_within_cycled(selected_population_size, offspring_size);
         state
             .population
             .chromosomes
@@ -39,6 +40,9 @@ impl<G: EvolveGenotype> Crossover for Clone<G> {
             .for_each(|c| c.reset_age());
         state.add_duration(StrategyAction::Crossover, now.elapsed());
     }
+    fn selection_rate(&self) -> Option<f32> {
+        Some(self.selection_rate)
+    }
 }
 
 impl<G: EvolveGenotype> Clone<G> {
@@ -0,0 +1,88 @@
+use super::Crossover;
+use crate::genotype::{CrossoverOrderedMethod, EvolveGenotype};
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::{StrategyAction, StrategyReporter, StrategyState};
+use itertools::Itertools;
+use rand::distributions::{Bernoulli, Distribution};
+use rand::Rng;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// Cycle Crossover (CX) for permutations. The positions are partitioned into cycles between the
+/// parents and the children take the alternating cycles from alternating parents. Each gene keeps
+/// the position it has in one of the parents, so there is no randomness in the crossover itself.
+///
+/// Only allowed for genotypes where the genes are a permutation of the alleles, like
+/// [UniqueGenotype](crate::genotype::UniqueGenotype), as it routes to
+/// [crossover_chromosome_ordered](crate::genotype::EvolveGenotype::crossover_chromosome_ordered).
+#[derive(Clone, Debug)]
+pub struct Cycle<G: EvolveGenotype> {
+    _phantom: PhantomData<G>,
+    pub selection_rate: f32,
+    pub crossover_rate: f32,
+    pub crossover_sampler: Bernoulli,
+}
+impl<G: EvolveGenotype> Crossover for Cycle<G> {
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        genotype: &G,
+        state: &mut EvolveState<G>,
+        config: &EvolveConfig,
+        _reporter: &mut SR,
+        rng: &mut R,
+    ) {
+        let now = Instant::now();
+        let existing_population_size = state.population.chromosomes.len();
+        let selected_population_size =
+            (existing_population_size as f32 * self.selection_rate).ceil() as usize;
+        let offspring_size = config.offspring_size(selected_population_size);
+        state
+            .population
+            .extend_from_within_cycled(selected_population_size, offspring_size);
+        let iterator = state
+            .population
+            .chromosomes
+            .iter_mut()
+            .skip(existing_population_size);
+        for (father, mother) in iterator.tuples() {
+            if self.crossover_sampler.sample(rng) {
+                genotype.crossover_chromosome_ordered(
+                    CrossoverOrderedMethod::Cycle,
+                    father,
+                    mother,
+                    rng,
+                );
+            } else {
+                father.reset_age();
+                mother.reset_age();
+            }
+        }
+        if offspring_size % 2 == 1 {
+            if let Some(chromosome) = state.population.chromosomes.last_mut() {
+                chromosome.reset_age();
+            }
+        }
+
+        state.add_duration(StrategyAction::Crossover, now.elapsed());
+    }
+    fn require_crossover_ordered(&self) -> bool {
+        true
+    }
+    fn selection_rate(&self) -> Option<f32> {
+        Some(self.selection_rate)
+    }
+}
+
+impl<G: EvolveGenotype> Cycle<G> {
+    pub fn new(selection_rate: f32, crossover_rate: f32) -> Self {
+        let crossover_sampler = Bernoulli::new(crossover_rate as f64).unwrap();
+        Self {
+            _phantom: PhantomData,
+            selection_rate,
+            crossover_rate,
+            crossover_sampler,
+        }
+    }
+}
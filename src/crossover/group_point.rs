@@ -0,0 +1,128 @@
+use super::Crossover;
+use crate::genotype::EvolveGenotype;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::{StrategyAction, StrategyReporter, StrategyState};
+use itertools::Itertools;
+use rand::distributions::{Bernoulli, Distribution};
+use rand::Rng;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// Crossover multiple gene positions, like [CrossoverMultiPoint](crate::crossover::CrossoverMultiPoint),
+/// but only at the provided group boundaries. A boundary is the gene index at which a group
+/// starts, so a boundary `3` cuts between gene index 2 and 3. This keeps related genes (e.g. the
+/// genes encoding a single item) together, as groups are never split. The boundaries are chosen
+/// with uniform probability and without duplicates, for at most number_of_crossovers cuts per
+/// pair. Boundaries of zero or beyond the genes_size are ignored.
+///
+/// The genes are swapped directly, so only allowed for genotypes without constraints between the
+/// genes (see [EvolveGenotype::has_raw_gene_swap](crate::genotype::EvolveGenotype::has_raw_gene_swap)).
+/// Not allowed for [UniqueGenotype](crate::genotype::UniqueGenotype) and
+/// [MultiUniqueGenotype](crate::genotype::MultiUniqueGenotype) as it would not preserve the gene
+/// uniqueness in the children (the group boundaries need not match the sets). Neither for
+/// [SimplexGenotype](crate::genotype::SimplexGenotype),
+/// [OneHotGenotype](crate::genotype::OneHotGenotype) and
+/// [LatinSquareGenotype](crate::genotype::LatinSquareGenotype).
+#[derive(Clone, Debug)]
+pub struct GroupPoint<G: EvolveGenotype> {
+    _phantom: PhantomData<G>,
+    pub selection_rate: f32,
+    pub crossover_rate: f32,
+    pub crossover_sampler: Bernoulli,
+    pub boundaries: Vec<usize>,
+    pub number_of_crossovers: usize,
+}
+impl<G: EvolveGenotype> Crossover for GroupPoint<G> {
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        genotype: &G,
+        state: &mut EvolveState<G>,
+        config: &EvolveConfig,
+        _reporter: &mut SR,
+        rng: &mut R,
+    ) {
+        let now = Instant::now();
+        let genes_size = genotype.genes_size();
+        let boundaries: Vec<usize> = self
+            .boundaries
+            .iter()
+            .copied()
+            .filter(|boundary| *boundary > 0 && *boundary < genes_size)
+            .collect();
+        let number_of_crossovers = self.number_of_crossovers.min(boundaries.len());
+
+        let existing_population_size = state.population.chromosomes.len();
+        let selected_population_size =
+            (existing_population_size as f32 * self.selection_rate).ceil() as usize;
+        let offspring_size = config.offspring_size(selected_population_size);
+        state
+            .population
+            .extend_from_within_cycled(selected_population_size, offspring_size);
+        let iterator = state
+            .population
+            .chromosomes
+            .iter_mut()
+            .skip(existing_population_size);
+        for (father, mother) in iterator.tuples() {
+            if self.crossover_sampler.sample(rng) {
+                rand::seq::index::sample(rng, boundaries.len(), number_of_crossovers)
+                    .iter()
+                    .map(|index| boundaries[index])
+                    .sorted_unstable()
+                    .chunks(2)
+                    .into_iter()
+                    .for_each(|mut chunk| {
+                        if let Some(start_index) = chunk.next() {
+                            let end_index = chunk.next().unwrap_or(genes_size);
+                            let mother_back = &mut mother.genes[start_index..end_index];
+                            let father_back = &mut father.genes[start_index..end_index];
+                            father_back.swap_with_slice(mother_back);
+                        }
+                    });
+                genotype.reset_chromosome_metadata(father);
+                genotype.reset_chromosome_metadata(mother);
+            } else {
+                father.reset_age();
+                mother.reset_age();
+            }
+        }
+        if offspring_size % 2 == 1 {
+            if let Some(chromosome) = state.population.chromosomes.last_mut() {
+                chromosome.reset_age();
+            }
+        }
+
+        state.add_duration(StrategyAction::Crossover, now.elapsed());
+    }
+    fn require_crossover_indexes(&self) -> bool {
+        true
+    }
+    fn require_raw_gene_swap(&self) -> bool {
+        true
+    }
+    fn selection_rate(&self) -> Option<f32> {
+        Some(self.selection_rate)
+    }
+}
+
+impl<G: EvolveGenotype> GroupPoint<G> {
+    pub fn new(
+        selection_rate: f32,
+        crossover_rate: f32,
+        boundaries: Vec<usize>,
+        number_of_crossovers: usize,
+    ) -> Self {
+        let crossover_sampler = Bernoulli::new(crossover_rate as f64).unwrap();
+        let boundaries = boundaries.into_iter().sorted_unstable().dedup().collect();
+        Self {
+            _phantom: PhantomData,
+            selection_rate,
+            crossover_rate,
+            crossover_sampler,
+            boundaries,
+            number_of_crossovers,
+        }
+    }
+}
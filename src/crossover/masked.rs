@@ -0,0 +1,122 @@
+use super::Crossover;
+use crate::genotype::EvolveGenotype;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::{StrategyAction, StrategyReporter, StrategyState};
+use itertools::Itertools;
+use rand::distributions::{Bernoulli, Distribution};
+use rand::Rng;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// Crossover only a user-defined subset of the genes, like
+/// [CrossoverUniform](crate::crossover::CrossoverUniform) restricted to a mask over the gene
+/// indices (e.g. keep a structural prefix intact). Each gene is swapped between the parents with
+/// its own probability. A boolean mask (see [new](Self::new)) swaps the masked genes with 50%
+/// probability and never swaps the other genes. The mask must have the genes_size length, which
+/// is validated in the builder.
+///
+/// The genes are swapped directly, so only allowed for genotypes without constraints between the
+/// genes (see [EvolveGenotype::has_raw_gene_swap](crate::genotype::EvolveGenotype::has_raw_gene_swap)).
+/// Not allowed for [UniqueGenotype](crate::genotype::UniqueGenotype) and
+/// [MultiUniqueGenotype](crate::genotype::MultiUniqueGenotype) as it would not preserve the gene
+/// uniqueness in the children. Neither for [SimplexGenotype](crate::genotype::SimplexGenotype),
+/// [OneHotGenotype](crate::genotype::OneHotGenotype) and
+/// [LatinSquareGenotype](crate::genotype::LatinSquareGenotype).
+#[derive(Clone, Debug)]
+pub struct Masked<G: EvolveGenotype> {
+    _phantom: PhantomData<G>,
+    pub selection_rate: f32,
+    pub crossover_rate: f32,
+    pub crossover_sampler: Bernoulli,
+    pub gene_probabilities: Vec<f32>,
+}
+impl<G: EvolveGenotype> Crossover for Masked<G> {
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        genotype: &G,
+        state: &mut EvolveState<G>,
+        config: &EvolveConfig,
+        _reporter: &mut SR,
+        rng: &mut R,
+    ) {
+        let now = Instant::now();
+        let existing_population_size = state.population.chromosomes.len();
+        let selected_population_size =
+            (existing_population_size as f32 * self.selection_rate).ceil() as usize;
+        let offspring_size = config.offspring_size(selected_population_size);
+        state
+            .population
+            .extend_from_within_cycled(selected_population_size, offspring_size);
+        let iterator = state
+            .population
+            .chromosomes
+            .iter_mut()
+            .skip(existing_population_size);
+        for (father, mother) in iterator.tuples() {
+            if self.crossover_sampler.sample(rng) {
+                self.gene_probabilities
+                    .iter()
+                    .zip(father.genes.iter_mut().zip(mother.genes.iter_mut()))
+                    .for_each(|(probability, (father_gene, mother_gene))| {
+                        if *probability >= 1.0
+                            || (*probability > 0.0 && rng.gen::<f32>() < *probability)
+                        {
+                            std::mem::swap(father_gene, mother_gene);
+                        }
+                    });
+                genotype.reset_chromosome_metadata(father);
+                genotype.reset_chromosome_metadata(mother);
+            } else {
+                father.reset_age();
+                mother.reset_age();
+            }
+        }
+        if offspring_size % 2 == 1 {
+            if let Some(chromosome) = state.population.chromosomes.last_mut() {
+                chromosome.reset_age();
+            }
+        }
+
+        state.add_duration(StrategyAction::Crossover, now.elapsed());
+    }
+    fn require_crossover_indexes(&self) -> bool {
+        true
+    }
+    fn require_raw_gene_swap(&self) -> bool {
+        true
+    }
+    fn mask_size(&self) -> Option<usize> {
+        Some(self.gene_probabilities.len())
+    }
+    fn selection_rate(&self) -> Option<f32> {
+        Some(self.selection_rate)
+    }
+}
+
+impl<G: EvolveGenotype> Masked<G> {
+    /// Swap the genes where the mask is true with 50% probability
+    pub fn new(selection_rate: f32, crossover_rate: f32, mask: Vec<bool>) -> Self {
+        let gene_probabilities = mask
+            .into_iter()
+            .map(|masked| if masked { 0.5 } else { 0.0 })
+            .collect();
+        Self::new_with_probabilities(selection_rate, crossover_rate, gene_probabilities)
+    }
+    /// Swap each gene with its own probability, zero never swaps and one always swaps
+    pub fn new_with_probabilities(
+        selection_rate: f32,
+        crossover_rate: f32,
+        gene_probabilities: Vec<f32>,
+    ) -> Self {
+        let crossover_sampler = Bernoulli::new(crossover_rate as f64).unwrap();
+        Self {
+            _phantom: PhantomData,
+            selection_rate,
+            crossover_rate,
+            crossover_sampler,
+            gene_probabilities,
+        }
+    }
+}
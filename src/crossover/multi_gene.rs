@@ -31,7 +31,7 @@ impl<G: EvolveGenotype> Crossover for MultiGene<G> {
         &mut self,
         genotype: &G,
         state: &mut EvolveState<G>,
-        _config: &EvolveConfig,
+        config: &EvolveConfig,
         _reporter: &mut SR,
         rng: &mut R,
     ) {
@@ -39,9 +39,10 @@ impl<G: EvolveGenotype> Crossover for MultiGene<G> {
         let existing_population_size = state.population.chromosomes.len();
         let selected_population_size =
             (existing_population_size as f32 * self.selection_rate).ceil() as usize;
+        let offspring_size = config.offspring_size(selected_population_size);
         state
             .population
-            .extend_from_within(selected_population_size);
+            .extend_from_within_cycled(selected_population_size, offspring_size);
         let iterator = state
             .population
             .chromosomes
@@ -61,7 +62,7 @@ impl<G: EvolveGenotype> Crossover for MultiGene<G> {
                 mother.reset_age();
             }
         }
-        if selected_population_size % 2 == 1 {
+        if offspring_size % 2 == 1 {
             if let Some(chromosome) = state.population.chromosomes.last_mut() {
                 chromosome.reset_age();
             }
@@ -71,6 +72,12 @@ impl<G: EvolveGenotype> Crossover for MultiGene<G> {
     fn require_crossover_indexes(&self) -> bool {
         true
     }
+    fn number_of_crossovers(&self) -> Option<usize> {
+        Some(self.number_of_crossovers)
+    }
+    fn selection_rate(&self) -> Option<f32> {
+        Some(self.selection_rate)
+    }
 }
 
 impl<G: EvolveGenotype> MultiGene<G> {
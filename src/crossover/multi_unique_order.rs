@@ -0,0 +1,147 @@
+use super::Crossover;
+use crate::allele::Allele;
+use crate::genotype::MultiUniqueGenotype;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::{StrategyAction, StrategyReporter, StrategyState};
+use itertools::Itertools;
+use rand::distributions::{Bernoulli, Distribution};
+use rand::Rng;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// Order crossover (OX) within a single set of the
+/// [MultiUniqueGenotype](crate::genotype::MultiUniqueGenotype). For each crossover pair a set is
+/// chosen with uniform probability (sets of size one are skipped, as there is no ordering to mix).
+/// Each child keeps a random slice of the set from its own parent and fills the remaining
+/// positions with the genes of the other parent in their order of appearance (starting after the
+/// slice and wrapping around), skipping the genes already in the slice. The other sets are
+/// untouched. This mixes the ordering within a set, which is finer than the whole-set point
+/// crossover, while each set stays a valid permutation of its allele_list.
+///
+/// Only available for [MultiUniqueGenotype](crate::genotype::MultiUniqueGenotype), so it is not
+/// part of the [CrossoverWrapper](crate::crossover::CrossoverWrapper).
+#[derive(Clone, Debug)]
+pub struct MultiUniqueOrder<T: Allele + Hash + PartialEq> {
+    _phantom: PhantomData<T>,
+    pub selection_rate: f32,
+    pub crossover_rate: f32,
+    pub crossover_sampler: Bernoulli,
+}
+impl<T: Allele + Hash + PartialEq> Crossover for MultiUniqueOrder<T> {
+    type Genotype = MultiUniqueGenotype<T>;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = Self::Genotype>>(
+        &mut self,
+        genotype: &Self::Genotype,
+        state: &mut EvolveState<Self::Genotype>,
+        config: &EvolveConfig,
+        _reporter: &mut SR,
+        rng: &mut R,
+    ) {
+        let now = Instant::now();
+        let existing_population_size = state.population.chromosomes.len();
+        let selected_population_size =
+            (existing_population_size as f32 * self.selection_rate).ceil() as usize;
+        let offspring_size = config.offspring_size(selected_population_size);
+        state
+            .population
+            .extend_from_within_cycled(selected_population_size, offspring_size);
+        let iterator = state
+            .population
+            .chromosomes
+            .iter_mut()
+            .skip(existing_population_size);
+        for (father, mother) in iterator.tuples() {
+            if self.crossover_sampler.sample(rng) {
+                if let Some(set_index) = self.sample_set_index(genotype, rng) {
+                    let start = genotype.allele_list_index_offsets[set_index];
+                    let end = start + genotype.allele_list_sizes[set_index];
+                    let (slice_start, slice_end) = Self::sample_slice(end - start, rng);
+
+                    let father_set = father.genes[start..end].to_vec();
+                    let mother_set = mother.genes[start..end].to_vec();
+                    Self::order_crossover(
+                        &mut father.genes[start..end],
+                        &mother_set,
+                        slice_start,
+                        slice_end,
+                    );
+                    Self::order_crossover(
+                        &mut mother.genes[start..end],
+                        &father_set,
+                        slice_start,
+                        slice_end,
+                    );
+                }
+                father.reset_metadata(genotype.genes_hashing);
+                mother.reset_metadata(genotype.genes_hashing);
+            } else {
+                father.reset_age();
+                mother.reset_age();
+            }
+        }
+        if offspring_size % 2 == 1 {
+            if let Some(chromosome) = state.population.chromosomes.last_mut() {
+                chromosome.reset_age();
+            }
+        }
+
+        state.add_duration(StrategyAction::Crossover, now.elapsed());
+    }
+    fn selection_rate(&self) -> Option<f32> {
+        Some(self.selection_rate)
+    }
+}
+
+impl<T: Allele + Hash + PartialEq> MultiUniqueOrder<T> {
+    pub fn new(selection_rate: f32, crossover_rate: f32) -> Self {
+        let crossover_sampler = Bernoulli::new(crossover_rate as f64).unwrap();
+        Self {
+            _phantom: PhantomData,
+            selection_rate,
+            crossover_rate,
+            crossover_sampler,
+        }
+    }
+
+    /// Uniformly sample one of the sets with more than one gene, None if there are none
+    fn sample_set_index<R: Rng>(
+        &self,
+        genotype: &MultiUniqueGenotype<T>,
+        rng: &mut R,
+    ) -> Option<usize> {
+        let set_indices: Vec<usize> = genotype
+            .allele_list_sizes
+            .iter()
+            .positions(|size| *size > 1)
+            .collect();
+        if set_indices.is_empty() {
+            None
+        } else {
+            Some(set_indices[rng.gen_range(0..set_indices.len())])
+        }
+    }
+
+    /// Sample a non-empty slice `start..end` within `0..set_size`
+    fn sample_slice<R: Rng>(set_size: usize, rng: &mut R) -> (usize, usize) {
+        let start = rng.gen_range(0..set_size);
+        let end = rng.gen_range(start + 1..=set_size);
+        (start, end)
+    }
+
+    /// Keep `genes[slice_start..slice_end]` and fill the other positions (starting at slice_end
+    /// and wrapping around) with the donor genes in order (also starting at slice_end and
+    /// wrapping around), skipping the genes in the kept slice
+    pub fn order_crossover(genes: &mut [T], donor: &[T], slice_start: usize, slice_end: usize) {
+        let set_size = genes.len();
+        let kept = genes[slice_start..slice_end].to_vec();
+        let mut donor_genes = (0..set_size)
+            .map(|offset| donor[(slice_end + offset) % set_size])
+            .filter(|gene| !kept.contains(gene));
+        for offset in 0..(set_size - kept.len()) {
+            let index = (slice_end + offset) % set_size;
+            genes[index] = donor_genes.next().unwrap();
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use super::Crossover;
+use crate::genotype::EvolveGenotype;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::{StrategyAction, StrategyReporter, StrategyState};
+use rand::Rng;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// No recombination at all, for a mutation-only evolutionary algorithm. Each parent clones
+/// once as offspring, which is then left to the [Mutate](crate::mutate) phase alone. Like
+/// [CrossoverClone](crate::crossover::CrossoverClone) with a selection_rate of 1.0, use that
+/// instead when only a fraction of the parents should produce offspring.
+/// Allowed for unique genotypes.
+#[derive(Clone, Debug)]
+pub struct Noop<G: EvolveGenotype> {
+    _phantom: PhantomData<G>,
+}
+impl<G: EvolveGenotype> Crossover for Noop<G> {
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        _genotype: &G,
+        state: &mut EvolveState<G>,
+        _config: &EvolveConfig,
+        _reporter: &mut SR,
+        _rng: &mut R,
+    ) {
+        let now = Instant::now();
+        let existing_population_size = state.population.chromosomes.len();
+        state
+            .population
+            .extend_from_within(existing_population_size);
+        state
+            .population
+            .chromosomes
+            .iter_mut()
+            .skip(existing_population_size)
+            .for_each(|c| c.reset_age());
+        state.add_duration(StrategyAction::Crossover, now.elapsed());
+    }
+}
+
+impl<G: EvolveGenotype> Noop<G> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<G: EvolveGenotype> Default for Noop<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
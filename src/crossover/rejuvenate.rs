@@ -43,6 +43,9 @@ impl<G: EvolveGenotype> Crossover for Rejuvenate<G> {
             .for_each(|c| c.reset_age());
         state.add_duration(StrategyAction::Crossover, now.elapsed());
     }
+    fn selection_rate(&self) -> Option<f32> {
+        Some(self.selection_rate)
+    }
 }
 
 impl<G: EvolveGenotype> Rejuvenate<G> {
@@ -27,7 +27,7 @@ impl<G: EvolveGenotype> Crossover for SinglePoint<G> {
         &mut self,
         genotype: &G,
         state: &mut EvolveState<G>,
-        _config: &EvolveConfig,
+        config: &EvolveConfig,
         _reporter: &mut SR,
         rng: &mut R,
     ) {
@@ -35,9 +35,10 @@ impl<G: EvolveGenotype> Crossover for SinglePoint<G> {
         let existing_population_size = state.population.chromosomes.len();
         let selected_population_size =
             (existing_population_size as f32 * self.selection_rate).ceil() as usize;
+        let offspring_size = config.offspring_size(selected_population_size);
         state
             .population
-            .extend_from_within(selected_population_size);
+            .extend_from_within_cycled(selected_population_size, offspring_size);
         let iterator = state
             .population
             .chromosomes
@@ -51,7 +52,7 @@ impl<G: EvolveGenotype> Crossover for SinglePoint<G> {
                 mother.reset_age();
             }
         }
-        if selected_population_size % 2 == 1 {
+        if offspring_size % 2 == 1 {
             if let Some(chromosome) = state.population.chromosomes.last_mut() {
                 chromosome.reset_age();
             }
@@ -62,6 +63,9 @@ impl<G: EvolveGenotype> Crossover for SinglePoint<G> {
     fn require_crossover_points(&self) -> bool {
         true
     }
+    fn selection_rate(&self) -> Option<f32> {
+        Some(self.selection_rate)
+    }
 }
 
 impl<G: EvolveGenotype> SinglePoint<G> {
@@ -1,6 +1,12 @@
 pub use super::clone::Clone as CrossoverClone;
+pub use super::cycle::Cycle as CrossoverCycle;
+pub use super::group_point::GroupPoint as CrossoverGroupPoint;
+pub use super::masked::Masked as CrossoverMasked;
 pub use super::multi_gene::MultiGene as CrossoverMultiGene;
 pub use super::multi_point::MultiPoint as CrossoverMultiPoint;
+pub use super::noop::Noop as CrossoverNoop;
+pub use super::ordered::Ordered as CrossoverOrdered;
+pub use super::partially_mapped::PartiallyMapped as CrossoverPartiallyMapped;
 pub use super::rejuvenate::Rejuvenate as CrossoverRejuvenate;
 pub use super::single_gene::SingleGene as CrossoverSingleGene;
 pub use super::single_point::SinglePoint as CrossoverSinglePoint;
@@ -15,8 +21,14 @@ use rand::Rng;
 #[derive(Clone, Debug)]
 pub enum Wrapper<G: EvolveGenotype> {
     Clone(CrossoverClone<G>),
+    Cycle(CrossoverCycle<G>),
+    GroupPoint(CrossoverGroupPoint<G>),
+    Masked(CrossoverMasked<G>),
     MultiGene(CrossoverMultiGene<G>),
     MultiPoint(CrossoverMultiPoint<G>),
+    Noop(CrossoverNoop<G>),
+    Ordered(CrossoverOrdered<G>),
+    PartiallyMapped(CrossoverPartiallyMapped<G>),
     Rejuvenate(CrossoverRejuvenate<G>),
     SingleGene(CrossoverSingleGene<G>),
     SinglePoint(CrossoverSinglePoint<G>),
@@ -36,10 +48,20 @@ impl<G: EvolveGenotype> Crossover for Wrapper<G> {
     ) {
         match self {
             Wrapper::Clone(crossover) => crossover.call(genotype, state, config, reporter, rng),
+            Wrapper::Cycle(crossover) => crossover.call(genotype, state, config, reporter, rng),
+            Wrapper::GroupPoint(crossover) => {
+                crossover.call(genotype, state, config, reporter, rng)
+            }
+            Wrapper::Masked(crossover) => crossover.call(genotype, state, config, reporter, rng),
             Wrapper::MultiGene(crossover) => crossover.call(genotype, state, config, reporter, rng),
             Wrapper::MultiPoint(crossover) => {
                 crossover.call(genotype, state, config, reporter, rng)
             }
+            Wrapper::Noop(crossover) => crossover.call(genotype, state, config, reporter, rng),
+            Wrapper::Ordered(crossover) => crossover.call(genotype, state, config, reporter, rng),
+            Wrapper::PartiallyMapped(crossover) => {
+                crossover.call(genotype, state, config, reporter, rng)
+            }
             Wrapper::Rejuvenate(crossover) => {
                 crossover.call(genotype, state, config, reporter, rng)
             }
@@ -58,8 +80,14 @@ impl<G: EvolveGenotype> Crossover for Wrapper<G> {
     fn require_crossover_indexes(&self) -> bool {
         match self {
             Wrapper::Clone(crossover) => crossover.require_crossover_indexes(),
+            Wrapper::Cycle(crossover) => crossover.require_crossover_indexes(),
+            Wrapper::GroupPoint(crossover) => crossover.require_crossover_indexes(),
+            Wrapper::Masked(crossover) => crossover.require_crossover_indexes(),
             Wrapper::MultiGene(crossover) => crossover.require_crossover_indexes(),
             Wrapper::MultiPoint(crossover) => crossover.require_crossover_indexes(),
+            Wrapper::Noop(crossover) => crossover.require_crossover_indexes(),
+            Wrapper::Ordered(crossover) => crossover.require_crossover_indexes(),
+            Wrapper::PartiallyMapped(crossover) => crossover.require_crossover_indexes(),
             Wrapper::Rejuvenate(crossover) => crossover.require_crossover_indexes(),
             Wrapper::SingleGene(crossover) => crossover.require_crossover_indexes(),
             Wrapper::SinglePoint(crossover) => crossover.require_crossover_indexes(),
@@ -71,14 +99,90 @@ impl<G: EvolveGenotype> Crossover for Wrapper<G> {
     fn require_crossover_points(&self) -> bool {
         match self {
             Wrapper::Clone(crossover) => crossover.require_crossover_points(),
+            Wrapper::Cycle(crossover) => crossover.require_crossover_points(),
+            Wrapper::GroupPoint(crossover) => crossover.require_crossover_points(),
+            Wrapper::Masked(crossover) => crossover.require_crossover_points(),
             Wrapper::MultiGene(crossover) => crossover.require_crossover_points(),
             Wrapper::MultiPoint(crossover) => crossover.require_crossover_points(),
+            Wrapper::Noop(crossover) => crossover.require_crossover_points(),
+            Wrapper::Ordered(crossover) => crossover.require_crossover_points(),
+            Wrapper::PartiallyMapped(crossover) => crossover.require_crossover_points(),
             Wrapper::Rejuvenate(crossover) => crossover.require_crossover_points(),
             Wrapper::SingleGene(crossover) => crossover.require_crossover_points(),
             Wrapper::SinglePoint(crossover) => crossover.require_crossover_points(),
             Wrapper::Uniform(crossover) => crossover.require_crossover_points(),
         }
     }
+    /// to guard against invalid Crossover strategies, the ordered crossovers require a genotype
+    /// where the genes are a permutation
+    fn require_crossover_ordered(&self) -> bool {
+        match self {
+            Wrapper::Clone(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::Cycle(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::GroupPoint(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::Masked(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::MultiGene(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::MultiPoint(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::Noop(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::Ordered(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::PartiallyMapped(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::Rejuvenate(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::SingleGene(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::SinglePoint(crossover) => crossover.require_crossover_ordered(),
+            Wrapper::Uniform(crossover) => crossover.require_crossover_ordered(),
+        }
+    }
+    fn number_of_crossovers(&self) -> Option<usize> {
+        match self {
+            Wrapper::Clone(crossover) => crossover.number_of_crossovers(),
+            Wrapper::Cycle(crossover) => crossover.number_of_crossovers(),
+            Wrapper::GroupPoint(crossover) => crossover.number_of_crossovers(),
+            Wrapper::Masked(crossover) => crossover.number_of_crossovers(),
+            Wrapper::MultiGene(crossover) => crossover.number_of_crossovers(),
+            Wrapper::MultiPoint(crossover) => crossover.number_of_crossovers(),
+            Wrapper::Noop(crossover) => crossover.number_of_crossovers(),
+            Wrapper::Ordered(crossover) => crossover.number_of_crossovers(),
+            Wrapper::PartiallyMapped(crossover) => crossover.number_of_crossovers(),
+            Wrapper::Rejuvenate(crossover) => crossover.number_of_crossovers(),
+            Wrapper::SingleGene(crossover) => crossover.number_of_crossovers(),
+            Wrapper::SinglePoint(crossover) => crossover.number_of_crossovers(),
+            Wrapper::Uniform(crossover) => crossover.number_of_crossovers(),
+        }
+    }
+    fn mask_size(&self) -> Option<usize> {
+        match self {
+            Wrapper::Clone(crossover) => crossover.mask_size(),
+            Wrapper::Cycle(crossover) => crossover.mask_size(),
+            Wrapper::GroupPoint(crossover) => crossover.mask_size(),
+            Wrapper::Masked(crossover) => crossover.mask_size(),
+            Wrapper::MultiGene(crossover) => crossover.mask_size(),
+            Wrapper::MultiPoint(crossover) => crossover.mask_size(),
+            Wrapper::Noop(crossover) => crossover.mask_size(),
+            Wrapper::Ordered(crossover) => crossover.mask_size(),
+            Wrapper::PartiallyMapped(crossover) => crossover.mask_size(),
+            Wrapper::Rejuvenate(crossover) => crossover.mask_size(),
+            Wrapper::SingleGene(crossover) => crossover.mask_size(),
+            Wrapper::SinglePoint(crossover) => crossover.mask_size(),
+            Wrapper::Uniform(crossover) => crossover.mask_size(),
+        }
+    }
+    fn selection_rate(&self) -> Option<f32> {
+        match self {
+            Wrapper::Clone(crossover) => crossover.selection_rate(),
+            Wrapper::Cycle(crossover) => crossover.selection_rate(),
+            Wrapper::GroupPoint(crossover) => crossover.selection_rate(),
+            Wrapper::Masked(crossover) => crossover.selection_rate(),
+            Wrapper::MultiGene(crossover) => crossover.selection_rate(),
+            Wrapper::MultiPoint(crossover) => crossover.selection_rate(),
+            Wrapper::Noop(crossover) => crossover.selection_rate(),
+            Wrapper::Ordered(crossover) => crossover.selection_rate(),
+            Wrapper::PartiallyMapped(crossover) => crossover.selection_rate(),
+            Wrapper::Rejuvenate(crossover) => crossover.selection_rate(),
+            Wrapper::SingleGene(crossover) => crossover.selection_rate(),
+            Wrapper::SinglePoint(crossover) => crossover.selection_rate(),
+            Wrapper::Uniform(crossover) => crossover.selection_rate(),
+        }
+    }
 }
 
 impl<G: EvolveGenotype> From<CrossoverClone<G>> for Wrapper<G> {
@@ -86,6 +190,21 @@ impl<G: EvolveGenotype> From<CrossoverClone<G>> for Wrapper<G> {
         Wrapper::Clone(crossover)
     }
 }
+impl<G: EvolveGenotype> From<CrossoverCycle<G>> for Wrapper<G> {
+    fn from(crossover: CrossoverCycle<G>) -> Self {
+        Wrapper::Cycle(crossover)
+    }
+}
+impl<G: EvolveGenotype> From<CrossoverGroupPoint<G>> for Wrapper<G> {
+    fn from(crossover: CrossoverGroupPoint<G>) -> Self {
+        Wrapper::GroupPoint(crossover)
+    }
+}
+impl<G: EvolveGenotype> From<CrossoverMasked<G>> for Wrapper<G> {
+    fn from(crossover: CrossoverMasked<G>) -> Self {
+        Wrapper::Masked(crossover)
+    }
+}
 impl<G: EvolveGenotype> From<CrossoverMultiGene<G>> for Wrapper<G> {
     fn from(crossover: CrossoverMultiGene<G>) -> Self {
         Wrapper::MultiGene(crossover)
@@ -96,6 +215,21 @@ impl<G: EvolveGenotype> From<CrossoverMultiPoint<G>> for Wrapper<G> {
         Wrapper::MultiPoint(crossover)
     }
 }
+impl<G: EvolveGenotype> From<CrossoverNoop<G>> for Wrapper<G> {
+    fn from(crossover: CrossoverNoop<G>) -> Self {
+        Wrapper::Noop(crossover)
+    }
+}
+impl<G: EvolveGenotype> From<CrossoverOrdered<G>> for Wrapper<G> {
+    fn from(crossover: CrossoverOrdered<G>) -> Self {
+        Wrapper::Ordered(crossover)
+    }
+}
+impl<G: EvolveGenotype> From<CrossoverPartiallyMapped<G>> for Wrapper<G> {
+    fn from(crossover: CrossoverPartiallyMapped<G>) -> Self {
+        Wrapper::PartiallyMapped(crossover)
+    }
+}
 impl<G: EvolveGenotype> From<CrossoverRejuvenate<G>> for Wrapper<G> {
     fn from(crossover: CrossoverRejuvenate<G>) -> Self {
         Wrapper::Rejuvenate(crossover)
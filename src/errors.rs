@@ -1,5 +1,94 @@
+use std::error::Error;
+use std::fmt;
+
+/// The error of the strategy builders. Holds the message (also the Display) and the typed
+/// [BuilderError] kind, for programmatic handling (see [kind](Self::kind)).
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct TryFromStrategyBuilderError(pub &'static str);
+pub struct TryFromStrategyBuilderError(pub &'static str, pub BuilderError);
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct TryFromGenotypeBuilderError(pub &'static str);
+
+/// The outcome of the dry-run validation of a strategy builder. The errors fail the build, the
+/// warnings don't, but are likely misconfigurations.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct BuilderValidation {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl BuilderValidation {
+    /// True when there are no errors, the warnings don't fail the build
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// The typed kind of a [TryFromStrategyBuilderError], for programmatic handling of the strategy
+/// builder errors. Set on construction of the error, so it is independent of the message.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BuilderError {
+    MissingGenotype,
+    MissingFitness,
+    MissingMutate,
+    MissingCrossover,
+    MissingSelect,
+    MissingVariant,
+    MissingEndingCondition,
+    InvalidTargetPopulationSize,
+    /// The Crossover strategy requires crossover_indexes, crossover_points, crossover_ordered,
+    /// raw_gene_swap or a matching mask_size (the `requires`), which the genotype does not provide
+    IncompatibleCrossover {
+        requires: &'static str,
+    },
+    /// The genotype's mutation_type does not allow permutation
+    IncompatibleMutationType,
+    /// The seed genes or chromosomes don't match the genotype's genes_size
+    IncompatibleSeedGenes,
+    /// A parameter is out of its valid range or conflicts with another parameter
+    InvalidParameter,
+}
+
+impl TryFromStrategyBuilderError {
+    pub fn kind(&self) -> BuilderError {
+        self.1
+    }
+}
+
+impl fmt::Display for TryFromStrategyBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+impl Error for TryFromStrategyBuilderError {}
+
+impl fmt::Display for TryFromGenotypeBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+impl Error for TryFromGenotypeBuilderError {}
+
+impl fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuilderError::MissingGenotype => write!(f, "missing genotype"),
+            BuilderError::MissingFitness => write!(f, "missing fitness"),
+            BuilderError::MissingMutate => write!(f, "missing mutate strategy"),
+            BuilderError::MissingCrossover => write!(f, "missing crossover strategy"),
+            BuilderError::MissingSelect => write!(f, "missing select strategy"),
+            BuilderError::MissingVariant => write!(f, "missing strategy variant"),
+            BuilderError::MissingEndingCondition => write!(f, "missing ending condition"),
+            BuilderError::InvalidTargetPopulationSize => {
+                write!(f, "invalid target_population_size")
+            }
+            BuilderError::IncompatibleCrossover { requires } => {
+                write!(f, "incompatible crossover, requires {}", requires)
+            }
+            BuilderError::IncompatibleMutationType => write!(f, "incompatible mutation_type"),
+            BuilderError::IncompatibleSeedGenes => write!(f, "incompatible seed genes"),
+            BuilderError::InvalidParameter => write!(f, "invalid parameter"),
+        }
+    }
+}
+impl Error for BuilderError {}
@@ -4,20 +4,24 @@
 //! selected for, killing of the offspring again. This reduces the efficiency, but also has the
 //! risk of local optimum lock-in. To increase the variation in the population, an
 //! [extension](crate::extension) mechanisms can optionally be used
+mod chain;
 mod mass_deduplication;
 mod mass_degeneration;
 mod mass_extinction;
 mod mass_genesis;
+mod scale_reset;
 
 use crate::chromosome::Chromosome;
 mod noop;
 mod wrapper;
 
+pub use self::chain::Chain as ExtensionChain;
 pub use self::mass_deduplication::MassDeduplication as ExtensionMassDeduplication;
 pub use self::mass_degeneration::MassDegeneration as ExtensionMassDegeneration;
 pub use self::mass_extinction::MassExtinction as ExtensionMassExtinction;
 pub use self::mass_genesis::MassGenesis as ExtensionMassGenesis;
 pub use self::noop::Noop as ExtensionNoop;
+pub use self::scale_reset::ScaleReset as ExtensionScaleReset;
 pub use self::wrapper::Wrapper as ExtensionWrapper;
 
 use crate::genotype::{EvolveGenotype, Genotype};
@@ -66,7 +70,8 @@ pub type ExtensionAllele<E> = <<E as Extension>::Genotype as Genotype>::Allele;
 ///             if let Some(cardinality) = state.population_cardinality() {
 ///                 if cardinality <= self.cardinality_threshold {
 ///                     reporter.on_extension_event(
-///                         ExtensionEvent("DeduplicatePopulation".to_string()),
+///                         ExtensionEvent::new("DeduplicatePopulation")
+///                             .with_cardinality(cardinality, self.cardinality_threshold),
 ///                         genotype,
 ///                         state,
 ///                         config,
@@ -161,5 +166,31 @@ pub trait Extension: Clone + Send + Sync + std::fmt::Debug {
     }
 }
 
+/// The event reported by an extension when it is triggered. The provided extensions trigger on
+/// the population_cardinality dropping to their cardinality_threshold, which are passed along for
+/// reacting on the trigger (e.g. adjusting hyperparameters or external metrics)
 #[derive(Clone, Debug)]
-pub struct ExtensionEvent(pub String);
+pub struct ExtensionEvent {
+    pub name: String,
+    pub population_cardinality: Option<usize>,
+    pub cardinality_threshold: Option<usize>,
+}
+
+impl ExtensionEvent {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            population_cardinality: None,
+            cardinality_threshold: None,
+        }
+    }
+    pub fn with_cardinality(
+        mut self,
+        population_cardinality: usize,
+        cardinality_threshold: usize,
+    ) -> Self {
+        self.population_cardinality = Some(population_cardinality);
+        self.cardinality_threshold = Some(cardinality_threshold);
+        self
+    }
+}
@@ -0,0 +1,54 @@
+use super::{Extension, ExtensionWrapper};
+use crate::genotype::EvolveGenotype;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::StrategyReporter;
+use rand::Rng;
+
+/// Calls multiple extensions in order each generation, e.g. a
+/// [MassDegeneration](super::ExtensionMassDegeneration) and a
+/// [MassExtinction](super::ExtensionMassExtinction) with different cardinality thresholds. Each
+/// extension triggers and reports its own events independently. Note the population cardinality
+/// is not recalculated between the extensions, so a later extension triggers on the cardinality
+/// from before the earlier extensions changed the population.
+///
+/// # Example
+/// ```rust
+/// use genetic_algorithm::strategy::evolve::prelude::*;
+///
+/// let extension: ExtensionChain<BinaryGenotype> = ExtensionChain::new(vec![
+///     ExtensionMassDegeneration::new(20, 10, 0.02).into(),
+///     ExtensionMassExtinction::new(5, 0.10, 0.02).into(),
+/// ]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Chain<G: EvolveGenotype> {
+    pub extensions: Vec<ExtensionWrapper<G>>,
+}
+
+impl<G: EvolveGenotype> Extension for Chain<G> {
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        genotype: &G,
+        state: &mut EvolveState<G>,
+        config: &EvolveConfig,
+        reporter: &mut SR,
+        rng: &mut R,
+    ) {
+        for extension in self.extensions.iter_mut() {
+            extension.call(genotype, state, config, reporter, rng);
+        }
+    }
+}
+
+impl<G: EvolveGenotype> Chain<G> {
+    pub fn new(extensions: Vec<ExtensionWrapper<G>>) -> Self {
+        Self { extensions }
+    }
+    /// Append an extension to the end of the chain
+    pub fn with_extension<E: Into<ExtensionWrapper<G>>>(mut self, extension: E) -> Self {
+        self.extensions.push(extension.into());
+        self
+    }
+}
@@ -34,7 +34,8 @@ impl<G: EvolveGenotype> Extension for MassDeduplication<G> {
             if let Some(cardinality) = state.population_cardinality() {
                 if cardinality <= self.cardinality_threshold {
                     reporter.on_extension_event(
-                        ExtensionEvent("MassDeduplication".to_string()),
+                        ExtensionEvent::new("MassDeduplication")
+                            .with_cardinality(cardinality, self.cardinality_threshold),
                         genotype,
                         state,
                         config,
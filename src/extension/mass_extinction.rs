@@ -37,7 +37,8 @@ impl<G: EvolveGenotype> Extension for MassExtinction<G> {
             if let Some(cardinality) = state.population_cardinality() {
                 if cardinality <= self.cardinality_threshold {
                     reporter.on_extension_event(
-                        ExtensionEvent("MassExtinction".to_string()),
+                        ExtensionEvent::new("MassExtinction")
+                            .with_cardinality(cardinality, self.cardinality_threshold),
                         genotype,
                         state,
                         config,
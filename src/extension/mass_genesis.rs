@@ -35,7 +35,8 @@ impl<G: EvolveGenotype> Extension for MassGenesis<G> {
             if let Some(cardinality) = state.population_cardinality() {
                 if cardinality <= self.cardinality_threshold {
                     reporter.on_extension_event(
-                        ExtensionEvent("MassGenesis".to_string()),
+                        ExtensionEvent::new("MassGenesis")
+                            .with_cardinality(cardinality, self.cardinality_threshold),
                         genotype,
                         state,
                         config,
@@ -0,0 +1,75 @@
+use super::{Extension, ExtensionEvent};
+use crate::genotype::EvolveGenotype;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::StrategyReporter;
+use crate::strategy::{StrategyAction, StrategyState};
+use rand::Rng;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// Reset the scale index of a scaled genotype (e.g. a
+/// [RangeGenotype](crate::genotype::RangeGenotype) with
+/// [MutationType::RangeScaled](crate::genotype::MutationType::RangeScaled) or
+/// [MutationType::StepScaled](crate::genotype::MutationType::StepScaled)) back to zero when the
+/// population cardinality drops to the cardinality_threshold. This widens the mutation ranges
+/// again, re-exploring around the current population before zooming in again. Only triggers when
+/// zoomed in (scale index > 0) and at most max_number_of_resets times, as endlessly re-exploring
+/// would never finish on max_stale_generations.
+///
+/// The genotype is read-only during the extension step, so the reset is requested on the
+/// [EvolveState] and applied at the end of the generation, together with resetting the
+/// scale_generation and stale_generations.
+#[derive(Debug, Clone)]
+pub struct ScaleReset<G: EvolveGenotype> {
+    _phantom: PhantomData<G>,
+    pub cardinality_threshold: usize,
+    pub max_number_of_resets: usize,
+    pub number_of_resets: usize,
+}
+
+impl<G: EvolveGenotype> Extension for ScaleReset<G> {
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        genotype: &G,
+        state: &mut EvolveState<G>,
+        config: &EvolveConfig,
+        reporter: &mut SR,
+        _rng: &mut R,
+    ) {
+        if state.population.size() >= config.target_population_size
+            && self.number_of_resets < self.max_number_of_resets
+            && genotype
+                .current_scale_index()
+                .is_some_and(|index| index > 0)
+        {
+            let now = Instant::now();
+            if let Some(cardinality) = state.population_cardinality() {
+                if cardinality <= self.cardinality_threshold {
+                    reporter.on_extension_event(
+                        ExtensionEvent::new("ScaleReset")
+                            .with_cardinality(cardinality, self.cardinality_threshold),
+                        genotype,
+                        state,
+                        config,
+                    );
+                    state.scale_index_reset_requested = true;
+                    self.number_of_resets += 1;
+                }
+            }
+            state.add_duration(StrategyAction::Extension, now.elapsed());
+        }
+    }
+}
+
+impl<G: EvolveGenotype> ScaleReset<G> {
+    pub fn new(cardinality_threshold: usize, max_number_of_resets: usize) -> Self {
+        Self {
+            _phantom: PhantomData,
+            cardinality_threshold,
+            max_number_of_resets,
+            number_of_resets: 0,
+        }
+    }
+}
@@ -1,8 +1,10 @@
+pub use super::chain::Chain as ExtensionChain;
 pub use super::mass_deduplication::MassDeduplication as ExtensionMassDeduplication;
 pub use super::mass_degeneration::MassDegeneration as ExtensionMassDegeneration;
 pub use super::mass_extinction::MassExtinction as ExtensionMassExtinction;
 pub use super::mass_genesis::MassGenesis as ExtensionMassGenesis;
 pub use super::noop::Noop as ExtensionNoop;
+pub use super::scale_reset::ScaleReset as ExtensionScaleReset;
 pub use super::Extension;
 
 use crate::genotype::EvolveGenotype;
@@ -12,11 +14,13 @@ use rand::Rng;
 
 #[derive(Clone, Debug)]
 pub enum Wrapper<G: EvolveGenotype> {
+    Chain(ExtensionChain<G>),
     MassDeduplication(ExtensionMassDeduplication<G>),
     MassDegeneration(ExtensionMassDegeneration<G>),
     MassExtinction(ExtensionMassExtinction<G>),
     MassGenesis(ExtensionMassGenesis<G>),
     Noop(ExtensionNoop<G>),
+    ScaleReset(ExtensionScaleReset<G>),
 }
 
 impl<G: EvolveGenotype> Extension for Wrapper<G> {
@@ -31,6 +35,7 @@ impl<G: EvolveGenotype> Extension for Wrapper<G> {
         rng: &mut R,
     ) {
         match self {
+            Wrapper::Chain(extension) => extension.call(genotype, state, config, reporter, rng),
             Wrapper::MassDeduplication(extension) => {
                 extension.call(genotype, state, config, reporter, rng)
             }
@@ -44,10 +49,18 @@ impl<G: EvolveGenotype> Extension for Wrapper<G> {
                 extension.call(genotype, state, config, reporter, rng)
             }
             Wrapper::Noop(extension) => extension.call(genotype, state, config, reporter, rng),
+            Wrapper::ScaleReset(extension) => {
+                extension.call(genotype, state, config, reporter, rng)
+            }
         }
     }
 }
 
+impl<G: EvolveGenotype> From<ExtensionChain<G>> for Wrapper<G> {
+    fn from(extension: ExtensionChain<G>) -> Self {
+        Wrapper::Chain(extension)
+    }
+}
 impl<G: EvolveGenotype> From<ExtensionMassDeduplication<G>> for Wrapper<G> {
     fn from(extension: ExtensionMassDeduplication<G>) -> Self {
         Wrapper::MassDeduplication(extension)
@@ -73,3 +86,8 @@ impl<G: EvolveGenotype> From<ExtensionNoop<G>> for Wrapper<G> {
         Wrapper::Noop(extension)
     }
 }
+impl<G: EvolveGenotype> From<ExtensionScaleReset<G>> for Wrapper<G> {
+    fn from(extension: ExtensionScaleReset<G>) -> Self {
+        Wrapper::ScaleReset(extension)
+    }
+}
@@ -7,17 +7,30 @@
 //!
 //! See [Fitness] Trait for examples and further documentation
 pub mod cache;
+mod duplicate_penalty;
+mod multi_objective;
 pub mod placeholders;
 pub mod prelude;
+mod resampling;
+mod weighted_objectives;
 
 pub use self::cache::Cache as FitnessCache;
+pub use self::duplicate_penalty::DuplicatePenalty as FitnessDuplicatePenalty;
+pub use self::multi_objective::MultiObjective as FitnessMultiObjective;
+pub use self::multi_objective::MultiObjectiveFitness;
+pub use self::resampling::Resampling as FitnessResampling;
+pub use self::resampling::ResamplingAggregation as FitnessResamplingAggregation;
+pub use self::weighted_objectives::WeightedObjective as FitnessWeightedObjective;
+pub use self::weighted_objectives::WeightedObjectives as FitnessWeightedObjectives;
 
+use crate::allele::Allele;
 use crate::chromosome::Chromosome;
 use crate::genotype::Genotype;
 use crate::population::Population;
 use crate::strategy::{StrategyAction, StrategyConfig, StrategyState};
 use rayon::prelude::*;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Instant;
 use thread_local::ThreadLocal;
 
@@ -30,6 +43,150 @@ pub enum FitnessOrdering {
     Minimize,
 }
 
+impl FitnessOrdering {
+    /// Returns true if fitness score `a` is strictly better than `b`, given the ordering. A `None`
+    /// fitness score (invalid chromosome) is always worse than any valid fitness score, also when
+    /// minimizing. Equal fitness scores are never better.
+    pub fn is_better(&self, a: Option<FitnessValue>, b: Option<FitnessValue>) -> bool {
+        match (a, b) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(a), Some(b)) => match self {
+                FitnessOrdering::Maximize => a > b,
+                FitnessOrdering::Minimize => a < b,
+            },
+        }
+    }
+
+    /// Returns true if the objectives `a` Pareto-dominate `b`, given the ordering for all
+    /// objectives: `a` is at least as good in every objective and strictly better in at least
+    /// one. Objectives of different lengths never dominate.
+    pub fn dominates(&self, a: &[FitnessValue], b: &[FitnessValue]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut strictly_better = false;
+        for (&a, &b) in a.iter().zip(b) {
+            if self.is_better(Some(b), Some(a)) {
+                return false;
+            } else if a != b {
+                strictly_better = true;
+            }
+        }
+        strictly_better
+    }
+
+    /// Returns the better of fitness scores `a` and `b`, given the ordering. Returns `a` on equal
+    /// fitness scores. See [is_better](Self::is_better) for `None` handling.
+    pub fn better_of(
+        &self,
+        a: Option<FitnessValue>,
+        b: Option<FitnessValue>,
+    ) -> Option<FitnessValue> {
+        if self.is_better(b, a) {
+            b
+        } else {
+            a
+        }
+    }
+}
+
+/// How to convert a non-finite (NaN or infinite) float fitness to a [FitnessValue]. A plain `as`
+/// cast silently maps NaN to zero and infinity to the extremes, so an infinite fitness (e.g. a
+/// division by zero in a user objective) could become the best chromosome.
+/// * [FitnessNonFinitePolicy::TreatAsWorst]: the worst possible fitness score for the
+///   [FitnessOrdering], so the chromosome stays valid but never becomes best (default)
+/// * [FitnessNonFinitePolicy::Skip]: no fitness score (`None`), so the chromosome is assumed
+///   invalid and taken last in the [selection](crate::select) phase
+/// * [FitnessNonFinitePolicy::Error]: panic, for fitness functions which should never produce a
+///   non-finite value
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FitnessNonFinitePolicy {
+    #[default]
+    TreatAsWorst,
+    Skip,
+    Error,
+}
+
+impl FitnessNonFinitePolicy {
+    /// Convert a float fitness value to a [FitnessValue], applying the policy to non-finite
+    /// values. Finite values are just cast (truncating towards zero).
+    pub fn to_fitness_value(
+        &self,
+        value: f64,
+        fitness_ordering: FitnessOrdering,
+    ) -> Option<FitnessValue> {
+        if value.is_finite() {
+            Some(value as FitnessValue)
+        } else {
+            match self {
+                FitnessNonFinitePolicy::TreatAsWorst => match fitness_ordering {
+                    FitnessOrdering::Maximize => Some(FitnessValue::MIN),
+                    FitnessOrdering::Minimize => Some(FitnessValue::MAX),
+                },
+                FitnessNonFinitePolicy::Skip => None,
+                FitnessNonFinitePolicy::Error => {
+                    panic!("non-finite fitness value: {}", value)
+                }
+            }
+        }
+    }
+}
+
+/// The strategy context of a fitness calculation, passed to
+/// [calculate_for_chromosome_with_context](Fitness::calculate_for_chromosome_with_context), e.g.
+/// for penalty weights annealed over the generations. The best_fitness_score and
+/// population_cardinality are the last known values of the strategy, before the current fitness
+/// calculation. Outside of a strategy (e.g. `call_for_population`), the default context is used.
+///
+/// The strategies call [call_for_chromosome](Fitness::call_for_chromosome) within the
+/// [scope](FitnessContext::scope) of the context, so existing overrides of `call_for_chromosome`
+/// keep working. Its default implementation passes the [current](FitnessContext::current)
+/// context on to [call_for_chromosome_with_context](Fitness::call_for_chromosome_with_context).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FitnessContext {
+    pub generation: usize,
+    pub best_fitness_score: Option<FitnessValue>,
+    pub population_cardinality: Option<usize>,
+}
+
+thread_local! {
+    static CURRENT_FITNESS_CONTEXT: Cell<FitnessContext> = Cell::new(FitnessContext::default());
+}
+
+impl FitnessContext {
+    pub fn from_state<G: Genotype, S: StrategyState<G>>(state: &S) -> Self {
+        Self {
+            generation: state.current_generation(),
+            best_fitness_score: state.best_fitness_score(),
+            population_cardinality: state.population_cardinality(),
+        }
+    }
+    /// The context of the fitness calculation in progress on the current thread, the default
+    /// context outside of a [scope](FitnessContext::scope)
+    pub fn current() -> Self {
+        CURRENT_FITNESS_CONTEXT.with(|current| current.get())
+    }
+    /// Run the closure with this context as the current context of the current thread
+    pub fn scope<T>(self, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT_FITNESS_CONTEXT.with(|current| current.replace(self));
+        let result = f();
+        CURRENT_FITNESS_CONTEXT.with(|current| current.set(previous));
+        result
+    }
+}
+
+/// Whether the fitness score of the chromosome will be read from the cache instead of calculated,
+/// for counting the fitness evaluations
+pub(crate) fn is_cached<T: Allele>(
+    cache: Option<&FitnessCache>,
+    chromosome: &Chromosome<T>,
+) -> bool {
+    cache
+        .zip(chromosome.genes_hash())
+        .is_some_and(|(cache, genes_hash)| cache.contains(genes_hash))
+}
+
 /// This is just a shortcut for `Self::Genotype`
 pub type FitnessGenotype<F> = <F as Fitness>::Genotype;
 /// This is just a shortcut for `Chromosome<<Self::Genotype as Genotype>::Allele>`
@@ -38,6 +195,8 @@ pub type FitnessChromosome<F> = Chromosome<<<F as Fitness>::Genotype as Genotype
 pub type FitnessGenes<F> = Vec<<<F as Fitness>::Genotype as Genotype>::Allele>;
 /// This is just a shortcut for `Population<<Self::Genotype as Genotype>::Allele>`
 pub type FitnessPopulation<F> = Population<<<F as Fitness>::Genotype as Genotype>::Allele>;
+/// This is just a shortcut for `<Self::Genotype as Genotype>::Allele`
+pub type FitnessAllele<F> = <<F as Fitness>::Genotype as Genotype>::Allele;
 
 /// The fitness function, is implemented as a fitness method object.
 ///
@@ -56,6 +215,19 @@ pub type FitnessPopulation<F> = Population<<<F as Fitness>::Genotype as Genotype
 /// allocations on initialization and reusing them for each chromosome can really impact
 /// performance. For parallel evaluation, each thread gets its own clone via ThreadLocal.
 ///
+/// If the fitness depends on the progress of the strategy (e.g. annealed penalty weights),
+/// override [calculate_for_chromosome_with_context](Fitness::calculate_for_chromosome_with_context)
+/// as well, which receives the [FitnessContext]. It defaults to `calculate_for_chromosome`, which
+/// remains the fallback without context. The strategies still call `call_for_chromosome`, whose
+/// default implementation passes the context on. Don't combine a context dependent fitness with
+/// the fitness cache, as the cache ignores the context.
+///
+/// For batched evaluation (e.g. on the GPU), return a [batch_size](Fitness::batch_size) and
+/// override [calculate_for_batch](Fitness::calculate_for_batch), which receives the genes of a
+/// batch of chromosomes as a contiguous row-major matrix and returns the fitness values in bulk.
+/// The strategies then never call `calculate_for_chromosome` directly, see
+/// [calculate_for_batch](Fitness::calculate_for_batch).
+///
 /// # Example (calculate_for_chromosome, standard GenesOwner chromosome):
 /// ```rust
 /// use genetic_algorithm::fitness::prelude::*;
@@ -83,12 +255,17 @@ pub trait Fitness: Clone + Send + Sync + std::fmt::Debug {
         thread_local: Option<&ThreadLocal<RefCell<Self>>>,
     ) {
         let now = Instant::now();
-        self.call_for_population(
+        let context = FitnessContext::from_state(state);
+        let number_of_evaluations = self.call_for_population_with_progress(
             state.population_as_mut(),
             genotype,
             thread_local,
             config.fitness_cache(),
+            config.fitness_chunk_size(),
+            None,
+            Some(&context),
         );
+        state.add_fitness_evaluations(number_of_evaluations);
         state.add_duration(StrategyAction::Fitness, now.elapsed());
     }
     fn call_for_state_chromosome<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
@@ -97,9 +274,15 @@ pub trait Fitness: Clone + Send + Sync + std::fmt::Debug {
         state: &mut S,
         config: &C,
     ) {
+        let context = FitnessContext::from_state(state);
         if let Some(chromosome) = state.chromosome_as_mut() {
             let now = Instant::now();
-            self.call_for_chromosome(chromosome, genotype, config.fitness_cache());
+            let cached = is_cached(config.fitness_cache(), chromosome);
+            context
+                .scope(|| self.call_for_chromosome(chromosome, genotype, config.fitness_cache()));
+            if !cached {
+                state.add_fitness_evaluations(1);
+            }
             state.add_duration(StrategyAction::Fitness, now.elapsed());
         }
     }
@@ -111,54 +294,251 @@ pub trait Fitness: Clone + Send + Sync + std::fmt::Debug {
         thread_local: Option<&ThreadLocal<RefCell<Self>>>,
         cache: Option<&FitnessCache>,
     ) {
-        if let Some(thread_local) = thread_local {
-            population
+        self.call_for_population_with_progress(
+            population,
+            genotype,
+            thread_local,
+            cache,
+            None,
+            None,
+            None,
+        );
+    }
+    /// Like `call_for_population`, with three additional options:
+    /// * `chunk_size`: the number of consecutive chromosomes per rayon work unit in
+    ///   multithreading, each chunk is calculated by a single thread. Larger chunks improve cache
+    ///   behaviour for cheap fitness functions. Dynamic chunking (rayon's default splitting) if
+    ///   None. Ignored when single threaded.
+    /// * `progress`: callback invoked after each calculated chromosome, with the number of
+    ///   calculated chromosomes so far (shared atomic counter in multithreading). Only
+    ///   chromosomes without a fitness_score are calculated and counted.
+    /// * `context`: the [FitnessContext] for all chromosomes, the default context if None
+    ///
+    /// Returns the number of actually calculated chromosomes, so excluding the cache hits.
+    #[allow(clippy::too_many_arguments)]
+    fn call_for_population_with_progress(
+        &mut self,
+        population: &mut FitnessPopulation<Self>,
+        genotype: &Self::Genotype,
+        thread_local: Option<&ThreadLocal<RefCell<Self>>>,
+        cache: Option<&FitnessCache>,
+        chunk_size: Option<usize>,
+        progress: Option<&(dyn Fn(usize) + Sync)>,
+        context: Option<&FitnessContext>,
+    ) -> usize {
+        let context = context.copied().unwrap_or_default();
+        let counter = AtomicUsize::new(0);
+        let calculations = AtomicUsize::new(0);
+        let report_progress = || {
+            if let Some(progress) = progress {
+                progress(counter.fetch_add(1, Ordering::Relaxed) + 1);
+            }
+        };
+        if let Some(batch_size) = self.batch_size() {
+            let mut pending_chromosomes: Vec<&mut FitnessChromosome<Self>> = Vec::new();
+            for chromosome in population
                 .chromosomes
-                .par_iter_mut()
+                .iter_mut()
                 .filter(|c| c.fitness_score().is_none())
-                .for_each_init(
-                    || {
-                        thread_local
-                            .get_or(|| std::cell::RefCell::new(self.clone()))
-                            .borrow_mut()
-                    },
-                    |fitness, chromosome| {
-                        fitness.call_for_chromosome(chromosome, genotype, cache);
-                    },
+            {
+                match cache
+                    .zip(chromosome.genes_hash())
+                    .and_then(|(cache, genes_hash)| cache.read(genes_hash))
+                {
+                    Some(value) => {
+                        chromosome.set_fitness_score(Some(value));
+                        report_progress();
+                    }
+                    None => pending_chromosomes.push(chromosome),
+                }
+            }
+            calculations.store(pending_chromosomes.len(), Ordering::Relaxed);
+            let mut genes_matrix: Vec<FitnessAllele<Self>> = Vec::new();
+            for batch in pending_chromosomes.chunks_mut(batch_size.max(1)) {
+                genes_matrix.clear();
+                batch
+                    .iter()
+                    .for_each(|c| genes_matrix.extend_from_slice(&c.genes));
+                let genes_size = batch[0].genes.len();
+                let values =
+                    self.calculate_for_batch(&genes_matrix, genes_size, genotype, &context);
+                assert_eq!(
+                    values.len(),
+                    batch.len(),
+                    "calculate_for_batch must return a fitness value for each chromosome"
                 );
+                for (chromosome, value) in batch.iter_mut().zip(values) {
+                    if let (Some(cache), Some(genes_hash), Some(value)) =
+                        (cache, chromosome.genes_hash(), value)
+                    {
+                        cache.write(genes_hash, value);
+                    }
+                    chromosome.set_fitness_score(value);
+                    report_progress();
+                }
+            }
+        } else if let Some(thread_local) = thread_local {
+            let init = || {
+                thread_local
+                    .get_or(|| std::cell::RefCell::new(self.clone()))
+                    .borrow_mut()
+            };
+            let calculate = |fitness: &mut Self, chromosome: &mut FitnessChromosome<Self>| {
+                if !is_cached(cache, chromosome) {
+                    calculations.fetch_add(1, Ordering::Relaxed);
+                }
+                context.scope(|| fitness.call_for_chromosome(chromosome, genotype, cache));
+                report_progress();
+            };
+            if let Some(chunk_size) = chunk_size {
+                population
+                    .chromosomes
+                    .par_chunks_mut(chunk_size.max(1))
+                    .for_each_init(init, |fitness, chunk| {
+                        chunk
+                            .iter_mut()
+                            .filter(|c| c.fitness_score().is_none())
+                            .for_each(|chromosome| calculate(fitness, chromosome));
+                    });
+            } else {
+                population
+                    .chromosomes
+                    .par_iter_mut()
+                    .filter(|c| c.fitness_score().is_none())
+                    .for_each_init(init, |fitness, chromosome| calculate(fitness, chromosome));
+            }
         } else {
             population
                 .chromosomes
                 .iter_mut()
                 .filter(|c| c.fitness_score().is_none())
-                .for_each(|c| self.call_for_chromosome(c, genotype, cache));
+                .for_each(|c| {
+                    if !is_cached(cache, c) {
+                        calculations.fetch_add(1, Ordering::Relaxed);
+                    }
+                    context.scope(|| self.call_for_chromosome(c, genotype, cache));
+                    report_progress();
+                });
         }
+        calculations.into_inner()
     }
+    /// The method called by the strategies. Calculates with the
+    /// [current](FitnessContext::current) [FitnessContext], which is the default context outside
+    /// of a strategy
     fn call_for_chromosome(
         &mut self,
         chromosome: &mut FitnessChromosome<Self>,
         genotype: &Self::Genotype,
         cache: Option<&FitnessCache>,
+    ) {
+        self.call_for_chromosome_with_context(
+            chromosome,
+            genotype,
+            cache,
+            &FitnessContext::current(),
+        );
+    }
+    fn call_for_chromosome_with_context(
+        &mut self,
+        chromosome: &mut FitnessChromosome<Self>,
+        genotype: &Self::Genotype,
+        cache: Option<&FitnessCache>,
+        context: &FitnessContext,
     ) {
         let value = match (cache, chromosome.genes_hash()) {
             (Some(cache), Some(genes_hash)) => {
                 if let Some(value) = cache.read(genes_hash) {
                     Some(value)
-                } else if let Some(value) = self.calculate_for_chromosome(chromosome, genotype) {
+                } else if let Some(value) = self.calculate_for_single(chromosome, genotype, context)
+                {
                     cache.write(genes_hash, value);
                     Some(value)
                 } else {
                     None
                 }
             }
-            _ => self.calculate_for_chromosome(chromosome, genotype),
+            _ => self.calculate_for_single(chromosome, genotype, context),
         };
         chromosome.set_fitness_score(value);
     }
+    /// Optional problem specific optimality certificate (e.g. zero conflicts in N-Queens), checked
+    /// on the best chromosome before each generation. Return true to stop the run immediately,
+    /// even without a target_fitness_score. Ignored by
+    /// [Permutate](crate::strategy::permutate::Permutate), which is exhaustive anyway. Defaults to
+    /// false
+    fn should_terminate(&self, _best: &FitnessChromosome<Self>) -> bool {
+        false
+    }
+    /// Calculate a single chromosome, as a batch of one if batched (see
+    /// [batch_size](Fitness::batch_size))
+    fn calculate_for_single(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        genotype: &Self::Genotype,
+        context: &FitnessContext,
+    ) -> Option<FitnessValue> {
+        if self.batch_size().is_some() {
+            self.calculate_for_batch(&chromosome.genes, chromosome.genes.len(), genotype, context)
+                .into_iter()
+                .next()
+                .flatten()
+        } else {
+            self.calculate_for_chromosome_with_context(chromosome, genotype, context)
+        }
+    }
     /// Must be implemented by client
     fn calculate_for_chromosome(
         &mut self,
         chromosome: &FitnessChromosome<Self>,
         genotype: &Self::Genotype,
     ) -> Option<FitnessValue>;
+    /// Optionally implemented by client, for a fitness depending on the strategy's progress.
+    /// Defaults to `calculate_for_chromosome`
+    fn calculate_for_chromosome_with_context(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        genotype: &Self::Genotype,
+        _context: &FitnessContext,
+    ) -> Option<FitnessValue> {
+        self.calculate_for_chromosome(chromosome, genotype)
+    }
+    /// Optionally implemented by client, enables the batched evaluation with
+    /// [calculate_for_batch](Fitness::calculate_for_batch) with at most batch_size chromosomes per
+    /// call. Defaults to None (no batching)
+    fn batch_size(&self) -> Option<usize> {
+        None
+    }
+    /// Optionally implemented by client, for batched evaluation (e.g. on the GPU). Only called
+    /// when [batch_size](Fitness::batch_size) is set. The genes of the batch are provided as a
+    /// contiguous row-major matrix, a row of genes_size genes for each chromosome. Must return a
+    /// fitness value for each row, in the same order.
+    ///
+    /// The population evaluations of the strategies are split in batches of batch_size (the last
+    /// one possibly smaller), sequentially on the main fitness (par_fitness is ignored). Cached
+    /// fitness scores are not recalculated. Single chromosome evaluations (e.g.
+    /// [HillClimb](crate::strategy::hill_climb::HillClimb) stochastic,
+    /// [Permutate](crate::strategy::permutate::Permutate)) are a batch of one.
+    ///
+    /// The per chromosome path (overrides of `call_for_chromosome`,
+    /// `call_for_chromosome_with_context` and `calculate_for_chromosome_with_context`) is bypassed
+    /// for the population evaluations when batched. So a wrapping fitness must forward both
+    /// `batch_size` and `calculate_for_batch` to the wrapped fitness (like
+    /// [FitnessDuplicatePenalty]).
+    ///
+    /// Defaults to `calculate_for_chromosome_with_context` for each row
+    fn calculate_for_batch(
+        &mut self,
+        genes_matrix: &[FitnessAllele<Self>],
+        genes_size: usize,
+        genotype: &Self::Genotype,
+        context: &FitnessContext,
+    ) -> Vec<Option<FitnessValue>> {
+        genes_matrix
+            .chunks(genes_size.max(1))
+            .map(|genes| {
+                let chromosome = Chromosome::new(genes.to_vec());
+                self.calculate_for_chromosome_with_context(&chromosome, genotype, context)
+            })
+            .collect()
+    }
 }
@@ -50,6 +50,14 @@ impl Cache {
         value
     }
 
+    /// Whether the genes_hash is cached, without counting it as a hit or miss
+    pub fn contains(&self, genes_hash: GenesHash) -> bool {
+        self.cache_state
+            .read()
+            .map(|c| c.contains(&genes_hash))
+            .unwrap()
+    }
+
     pub fn write(&self, genes_hash: GenesHash, value: FitnessValue) {
         self.cache_state.write().unwrap().put(genes_hash, value);
     }
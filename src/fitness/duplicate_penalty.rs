@@ -0,0 +1,117 @@
+use super::{
+    Fitness, FitnessAllele, FitnessChromosome, FitnessContext, FitnessGenotype, FitnessOrdering,
+    FitnessValue,
+};
+use crate::genotype::Genotype;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Wraps a [Fitness] and applies a soft penalty per duplicate allele in the chromosome. Useful for
+/// "mostly unique" genes, e.g. a [ListGenotype](crate::genotype::ListGenotype) approximating a
+/// [UniqueGenotype](crate::genotype::UniqueGenotype), while keeping gene crossover available.
+///
+/// The number of duplicates is the genes_size minus the number of distinct alleles, so `[1, 1, 2,
+/// 2]` has two duplicates. The penalty per duplicate is subtracted when maximizing and added when
+/// minimizing, so the fitness_ordering must match the strategy (also with `call_with_ordering`,
+/// which doesn't override it). A `None` fitness score of the wrapped fitness is passed through as
+/// is. The [FitnessContext] and the batched evaluation (see
+/// [calculate_for_batch](Fitness::calculate_for_batch)) are passed through to the wrapped fitness.
+///
+/// # Example:
+/// ```
+/// use genetic_algorithm::fitness::placeholders::SumGenes;
+/// use genetic_algorithm::fitness::{FitnessDuplicatePenalty, FitnessOrdering};
+/// use genetic_algorithm::genotype::ListGenotype;
+///
+/// let fitness = FitnessDuplicatePenalty::new(
+///     SumGenes::<ListGenotype<i32>>::new(),
+///     100,
+///     FitnessOrdering::Maximize,
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct DuplicatePenalty<F: Fitness> {
+    pub fitness: F,
+    pub penalty: FitnessValue,
+    pub fitness_ordering: FitnessOrdering,
+}
+
+impl<F: Fitness> DuplicatePenalty<F> {
+    pub fn new(fitness: F, penalty: FitnessValue, fitness_ordering: FitnessOrdering) -> Self {
+        Self {
+            fitness,
+            penalty,
+            fitness_ordering,
+        }
+    }
+}
+
+impl<F: Fitness> DuplicatePenalty<F>
+where
+    <F::Genotype as Genotype>::Allele: Eq + Hash,
+{
+    /// The genes_size minus the number of distinct alleles
+    pub fn duplicates_count(&self, chromosome: &FitnessChromosome<Self>) -> usize {
+        Self::genes_duplicates_count(&chromosome.genes)
+    }
+    fn genes_duplicates_count(genes: &[FitnessAllele<Self>]) -> usize {
+        let distinct: HashSet<_> = genes.iter().collect();
+        genes.len() - distinct.len()
+    }
+    fn apply_penalty(
+        &self,
+        genes: &[FitnessAllele<Self>],
+        value: Option<FitnessValue>,
+    ) -> Option<FitnessValue> {
+        let penalty = Self::genes_duplicates_count(genes) as FitnessValue * self.penalty;
+        value.map(|value| match self.fitness_ordering {
+            FitnessOrdering::Maximize => value - penalty,
+            FitnessOrdering::Minimize => value + penalty,
+        })
+    }
+}
+
+impl<F: Fitness> Fitness for DuplicatePenalty<F>
+where
+    <F::Genotype as Genotype>::Allele: Eq + Hash,
+{
+    type Genotype = F::Genotype;
+    fn calculate_for_chromosome(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        genotype: &FitnessGenotype<Self>,
+    ) -> Option<FitnessValue> {
+        let value = self.fitness.calculate_for_chromosome(chromosome, genotype);
+        self.apply_penalty(&chromosome.genes, value)
+    }
+    fn calculate_for_chromosome_with_context(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        genotype: &FitnessGenotype<Self>,
+        context: &FitnessContext,
+    ) -> Option<FitnessValue> {
+        let value = self
+            .fitness
+            .calculate_for_chromosome_with_context(chromosome, genotype, context);
+        self.apply_penalty(&chromosome.genes, value)
+    }
+    fn batch_size(&self) -> Option<usize> {
+        self.fitness.batch_size()
+    }
+    fn calculate_for_batch(
+        &mut self,
+        genes_matrix: &[FitnessAllele<Self>],
+        genes_size: usize,
+        genotype: &FitnessGenotype<Self>,
+        context: &FitnessContext,
+    ) -> Vec<Option<FitnessValue>> {
+        let values = self
+            .fitness
+            .calculate_for_batch(genes_matrix, genes_size, genotype, context);
+        genes_matrix
+            .chunks(genes_size.max(1))
+            .zip(values)
+            .map(|(genes, value)| self.apply_penalty(genes, value))
+            .collect()
+    }
+}
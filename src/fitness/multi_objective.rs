@@ -0,0 +1,94 @@
+use super::{Fitness, FitnessCache, FitnessChromosome, FitnessGenotype, FitnessValue};
+use crate::chromosome::Chromosome;
+use crate::genotype::Genotype;
+
+/// A multi-objective fitness, returning a [FitnessValue] per objective instead of a single
+/// fitness score. Wrap it in a [FitnessMultiObjective](MultiObjective) to use it as a [Fitness]
+/// with the [SelectNsga2](crate::select::SelectNsga2) Pareto-based selection. All objectives
+/// share the fitness_ordering of the strategy, so negate the objectives in the other direction.
+///
+/// # Example:
+/// ```
+/// use genetic_algorithm::chromosome::Chromosome;
+/// use genetic_algorithm::fitness::{FitnessMultiObjective, FitnessValue, MultiObjectiveFitness};
+/// use genetic_algorithm::genotype::BinaryGenotype;
+///
+/// #[derive(Clone, Debug)]
+/// struct TrueAndFalseCount;
+/// impl MultiObjectiveFitness for TrueAndFalseCount {
+///     type Genotype = BinaryGenotype;
+///     fn calculate_objectives_for_chromosome(
+///         &mut self,
+///         chromosome: &Chromosome<bool>,
+///         _genotype: &Self::Genotype,
+///     ) -> Option<Vec<FitnessValue>> {
+///         let true_count = chromosome.genes.iter().filter(|&value| *value).count();
+///         let false_count = chromosome.genes.len() - true_count;
+///         Some(vec![true_count as FitnessValue, false_count as FitnessValue])
+///     }
+/// }
+///
+/// let fitness = FitnessMultiObjective::new(TrueAndFalseCount);
+/// ```
+pub trait MultiObjectiveFitness: Clone + Send + Sync + std::fmt::Debug {
+    type Genotype: Genotype;
+    /// Must be implemented by client, return None for an invalid chromosome
+    fn calculate_objectives_for_chromosome(
+        &mut self,
+        chromosome: &Chromosome<<Self::Genotype as Genotype>::Allele>,
+        genotype: &Self::Genotype,
+    ) -> Option<Vec<FitnessValue>>;
+}
+
+/// Wraps a [MultiObjectiveFitness] as a [Fitness]. The objectives are stored on the chromosome
+/// (see [objectives](crate::chromosome::Chromosome::objectives)) for the
+/// [SelectNsga2](crate::select::SelectNsga2) selection and the
+/// [pareto_front](crate::strategy::evolve::Evolve::pareto_front). The fitness score is the sum of
+/// the objectives, which is only used for the best chromosome tracking and the ending conditions.
+///
+/// The fitness cache is bypassed, as it only stores the fitness score and not the objectives.
+#[derive(Clone, Debug)]
+pub struct MultiObjective<F: MultiObjectiveFitness> {
+    pub multi_objective_fitness: F,
+}
+
+impl<F: MultiObjectiveFitness> MultiObjective<F> {
+    pub fn new(multi_objective_fitness: F) -> Self {
+        Self {
+            multi_objective_fitness,
+        }
+    }
+}
+
+impl<F: MultiObjectiveFitness> Fitness for MultiObjective<F> {
+    type Genotype = F::Genotype;
+    fn call_for_chromosome(
+        &mut self,
+        chromosome: &mut FitnessChromosome<Self>,
+        genotype: &Self::Genotype,
+        _cache: Option<&FitnessCache>,
+    ) {
+        match self
+            .multi_objective_fitness
+            .calculate_objectives_for_chromosome(chromosome, genotype)
+        {
+            Some(objectives) => {
+                chromosome.set_fitness_score(Some(objectives.iter().sum()));
+                chromosome.objectives = objectives;
+            }
+            None => {
+                chromosome.set_fitness_score(None);
+                chromosome.objectives.clear();
+            }
+        }
+    }
+    fn calculate_for_chromosome(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        genotype: &FitnessGenotype<Self>,
+    ) -> Option<FitnessValue> {
+        self.multi_objective_fitness
+            .calculate_objectives_for_chromosome(chromosome, genotype)
+            .map(|objectives| objectives.iter().sum())
+    }
+}
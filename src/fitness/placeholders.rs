@@ -1,6 +1,8 @@
 //! placeholders for testing and bootstrapping, not really used in practice
-use crate::fitness::{Fitness, FitnessChromosome, FitnessValue};
-use crate::genotype::{BinaryGenotype, Genotype};
+use crate::fitness::{
+    Fitness, FitnessChromosome, FitnessNonFinitePolicy, FitnessOrdering, FitnessValue,
+};
+use crate::genotype::{BinaryGenotype, Genotype, UniqueGenotype};
 use rand::distributions::{Distribution, Uniform};
 use rand::rngs::SmallRng;
 use rand::SeedableRng;
@@ -51,9 +53,15 @@ impl Fitness for CountTrue {
 /// There are 2 constructors:
 /// * new(), precision is defaulted to 1.0
 /// * new_with_precision(precision)
+///
+/// A non-finite sum is converted according to the [FitnessNonFinitePolicy], set with
+/// `with_nonfinite_fitness(policy)` (defaults to TreatAsWorst for Maximize, set the ordering with
+/// `with_fitness_ordering(ordering)` when minimizing)
 #[derive(Clone, Debug)]
 pub struct SumGenes<G: Genotype> {
     precision: f64,
+    nonfinite_fitness: FitnessNonFinitePolicy,
+    fitness_ordering: FitnessOrdering,
     _phantom: PhantomData<G>,
 }
 impl<G: Genotype> SumGenes<G> {
@@ -66,11 +74,21 @@ impl<G: Genotype> SumGenes<G> {
             ..Default::default()
         }
     }
+    pub fn with_nonfinite_fitness(mut self, nonfinite_fitness: FitnessNonFinitePolicy) -> Self {
+        self.nonfinite_fitness = nonfinite_fitness;
+        self
+    }
+    pub fn with_fitness_ordering(mut self, fitness_ordering: FitnessOrdering) -> Self {
+        self.fitness_ordering = fitness_ordering;
+        self
+    }
 }
 impl<G: Genotype> Default for SumGenes<G> {
     fn default() -> Self {
         Self {
             precision: 1.0_f64,
+            nonfinite_fitness: FitnessNonFinitePolicy::default(),
+            fitness_ordering: FitnessOrdering::Maximize,
             _phantom: PhantomData,
         }
     }
@@ -89,7 +107,55 @@ where
             .genes
             .iter()
             .fold(0.0_f64, |acc, &e| acc + e.into());
-        Some((sum / self.precision) as FitnessValue)
+        self.nonfinite_fitness
+            .to_fitness_value(sum / self.precision, self.fitness_ordering)
+    }
+}
+
+/// placeholder for testing and bootstrapping of TSP-style routing problems
+/// Sums the distances between consecutive genes (cities) of the permutation, including the
+/// return from the last to the first city, and converts to [FitnessValue]. Use with
+/// [FitnessOrdering::Minimize].
+/// There are 2 constructors:
+/// * new(distance_matrix), precision is defaulted to 1.0
+/// * new_with_precision(distance_matrix, precision)
+///
+/// The distance_matrix is indexed as `distance_matrix[from][to]` and needs to cover all the
+/// alleles of the [UniqueGenotype]
+#[derive(Clone, Debug)]
+pub struct TourDistance {
+    pub distance_matrix: Vec<Vec<f64>>,
+    precision: f64,
+}
+impl TourDistance {
+    pub fn new(distance_matrix: Vec<Vec<f64>>) -> Self {
+        Self::new_with_precision(distance_matrix, 1.0_f64)
+    }
+    pub fn new_with_precision(distance_matrix: Vec<Vec<f64>>, precision: f64) -> Self {
+        Self {
+            distance_matrix,
+            precision,
+        }
+    }
+}
+impl Fitness for TourDistance {
+    type Genotype = UniqueGenotype;
+    fn calculate_for_chromosome(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        _genotype: &Self::Genotype,
+    ) -> Option<FitnessValue> {
+        let genes = &chromosome.genes;
+        if genes.len() < 2 {
+            return Some(0);
+        }
+        let distance: f64 = genes
+            .iter()
+            .zip(genes.iter().cycle().skip(1))
+            .map(|(&from, &to)| self.distance_matrix[from][to])
+            .sum();
+        FitnessNonFinitePolicy::default()
+            .to_fitness_value(distance / self.precision, FitnessOrdering::Minimize)
     }
 }
 
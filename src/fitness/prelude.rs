@@ -2,12 +2,15 @@
 pub use crate::chromosome::{Chromosome, GenesHash};
 #[doc(no_inline)]
 pub use crate::fitness::{
-    Fitness, FitnessChromosome, FitnessGenes, FitnessGenotype, FitnessOrdering, FitnessPopulation,
-    FitnessValue,
+    Fitness, FitnessAllele, FitnessChromosome, FitnessContext, FitnessDuplicatePenalty,
+    FitnessGenes, FitnessGenotype, FitnessMultiObjective, FitnessNonFinitePolicy, FitnessOrdering,
+    FitnessPopulation, FitnessResampling, FitnessResamplingAggregation, FitnessValue,
+    FitnessWeightedObjectives, MultiObjectiveFitness,
 };
 #[doc(no_inline)]
 pub use crate::genotype::{
-    Allele, BinaryGenotype, Genotype, GenotypeBuilder, ListGenotype, MultiListGenotype,
-    MultiRangeGenotype, MultiUniqueGenotype, RangeAllele, RangeGenotype,
-    TryFromGenotypeBuilderError, UniqueGenotype,
+    Allele, BinaryGenotype, Genotype, GenotypeBuilder, GrayCodeGenotype, LatinSquareGenotype,
+    ListGenotype, MaskedValueGenotype, MultiListGenotype, MultiRangeGenotype, MultiUniqueGenotype,
+    OneHotGenotype, RangeAllele, RangeGenotype, SimplexGenotype, TryFromGenotypeBuilderError,
+    UniqueGenotype,
 };
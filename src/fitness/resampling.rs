@@ -0,0 +1,145 @@
+use super::{
+    Fitness, FitnessChromosome, FitnessContext, FitnessGenotype, FitnessPopulation, FitnessValue,
+};
+use crate::population::Population;
+use crate::strategy::{StrategyAction, StrategyConfig, StrategyState};
+use std::cell::RefCell;
+use std::time::Instant;
+use thread_local::ThreadLocal;
+
+/// The aggregation of the fitness samples of the [Resampling]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ResamplingAggregation {
+    /// The mean of the samples (truncated towards zero)
+    #[default]
+    Mean,
+    /// The median of the samples (the mean of the two middle samples for an even number of
+    /// samples), which is insensitive to outliers
+    Median,
+}
+
+/// Noise tolerant fitness for a stochastic fitness function (e.g. simulation based). Each
+/// chromosome is calculated samples times and the aggregated fitness score is stored (see
+/// [ResamplingAggregation]). The samples without fitness score are ignored, the fitness score is
+/// `None` when all samples are `None`. The samples of the population are calculated with
+/// [call_for_population_with_progress](Fitness::call_for_population_with_progress), so
+/// par_fitness and batching apply, but the fitness_cache is never used, as it would lock in a
+/// single sample.
+///
+/// A lucky aggregate of the best chromosome can still lock in a false optimum, as the new
+/// chromosomes need to beat it. Set a best_reevaluation_interval to recalculate the best
+/// chromosome every interval generations, which replaces its fitness score (and with
+/// genes_hashing that of its copies in the population) with the fresh aggregate.
+///
+/// Set with
+/// [EvolveBuilder::with_fitness_resampling](crate::strategy::evolve::EvolveBuilder::with_fitness_resampling).
+#[derive(Clone, Debug)]
+pub struct Resampling {
+    pub samples: usize,
+    pub aggregation: ResamplingAggregation,
+    pub best_reevaluation_interval: Option<usize>,
+}
+
+impl Resampling {
+    /// Mean aggregation without re-evaluation of the best chromosome
+    pub fn new(samples: usize) -> Self {
+        Self {
+            samples,
+            aggregation: ResamplingAggregation::Mean,
+            best_reevaluation_interval: None,
+        }
+    }
+
+    /// Calculate the chromosomes without fitness score in the population of the state, like
+    /// [Fitness::call_for_state_population]
+    pub fn call_for_state_population<
+        F: Fitness,
+        S: StrategyState<FitnessGenotype<F>>,
+        C: StrategyConfig,
+    >(
+        &self,
+        fitness: &mut F,
+        genotype: &FitnessGenotype<F>,
+        state: &mut S,
+        config: &C,
+        thread_local: Option<&ThreadLocal<RefCell<F>>>,
+    ) {
+        let now = Instant::now();
+        let context = FitnessContext::from_state(state);
+        let population = state.population_as_mut();
+        let pending_indices: Vec<usize> = population
+            .chromosomes
+            .iter()
+            .enumerate()
+            .filter(|(_, chromosome)| chromosome.fitness_score().is_none())
+            .map(|(index, _)| index)
+            .collect();
+        let mut samples_population: FitnessPopulation<F> = Population::new(
+            pending_indices
+                .iter()
+                .flat_map(|index| (0..self.samples).map(|_| population.chromosomes[*index].clone()))
+                .collect(),
+            false,
+        );
+        fitness.call_for_population_with_progress(
+            &mut samples_population,
+            genotype,
+            thread_local,
+            None,
+            config.fitness_chunk_size(),
+            None,
+            Some(&context),
+        );
+        let values: Vec<Option<FitnessValue>> = samples_population
+            .chromosomes
+            .iter()
+            .map(|chromosome| chromosome.fitness_score())
+            .collect();
+        pending_indices
+            .iter()
+            .zip(values.chunks(self.samples.max(1)))
+            .for_each(|(index, values)| {
+                population.chromosomes[*index].set_fitness_score(self.aggregate(values));
+            });
+        state.add_fitness_evaluations(pending_indices.len() * self.samples);
+        state.add_duration(StrategyAction::Fitness, now.elapsed());
+    }
+
+    /// Calculate a single chromosome sequentially, overwriting its fitness score
+    pub fn call_for_chromosome<F: Fitness>(
+        &self,
+        fitness: &mut F,
+        chromosome: &mut FitnessChromosome<F>,
+        genotype: &FitnessGenotype<F>,
+        context: &FitnessContext,
+    ) {
+        let values: Vec<Option<FitnessValue>> = (0..self.samples)
+            .map(|_| fitness.calculate_for_single(chromosome, genotype, context))
+            .collect();
+        chromosome.set_fitness_score(self.aggregate(&values));
+    }
+
+    /// The aggregated fitness score of the samples, ignoring the samples without fitness score
+    pub fn aggregate(&self, values: &[Option<FitnessValue>]) -> Option<FitnessValue> {
+        let mut values: Vec<FitnessValue> = values.iter().flatten().copied().collect();
+        if values.is_empty() {
+            return None;
+        }
+        match self.aggregation {
+            ResamplingAggregation::Mean => {
+                let sum: i128 = values.iter().map(|value| *value as i128).sum();
+                Some((sum / values.len() as i128) as FitnessValue)
+            }
+            ResamplingAggregation::Median => {
+                values.sort_unstable();
+                let middle = values.len() / 2;
+                if values.len() % 2 == 0 {
+                    let sum = values[middle - 1] as i128 + values[middle] as i128;
+                    Some((sum / 2) as FitnessValue)
+                } else {
+                    Some(values[middle])
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use super::{Fitness, FitnessChromosome, FitnessGenotype, FitnessValue};
+use crate::chromosome::Chromosome;
+use crate::genotype::Genotype;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// A single objective of the [WeightedObjectives], calculating a partial fitness value for the
+/// chromosome
+pub type WeightedObjective<T> = Arc<dyn Fn(&Chromosome<T>) -> FitnessValue + Send + Sync>;
+
+/// Scalarized multi-objective fitness, the weighted sum of the objectives (rounded to the nearest
+/// [FitnessValue]). This is the simplest multi-objective approach and works with all strategies,
+/// as the result is a single fitness score. Mind the relative scale of the objectives when
+/// choosing the weights, and use negative weights for objectives to minimize in a maximizing
+/// strategy (and vice versa).
+///
+/// The objectives are shared closures (`Fn + Send + Sync`) instead of `FnMut`, as the fitness is
+/// cloned per thread for [par_fitness](crate::strategy::evolve::EvolveBuilder::with_par_fitness).
+///
+/// Add an objective with [with_normalized_objective](Self::with_normalized_objective) to
+/// normalize it from its (min, max) bounds to the [NORMALIZED_RANGE](Self::NORMALIZED_RANGE)
+/// before weighting, so the weights reflect importance rather than magnitude. The bounds are
+/// fixed, so the fitness score of a chromosome is the same in every generation and thread.
+///
+/// # Example:
+/// ```
+/// use genetic_algorithm::fitness::FitnessWeightedObjectives;
+/// use genetic_algorithm::genotype::BinaryGenotype;
+///
+/// let fitness = FitnessWeightedObjectives::<BinaryGenotype>::new(vec![])
+///     .with_objective(|c| c.genes.iter().filter(|&v| *v).count() as isize, 1.0)
+///     .with_objective(|c| c.genes.first().map_or(0, |&v| v as isize), 0.5);
+/// ```
+#[derive(Clone)]
+pub struct WeightedObjectives<G: Genotype> {
+    _phantom: PhantomData<G>,
+    pub objectives: Vec<(WeightedObjective<G::Allele>, f64)>,
+    /// The fixed (min, max) bounds per objective, for the normalized objectives
+    pub objective_bounds: Vec<Option<(FitnessValue, FitnessValue)>>,
+}
+
+impl<G: Genotype> WeightedObjectives<G> {
+    /// The range each normalized objective is scaled to, before weighting. Large enough to
+    /// survive the rounding to [FitnessValue]
+    pub const NORMALIZED_RANGE: f64 = 1000.0;
+
+    #[allow(clippy::type_complexity)]
+    pub fn new(
+        objectives: Vec<(
+            Box<dyn Fn(&Chromosome<G::Allele>) -> FitnessValue + Send + Sync>,
+            f64,
+        )>,
+    ) -> Self {
+        let objective_bounds = vec![None; objectives.len()];
+        Self {
+            _phantom: PhantomData,
+            objectives: objectives
+                .into_iter()
+                .map(|(objective, weight)| (Arc::from(objective), weight))
+                .collect(),
+            objective_bounds,
+        }
+    }
+    pub fn with_objective<O>(mut self, objective: O, weight: f64) -> Self
+    where
+        O: Fn(&Chromosome<G::Allele>) -> FitnessValue + Send + Sync + 'static,
+    {
+        self.objectives.push((Arc::new(objective), weight));
+        self.objective_bounds.push(None);
+        self
+    }
+    /// Add an objective which is normalized from the fixed (min, max) bounds to the
+    /// [NORMALIZED_RANGE](Self::NORMALIZED_RANGE) before weighting. Values outside the bounds are
+    /// clamped. Bounds without spread (min >= max) normalize to zero
+    pub fn with_normalized_objective<O>(
+        mut self,
+        objective: O,
+        weight: f64,
+        bounds: (FitnessValue, FitnessValue),
+    ) -> Self
+    where
+        O: Fn(&Chromosome<G::Allele>) -> FitnessValue + Send + Sync + 'static,
+    {
+        self.objectives.push((Arc::new(objective), weight));
+        self.objective_bounds.push(Some(bounds));
+        self
+    }
+    /// The weighted sum of the objectives (normalized for the ones with bounds), before rounding
+    pub fn weighted_sum(&self, chromosome: &Chromosome<G::Allele>) -> f64 {
+        self.objectives
+            .iter()
+            .enumerate()
+            .map(|(index, (objective, weight))| {
+                let value = objective(chromosome);
+                match self.objective_bounds.get(index).copied().flatten() {
+                    Some((min, max)) if max > min => {
+                        (value.clamp(min, max) - min) as f64 / (max - min) as f64
+                            * Self::NORMALIZED_RANGE
+                            * weight
+                    }
+                    Some(_) => 0.0,
+                    None => value as f64 * weight,
+                }
+            })
+            .sum()
+    }
+}
+
+impl<G: Genotype> fmt::Debug for WeightedObjectives<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeightedObjectives")
+            .field(
+                "weights",
+                &self.objectives.iter().map(|(_, w)| *w).collect::<Vec<_>>(),
+            )
+            .field("objective_bounds", &self.objective_bounds)
+            .finish()
+    }
+}
+
+impl<G: Genotype> Fitness for WeightedObjectives<G> {
+    type Genotype = G;
+    fn calculate_for_chromosome(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        _genotype: &FitnessGenotype<Self>,
+    ) -> Option<FitnessValue> {
+        Some(self.weighted_sum(chromosome).round() as FitnessValue)
+    }
+}
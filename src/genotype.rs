@@ -1,34 +1,91 @@
 //! The search space for the algorithm.
 mod binary;
 mod builder;
+mod gray_code;
+mod latin_square;
 mod list;
+mod masked_value;
 mod multi_list;
 mod multi_range;
 mod multi_unique;
 mod mutation_type;
+mod one_hot;
+mod permutation_order;
 mod range;
+mod simplex;
 mod unique;
 
 pub use self::binary::Binary as BinaryGenotype;
 pub use self::builder::{
     Builder as GenotypeBuilder, TryFromBuilderError as TryFromGenotypeBuilderError,
 };
+pub use self::gray_code::GrayCode as GrayCodeGenotype;
+pub use self::latin_square::LatinSquare as LatinSquareGenotype;
 pub use self::list::List as ListGenotype;
+pub use self::masked_value::MaskedValue as MaskedValueGenotype;
 pub use self::multi_list::MultiList as MultiListGenotype;
 pub use self::multi_range::MultiRange as MultiRangeGenotype;
 pub use self::multi_unique::MultiUnique as MultiUniqueGenotype;
 pub use self::mutation_type::MutationType;
+pub use self::one_hot::OneHot as OneHotGenotype;
+pub(crate) use self::permutation_order::PermutationIndexShuffle;
+pub use self::permutation_order::PermutationOrder;
 pub use self::range::Range as RangeGenotype;
+pub use self::simplex::Simplex as SimplexGenotype;
 pub use self::unique::Unique as UniqueGenotype;
 
 pub use crate::allele::{Allele, RangeAllele};
-use crate::chromosome::{Chromosome, Genes};
+use crate::chromosome::{Chromosome, Genes, GenesHash};
 pub use crate::impl_allele;
 use crate::population::Population;
 use itertools::Itertools;
 use num::BigUint;
+use rand::distributions::{Distribution, WeightedIndex};
 use rand::Rng;
+use rayon::iter::{FlatMapIter, IterBridge, ParallelBridge, ParallelIterator};
 use std::fmt;
+use std::ops::Range;
+
+/// The weighted gene index sampler for the genes_mutation_weights. Err if the weights don't match
+/// the genes_size, or are not valid for a [WeightedIndex] (negative or all zero)
+pub(crate) fn genes_mutation_weighted_index(
+    genes_mutation_weights: Option<&Vec<f32>>,
+    genes_size: usize,
+) -> Result<Option<WeightedIndex<f32>>, ()> {
+    match genes_mutation_weights {
+        None => Ok(None),
+        Some(weights) if weights.len() != genes_size => Err(()),
+        Some(weights) => WeightedIndex::new(weights).map(Some).map_err(|_| ()),
+    }
+}
+
+/// Sample the gene indices for mutation weighted by the genes_mutation_weights, like
+/// [sample_gene_indices](Genotype::sample_gene_indices). Without duplicates, at most the genes
+/// with a positive weight are sampled
+pub(crate) fn sample_weighted_gene_indices<R: Rng>(
+    genes_mutation_weights: &[f32],
+    weighted_index: &WeightedIndex<f32>,
+    count: usize,
+    allow_duplicates: bool,
+    rng: &mut R,
+) -> Vec<usize> {
+    if allow_duplicates {
+        (0..count).map(|_| weighted_index.sample(rng)).collect()
+    } else {
+        let positive_count = genes_mutation_weights
+            .iter()
+            .filter(|weight| **weight > 0.0)
+            .count();
+        rand::seq::index::sample_weighted(
+            rng,
+            genes_mutation_weights.len(),
+            |index| genes_mutation_weights[index] as f64,
+            count.min(positive_count),
+        )
+        .unwrap()
+        .into_vec()
+    }
+}
 
 /// Standard genotype, suitable for [Evolve](crate::strategy::evolve::Evolve).
 /// Each implemented genotype handles its own random genes initialization and mutation.
@@ -59,6 +116,30 @@ pub trait Genotype:
         chromosome: &mut Chromosome<Self::Allele>,
         rng: &mut R,
     );
+    /// Mutate the genes at the provided indices, where the mutation itself is the same as in
+    /// `mutate_chromosome_genes`. Used when the caller controls the mutated loci. Defaults to
+    /// `mutate_chromosome_genes` with a mutation per index, ignoring the loci, so implement it
+    /// for genotypes used with loci controlling mutations.
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        self.mutate_chromosome_genes(indices.len(), true, chromosome, rng);
+    }
+
+    /// Borrow the genes as slice, for fitness calculation without cloning the genes
+    fn genes_slice<'a>(&self, chromosome: &'a Chromosome<Self::Allele>) -> &'a [Self::Allele] {
+        chromosome.genes.as_slice()
+    }
+    /// Iterate over the genes by reference, for fitness calculation without cloning the genes
+    fn genes_iter<'a>(
+        &self,
+        chromosome: &'a Chromosome<Self::Allele>,
+    ) -> std::slice::Iter<'a, Self::Allele> {
+        chromosome.genes.iter()
+    }
 
     fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>);
     fn seed_genes_list(&self) -> &Vec<Genes<Self::Allele>>;
@@ -76,9 +157,22 @@ pub trait Genotype:
         self.reset_scale_index();
     }
 
+    /// Calculate the genes_hash of the chromosome, see [Chromosome::calculate_hash]. Overridden by
+    /// the range genotypes for the quantized hashing of `with_genes_key_precision`
+    fn calculate_genes_hash(&self, chromosome: &Chromosome<Self::Allele>) -> GenesHash {
+        chromosome.calculate_hash()
+    }
+    /// Reset the age and fitness_score of the chromosome and store the genes_hash (when
+    /// genes_hashing), see [Chromosome::reset_metadata]
+    fn reset_chromosome_metadata(&self, chromosome: &mut Chromosome<Self::Allele>) {
+        chromosome.reset_metadata(false);
+        if self.genes_hashing() {
+            chromosome.set_genes_hash(Some(self.calculate_genes_hash(chromosome)));
+        }
+    }
     fn chromosome_constructor_random<R: Rng>(&self, rng: &mut R) -> Chromosome<Self::Allele> {
         let mut chromosome = Chromosome::new(self.random_genes_factory(rng));
-        chromosome.reset_metadata(self.genes_hashing());
+        self.reset_chromosome_metadata(&mut chromosome);
         chromosome
     }
     fn chromosome_constructor_genes(
@@ -86,7 +180,7 @@ pub trait Genotype:
         genes: &Genes<Self::Allele>,
     ) -> Chromosome<Self::Allele> {
         let mut chromosome = Chromosome::new(genes.clone());
-        chromosome.reset_metadata(self.genes_hashing());
+        self.reset_chromosome_metadata(&mut chromosome);
         chromosome
     }
     fn population_constructor<R: Rng>(
@@ -155,6 +249,24 @@ pub trait Genotype:
 }
 
 /// Genotype suitable for [Evolve](crate::strategy::evolve::Evolve).
+/// The permutation preserving crossover methods of
+/// [crossover_chromosome_ordered](EvolveGenotype::crossover_chromosome_ordered):
+/// * [CrossoverOrderedMethod::Order]: Order Crossover (OX1), each child keeps a random slice of
+///   its own parent and fills the remaining positions with the genes of the other parent in their
+///   order of appearance (starting after the slice and wrapping around)
+/// * [CrossoverOrderedMethod::PartiallyMapped]: Partially Mapped Crossover (PMX), each child keeps
+///   a random slice of its own parent and takes the other positions from the other parent, mapping
+///   the genes which are already in the slice through the slice's position mapping
+/// * [CrossoverOrderedMethod::Cycle]: Cycle Crossover (CX), the positions are partitioned into
+///   cycles between the parents, the children take the alternating cycles from alternating parents,
+///   so each gene keeps the position of one of the parents
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CrossoverOrderedMethod {
+    Order,
+    PartiallyMapped,
+    Cycle,
+}
+
 pub trait EvolveGenotype: Genotype {
     /// Crossover genes between a pair of chromosomes.
     /// Choose between allowing duplicates or not (~2x slower).
@@ -178,6 +290,18 @@ pub trait EvolveGenotype: Genotype {
         mother: &mut Chromosome<Self::Allele>,
         rng: &mut R,
     );
+    /// Permutation preserving crossover between a pair of chromosomes, for genotypes where the
+    /// genes are a permutation of the alleles (see [CrossoverOrderedMethod]).
+    /// panics if there is no ordered crossover
+    fn crossover_chromosome_ordered<R: Rng>(
+        &self,
+        _method: CrossoverOrderedMethod,
+        _father: &mut Chromosome<Self::Allele>,
+        _mother: &mut Chromosome<Self::Allele>,
+        _rng: &mut R,
+    ) {
+        panic!("Genotype does not support ordered crossover")
+    }
     /// to guard against invalid crossover strategies which break the internal consistency
     /// of the genes, unique genotypes can't simply exchange genes without gene duplication issues
     fn has_crossover_indexes(&self) -> bool {
@@ -188,6 +312,57 @@ pub trait EvolveGenotype: Genotype {
     fn has_crossover_points(&self) -> bool {
         false
     }
+    /// to guard against invalid crossover strategies, the ordered crossovers are only valid for
+    /// genotypes where the genes are a permutation
+    fn has_crossover_ordered(&self) -> bool {
+        false
+    }
+    /// to guard against invalid crossover strategies which swap genes between the parents
+    /// directly, bypassing the genotype. Only valid for genotypes without constraints between the
+    /// genes (e.g. not the sum of the simplex, the single hot gene per group or the latin square)
+    fn has_raw_gene_swap(&self) -> bool {
+        false
+    }
+}
+
+/// Genotype with a distance metric between genes, used for niching (see
+/// [SelectSharing](crate::select::SelectSharing)). Hamming distance for
+/// [BinaryGenotype], [ListGenotype] and [MultiListGenotype], euclidean distance for
+/// [RangeGenotype] and [MultiRangeGenotype] and swap distance (the minimal number of swaps to
+/// transform one permutation into the other) for [UniqueGenotype].
+pub trait GenotypeDistance: Genotype {
+    /// The distance between two sets of genes of genes_size, zero for equal genes
+    fn genes_distance(&self, genes_a: &[Self::Allele], genes_b: &[Self::Allele]) -> f64;
+
+    fn chromosome_distance(
+        &self,
+        chromosome_a: &Chromosome<Self::Allele>,
+        chromosome_b: &Chromosome<Self::Allele>,
+    ) -> f64 {
+        self.genes_distance(&chromosome_a.genes, &chromosome_b.genes)
+    }
+}
+
+/// The number of genes which differ between the two sets of genes
+pub(crate) fn hamming_distance<T: PartialEq>(genes_a: &[T], genes_b: &[T]) -> f64 {
+    genes_a
+        .iter()
+        .zip(genes_b.iter())
+        .filter(|(gene_a, gene_b)| gene_a != gene_b)
+        .count() as f64
+}
+
+/// The euclidean distance between the two sets of numeric genes
+pub(crate) fn euclidean_distance<T: num::ToPrimitive>(genes_a: &[T], genes_b: &[T]) -> f64 {
+    genes_a
+        .iter()
+        .zip(genes_b.iter())
+        .map(|(gene_a, gene_b)| {
+            let delta = gene_a.to_f64().unwrap_or(0.0) - gene_b.to_f64().unwrap_or(0.0);
+            delta * delta
+        })
+        .sum::<f64>()
+        .sqrt()
 }
 
 /// Genotype suitable for [HillClimb](crate::strategy::hill_climb::HillClimb).
@@ -201,6 +376,24 @@ pub trait HillClimbGenotype: Genotype {
         _rng: &mut R,
     );
 
+    /// a random sample of sample_size neighbouring mutations of the chromosome, without building
+    /// the full neighbourhood. Each neighbour is a single random gene mutation (like
+    /// HillClimbVariant::Stochastic), sampled with replacement.
+    /// used in HillClimbVariant::SteepestAscent with a neighbour_sample_size
+    fn fill_sampled_neighbouring_population<R: Rng>(
+        &self,
+        chromosome: &Chromosome<Self::Allele>,
+        population: &mut Population<Self::Allele>,
+        sample_size: usize,
+        rng: &mut R,
+    ) {
+        for _ in 0..sample_size {
+            let mut new_chromosome = population.new_chromosome(chromosome);
+            self.mutate_chromosome_genes(1, true, &mut new_chromosome, rng);
+            population.chromosomes.push(new_chromosome);
+        }
+    }
+
     /// chromosome neighbours size for the all possible neighbouring mutation combinations
     fn neighbouring_population_size(&self) -> BigUint;
 
@@ -209,6 +402,32 @@ pub trait HillClimbGenotype: Genotype {
     }
 }
 
+/// The parallel iterator of
+/// [chromosome_permutations_par_iter](PermutateGenotype::chromosome_permutations_par_iter),
+/// yielding the chromosome permutations with their permutation index (unordered)
+pub type ChromosomePermutationsParIter<'a, A> = FlatMapIter<
+    IterBridge<ChromosomePermutationsBatches<'a, A>>,
+    fn(Vec<(usize, Chromosome<A>)>) -> std::vec::IntoIter<(usize, Chromosome<A>)>,
+>;
+
+/// Batches the enumerated chromosome permutations for bridging to rayon, see
+/// [ChromosomePermutationsParIter]
+pub struct ChromosomePermutationsBatches<'a, A: Allele> {
+    iter: Box<dyn Iterator<Item = (usize, Chromosome<A>)> + Send + 'a>,
+    batch_size: usize,
+}
+impl<'a, A: Allele> Iterator for ChromosomePermutationsBatches<'a, A> {
+    type Item = Vec<(usize, Chromosome<A>)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch: Vec<_> = self.iter.by_ref().take(self.batch_size).collect();
+        if batch.is_empty() {
+            None
+        } else {
+            Some(batch)
+        }
+    }
+}
+
 /// Genotype suitable for [Permutate](crate::strategy::permutate::Permutate).
 /// Not all genotypes are permutable, only countable ones (e.g. range genotypes cannot be permutated, unless scaled).
 pub trait PermutateGenotype: Genotype {
@@ -218,6 +437,30 @@ pub trait PermutateGenotype: Genotype {
         _chromosome: Option<&Chromosome<Self::Allele>>,
     ) -> Box<dyn Iterator<Item = Chromosome<Self::Allele>> + Send + 'a>;
 
+    /// parallel chromosome iterator for the gene combinations within the permutation_indices
+    /// (positions in the order of [chromosome_permutations_into_iter](Self::chromosome_permutations_into_iter)),
+    /// yielded together with their permutation index. The sequential iterator is handed to the
+    /// rayon threads in batches of batch_size, so the bridging overhead is per batch instead of per
+    /// chromosome. Used for the fully parallel [Permutate](crate::strategy::permutate::Permutate)
+    fn chromosome_permutations_par_iter<'a>(
+        &'a self,
+        chromosome: Option<&Chromosome<Self::Allele>>,
+        permutation_indices: Range<usize>,
+        batch_size: usize,
+    ) -> ChromosomePermutationsParIter<'a, Self::Allele> {
+        let iter = self
+            .chromosome_permutations_into_iter(chromosome)
+            .enumerate()
+            .skip(permutation_indices.start)
+            .take(permutation_indices.len());
+        ChromosomePermutationsBatches {
+            iter: Box::new(iter),
+            batch_size: batch_size.max(1),
+        }
+        .par_bridge()
+        .flat_map_iter(Vec::into_iter as fn(_) -> _)
+    }
+
     /// chromosome iterator size for the all possible gene combinations for [Permutate](crate::strategy::permutate::Permutate)
     fn chromosome_permutations_size(&self) -> BigUint;
 
@@ -1,16 +1,19 @@
 use super::builder::{Builder, TryFromBuilderError};
-use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype};
+use super::{
+    genes_mutation_weighted_index, hamming_distance, sample_weighted_gene_indices, EvolveGenotype,
+    Genotype, GenotypeDistance, HillClimbGenotype, MutationType, PermutateGenotype,
+};
 use crate::chromosome::{Chromosome, Genes};
 use crate::population::Population;
 use itertools::Itertools;
 use num::BigUint;
-use rand::distributions::{Standard, Uniform};
+use rand::distributions::{Standard, Uniform, WeightedIndex};
 use rand::prelude::*;
 use std::fmt;
 
 /// Genes are a vector of booleans. On random initialization, each gene has a 50% probability of
-/// becoming true or false. Each gene has an equal probability of mutating. If a gene mutates, its
-/// value is flipped.
+/// becoming true or false. Each gene has an equal probability of mutating (unless
+/// genes_mutation_weights are provided). If a gene mutates, its value is flipped.
 ///
 /// # Example:
 /// ```
@@ -18,6 +21,7 @@ use std::fmt;
 ///
 /// let genotype = BinaryGenotype::builder()
 ///     .with_genes_size(100)
+///     .with_genes_mutation_weights(vec![1.0; 100]) // optional, defaults to None, relative mutation probability per gene
 ///     .with_genes_hashing(true) // optional, defaults to true
 ///     .with_chromosome_recycling(true) // optional, defaults to true
 ///     .build()
@@ -27,6 +31,8 @@ use std::fmt;
 pub struct Binary {
     pub genes_size: usize,
     gene_index_sampler: Uniform<usize>,
+    pub genes_mutation_weights: Option<Vec<f32>>,
+    gene_mutation_index_sampler: Option<WeightedIndex<f32>>,
     pub seed_genes_list: Vec<Vec<bool>>,
     pub genes_hashing: bool,
     pub chromosome_recycling: bool,
@@ -42,9 +48,18 @@ impl TryFrom<Builder<Self>> for Binary {
             ))
         } else {
             let genes_size = builder.genes_size.unwrap();
+            let gene_mutation_index_sampler =
+                genes_mutation_weighted_index(builder.genes_mutation_weights.as_ref(), genes_size)
+                    .map_err(|_| {
+                        TryFromBuilderError(
+                            "BinaryGenotype requires non-negative genes_mutation_weights of genes_size length with a positive sum",
+                        )
+                    })?;
             Ok(Self {
                 genes_size,
                 gene_index_sampler: Uniform::from(0..genes_size),
+                genes_mutation_weights: builder.genes_mutation_weights,
+                gene_mutation_index_sampler,
                 seed_genes_list: builder.seed_genes_list,
                 genes_hashing: builder.genes_hashing,
                 chromosome_recycling: builder.chromosome_recycling,
@@ -66,7 +81,11 @@ impl Genotype for Binary {
         self.genes_size
     }
     fn sample_gene_index<R: Rng>(&self, rng: &mut R) -> usize {
-        self.gene_index_sampler.sample(rng)
+        if let Some(weighted_index) = self.gene_mutation_index_sampler.as_ref() {
+            weighted_index.sample(rng)
+        } else {
+            self.gene_index_sampler.sample(rng)
+        }
     }
     fn sample_gene_indices<R: Rng>(
         &self,
@@ -74,7 +93,12 @@ impl Genotype for Binary {
         allow_duplicates: bool,
         rng: &mut R,
     ) -> Vec<usize> {
-        if allow_duplicates {
+        if let (Some(weights), Some(weighted_index)) = (
+            self.genes_mutation_weights.as_ref(),
+            self.gene_mutation_index_sampler.as_ref(),
+        ) {
+            sample_weighted_gene_indices(weights, weighted_index, count, allow_duplicates, rng)
+        } else if allow_duplicates {
             rng.sample_iter(self.gene_index_sampler)
                 .take(count)
                 .collect()
@@ -90,7 +114,13 @@ impl Genotype for Binary {
         chromosome: &mut Chromosome<Self::Allele>,
         rng: &mut R,
     ) {
-        if allow_duplicates {
+        if self.genes_mutation_weights.is_some() {
+            self.sample_gene_indices(number_of_mutations, allow_duplicates, rng)
+                .into_iter()
+                .for_each(|index| {
+                    chromosome.genes[index] = !chromosome.genes[index];
+                });
+        } else if allow_duplicates {
             rng.sample_iter(self.gene_index_sampler)
                 .take(number_of_mutations)
                 .for_each(|index| {
@@ -109,6 +139,17 @@ impl Genotype for Binary {
         }
         chromosome.reset_metadata(self.genes_hashing);
     }
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        _rng: &mut R,
+    ) {
+        indices.iter().for_each(|index| {
+            chromosome.genes[*index] = !chromosome.genes[*index];
+        });
+        chromosome.reset_metadata(self.genes_hashing);
+    }
     fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
         self.seed_genes_list = seed_genes_list;
     }
@@ -212,6 +253,14 @@ impl EvolveGenotype for Binary {
     fn has_crossover_points(&self) -> bool {
         true
     }
+    fn has_raw_gene_swap(&self) -> bool {
+        true
+    }
+}
+impl GenotypeDistance for Binary {
+    fn genes_distance(&self, genes_a: &[bool], genes_b: &[bool]) -> f64 {
+        hamming_distance(genes_a, genes_b)
+    }
 }
 impl HillClimbGenotype for Binary {
     fn fill_neighbouring_population<R: Rng>(
@@ -1,4 +1,4 @@
-use super::{Genotype, MutationType};
+use super::{Genotype, MutationType, PermutationOrder};
 use crate::chromosome::Genes;
 pub use crate::errors::TryFromGenotypeBuilderError as TryFromBuilderError;
 use std::ops::RangeInclusive;
@@ -31,9 +31,19 @@ pub struct Builder<G: Genotype> {
     pub allele_lists: Option<Vec<Vec<G::Allele>>>,
     pub allele_range: Option<RangeInclusive<G::Allele>>,
     pub allele_ranges: Option<Vec<RangeInclusive<G::Allele>>>,
+    pub allele_step: Option<G::Allele>,
+    pub allele_steps: Option<Vec<Option<G::Allele>>>,
+    pub allele_sum: Option<G::Allele>,
+    pub group_sizes: Option<Vec<usize>>,
+    pub integer_ranges: Option<Vec<RangeInclusive<i64>>>,
+    pub include_self_neighbour: bool,
+    pub genes_key_precision: Option<u32>,
+    pub latin_square_size: Option<usize>,
     pub mutation_type: Option<MutationType<G::Allele>>,
     pub mutation_types: Option<Vec<MutationType<G::Allele>>>,
     pub seed_genes_list: Vec<Genes<G::Allele>>,
+    pub genes_mutation_weights: Option<Vec<f32>>,
+    pub permutation_order: PermutationOrder,
     pub genes_hashing: bool,
     pub chromosome_recycling: bool,
 }
@@ -59,6 +69,27 @@ impl<G: Genotype> Builder<G> {
         self
     }
 
+    /// Generate the allele_list from a closure over the allele index, directly into the builder.
+    /// Useful for large generated sets (e.g. all primes under N), which then don't need to be
+    /// precomputed and held by the caller as well.
+    pub fn with_allele_list_fn<F: FnMut(usize) -> G::Allele>(
+        self,
+        allele_list_size: usize,
+        allele_fn: F,
+    ) -> Self {
+        self.with_allele_list((0..allele_list_size).map(allele_fn).collect())
+    }
+
+    /// Generate the allele_lists from a closure over the gene index, directly into the builder.
+    /// Sets the genes_size as well, like `with_allele_lists`.
+    pub fn with_allele_lists_fn<F: FnMut(usize) -> Vec<G::Allele>>(
+        self,
+        genes_size: usize,
+        allele_list_fn: F,
+    ) -> Self {
+        self.with_allele_lists((0..genes_size).map(allele_list_fn).collect())
+    }
+
     pub fn with_allele_range(mut self, allele_range: RangeInclusive<G::Allele>) -> Self {
         self.allele_range = Some(allele_range);
         self
@@ -70,6 +101,59 @@ impl<G: Genotype> Builder<G> {
         self
     }
 
+    /// Only used by [RangeGenotype](super::RangeGenotype). Discretize the allele_range to the grid
+    /// `start + k * allele_step` (e.g. `0..=100` with step 5), which the random initialization,
+    /// mutation, neighbouring and permutation respect
+    pub fn with_allele_step(mut self, allele_step: G::Allele) -> Self {
+        self.allele_step = Some(allele_step);
+        self
+    }
+
+    /// Only used by [MultiRangeGenotype](super::MultiRangeGenotype). Discretize the allele_range
+    /// of each gene with `Some(allele_step)` to the grid `start + k * allele_step`, leaving the
+    /// genes with `None` continuous
+    pub fn with_allele_steps(mut self, allele_steps: Vec<Option<G::Allele>>) -> Self {
+        self.allele_steps = Some(allele_steps);
+        self
+    }
+
+    pub fn with_allele_sum(mut self, allele_sum: G::Allele) -> Self {
+        self.allele_sum = Some(allele_sum);
+        self
+    }
+
+    pub fn with_group_sizes(mut self, group_sizes: Vec<usize>) -> Self {
+        self.genes_size = Some(group_sizes.iter().sum());
+        self.group_sizes = Some(group_sizes);
+        self
+    }
+
+    /// Only used by [GrayCodeGenotype](super::GrayCodeGenotype), which derives the genes_size
+    /// from the number of bits per integer_range
+    pub fn with_integer_ranges(mut self, integer_ranges: Vec<RangeInclusive<i64>>) -> Self {
+        self.integer_ranges = Some(integer_ranges);
+        self
+    }
+
+    pub fn with_include_self_neighbour(mut self, include_self_neighbour: bool) -> Self {
+        self.include_self_neighbour = include_self_neighbour;
+        self
+    }
+
+    /// The number of decimals the float genes are rounded to before hashing them into the
+    /// genes_key. Only used by [RangeGenotype](super::RangeGenotype) and
+    /// [MultiRangeGenotype](super::MultiRangeGenotype), ignored by the other genotypes
+    pub fn with_genes_key_precision(mut self, decimals: u32) -> Self {
+        self.genes_key_precision = Some(decimals);
+        self
+    }
+
+    pub fn with_latin_square_size(mut self, latin_square_size: usize) -> Self {
+        self.genes_size = Some(latin_square_size * latin_square_size);
+        self.latin_square_size = Some(latin_square_size);
+        self
+    }
+
     pub fn with_mutation_type(mut self, mutation_type: MutationType<G::Allele>) -> Self {
         self.mutation_type = Some(mutation_type);
         self
@@ -149,6 +233,24 @@ impl<G: Genotype> Builder<G> {
         self
     }
 
+    /// Relative mutation probability per gene (of genes_size length), so some genes mutate more
+    /// often than others (e.g. fine-tuning parameters at the end). Genes with a zero weight never
+    /// mutate. Only affects the sampled gene indices of the mutation, not the crossover. Only
+    /// used by [BinaryGenotype](super::BinaryGenotype), [ListGenotype](super::ListGenotype) and
+    /// [RangeGenotype](super::RangeGenotype)
+    pub fn with_genes_mutation_weights(mut self, genes_mutation_weights: Vec<f32>) -> Self {
+        self.genes_mutation_weights = Some(genes_mutation_weights);
+        self
+    }
+
+    /// The order of the chromosome permutations for the Permutate strategy (see
+    /// [PermutationOrder]). Only used by [MultiListGenotype](super::MultiListGenotype) and
+    /// [MultiUniqueGenotype](super::MultiUniqueGenotype)
+    pub fn with_permutation_order(mut self, permutation_order: PermutationOrder) -> Self {
+        self.permutation_order = permutation_order;
+        self
+    }
+
     pub fn with_genes_hashing(mut self, genes_hashing: bool) -> Self {
         self.genes_hashing = genes_hashing;
         self
@@ -172,9 +274,19 @@ impl<G: Genotype> Default for Builder<G> {
             allele_lists: None,
             allele_range: None,
             allele_ranges: None,
+            allele_step: None,
+            allele_steps: None,
+            allele_sum: None,
+            group_sizes: None,
+            integer_ranges: None,
+            include_self_neighbour: false,
+            genes_key_precision: None,
+            latin_square_size: None,
             mutation_type: None,
             mutation_types: None,
             seed_genes_list: vec![],
+            genes_mutation_weights: None,
+            permutation_order: PermutationOrder::Nested,
             genes_hashing: true,
             chromosome_recycling: true,
         }
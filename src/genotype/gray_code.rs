@@ -0,0 +1,400 @@
+use super::builder::{Builder, TryFromBuilderError};
+use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype};
+use crate::chromosome::{Chromosome, Genes};
+use crate::population::Population;
+use itertools::Itertools;
+use num::BigUint;
+use rand::distributions::{Distribution, Uniform};
+use rand::prelude::*;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// Genes are a concatinated vector of booleans, grouped into Gray coded bit strings, one for each
+/// integer variable. The number of bits of each group is the minimum to cover its integer_range,
+/// so the genes_size is derived. Adjacent integer values differ in a single bit (no Hamming
+/// cliffs), so a single bit mutation is able to step to a neighbouring value. Decode the genes
+/// with [decode](Self::decode) in the fitness function.
+///
+/// On random initialization, each variable gets a uniformly random value within its range. Each
+/// gene has an equal probability of mutating. If a gene mutates, its value is flipped, like the
+/// [BinaryGenotype](super::BinaryGenotype), and the crossovers swap single genes or cut at any
+/// point as well. When the integer_range size is not a power of two, the bit strings beyond the
+/// range end wrap around to the range start (modulo the range size), so each integer value is
+/// decoded from one or two bit strings and none is heavily favoured. The permutations only iterate
+/// over the valid values.
+///
+/// # Example:
+/// ```
+/// use genetic_algorithm::genotype::{Genotype, GrayCodeGenotype};
+///
+/// let genotype = GrayCodeGenotype::builder()
+///     .with_integer_ranges(vec![0..=7, -10..=10])
+///     .with_genes_hashing(true) // optional, defaults to true
+///     .with_chromosome_recycling(true) // optional, defaults to true
+///     .build()
+///     .unwrap();
+///
+/// // chromosome genes example: [false,true,true, false,false,true,true,false]
+/// // two Gray coded groups of 3 and 5 bits, for integer values 2 and -6
+/// assert_eq!(genotype.genes_size(), 8);
+/// assert_eq!(
+///     genotype.decode(&[false, true, true, false, false, true, true, false]),
+///     vec![2, -6]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct GrayCode {
+    pub genes_size: usize,
+    pub integer_ranges: Vec<RangeInclusive<i64>>,
+    pub bit_sizes: Vec<usize>,
+    pub bit_index_offsets: Vec<usize>,
+    gene_index_sampler: Uniform<usize>,
+    pub seed_genes_list: Vec<Vec<bool>>,
+    pub genes_hashing: bool,
+    pub chromosome_recycling: bool,
+}
+
+impl TryFrom<Builder<Self>> for GrayCode {
+    type Error = TryFromBuilderError;
+
+    fn try_from(builder: Builder<Self>) -> Result<Self, Self::Error> {
+        if builder.integer_ranges.is_none() {
+            Err(TryFromBuilderError(
+                "GrayCodeGenotype requires integer_ranges",
+            ))
+        } else if builder.integer_ranges.as_ref().unwrap().is_empty() {
+            Err(TryFromBuilderError(
+                "GrayCodeGenotype requires non-empty integer_ranges",
+            ))
+        } else if builder
+            .integer_ranges
+            .as_ref()
+            .unwrap()
+            .iter()
+            .any(|range| range.start() >= range.end())
+        {
+            Err(TryFromBuilderError(
+                "GrayCodeGenotype requires integer_ranges with start < end",
+            ))
+        } else {
+            let integer_ranges = builder.integer_ranges.unwrap();
+            let bit_sizes: Vec<usize> = integer_ranges
+                .iter()
+                .map(|range| (u64::BITS - Self::span(range).leading_zeros()) as usize)
+                .collect();
+            let bit_index_offsets = bit_sizes.iter().fold(vec![0], |mut acc, size| {
+                acc.push(*acc.last().unwrap() + size);
+                acc
+            });
+            let genes_size = bit_sizes.iter().sum();
+            if builder
+                .seed_genes_list
+                .iter()
+                .any(|genes| genes.len() != genes_size)
+            {
+                return Err(TryFromBuilderError(
+                    "GrayCodeGenotype requires seed_genes_list of genes_size length",
+                ));
+            }
+
+            Ok(Self {
+                genes_size,
+                integer_ranges,
+                bit_sizes,
+                bit_index_offsets,
+                gene_index_sampler: Uniform::from(0..genes_size),
+                seed_genes_list: builder.seed_genes_list,
+                genes_hashing: builder.genes_hashing,
+                chromosome_recycling: builder.chromosome_recycling,
+            })
+        }
+    }
+}
+
+impl GrayCode {
+    /// The integer values of the genes, one for each integer_range. The bit strings beyond the
+    /// range end wrap around modulo the range size
+    pub fn decode(&self, genes: &[bool]) -> Vec<i64> {
+        self.integer_ranges
+            .iter()
+            .enumerate()
+            .map(|(index, range)| {
+                let offset = self.bit_index_offsets[index];
+                let bits = &genes[offset..offset + self.bit_sizes[index]];
+                let binary = Self::gray_to_binary(bits);
+                let value_offset = Self::span(range)
+                    .checked_add(1)
+                    .map_or(binary, |range_size| binary % range_size);
+                (*range.start() as i128 + value_offset as i128) as i64
+            })
+            .collect()
+    }
+    /// The genes of the integer values, one for each integer_range (clamped to the range). The
+    /// inverse of [decode](Self::decode), e.g. for the seed_genes_list
+    pub fn encode(&self, values: &[i64]) -> Vec<bool> {
+        self.integer_ranges
+            .iter()
+            .zip(values)
+            .enumerate()
+            .flat_map(|(index, (range, value))| {
+                let value_offset = (*value.clamp(range.start(), range.end()) as i128
+                    - *range.start() as i128) as u64;
+                Self::binary_to_gray(value_offset, self.bit_sizes[index])
+            })
+            .collect()
+    }
+
+    fn mutation_type(&self) -> &MutationType<bool> {
+        &MutationType::Random
+    }
+    /// The number of values in the range minus one
+    fn span(range: &RangeInclusive<i64>) -> u64 {
+        (*range.end() as i128 - *range.start() as i128) as u64
+    }
+    /// Most significant bit first
+    fn binary_to_gray(value: u64, bit_size: usize) -> impl Iterator<Item = bool> {
+        let gray = value ^ (value >> 1);
+        (0..bit_size).rev().map(move |bit| (gray >> bit) & 1 == 1)
+    }
+    /// Most significant bit first
+    fn gray_to_binary(bits: &[bool]) -> u64 {
+        let mut previous_bit = false;
+        bits.iter().fold(0, |value, gray_bit| {
+            previous_bit ^= *gray_bit;
+            (value << 1) | previous_bit as u64
+        })
+    }
+}
+
+impl Genotype for GrayCode {
+    type Allele = bool;
+
+    fn genes_size(&self) -> usize {
+        self.genes_size
+    }
+    fn sample_gene_index<R: Rng>(&self, rng: &mut R) -> usize {
+        self.gene_index_sampler.sample(rng)
+    }
+    fn sample_gene_indices<R: Rng>(
+        &self,
+        count: usize,
+        allow_duplicates: bool,
+        rng: &mut R,
+    ) -> Vec<usize> {
+        if allow_duplicates {
+            rng.sample_iter(self.gene_index_sampler)
+                .take(count)
+                .collect()
+        } else {
+            rand::seq::index::sample(rng, self.genes_size, count.min(self.genes_size)).into_vec()
+        }
+    }
+
+    fn mutate_chromosome_genes<R: Rng>(
+        &self,
+        number_of_mutations: usize,
+        allow_duplicates: bool,
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        self.sample_gene_indices(number_of_mutations, allow_duplicates, rng)
+            .into_iter()
+            .for_each(|index| {
+                chromosome.genes[index] = !chromosome.genes[index];
+            });
+        chromosome.reset_metadata(self.genes_hashing);
+    }
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        _rng: &mut R,
+    ) {
+        indices.iter().for_each(|index| {
+            chromosome.genes[*index] = !chromosome.genes[*index];
+        });
+        chromosome.reset_metadata(self.genes_hashing);
+    }
+    fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
+        self.seed_genes_list = seed_genes_list;
+    }
+    fn seed_genes_list(&self) -> &Vec<Genes<Self::Allele>> {
+        &self.seed_genes_list
+    }
+    fn random_genes_factory<R: Rng>(&self, rng: &mut R) -> Vec<bool> {
+        if self.seed_genes_list.is_empty() {
+            self.integer_ranges
+                .iter()
+                .zip(self.bit_sizes.iter())
+                .flat_map(|(range, bit_size)| {
+                    Self::binary_to_gray(rng.gen_range(0..=Self::span(range)), *bit_size)
+                })
+                .collect()
+        } else {
+            self.seed_genes_list.choose(rng).unwrap().clone()
+        }
+    }
+    fn genes_capacity(&self) -> usize {
+        self.genes_size
+    }
+    fn genes_hashing(&self) -> bool {
+        self.genes_hashing
+    }
+    fn chromosome_recycling(&self) -> bool {
+        self.chromosome_recycling
+    }
+}
+
+impl EvolveGenotype for GrayCode {
+    fn crossover_chromosome_genes<R: Rng>(
+        &self,
+        number_of_crossovers: usize,
+        allow_duplicates: bool,
+        father: &mut Chromosome<Self::Allele>,
+        mother: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        self.sample_gene_indices(number_of_crossovers, allow_duplicates, rng)
+            .into_iter()
+            .for_each(|index| {
+                std::mem::swap(&mut father.genes[index], &mut mother.genes[index]);
+            });
+        mother.reset_metadata(self.genes_hashing);
+        father.reset_metadata(self.genes_hashing);
+    }
+    fn crossover_chromosome_points<R: Rng>(
+        &self,
+        number_of_crossovers: usize,
+        allow_duplicates: bool,
+        father: &mut Chromosome<Self::Allele>,
+        mother: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        if allow_duplicates {
+            rng.sample_iter(self.gene_index_sampler)
+                .take(number_of_crossovers)
+                .for_each(|index| {
+                    let mother_back = &mut mother.genes[index..];
+                    let father_back = &mut father.genes[index..];
+                    father_back.swap_with_slice(mother_back);
+                });
+        } else {
+            rand::seq::index::sample(
+                rng,
+                self.genes_size(),
+                number_of_crossovers.min(self.genes_size()),
+            )
+            .iter()
+            .sorted_unstable()
+            .chunks(2)
+            .into_iter()
+            .for_each(|mut chunk| match (chunk.next(), chunk.next()) {
+                (Some(start_index), Some(end_index)) => {
+                    let mother_back = &mut mother.genes[start_index..end_index];
+                    let father_back = &mut father.genes[start_index..end_index];
+                    father_back.swap_with_slice(mother_back);
+                }
+                (Some(start_index), _) => {
+                    let mother_back = &mut mother.genes[start_index..];
+                    let father_back = &mut father.genes[start_index..];
+                    father_back.swap_with_slice(mother_back);
+                }
+                _ => (),
+            });
+        }
+        mother.reset_metadata(self.genes_hashing);
+        father.reset_metadata(self.genes_hashing);
+    }
+
+    fn has_crossover_indexes(&self) -> bool {
+        true
+    }
+    fn has_crossover_points(&self) -> bool {
+        true
+    }
+    fn has_raw_gene_swap(&self) -> bool {
+        true
+    }
+}
+impl HillClimbGenotype for GrayCode {
+    fn fill_neighbouring_population<R: Rng>(
+        &self,
+        chromosome: &Chromosome<Self::Allele>,
+        population: &mut Population<Self::Allele>,
+        _rng: &mut R,
+    ) {
+        (0..self.genes_size).for_each(|index| {
+            let mut new_chromosome = population.new_chromosome(chromosome);
+            new_chromosome.genes[index] = !new_chromosome.genes[index];
+            new_chromosome.reset_metadata(self.genes_hashing);
+            population.chromosomes.push(new_chromosome);
+        });
+    }
+
+    fn neighbouring_population_size(&self) -> BigUint {
+        BigUint::from(self.genes_size)
+    }
+}
+
+impl PermutateGenotype for GrayCode {
+    fn chromosome_permutations_into_iter<'a>(
+        &'a self,
+        _chromosome: Option<&Chromosome<Self::Allele>>,
+    ) -> Box<dyn Iterator<Item = Chromosome<Self::Allele>> + Send + 'a> {
+        if self.seed_genes_list.is_empty() {
+            Box::new(
+                self.integer_ranges
+                    .iter()
+                    .cloned()
+                    .multi_cartesian_product()
+                    .map(|values| Chromosome::new(self.encode(&values))),
+            )
+        } else {
+            Box::new(
+                self.seed_genes_list
+                    .clone()
+                    .into_iter()
+                    .map(Chromosome::new),
+            )
+        }
+    }
+    fn chromosome_permutations_size(&self) -> BigUint {
+        if self.seed_genes_list.is_empty() {
+            self.integer_ranges
+                .iter()
+                .map(|range| BigUint::from(Self::span(range)) + 1u8)
+                .product()
+        } else {
+            self.seed_genes_list.len().into()
+        }
+    }
+    fn allows_permutation(&self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for GrayCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "genotype:")?;
+        writeln!(f, "  genes_size: {}", self.genes_size)?;
+        writeln!(f, "  integer_ranges: {:?}", self.integer_ranges)?;
+        writeln!(f, "  bit_sizes: {:?}", self.bit_sizes)?;
+        writeln!(f, "  mutation_type: {:?}", self.mutation_type())?;
+        writeln!(
+            f,
+            "  chromosome_permutations_size: {}",
+            self.chromosome_permutations_size_report()
+        )?;
+        writeln!(
+            f,
+            "  neighbouring_population_size: {}",
+            self.neighbouring_population_size_report()
+        )?;
+        writeln!(
+            f,
+            "  expected_number_of_sampled_index_duplicates: {}",
+            self.expected_number_of_sampled_index_duplicates_report()
+        )?;
+        writeln!(f, "  seed_genes: {:?}", self.seed_genes_list.len())
+    }
+}
@@ -0,0 +1,362 @@
+use super::builder::{Builder, TryFromBuilderError};
+use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType};
+use crate::chromosome::{Chromosome, Genes};
+use crate::population::Population;
+use itertools::Itertools;
+use num::BigUint;
+use rand::distributions::{Distribution, Uniform};
+use rand::prelude::*;
+use std::fmt;
+
+/// Genes form an n×n grid (row-major, the gene for row `r` and column `c` is at index `r * n +
+/// c`), where each row and each column is a permutation of `0..n` (a Latin square). This supports
+/// scheduling and experimental-design problems directly. The genes_size is derived to be `n * n`.
+/// On random initialization, the cyclic square `(r + c) % n` is shuffled by random row, column and
+/// symbol permutations.
+///
+/// All operations preserve the Latin square constraint:
+/// * A mutation swaps two random rows, two random columns or two random symbols, or applies a
+///   row cycle switch (with equal probability). Mutating at a specific gene index applies a row
+///   cycle switch between its row and another random row, starting at its column
+/// * The neighbours for [HillClimb](crate::strategy::hill_climb::HillClimb) are all row swaps,
+///   column swaps, symbol swaps and row cycle switches
+///
+/// The row, column and symbol swaps keep the square within its isotopy class (so they alone would
+/// never leave the cyclic class of the random initialization). A row cycle switch exchanges the
+/// symbols of two rows along a single cycle of columns, which does leave the class.
+///
+/// # Panics
+///
+/// Does not support crossover, as there is no simple operation combining two Latin squares which
+/// inherits from both parents. Use [CrossoverClone](crate::crossover::CrossoverClone) or
+/// [CrossoverRejuvenate](crate::crossover::CrossoverRejuvenate) to evolve by mutation only. Will
+/// panic when tried directly, but [EvolveBuilder](crate::strategy::evolve::EvolveBuilder) rejects
+/// these crossovers with a build error.
+///
+/// # Example:
+/// ```
+/// use genetic_algorithm::genotype::{Genotype, LatinSquareGenotype};
+///
+/// let genotype = LatinSquareGenotype::builder()
+///     .with_latin_square_size(4)
+///     .with_genes_hashing(true) // optional, defaults to true
+///     .with_chromosome_recycling(true) // optional, defaults to true
+///     .build()
+///     .unwrap();
+///
+/// // chromosome genes example: [0,1,2,3, 1,2,3,0, 2,3,0,1, 3,0,1,2]
+/// ```
+#[derive(Clone, Debug)]
+pub struct LatinSquare {
+    pub genes_size: usize,
+    pub latin_square_size: usize,
+    line_index_sampler: Uniform<usize>,
+    gene_index_sampler: Uniform<usize>,
+    pub seed_genes_list: Vec<Vec<usize>>,
+    pub genes_hashing: bool,
+    pub chromosome_recycling: bool,
+}
+
+impl TryFrom<Builder<Self>> for LatinSquare {
+    type Error = TryFromBuilderError;
+
+    fn try_from(builder: Builder<Self>) -> Result<Self, Self::Error> {
+        if builder.latin_square_size.is_none() {
+            Err(TryFromBuilderError(
+                "LatinSquareGenotype requires a latin_square_size",
+            ))
+        } else if builder.latin_square_size.unwrap() < 2 {
+            Err(TryFromBuilderError(
+                "LatinSquareGenotype requires a latin_square_size > 1",
+            ))
+        } else {
+            let latin_square_size = builder.latin_square_size.unwrap();
+            let genes_size = latin_square_size * latin_square_size;
+            Ok(Self {
+                genes_size,
+                latin_square_size,
+                line_index_sampler: Uniform::from(0..latin_square_size),
+                gene_index_sampler: Uniform::from(0..genes_size),
+                seed_genes_list: builder.seed_genes_list,
+                genes_hashing: builder.genes_hashing,
+                chromosome_recycling: builder.chromosome_recycling,
+            })
+        }
+    }
+}
+
+impl LatinSquare {
+    fn mutation_type(&self) -> &MutationType<usize> {
+        &MutationType::Random
+    }
+    /// Sample two distinct rows, columns or symbols
+    fn sample_line_pair<R: Rng>(&self, rng: &mut R) -> (usize, usize) {
+        let first = self.line_index_sampler.sample(rng);
+        let mut second = rng.gen_range(0..self.latin_square_size - 1);
+        if second >= first {
+            second += 1;
+        }
+        (first, second)
+    }
+    fn swap_rows(&self, first: usize, second: usize, genes: &mut [usize]) {
+        let n = self.latin_square_size;
+        for column in 0..n {
+            genes.swap(first * n + column, second * n + column);
+        }
+    }
+    fn swap_columns(&self, first: usize, second: usize, genes: &mut [usize]) {
+        let n = self.latin_square_size;
+        for row in 0..n {
+            genes.swap(row * n + first, row * n + second);
+        }
+    }
+    fn swap_symbols(&self, first: usize, second: usize, genes: &mut [usize]) {
+        genes.iter_mut().for_each(|gene| {
+            if *gene == first {
+                *gene = second;
+            } else if *gene == second {
+                *gene = first;
+            }
+        });
+    }
+    /// Swaps the symbols of the two rows in the column, and continues in the column where the
+    /// first row now has a duplicate symbol, until the cycle is closed. Returns the columns of the
+    /// cycle
+    fn row_cycle_switch(
+        &self,
+        first: usize,
+        second: usize,
+        column: usize,
+        genes: &mut [usize],
+    ) -> Vec<usize> {
+        let n = self.latin_square_size;
+        let mut cycle = vec![];
+        let mut column = column;
+        loop {
+            genes.swap(first * n + column, second * n + column);
+            cycle.push(column);
+            let symbol = genes[first * n + column];
+            match (0..n).find(|other| *other != column && genes[first * n + other] == symbol) {
+                Some(other) => column = other,
+                None => break,
+            }
+        }
+        cycle
+    }
+    /// Swaps two random rows, columns or symbols, or applies a random row cycle switch
+    fn mutate_square<R: Rng>(&self, genes: &mut [usize], rng: &mut R) {
+        let (first, second) = self.sample_line_pair(rng);
+        match rng.gen_range(0..4) {
+            0 => self.swap_rows(first, second, genes),
+            1 => self.swap_columns(first, second, genes),
+            2 => self.swap_symbols(first, second, genes),
+            _ => {
+                let column = self.line_index_sampler.sample(rng);
+                self.row_cycle_switch(first, second, column, genes);
+            }
+        }
+    }
+    /// Returns true if each row and each column is a permutation of `0..n`
+    pub fn is_latin_square(&self, genes: &[usize]) -> bool {
+        let n = self.latin_square_size;
+        let is_permutation = |values: Vec<usize>| {
+            let mut seen = vec![false; n];
+            values
+                .into_iter()
+                .all(|value| value < n && !std::mem::replace(&mut seen[value], true))
+        };
+        genes.len() == self.genes_size
+            && (0..n).all(|row| is_permutation((0..n).map(|c| genes[row * n + c]).collect()))
+            && (0..n).all(|column| is_permutation((0..n).map(|r| genes[r * n + column]).collect()))
+    }
+}
+
+impl Genotype for LatinSquare {
+    type Allele = usize;
+
+    fn genes_size(&self) -> usize {
+        self.genes_size
+    }
+    fn sample_gene_index<R: Rng>(&self, rng: &mut R) -> usize {
+        self.gene_index_sampler.sample(rng)
+    }
+    fn sample_gene_indices<R: Rng>(
+        &self,
+        count: usize,
+        allow_duplicates: bool,
+        rng: &mut R,
+    ) -> Vec<usize> {
+        if allow_duplicates {
+            rng.sample_iter(self.gene_index_sampler)
+                .take(count)
+                .collect()
+        } else {
+            rand::seq::index::sample(rng, self.genes_size, count.min(self.genes_size)).into_vec()
+        }
+    }
+
+    /// Each mutation swaps two random rows, columns or symbols. As these operations don't target
+    /// specific genes, allow_duplicates has no effect
+    fn mutate_chromosome_genes<R: Rng>(
+        &self,
+        number_of_mutations: usize,
+        _allow_duplicates: bool,
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        for _ in 0..number_of_mutations {
+            self.mutate_square(&mut chromosome.genes, rng);
+        }
+        chromosome.reset_metadata(self.genes_hashing);
+    }
+    /// Applies a row cycle switch between the row of the gene at each index and another random
+    /// row, starting at the column of the gene, so the gene always changes
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        indices.iter().for_each(|index| {
+            let row = index / self.latin_square_size;
+            let column = index % self.latin_square_size;
+            let mut other_row = rng.gen_range(0..self.latin_square_size - 1);
+            if other_row >= row {
+                other_row += 1;
+            }
+            self.row_cycle_switch(row, other_row, column, &mut chromosome.genes);
+        });
+        chromosome.reset_metadata(self.genes_hashing);
+    }
+    fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
+        self.seed_genes_list = seed_genes_list;
+    }
+    fn seed_genes_list(&self) -> &Vec<Genes<Self::Allele>> {
+        &self.seed_genes_list
+    }
+    fn random_genes_factory<R: Rng>(&self, rng: &mut R) -> Vec<usize> {
+        if self.seed_genes_list.is_empty() {
+            let n = self.latin_square_size;
+            let mut rows: Vec<usize> = (0..n).collect();
+            let mut columns: Vec<usize> = (0..n).collect();
+            let mut symbols: Vec<usize> = (0..n).collect();
+            rows.shuffle(rng);
+            columns.shuffle(rng);
+            symbols.shuffle(rng);
+            (0..n)
+                .flat_map(|row| {
+                    let rows = &rows;
+                    let columns = &columns;
+                    let symbols = &symbols;
+                    (0..n).map(move |column| symbols[(rows[row] + columns[column]) % n])
+                })
+                .collect()
+        } else {
+            self.seed_genes_list.choose(rng).unwrap().clone()
+        }
+    }
+    fn genes_capacity(&self) -> usize {
+        self.genes_size
+    }
+    fn genes_hashing(&self) -> bool {
+        self.genes_hashing
+    }
+    fn chromosome_recycling(&self) -> bool {
+        self.chromosome_recycling
+    }
+}
+
+impl EvolveGenotype for LatinSquare {
+    fn crossover_chromosome_genes<R: Rng>(
+        &self,
+        _number_of_crossovers: usize,
+        _allow_duplicates: bool,
+        _father: &mut Chromosome<Self::Allele>,
+        _mother: &mut Chromosome<Self::Allele>,
+        _rng: &mut R,
+    ) {
+        panic!("LatinSquareGenotype does not support gene crossover")
+    }
+    fn crossover_chromosome_points<R: Rng>(
+        &self,
+        _number_of_crossovers: usize,
+        _allow_duplicates: bool,
+        _father: &mut Chromosome<Self::Allele>,
+        _mother: &mut Chromosome<Self::Allele>,
+        _rng: &mut R,
+    ) {
+        panic!("LatinSquareGenotype does not support point crossover")
+    }
+}
+impl HillClimbGenotype for LatinSquare {
+    fn fill_neighbouring_population<R: Rng>(
+        &self,
+        chromosome: &Chromosome<Self::Allele>,
+        population: &mut Population<Self::Allele>,
+        _rng: &mut R,
+    ) {
+        (0..self.latin_square_size)
+            .tuple_combinations()
+            .for_each(|(first, second)| {
+                let mut new_chromosome = population.new_chromosome(chromosome);
+                self.swap_rows(first, second, &mut new_chromosome.genes);
+                new_chromosome.reset_metadata(self.genes_hashing);
+                population.chromosomes.push(new_chromosome);
+
+                let mut new_chromosome = population.new_chromosome(chromosome);
+                self.swap_columns(first, second, &mut new_chromosome.genes);
+                new_chromosome.reset_metadata(self.genes_hashing);
+                population.chromosomes.push(new_chromosome);
+
+                let mut new_chromosome = population.new_chromosome(chromosome);
+                self.swap_symbols(first, second, &mut new_chromosome.genes);
+                new_chromosome.reset_metadata(self.genes_hashing);
+                population.chromosomes.push(new_chromosome);
+
+                // each cycle once, skipping a single cycle over all columns (a row swap)
+                let mut visited = vec![false; self.latin_square_size];
+                for column in 0..self.latin_square_size {
+                    if visited[column] {
+                        continue;
+                    }
+                    let mut new_chromosome = population.new_chromosome(chromosome);
+                    let cycle =
+                        self.row_cycle_switch(first, second, column, &mut new_chromosome.genes);
+                    cycle.iter().for_each(|column| visited[*column] = true);
+                    if cycle.len() < self.latin_square_size {
+                        new_chromosome.reset_metadata(self.genes_hashing);
+                        population.chromosomes.push(new_chromosome);
+                    } else {
+                        population.drop_chromosome(new_chromosome);
+                    }
+                }
+            });
+    }
+
+    /// The upper bound, as the number of row cycle switches depends on the square (at most n / 2
+    /// per pair of rows)
+    fn neighbouring_population_size(&self) -> BigUint {
+        let n = self.latin_square_size;
+        BigUint::from(3 * n * (n - 1) / 2 + (n / 2) * n * (n - 1) / 2)
+    }
+}
+
+impl fmt::Display for LatinSquare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "genotype:")?;
+        writeln!(f, "  genes_size: {}", self.genes_size)?;
+        writeln!(f, "  latin_square_size: {}", self.latin_square_size)?;
+        writeln!(f, "  mutation_type: {:?}", self.mutation_type())?;
+        writeln!(
+            f,
+            "  neighbouring_population_size: {}",
+            self.neighbouring_population_size_report()
+        )?;
+        writeln!(
+            f,
+            "  expected_number_of_sampled_index_duplicates: {}",
+            self.expected_number_of_sampled_index_duplicates_report()
+        )?;
+        writeln!(f, "  seed_genes: {:?}", self.seed_genes_list.len())
+    }
+}
@@ -1,11 +1,14 @@
 use super::builder::{Builder, TryFromBuilderError};
-use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype};
+use super::{
+    genes_mutation_weighted_index, hamming_distance, sample_weighted_gene_indices, EvolveGenotype,
+    Genotype, GenotypeDistance, HillClimbGenotype, MutationType, PermutateGenotype,
+};
 use crate::allele::Allele;
 use crate::chromosome::{Chromosome, Genes};
 use crate::population::Population;
 use itertools::Itertools;
 use num::BigUint;
-use rand::distributions::{Distribution, Uniform};
+use rand::distributions::{Distribution, Uniform, WeightedIndex};
 use rand::prelude::*;
 use std::fmt;
 use std::hash::Hash;
@@ -27,6 +30,7 @@ pub type DefaultAllele = usize;
 /// let genotype = ListGenotype::builder()
 ///     .with_genes_size(100)
 ///     .with_allele_list((0..10).collect())
+///     .with_genes_mutation_weights(vec![1.0; 100]) // optional, defaults to None, relative mutation probability per gene
 ///     .with_genes_hashing(true) // optional, defaults to true
 ///     .with_chromosome_recycling(true) // optional, defaults to true
 ///     .build()
@@ -85,6 +89,8 @@ pub struct List<T: Allele + PartialEq + Hash = DefaultAllele> {
     pub allele_list: Vec<T>,
     gene_index_sampler: Uniform<usize>,
     allele_index_sampler: Uniform<usize>,
+    pub genes_mutation_weights: Option<Vec<f32>>,
+    gene_mutation_index_sampler: Option<WeightedIndex<f32>>,
     pub seed_genes_list: Vec<Vec<T>>,
     pub genes_hashing: bool,
     pub chromosome_recycling: bool,
@@ -106,11 +112,22 @@ impl<T: Allele + PartialEq + Hash> TryFrom<Builder<Self>> for List<T> {
             ))
         } else {
             let allele_list = builder.allele_list.unwrap();
+            let gene_mutation_index_sampler = genes_mutation_weighted_index(
+                builder.genes_mutation_weights.as_ref(),
+                builder.genes_size.unwrap(),
+            )
+            .map_err(|_| {
+                TryFromBuilderError(
+                    "ListGenotype requires non-negative genes_mutation_weights of genes_size length with a positive sum",
+                )
+            })?;
             Ok(Self {
                 genes_size: builder.genes_size.unwrap(),
                 allele_list: allele_list.clone(),
                 gene_index_sampler: Uniform::from(0..builder.genes_size.unwrap()),
                 allele_index_sampler: Uniform::from(0..allele_list.len()),
+                genes_mutation_weights: builder.genes_mutation_weights,
+                gene_mutation_index_sampler,
                 seed_genes_list: builder.seed_genes_list,
                 genes_hashing: builder.genes_hashing,
                 chromosome_recycling: builder.chromosome_recycling,
@@ -135,7 +152,11 @@ impl<T: Allele + PartialEq + Hash> Genotype for List<T> {
         self.genes_size
     }
     fn sample_gene_index<R: Rng>(&self, rng: &mut R) -> usize {
-        self.gene_index_sampler.sample(rng)
+        if let Some(weighted_index) = self.gene_mutation_index_sampler.as_ref() {
+            weighted_index.sample(rng)
+        } else {
+            self.gene_index_sampler.sample(rng)
+        }
     }
     fn sample_gene_indices<R: Rng>(
         &self,
@@ -143,7 +164,12 @@ impl<T: Allele + PartialEq + Hash> Genotype for List<T> {
         allow_duplicates: bool,
         rng: &mut R,
     ) -> Vec<usize> {
-        if allow_duplicates {
+        if let (Some(weights), Some(weighted_index)) = (
+            self.genes_mutation_weights.as_ref(),
+            self.gene_mutation_index_sampler.as_ref(),
+        ) {
+            sample_weighted_gene_indices(weights, weighted_index, count, allow_duplicates, rng)
+        } else if allow_duplicates {
             rng.sample_iter(self.gene_index_sampler)
                 .take(count)
                 .collect()
@@ -159,7 +185,13 @@ impl<T: Allele + PartialEq + Hash> Genotype for List<T> {
         chromosome: &mut Chromosome<Self::Allele>,
         rng: &mut R,
     ) {
-        if allow_duplicates {
+        if self.genes_mutation_weights.is_some() {
+            self.sample_gene_indices(number_of_mutations, allow_duplicates, rng)
+                .into_iter()
+                .for_each(|index| {
+                    chromosome.genes[index] = self.sample_gene_random(rng);
+                });
+        } else if allow_duplicates {
             for _ in 0..number_of_mutations {
                 let index = self.gene_index_sampler.sample(rng);
                 chromosome.genes[index] = self.sample_gene_random(rng);
@@ -177,6 +209,17 @@ impl<T: Allele + PartialEq + Hash> Genotype for List<T> {
         }
         chromosome.reset_metadata(self.genes_hashing);
     }
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        indices.iter().for_each(|index| {
+            chromosome.genes[*index] = self.sample_gene_random(rng);
+        });
+        chromosome.reset_metadata(self.genes_hashing);
+    }
     fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
         self.seed_genes_list = seed_genes_list;
     }
@@ -282,6 +325,14 @@ impl<T: Allele + PartialEq + Hash> EvolveGenotype for List<T> {
     fn has_crossover_points(&self) -> bool {
         true
     }
+    fn has_raw_gene_swap(&self) -> bool {
+        true
+    }
+}
+impl<T: Allele + PartialEq + Hash> GenotypeDistance for List<T> {
+    fn genes_distance(&self, genes_a: &[T], genes_b: &[T]) -> f64 {
+        hamming_distance(genes_a, genes_b)
+    }
 }
 impl<T: Allele + PartialEq + Hash> HillClimbGenotype for List<T> {
     fn fill_neighbouring_population<R: Rng>(
@@ -0,0 +1,400 @@
+use super::builder::{Builder, TryFromBuilderError};
+use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype};
+use crate::allele::Allele;
+use crate::chromosome::{Chromosome, Genes};
+use crate::population::Population;
+use itertools::Itertools;
+use num::BigUint;
+use rand::distributions::{Bernoulli, Distribution, Uniform};
+use rand::prelude::*;
+use std::fmt;
+use std::hash::Hash;
+
+pub type DefaultAllele = usize;
+
+/// Genes are a vector of `(enabled, value)` pairs, where each value is taken from the value list
+/// using clone(). This evolves the selection of features (the enabled mask) jointly with their
+/// parameters (the values), e.g. for AutoML-style searches. The fitness should ignore the values
+/// of disabled genes, see [enabled_values](MaskedValue::enabled_values). On random
+/// initialization, each gene is enabled with a probability of 0.5 and gets a value from the value
+/// list with a uniform probability. Each gene has an equal probability of mutating. If a disabled
+/// gene mutates, it is enabled (keeping its value). So the value of a disabled gene is never
+/// mutated, until it is enabled. If an enabled gene mutates, it is either disabled or gets a new
+/// value from the value list with a uniform probability (regardless of current value, which could
+/// therefore be assigned again), both with a probability of 0.5. Defaults to usize as value.
+///
+/// The value list is set with `with_value_list`, which stores it as enabled pairs in the
+/// allele_list of the builder.
+///
+/// # Example (usize, default):
+/// ```
+/// use genetic_algorithm::genotype::{Genotype, MaskedValueGenotype};
+///
+/// let genotype = MaskedValueGenotype::builder()
+///     .with_genes_size(10)
+///     .with_value_list((0..5).collect())
+///     .with_genes_hashing(true) // optional, defaults to true
+///     .with_chromosome_recycling(true) // optional, defaults to true
+///     .build()
+///     .unwrap();
+///
+/// // chromosome genes example: [(true, 3), (false, 1), (false, 4), (true, 0), ...]
+/// // features 0 and 3 are enabled, with parameter values 3 and 0
+/// ```
+#[derive(Debug, Clone)]
+pub struct MaskedValue<T: Allele + PartialEq + Hash = DefaultAllele> {
+    pub genes_size: usize,
+    pub value_list: Vec<T>,
+    gene_index_sampler: Uniform<usize>,
+    value_index_sampler: Uniform<usize>,
+    toggle_sampler: Bernoulli,
+    pub seed_genes_list: Vec<Vec<(bool, T)>>,
+    pub genes_hashing: bool,
+    pub chromosome_recycling: bool,
+}
+
+impl<T: Allele + PartialEq + Hash> Builder<MaskedValue<T>> {
+    /// The possible values of each gene, regardless of the gene being enabled
+    pub fn with_value_list(self, value_list: Vec<T>) -> Self {
+        self.with_allele_list(value_list.into_iter().map(|value| (true, value)).collect())
+    }
+}
+
+impl<T: Allele + PartialEq + Hash> TryFrom<Builder<Self>> for MaskedValue<T> {
+    type Error = TryFromBuilderError;
+
+    fn try_from(builder: Builder<Self>) -> Result<Self, Self::Error> {
+        if !builder.genes_size.is_some_and(|x| x > 0) {
+            Err(TryFromBuilderError(
+                "MaskedValueGenotype requires a genes_size > 0",
+            ))
+        } else if builder.allele_list.is_none() {
+            Err(TryFromBuilderError(
+                "MaskedValueGenotype requires a value_list",
+            ))
+        } else if builder.allele_list.as_ref().map(|o| o.is_empty()).unwrap() {
+            Err(TryFromBuilderError(
+                "MaskedValueGenotype requires non-empty value_list",
+            ))
+        } else {
+            let value_list: Vec<T> = builder
+                .allele_list
+                .unwrap()
+                .into_iter()
+                .map(|(_, value)| value)
+                .collect();
+            Ok(Self {
+                genes_size: builder.genes_size.unwrap(),
+                gene_index_sampler: Uniform::from(0..builder.genes_size.unwrap()),
+                value_index_sampler: Uniform::from(0..value_list.len()),
+                toggle_sampler: Bernoulli::new(0.5).unwrap(),
+                value_list,
+                seed_genes_list: builder.seed_genes_list,
+                genes_hashing: builder.genes_hashing,
+                chromosome_recycling: builder.chromosome_recycling,
+            })
+        }
+    }
+}
+
+impl<T: Allele + PartialEq + Hash> MaskedValue<T> {
+    fn mutation_type(&self) -> &MutationType<(bool, T)> {
+        &MutationType::Random
+    }
+    pub fn sample_value_random<R: Rng>(&self, rng: &mut R) -> T {
+        self.value_list[self.value_index_sampler.sample(rng)]
+    }
+    /// Enables a disabled gene (keeping its value). Disables an enabled gene or gives it a new
+    /// value, with equal probability
+    pub fn mutate_gene<R: Rng>(&self, gene: &mut (bool, T), rng: &mut R) {
+        if !gene.0 || self.toggle_sampler.sample(rng) {
+            gene.0 = !gene.0;
+        } else {
+            gene.1 = self.sample_value_random(rng);
+        }
+    }
+    /// The gene indices and values of the enabled genes, the disabled genes are skipped. For use
+    /// in the fitness calculation
+    pub fn enabled_values<'a>(
+        &self,
+        genes: &'a [(bool, T)],
+    ) -> impl Iterator<Item = (usize, T)> + 'a {
+        genes
+            .iter()
+            .enumerate()
+            .filter(|(_, (enabled, _))| *enabled)
+            .map(|(index, (_, value))| (index, *value))
+    }
+}
+
+impl<T: Allele + PartialEq + Hash> Genotype for MaskedValue<T> {
+    type Allele = (bool, T);
+
+    fn genes_size(&self) -> usize {
+        self.genes_size
+    }
+    fn sample_gene_index<R: Rng>(&self, rng: &mut R) -> usize {
+        self.gene_index_sampler.sample(rng)
+    }
+    fn sample_gene_indices<R: Rng>(
+        &self,
+        count: usize,
+        allow_duplicates: bool,
+        rng: &mut R,
+    ) -> Vec<usize> {
+        if allow_duplicates {
+            rng.sample_iter(self.gene_index_sampler)
+                .take(count)
+                .collect()
+        } else {
+            rand::seq::index::sample(rng, self.genes_size, count.min(self.genes_size)).into_vec()
+        }
+    }
+
+    fn mutate_chromosome_genes<R: Rng>(
+        &self,
+        number_of_mutations: usize,
+        allow_duplicates: bool,
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        if allow_duplicates {
+            for _ in 0..number_of_mutations {
+                let index = self.gene_index_sampler.sample(rng);
+                self.mutate_gene(&mut chromosome.genes[index], rng);
+            }
+        } else {
+            rand::seq::index::sample(
+                rng,
+                self.genes_size,
+                number_of_mutations.min(self.genes_size),
+            )
+            .iter()
+            .for_each(|index| {
+                self.mutate_gene(&mut chromosome.genes[index], rng);
+            });
+        }
+        chromosome.reset_metadata(self.genes_hashing);
+    }
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        indices.iter().for_each(|index| {
+            self.mutate_gene(&mut chromosome.genes[*index], rng);
+        });
+        chromosome.reset_metadata(self.genes_hashing);
+    }
+    fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
+        self.seed_genes_list = seed_genes_list;
+    }
+    fn seed_genes_list(&self) -> &Vec<Genes<Self::Allele>> {
+        &self.seed_genes_list
+    }
+    fn random_genes_factory<R: Rng>(&self, rng: &mut R) -> Vec<(bool, T)> {
+        if self.seed_genes_list.is_empty() {
+            (0..self.genes_size)
+                .map(|_| {
+                    (
+                        self.toggle_sampler.sample(rng),
+                        self.sample_value_random(rng),
+                    )
+                })
+                .collect()
+        } else {
+            self.seed_genes_list.choose(rng).unwrap().clone()
+        }
+    }
+    fn genes_capacity(&self) -> usize {
+        self.genes_size
+    }
+    fn genes_hashing(&self) -> bool {
+        self.genes_hashing
+    }
+    fn chromosome_recycling(&self) -> bool {
+        self.chromosome_recycling
+    }
+}
+
+impl<T: Allele + PartialEq + Hash> EvolveGenotype for MaskedValue<T> {
+    fn crossover_chromosome_genes<R: Rng>(
+        &self,
+        number_of_crossovers: usize,
+        allow_duplicates: bool,
+        father: &mut Chromosome<Self::Allele>,
+        mother: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        if allow_duplicates {
+            rng.sample_iter(self.gene_index_sampler)
+                .take(number_of_crossovers)
+                .for_each(|index| {
+                    std::mem::swap(&mut father.genes[index], &mut mother.genes[index]);
+                });
+        } else {
+            rand::seq::index::sample(
+                rng,
+                self.genes_size(),
+                number_of_crossovers.min(self.genes_size()),
+            )
+            .iter()
+            .for_each(|index| {
+                std::mem::swap(&mut father.genes[index], &mut mother.genes[index]);
+            });
+        }
+        mother.reset_metadata(self.genes_hashing);
+        father.reset_metadata(self.genes_hashing);
+    }
+    fn crossover_chromosome_points<R: Rng>(
+        &self,
+        number_of_crossovers: usize,
+        allow_duplicates: bool,
+        father: &mut Chromosome<Self::Allele>,
+        mother: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        if allow_duplicates {
+            rng.sample_iter(self.gene_index_sampler)
+                .take(number_of_crossovers)
+                .for_each(|index| {
+                    let mother_back = &mut mother.genes[index..];
+                    let father_back = &mut father.genes[index..];
+                    father_back.swap_with_slice(mother_back);
+                });
+        } else {
+            rand::seq::index::sample(
+                rng,
+                self.genes_size(),
+                number_of_crossovers.min(self.genes_size()),
+            )
+            .iter()
+            .sorted_unstable()
+            .chunks(2)
+            .into_iter()
+            .for_each(|mut chunk| match (chunk.next(), chunk.next()) {
+                (Some(start_index), Some(end_index)) => {
+                    let mother_back = &mut mother.genes[start_index..end_index];
+                    let father_back = &mut father.genes[start_index..end_index];
+                    father_back.swap_with_slice(mother_back);
+                }
+                (Some(start_index), _) => {
+                    let mother_back = &mut mother.genes[start_index..];
+                    let father_back = &mut father.genes[start_index..];
+                    father_back.swap_with_slice(mother_back);
+                }
+                _ => (),
+            });
+        }
+        mother.reset_metadata(self.genes_hashing);
+        father.reset_metadata(self.genes_hashing);
+    }
+
+    fn has_crossover_indexes(&self) -> bool {
+        true
+    }
+    fn has_crossover_points(&self) -> bool {
+        true
+    }
+    fn has_raw_gene_swap(&self) -> bool {
+        true
+    }
+}
+impl<T: Allele + PartialEq + Hash> HillClimbGenotype for MaskedValue<T> {
+    /// A disabled gene only has the enabled neighbour. An enabled gene has the disabled neighbour
+    /// and a neighbour for each other value
+    fn fill_neighbouring_population<R: Rng>(
+        &self,
+        chromosome: &Chromosome<Self::Allele>,
+        population: &mut Population<Self::Allele>,
+        _rng: &mut R,
+    ) {
+        for index in 0..self.genes_size() {
+            let (enabled, current_value) = chromosome.genes[index];
+            let mut new_chromosome = population.new_chromosome(chromosome);
+            new_chromosome.genes[index] = (!enabled, current_value);
+            new_chromosome.reset_metadata(self.genes_hashing);
+            population.chromosomes.push(new_chromosome);
+            if enabled {
+                for value in self.value_list.clone() {
+                    if current_value != value {
+                        let mut new_chromosome = population.new_chromosome(chromosome);
+                        new_chromosome.genes[index] = (true, value);
+                        new_chromosome.reset_metadata(self.genes_hashing);
+                        population.chromosomes.push(new_chromosome);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The upper bound, for all genes enabled
+    fn neighbouring_population_size(&self) -> BigUint {
+        BigUint::from(self.value_list.len() * self.genes_size)
+    }
+}
+
+impl<T: Allele + PartialEq + Hash> PermutateGenotype for MaskedValue<T> {
+    /// Each gene is either disabled (with the first value of the value list, as the value of a
+    /// disabled gene is irrelevant), or enabled with each of the values
+    fn chromosome_permutations_into_iter<'a>(
+        &'a self,
+        _chromosome: Option<&Chromosome<Self::Allele>>,
+    ) -> Box<dyn Iterator<Item = Chromosome<Self::Allele>> + Send + 'a> {
+        if self.seed_genes_list.is_empty() {
+            let gene_states: Vec<(bool, T)> = std::iter::once((false, self.value_list[0]))
+                .chain(self.value_list.iter().map(|value| (true, *value)))
+                .collect();
+            Box::new(
+                (0..self.genes_size())
+                    .map(|_| gene_states.clone())
+                    .multi_cartesian_product()
+                    .map(Chromosome::new),
+            )
+        } else {
+            Box::new(
+                self.seed_genes_list
+                    .clone()
+                    .into_iter()
+                    .map(Chromosome::new),
+            )
+        }
+    }
+
+    fn chromosome_permutations_size(&self) -> BigUint {
+        if self.seed_genes_list.is_empty() {
+            BigUint::from(self.value_list.len() + 1).pow(self.genes_size() as u32)
+        } else {
+            self.seed_genes_list.len().into()
+        }
+    }
+    fn allows_permutation(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Allele + PartialEq + Hash> fmt::Display for MaskedValue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "genotype:")?;
+        writeln!(f, "  genes_size: {}", self.genes_size)?;
+        writeln!(f, "  value_list_size: {}", self.value_list.len())?;
+        writeln!(f, "  mutation_type: {:?}", self.mutation_type())?;
+        writeln!(
+            f,
+            "  chromosome_permutations_size: {}",
+            self.chromosome_permutations_size_report()
+        )?;
+        writeln!(
+            f,
+            "  neighbouring_population_size: {}",
+            self.neighbouring_population_size_report()
+        )?;
+        writeln!(
+            f,
+            "  expected_number_of_sampled_index_duplicates: {}",
+            self.expected_number_of_sampled_index_duplicates_report()
+        )?;
+        writeln!(f, "  seed_genes: {:?}", self.seed_genes_list.len())
+    }
+}
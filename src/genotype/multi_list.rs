@@ -1,10 +1,13 @@
 use super::builder::{Builder, TryFromBuilderError};
-use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype};
+use super::{
+    hamming_distance, EvolveGenotype, Genotype, GenotypeDistance, HillClimbGenotype, MutationType,
+    PermutateGenotype, PermutationIndexShuffle, PermutationOrder,
+};
 use crate::allele::Allele;
 use crate::chromosome::{Chromosome, Genes};
 use crate::population::Population;
 use itertools::Itertools;
-use num::BigUint;
+use num::{BigUint, ToPrimitive};
 use rand::distributions::{Distribution, Uniform, WeightedIndex};
 use rand::prelude::*;
 use std::fmt;
@@ -95,6 +98,7 @@ pub struct MultiList<T: Allele + PartialEq + Hash = DefaultAllele> {
     gene_index_sampler: Uniform<usize>,
     gene_weighted_index_sampler: WeightedIndex<usize>,
     allele_index_samplers: Vec<Uniform<usize>>,
+    pub permutation_order: PermutationOrder,
     pub seed_genes_list: Vec<Vec<T>>,
     pub genes_hashing: bool,
     pub chromosome_recycling: bool,
@@ -126,6 +130,7 @@ impl<T: Allele + PartialEq + Hash> TryFrom<Builder<Self>> for MultiList<T> {
                     .iter()
                     .map(|allele_value_size| Uniform::from(0..*allele_value_size))
                     .collect(),
+                permutation_order: builder.permutation_order,
                 seed_genes_list: builder.seed_genes_list,
                 genes_hashing: builder.genes_hashing,
                 chromosome_recycling: builder.chromosome_recycling,
@@ -141,6 +146,23 @@ impl<T: Allele + PartialEq + Hash> MultiList<T> {
     pub fn sample_gene_random<R: Rng>(&self, index: usize, rng: &mut R) -> T {
         self.allele_lists[index][self.allele_index_samplers[index].sample(rng)]
     }
+    /// The genes at the permutation index of the nested order, where the last gene varies fastest
+    fn permutation_genes(&self, permutation_index: u64) -> Genes<T> {
+        let mut remainder = permutation_index;
+        let mut genes: Genes<T> = self
+            .allele_lists
+            .iter()
+            .rev()
+            .map(|allele_list| {
+                let allele_list_size = allele_list.len() as u64;
+                let allele = allele_list[(remainder % allele_list_size) as usize];
+                remainder /= allele_list_size;
+                allele
+            })
+            .collect();
+        genes.reverse();
+        genes
+    }
 }
 
 impl<T: Allele + PartialEq + Hash> Genotype for MultiList<T> {
@@ -201,6 +223,17 @@ impl<T: Allele + PartialEq + Hash> Genotype for MultiList<T> {
         }
         chromosome.reset_metadata(self.genes_hashing);
     }
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        indices.iter().for_each(|index| {
+            chromosome.genes[*index] = self.sample_gene_random(*index, rng);
+        });
+        chromosome.reset_metadata(self.genes_hashing);
+    }
     fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
         self.seed_genes_list = seed_genes_list;
     }
@@ -306,6 +339,14 @@ impl<T: Allele + PartialEq + Hash> EvolveGenotype for MultiList<T> {
     fn has_crossover_points(&self) -> bool {
         true
     }
+    fn has_raw_gene_swap(&self) -> bool {
+        true
+    }
+}
+impl<T: Allele + PartialEq + Hash> GenotypeDistance for MultiList<T> {
+    fn genes_distance(&self, genes_a: &[T], genes_b: &[T]) -> f64 {
+        hamming_distance(genes_a, genes_b)
+    }
 }
 impl<T: Allele + PartialEq + Hash> HillClimbGenotype for MultiList<T> {
     fn fill_neighbouring_population<R: Rng>(
@@ -336,19 +377,33 @@ impl<T: Allele + PartialEq + Hash> PermutateGenotype for MultiList<T> {
         &'a self,
         _chromosome: Option<&Chromosome<Self::Allele>>,
     ) -> Box<dyn Iterator<Item = Chromosome<Self::Allele>> + Send + 'a> {
-        if self.seed_genes_list.is_empty() {
+        let shuffled_size = match self.permutation_order {
+            PermutationOrder::Shuffled(seed) => self
+                .chromosome_permutations_size()
+                .to_u64()
+                .map(|size| (size, seed)),
+            PermutationOrder::Nested => None,
+        };
+        if !self.seed_genes_list.is_empty() {
             Box::new(
-                self.allele_lists
+                self.seed_genes_list
                     .clone()
                     .into_iter()
-                    .multi_cartesian_product()
                     .map(Chromosome::new),
             )
+        } else if let Some((size, seed)) = shuffled_size {
+            let shuffle = PermutationIndexShuffle::new(size, seed);
+            Box::new(
+                (0..size).map(move |index| {
+                    Chromosome::new(self.permutation_genes(shuffle.index(index)))
+                }),
+            )
         } else {
             Box::new(
-                self.seed_genes_list
+                self.allele_lists
                     .clone()
                     .into_iter()
+                    .multi_cartesian_product()
                     .map(Chromosome::new),
             )
         }
@@ -389,6 +444,7 @@ impl<T: Allele + PartialEq + Hash> fmt::Display for MultiList<T> {
             "  expected_number_of_sampled_index_duplicates: {}",
             self.expected_number_of_sampled_index_duplicates_report()
         )?;
+        writeln!(f, "  permutation_order: {:?}", self.permutation_order)?;
         writeln!(f, "  seed_genes: {:?}", self.seed_genes_list.len())
     }
 }
@@ -1,13 +1,19 @@
 use super::builder::{Builder, TryFromBuilderError};
-use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype};
+use super::mutation_type::{positive_gaussian_sigmas, sample_gaussian_delta};
+use super::{
+    euclidean_distance, EvolveGenotype, Genotype, GenotypeDistance, HillClimbGenotype,
+    MutationType, PermutateGenotype,
+};
 use crate::allele::RangeAllele;
-use crate::chromosome::{Chromosome, Genes};
+use crate::chromosome::{Chromosome, Genes, GenesHash};
 use crate::population::Population;
 use itertools::Itertools;
-use num::BigUint;
+use num::{BigUint, ToPrimitive};
 use rand::distributions::{Distribution, Uniform};
 use rand::prelude::*;
+use rustc_hash::FxHasher;
 use std::fmt;
+use std::hash::Hasher;
 use std::ops::RangeInclusive;
 
 pub type DefaultAllele = f32;
@@ -54,12 +60,25 @@ pub type DefaultAllele = f32;
 /// * `MutationType::Random` - Samples uniformly from the full allele range
 /// * `MutationType::Range` - Mutates within a relative range around the current value
 /// * `MutationType::StepScaled` - Progressive refinement through multiple scale levels around the current value
+///   * The scaled mutation types (also `RangeScaled` and `GaussianScaled`) take their own per gene
+///     sizes (e.g. `0..1` and `0..10000` genes zooming in on their own units), but share the
+///     scale index, so they must have the same number of scales
+/// * `MutationType::Gaussian` - Normally distributed mutation around the current value, with a sigma per gene
 /// * `MutationType::Discrete` - Rounded-to-integer values with uniform selection (like `ListGenotype`).
 ///   * Mutations ignore current value - all rounded-to-integer in range equally likely
 ///   * Range `0.0..=4.0` yields values: 0.0, 1.0, 2.0, 3.0, 4.0 (with equal probability)
 ///   * Useful for encoding: enums (0.0..=4.0), booleans (0.0..=1.0), or discrete choices
 ///   * Neighbours and permutations include all integer values in the allele range
 ///
+/// # Allele steps
+///
+/// With allele_steps (see
+/// [with_allele_steps](crate::genotype::GenotypeBuilder::with_allele_steps)), each gene with
+/// `Some(allele_step)` is restricted to the grid `start + k * allele_step` within its own
+/// allele_range, like the allele_step of [RangeGenotype](crate::genotype::RangeGenotype). The
+/// genes with `None` stay continuous. Permutation is supported when each gene either has an
+/// allele_step or a permutable mutation type.
+///
 /// # Example (f32, default, random mutation):
 /// ```
 /// use genetic_algorithm::genotype::{Genotype, MultiRangeGenotype};
@@ -72,6 +91,7 @@ pub type DefaultAllele = f32;
 ///        10.0..=30.0
 ///     ]) // also default mutation range
 ///     .with_genes_hashing(true) // optional, defaults to true
+///     .with_genes_key_precision(3) // optional, defaults to None, quantizes float genes (to 3 decimals) for the genes_hash
 ///     .with_chromosome_recycling(true) // optional, defaults to true
 ///     .build()
 ///     .unwrap();
@@ -94,6 +114,7 @@ pub type DefaultAllele = f32;
 ///        MutationType::Range(1),
 ///        MutationType::Range(3),
 ///     ]) // optional, restricts mutations to a smaller relative range
+///     .with_allele_steps(vec![None, Some(5), None, None]) // optional, restricts the second gene to 5, 10, 15, 20
 ///     .with_genes_hashing(true) // optional, defaults to true
 ///     .with_chromosome_recycling(true) // optional, defaults to true
 ///     .build()
@@ -118,6 +139,7 @@ pub type DefaultAllele = f32;
 ///        MutationType::StepScaled(vec![3.0,0.3]),
 ///     ]) // optional, restricts mutations to relative step up or down of each scale
 ///     .with_genes_hashing(true) // optional, defaults to true
+///     .with_genes_key_precision(3) // optional, defaults to None, quantizes float genes (to 3 decimals) for the genes_hash
 ///     .with_chromosome_recycling(true) // optional, defaults to true
 ///     .build()
 ///     .unwrap();
@@ -139,6 +161,7 @@ pub type DefaultAllele = f32;
 ///         MutationType::StepScaled(vec![10.0, 1.0, 0.1]), // Continuous refinement
 ///     ])
 ///     .with_genes_hashing(true) // optional, defaults to true
+///     .with_genes_key_precision(3) // optional, defaults to None, quantizes float genes (to 3 decimals) for the genes_hash
 ///     .with_chromosome_recycling(true) // optional, defaults to true
 ///     .build();
 /// ```
@@ -148,6 +171,7 @@ where
 {
     pub genes_size: usize,
     pub allele_ranges: Vec<RangeInclusive<T>>,
+    pub allele_steps: Vec<Option<T>>,
     pub mutation_types: Vec<MutationType<T>>,
     gene_index_sampler: Uniform<usize>,
     allele_samplers: Vec<Uniform<T>>,
@@ -156,6 +180,7 @@ where
     pub current_scale_index: usize,
     pub seed_genes_list: Vec<Vec<T>>,
     pub genes_hashing: bool,
+    pub genes_key_precision: Option<u32>,
     pub chromosome_recycling: bool,
 }
 
@@ -166,6 +191,13 @@ where
     type Error = TryFromBuilderError;
 
     fn try_from(builder: Builder<Self>) -> Result<Self, Self::Error> {
+        let invalid_scales_sizes = builder
+            .mutation_types
+            .as_ref()
+            .is_some_and(|mutation_types| {
+                let mut scales_sizes = mutation_types.iter().filter_map(scales_size);
+                scales_sizes.clone().any(|size| size == 0) || !scales_sizes.all_equal()
+            });
         if builder.allele_ranges.is_none() {
             Err(TryFromBuilderError(
                 "MultiRangeGenotype requires a allele_ranges",
@@ -179,6 +211,47 @@ where
             Err(TryFromBuilderError(
                 "MultiRangeGenotype requires non-empty allele_ranges",
             ))
+        } else if builder
+            .mutation_types
+            .as_ref()
+            .zip(builder.allele_ranges.as_ref())
+            .is_some_and(|(mutation_types, allele_ranges)| {
+                mutation_types.len() != allele_ranges.len()
+            })
+        {
+            Err(TryFromBuilderError(
+                "MultiRangeGenotype requires mutation_types of the allele_ranges length",
+            ))
+        } else if builder
+            .allele_steps
+            .as_ref()
+            .zip(builder.allele_ranges.as_ref())
+            .is_some_and(|(allele_steps, allele_ranges)| allele_steps.len() != allele_ranges.len())
+        {
+            Err(TryFromBuilderError(
+                "MultiRangeGenotype requires allele_steps of the allele_ranges length",
+            ))
+        } else if builder.allele_steps.as_ref().is_some_and(|allele_steps| {
+            allele_steps
+                .iter()
+                .flatten()
+                .any(|allele_step| *allele_step <= T::zero())
+        }) {
+            Err(TryFromBuilderError(
+                "MultiRangeGenotype requires allele_steps > 0",
+            ))
+        } else if invalid_scales_sizes {
+            Err(TryFromBuilderError(
+                "MultiRangeGenotype requires the same non-zero number of scales for all scaled mutation_types",
+            ))
+        } else if builder
+            .mutation_types
+            .as_ref()
+            .is_some_and(|mutation_types| !mutation_types.iter().all(positive_gaussian_sigmas))
+        {
+            Err(TryFromBuilderError(
+                "MultiRangeGenotype requires Gaussian sigmas > 0",
+            ))
         } else {
             let allele_ranges = builder.allele_ranges.unwrap();
             let genes_size = allele_ranges.len();
@@ -223,22 +296,38 @@ where
                 })
                 .collect();
 
-            Ok(Self {
+            let mut genotype = Self {
                 genes_size,
                 allele_ranges: allele_ranges.clone(),
+                allele_steps: builder.allele_steps.unwrap_or(vec![None; genes_size]),
                 mutation_types: mutation_types.clone(),
                 gene_index_sampler: Uniform::from(0..genes_size),
                 allele_samplers,
                 allele_bandwidth_samplers,
                 current_scale_index: 0,
-                seed_genes_list: builder.seed_genes_list,
+                seed_genes_list: vec![],
                 genes_hashing: builder.genes_hashing,
+                genes_key_precision: builder.genes_key_precision,
                 chromosome_recycling: builder.chromosome_recycling,
-            })
+            };
+            // snap the seed genes to the allele_steps grid
+            genotype.set_seed_genes_list(builder.seed_genes_list);
+            Ok(genotype)
         }
     }
 }
 
+/// The number of scales of a scaled mutation type. The scale index is shared by all genes, so the
+/// scaled mutation types need the same number of scales (though each with its own per gene sizes)
+fn scales_size<T: RangeAllele>(mutation_type: &MutationType<T>) -> Option<usize> {
+    match mutation_type {
+        MutationType::RangeScaled(scales)
+        | MutationType::StepScaled(scales)
+        | MutationType::GaussianScaled(scales) => Some(scales.len()),
+        _ => None,
+    }
+}
+
 impl<T: RangeAllele> MultiRange<T>
 where
     Uniform<T>: Send + Sync,
@@ -247,14 +336,99 @@ where
         &self.mutation_types
     }
     pub fn sample_gene_random<R: Rng>(&self, index: usize, rng: &mut R) -> T {
-        match self.mutation_types[index] {
-            MutationType::Discrete => self.allele_samplers[index].sample(rng).floor(),
-            _ => self.allele_samplers[index].sample(rng),
+        if let Some(allele_step) = self.allele_steps[index] {
+            let number_of_steps =
+                rng.gen_range(0..self.allele_step_values_size(index, allele_step));
+            T::add_steps(
+                *self.allele_ranges[index].start(),
+                allele_step,
+                number_of_steps,
+            )
+        } else {
+            match self.mutation_types[index] {
+                MutationType::Discrete => self.allele_samplers[index].sample(rng).floor(),
+                _ => self.allele_samplers[index].sample(rng),
+            }
         }
     }
+
+    pub fn mutate_gene<R: Rng>(&self, chromosome: &mut Chromosome<T>, index: usize, rng: &mut R) {
+        if self.allele_steps[index].is_some() {
+            let current_value = chromosome.genes[index];
+            match self.mutation_types[index] {
+                MutationType::Random | MutationType::Discrete => {
+                    chromosome.genes[index] = self.sample_gene_random(index, rng);
+                }
+                _ => {
+                    self.mutate_gene_value(chromosome, index, rng);
+                    chromosome.genes[index] =
+                        self.snap_mutated_value(index, current_value, chromosome.genes[index]);
+                }
+            }
+        } else {
+            self.mutate_gene_value(chromosome, index, rng);
+        }
+    }
+
+    /// The number of values on the allele_step grid within the allele_range of the gene
+    fn allele_step_values_size(&self, index: usize, allele_step: T) -> usize {
+        self.allele_ranges[index]
+            .end()
+            .steps_from(*self.allele_ranges[index].start(), allele_step)
+            + 1
+    }
+
+    /// Snap the value to the nearest value on the allele_step grid of the gene (identity without
+    /// allele_step)
+    pub fn snap_to_allele_step(&self, index: usize, value: T) -> T {
+        if let Some(allele_step) = self.allele_steps[index] {
+            let allele_range_start = *self.allele_ranges[index].start();
+            let max_number_of_steps = self.allele_step_values_size(index, allele_step) - 1;
+            let number_of_steps = value
+                .steps_from(allele_range_start, allele_step)
+                .min(max_number_of_steps);
+            let lower_value = T::add_steps(allele_range_start, allele_step, number_of_steps);
+            if number_of_steps < max_number_of_steps && value > lower_value {
+                let upper_value =
+                    T::add_steps(allele_range_start, allele_step, number_of_steps + 1);
+                if upper_value - value < value - lower_value {
+                    return upper_value;
+                }
+            }
+            lower_value
+        } else {
+            value
+        }
+    }
+
+    /// Snap the mutated value to the allele_step grid of the gene. If the mutation is smaller
+    /// than the allele_step (snapping back to the current value), move to the neighbouring grid
+    /// value in the direction of the mutation instead
+    fn snap_mutated_value(&self, index: usize, current_value: T, mutated_value: T) -> T {
+        let snapped_value = self.snap_to_allele_step(index, mutated_value);
+        match (self.allele_steps[index], snapped_value == current_value) {
+            (Some(allele_step), true) if mutated_value != current_value => {
+                let allele_range_start = *self.allele_ranges[index].start();
+                let number_of_steps = current_value.steps_from(allele_range_start, allele_step);
+                if mutated_value > current_value {
+                    if number_of_steps + 1 < self.allele_step_values_size(index, allele_step) {
+                        T::add_steps(allele_range_start, allele_step, number_of_steps + 1)
+                    } else {
+                        current_value
+                    }
+                } else if number_of_steps > 0 {
+                    T::add_steps(allele_range_start, allele_step, number_of_steps - 1)
+                } else {
+                    current_value
+                }
+            }
+            _ => snapped_value,
+        }
+    }
+
     // all delta's are positive, because we support unsigned integers as RangeAllele
     // quite the overhead to make this work, but I think it is worth it
-    pub fn mutate_gene<R: Rng>(&self, chromosome: &mut Chromosome<T>, index: usize, rng: &mut R) {
+    fn mutate_gene_value<R: Rng>(&self, chromosome: &mut Chromosome<T>, index: usize, rng: &mut R) {
         match &self.mutation_types[index] {
             MutationType::Random => {
                 chromosome.genes[index] = self.allele_samplers[index].sample(rng);
@@ -344,6 +518,30 @@ where
                         T::clamped_sub(current_value, delta, *self.allele_ranges[index].start());
                 }
             }
+            MutationType::Gaussian(sigma) => {
+                // post-clamp
+                let current_value = chromosome.genes[index];
+                let delta = sample_gaussian_delta(*sigma, rng);
+                if rng.gen() {
+                    chromosome.genes[index] =
+                        T::clamped_add(current_value, delta, *self.allele_ranges[index].end());
+                } else {
+                    chromosome.genes[index] =
+                        T::clamped_sub(current_value, delta, *self.allele_ranges[index].start());
+                }
+            }
+            MutationType::GaussianScaled(sigmas) => {
+                // post-clamp
+                let current_value = chromosome.genes[index];
+                let delta = sample_gaussian_delta(sigmas[self.current_scale_index], rng);
+                if rng.gen() {
+                    chromosome.genes[index] =
+                        T::clamped_add(current_value, delta, *self.allele_ranges[index].end());
+                } else {
+                    chromosome.genes[index] =
+                        T::clamped_sub(current_value, delta, *self.allele_ranges[index].start());
+                }
+            }
         }
     }
 }
@@ -397,10 +595,30 @@ where
                 self.mutate_gene(chromosome, index, rng);
             });
         }
-        chromosome.reset_metadata(self.genes_hashing);
+        self.reset_chromosome_metadata(chromosome);
+    }
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        indices
+            .iter()
+            .for_each(|index| self.mutate_gene(chromosome, *index, rng));
+        self.reset_chromosome_metadata(chromosome);
     }
     fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
-        self.seed_genes_list = seed_genes_list;
+        self.seed_genes_list = seed_genes_list
+            .into_iter()
+            .map(|genes| {
+                genes
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, value)| self.snap_to_allele_step(index, value))
+                    .collect()
+            })
+            .collect();
     }
     fn seed_genes_list(&self) -> &Vec<Genes<Self::Allele>> {
         &self.seed_genes_list
@@ -409,9 +627,9 @@ where
         self.mutation_types
             .iter()
             .find_map(|mutation_type| match mutation_type {
-                MutationType::RangeScaled(scales) | MutationType::StepScaled(scales) => {
-                    Some(scales.len().saturating_sub(1))
-                }
+                MutationType::RangeScaled(scales)
+                | MutationType::StepScaled(scales)
+                | MutationType::GaussianScaled(scales) => Some(scales.len().saturating_sub(1)),
                 _ => None,
             })
     }
@@ -419,9 +637,9 @@ where
         self.mutation_types
             .iter()
             .find_map(|mutation_type| match mutation_type {
-                MutationType::RangeScaled(_) | MutationType::StepScaled(_) => {
-                    Some(self.current_scale_index)
-                }
+                MutationType::RangeScaled(_)
+                | MutationType::StepScaled(_)
+                | MutationType::GaussianScaled(_) => Some(self.current_scale_index),
                 _ => None,
             })
     }
@@ -456,6 +674,18 @@ where
     fn genes_hashing(&self) -> bool {
         self.genes_hashing
     }
+    /// Quantized to the genes_key_precision (if set), so near-equal genes share the genes_hash
+    fn calculate_genes_hash(&self, chromosome: &Chromosome<Self::Allele>) -> GenesHash {
+        if let Some(decimals) = self.genes_key_precision {
+            let mut hasher = FxHasher::default();
+            chromosome.genes.iter().for_each(|gene| {
+                T::hash_slice(&[gene.round_to_decimals(decimals)], &mut hasher);
+            });
+            hasher.finish()
+        } else {
+            chromosome.calculate_hash()
+        }
+    }
     fn chromosome_recycling(&self) -> bool {
         self.chromosome_recycling
     }
@@ -490,8 +720,8 @@ where
                 std::mem::swap(&mut father.genes[index], &mut mother.genes[index]);
             });
         }
-        mother.reset_metadata(self.genes_hashing);
-        father.reset_metadata(self.genes_hashing);
+        self.reset_chromosome_metadata(mother);
+        self.reset_chromosome_metadata(father);
     }
     fn crossover_chromosome_points<R: Rng>(
         &self,
@@ -533,8 +763,8 @@ where
                 _ => (),
             });
         }
-        mother.reset_metadata(self.genes_hashing);
-        father.reset_metadata(self.genes_hashing);
+        self.reset_chromosome_metadata(mother);
+        self.reset_chromosome_metadata(father);
     }
 
     fn has_crossover_indexes(&self) -> bool {
@@ -543,6 +773,17 @@ where
     fn has_crossover_points(&self) -> bool {
         true
     }
+    fn has_raw_gene_swap(&self) -> bool {
+        true
+    }
+}
+impl<T: RangeAllele + ToPrimitive> GenotypeDistance for MultiRange<T>
+where
+    Uniform<T>: Send + Sync,
+{
+    fn genes_distance(&self, genes_a: &[T], genes_b: &[T]) -> f64 {
+        euclidean_distance(genes_a, genes_b)
+    }
 }
 impl<T: RangeAllele> HillClimbGenotype for MultiRange<T>
 where
@@ -554,6 +795,7 @@ where
         population: &mut Population<Self::Allele>,
         rng: &mut R,
     ) {
+        let neighbours_start_index = population.chromosomes.len();
         self.mutation_types.iter().enumerate().for_each(
             |(index, mutation_type)| match mutation_type {
                 MutationType::Random => {
@@ -586,11 +828,34 @@ where
                         )
                     }
                 }
+                MutationType::Gaussian(sigma) => {
+                    let delta = sample_gaussian_delta(*sigma, rng);
+                    self.fill_neighbouring_population_step(index, chromosome, population, delta)
+                }
+                MutationType::GaussianScaled(sigmas) => {
+                    let delta = sample_gaussian_delta(sigmas[self.current_scale_index], rng);
+                    self.fill_neighbouring_population_step(index, chromosome, population, delta)
+                }
                 MutationType::Discrete => {
                     self.fill_neighbouring_population_discrete(index, chromosome, population)
                 }
             },
         );
+        if self.allele_steps.iter().any(Option::is_some) {
+            population.chromosomes[neighbours_start_index..]
+                .iter_mut()
+                .for_each(|new_chromosome| {
+                    new_chromosome
+                        .genes
+                        .iter_mut()
+                        .zip(chromosome.genes.iter())
+                        .enumerate()
+                        .for_each(|(index, (new_value, current_value))| {
+                            *new_value = self.snap_mutated_value(index, *current_value, *new_value);
+                        });
+                    self.reset_chromosome_metadata(new_chromosome);
+                });
+        }
     }
 
     fn neighbouring_population_size(&self) -> BigUint {
@@ -616,13 +881,13 @@ where
         if allele_range_start < current_value {
             let mut new_chromosome = population.new_chromosome(chromosome);
             new_chromosome.genes[index] = T::clamped_sub(current_value, step, allele_range_start);
-            new_chromosome.reset_metadata(self.genes_hashing);
+            self.reset_chromosome_metadata(&mut new_chromosome);
             population.chromosomes.push(new_chromosome);
         };
         if current_value < allele_range_end {
             let mut new_chromosome = population.new_chromosome(chromosome);
             new_chromosome.genes[index] = T::clamped_add(current_value, step, allele_range_end);
-            new_chromosome.reset_metadata(self.genes_hashing);
+            self.reset_chromosome_metadata(&mut new_chromosome);
             population.chromosomes.push(new_chromosome);
         };
     }
@@ -642,14 +907,14 @@ where
                 let mut new_chromosome = population.new_chromosome(chromosome);
                 new_chromosome.genes[index] =
                     T::clamped_sub(current_value, delta, allele_range_start);
-                new_chromosome.reset_metadata(self.genes_hashing);
+                self.reset_chromosome_metadata(&mut new_chromosome);
                 population.chromosomes.push(new_chromosome);
             };
             if current_value < allele_range_end {
                 let mut new_chromosome = population.new_chromosome(chromosome);
                 new_chromosome.genes[index] =
                     T::clamped_add(current_value, delta, allele_range_end);
-                new_chromosome.reset_metadata(self.genes_hashing);
+                self.reset_chromosome_metadata(&mut new_chromosome);
                 population.chromosomes.push(new_chromosome);
             };
         }
@@ -674,7 +939,7 @@ where
             if working_delta_down >= T::smallest_increment() {
                 let delta = rng.gen_range(T::smallest_increment()..=working_delta_down);
                 new_chromosome.genes[index] -= delta; // no need to check again
-                new_chromosome.reset_metadata(self.genes_hashing);
+                self.reset_chromosome_metadata(&mut new_chromosome);
                 population.chromosomes.push(new_chromosome);
             }
         };
@@ -685,7 +950,7 @@ where
             if working_delta_up >= T::smallest_increment() {
                 let delta = rng.gen_range(T::smallest_increment()..=working_delta_up);
                 new_chromosome.genes[index] += delta; // no need to check again
-                new_chromosome.reset_metadata(self.genes_hashing);
+                self.reset_chromosome_metadata(&mut new_chromosome);
                 population.chromosomes.push(new_chromosome);
             }
         };
@@ -704,7 +969,7 @@ where
         if allele_range_start < current_value {
             let mut new_chromosome = population.new_chromosome(chromosome);
             new_chromosome.genes[index] = rng.gen_range(allele_range_start..current_value);
-            new_chromosome.reset_metadata(self.genes_hashing);
+            self.reset_chromosome_metadata(&mut new_chromosome);
             population.chromosomes.push(new_chromosome);
         };
         if current_value < allele_range_end {
@@ -712,7 +977,7 @@ where
             let new_value =
                 rng.gen_range((current_value + T::smallest_increment())..=allele_range_end);
             new_chromosome.genes[index] = new_value;
-            new_chromosome.reset_metadata(self.genes_hashing);
+            self.reset_chromosome_metadata(&mut new_chromosome);
             population.chromosomes.push(new_chromosome);
         };
     }
@@ -731,7 +996,7 @@ where
             if working_value != current_value {
                 let mut new_chromosome = population.new_chromosome(chromosome);
                 new_chromosome.genes[index] = working_value;
-                new_chromosome.reset_metadata(self.genes_hashing);
+                self.reset_chromosome_metadata(&mut new_chromosome);
                 population.chromosomes.push(new_chromosome);
             }
             working_value += T::one();
@@ -752,21 +1017,34 @@ where
                 self.mutation_types
                     .iter()
                     .enumerate()
-                    .map(|(index, mutation_type)| match mutation_type {
-                        MutationType::Step(step) => self.permutable_gene_values_step(index, *step),
-                        MutationType::StepScaled(steps) => {
-                            self.permutable_gene_values_step_scaled(index, chromosome, steps)
-                        }
-                        MutationType::Discrete => {
-                            self.permutable_gene_values_discrete(index, chromosome)
-                        }
-                        _ => {
-                            panic!(
-                                "MultiRangeGenotype is not permutable for {:?}",
-                                mutation_type
-                            )
-                        }
-                    })
+                    .map(
+                        |(index, mutation_type)| match (mutation_type, self.allele_steps[index]) {
+                            (MutationType::Step(step), None) => {
+                                self.permutable_gene_values_step(index, *step)
+                            }
+                            (MutationType::StepScaled(steps), None) => {
+                                self.permutable_gene_values_step_scaled(index, chromosome, steps)
+                            }
+                            (MutationType::StepScaled(steps), Some(_)) => self
+                                .permutable_gene_values_step_scaled(index, chromosome, steps)
+                                .into_iter()
+                                .map(|value| self.snap_to_allele_step(index, value))
+                                .dedup()
+                                .collect(),
+                            (_, Some(allele_step)) => {
+                                self.permutable_gene_values_allele_step(index, allele_step)
+                            }
+                            (MutationType::Discrete, None) => {
+                                self.permutable_gene_values_discrete(index, chromosome)
+                            }
+                            _ => {
+                                panic!(
+                                    "MultiRangeGenotype is not permutable for {:?}",
+                                    mutation_type
+                                )
+                            }
+                        },
+                    )
                     .multi_cartesian_product()
                     .map(Chromosome::new),
             )
@@ -802,12 +1080,18 @@ where
         }
     }
     fn allows_permutation(&self) -> bool {
-        self.mutation_types.iter().all(|mutation_type| {
-            matches!(
-                mutation_type,
-                MutationType::Step(_) | MutationType::StepScaled(_) | MutationType::Discrete
-            )
-        })
+        self.mutation_types
+            .iter()
+            .zip(self.allele_steps.iter())
+            .all(|(mutation_type, allele_step)| {
+                allele_step.is_some()
+                    || matches!(
+                        mutation_type,
+                        MutationType::Step(_)
+                            | MutationType::StepScaled(_)
+                            | MutationType::Discrete
+                    )
+            })
     }
 }
 
@@ -870,6 +1154,18 @@ where
         .collect()
     }
 
+    pub fn permutable_gene_values_allele_step(&self, index: usize, allele_step: T) -> Vec<T> {
+        (0..self.allele_step_values_size(index, allele_step))
+            .map(|number_of_steps| {
+                T::add_steps(
+                    *self.allele_ranges[index].start(),
+                    allele_step,
+                    number_of_steps,
+                )
+            })
+            .collect()
+    }
+
     pub fn permutable_gene_values_discrete(
         &self,
         index: usize,
@@ -913,76 +1209,80 @@ where
         self.mutation_types
             .iter()
             .enumerate()
-            .map(|(index, mutation_type)| match mutation_type {
-                MutationType::Step(step) => {
-                    let allele_value_start = *self.allele_ranges[index].start();
-                    let allele_value_end = *self.allele_ranges[index].end();
-                    std::iter::successors(Some(allele_value_start), |value| {
-                        if *value < allele_value_end {
-                            let next_value = *value + *step;
-                            if next_value > allele_value_end {
-                                Some(allele_value_end)
+            .map(
+                |(index, mutation_type)| match (mutation_type, self.allele_steps[index]) {
+                    (MutationType::Step(step), None) => {
+                        let allele_value_start = *self.allele_ranges[index].start();
+                        let allele_value_end = *self.allele_ranges[index].end();
+                        std::iter::successors(Some(allele_value_start), |value| {
+                            if *value < allele_value_end {
+                                let next_value = *value + *step;
+                                if next_value > allele_value_end {
+                                    Some(allele_value_end)
+                                } else {
+                                    Some(next_value)
+                                }
                             } else {
-                                Some(next_value)
+                                None
                             }
-                        } else {
-                            None
-                        }
-                    })
-                    .count()
-                }
-                MutationType::StepScaled(steps) => {
-                    let (allele_value_start, allele_value_end) =
-                        if let Some(previous_scale_index) = scale_index.checked_sub(1) {
-                            let working_step = steps[previous_scale_index];
-                            (T::zero(), working_step + working_step)
-                        } else {
-                            (
-                                *self.allele_ranges[index].start(),
-                                *self.allele_ranges[index].end(),
-                            )
-                        };
+                        })
+                        .count()
+                    }
+                    // an upper bound with an allele_step, as the snapped values are deduplicated
+                    (MutationType::StepScaled(steps), _) => {
+                        let (allele_value_start, allele_value_end) =
+                            if let Some(previous_scale_index) = scale_index.checked_sub(1) {
+                                let working_step = steps[previous_scale_index];
+                                (T::zero(), working_step + working_step)
+                            } else {
+                                (
+                                    *self.allele_ranges[index].start(),
+                                    *self.allele_ranges[index].end(),
+                                )
+                            };
 
-                    let working_step = steps[scale_index];
-                    std::iter::successors(Some(allele_value_start), |value| {
-                        if *value < allele_value_end {
-                            let next_value = *value + working_step;
-                            if next_value > allele_value_end {
-                                Some(allele_value_end)
+                        let working_step = steps[scale_index];
+                        std::iter::successors(Some(allele_value_start), |value| {
+                            if *value < allele_value_end {
+                                let next_value = *value + working_step;
+                                if next_value > allele_value_end {
+                                    Some(allele_value_end)
+                                } else {
+                                    Some(next_value)
+                                }
                             } else {
-                                Some(next_value)
+                                None
                             }
-                        } else {
-                            None
-                        }
-                    })
-                    .count()
-                }
-                MutationType::Discrete => {
-                    let allele_value_start = self.allele_ranges[index].start().floor();
-                    let allele_value_end = self.allele_ranges[index].end().floor();
+                        })
+                        .count()
+                    }
+                    (_, Some(allele_step)) => self.allele_step_values_size(index, allele_step),
+                    (MutationType::Discrete, None) => {
+                        let allele_value_start = self.allele_ranges[index].start().floor();
+                        let allele_value_end = self.allele_ranges[index].end().floor();
 
-                    std::iter::successors(Some(allele_value_start), |value| {
-                        if *value < allele_value_end {
-                            let next_value = *value + T::one();
-                            if next_value > allele_value_end {
-                                Some(allele_value_end)
+                        std::iter::successors(Some(allele_value_start), |value| {
+                            if *value < allele_value_end {
+                                let next_value = *value + T::one();
+                                if next_value > allele_value_end {
+                                    Some(allele_value_end)
+                                } else {
+                                    Some(next_value)
+                                }
                             } else {
-                                Some(next_value)
+                                None
                             }
-                        } else {
-                            None
-                        }
-                    })
-                    .count()
-                }
-                _ => {
-                    panic!(
-                        "MultiRangeGenotype is not permutable for {:?}",
-                        mutation_type
-                    )
-                }
-            })
+                        })
+                        .count()
+                    }
+                    _ => {
+                        panic!(
+                            "MultiRangeGenotype is not permutable for {:?}",
+                            mutation_type
+                        )
+                    }
+                },
+            )
             .map(BigUint::from)
             .product()
     }
@@ -1036,6 +1336,7 @@ where
         Self {
             genes_size: self.genes_size,
             allele_ranges: self.allele_ranges.clone(),
+            allele_steps: self.allele_steps.clone(),
             mutation_types: self.mutation_types.clone(),
             gene_index_sampler: self.gene_index_sampler,
             allele_samplers,
@@ -1043,6 +1344,7 @@ where
             current_scale_index: self.current_scale_index,
             seed_genes_list: self.seed_genes_list.clone(),
             genes_hashing: self.genes_hashing,
+            genes_key_precision: self.genes_key_precision,
             chromosome_recycling: self.chromosome_recycling,
         }
     }
@@ -1056,6 +1358,7 @@ where
         f.debug_struct("Point")
             .field("genes_size", &self.genes_size)
             .field("allele_ranges", &self.allele_ranges)
+            .field("allele_steps", &self.allele_steps)
             .field("mutation_types", &self.mutation_types)
             .field("seed_genes_list", &self.seed_genes_list)
             .finish()
@@ -1070,6 +1373,7 @@ where
         writeln!(f, "genotype:")?;
         writeln!(f, "  genes_size: {}", self.genes_size)?;
         writeln!(f, "  mutation_types: {:?}", self.mutation_types())?;
+        writeln!(f, "  allele_steps: {:?}", self.allele_steps)?;
 
         writeln!(
             f,
@@ -1087,6 +1391,7 @@ where
             self.expected_number_of_sampled_index_duplicates_report()
         )?;
         writeln!(f, "  current scale index: {:?}", self.current_scale_index)?;
+        writeln!(f, "  genes_key_precision: {:?}", self.genes_key_precision)?;
         writeln!(f, "  seed_genes: {:?}", self.seed_genes_list.len())
     }
 }
@@ -1,11 +1,14 @@
 use super::builder::{Builder, TryFromBuilderError};
-use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype};
+use super::{
+    EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype,
+    PermutationIndexShuffle, PermutationOrder,
+};
 use crate::allele::Allele;
 use crate::chromosome::{Chromosome, Genes};
 use crate::population::Population;
 use factorial::Factorial;
 use itertools::Itertools;
-use num::BigUint;
+use num::{BigUint, ToPrimitive};
 use rand::distributions::{Distribution, Uniform, WeightedIndex};
 use rand::prelude::*;
 use std::collections::HashMap;
@@ -25,9 +28,9 @@ pub type DefaultAllele = usize;
 ///
 /// # Panics
 ///
-/// Does not support gene crossover, only point crossover is supported. Will panic is gene
-/// crossoveris tried, but [EvolveBuilder](crate::strategy::evolve::EvolveBuilder) shouldn't allow
-/// this.
+/// Does not support gene crossover, only point crossover is supported. Will panic if gene
+/// crossover is tried directly, but [EvolveBuilder](crate::strategy::evolve::EvolveBuilder)
+/// rejects these crossovers with a build error.
 ///
 /// # Example (usize, default):
 /// ```
@@ -78,6 +81,7 @@ pub struct MultiUnique<T: Allele + Hash = DefaultAllele> {
     allele_list_index_samplers: Vec<Uniform<usize>>,
     pub crossover_points: Vec<usize>,
     crossover_point_index_sampler: Option<Uniform<usize>>,
+    pub permutation_order: PermutationOrder,
     pub seed_genes_list: Vec<Vec<T>>,
     pub genes_hashing: bool,
     pub chromosome_recycling: bool,
@@ -127,6 +131,7 @@ impl<T: Allele + Hash> TryFrom<Builder<Self>> for MultiUnique<T> {
                     .collect(),
                 crossover_points,
                 crossover_point_index_sampler,
+                permutation_order: builder.permutation_order,
                 seed_genes_list: builder.seed_genes_list,
                 genes_hashing: builder.genes_hashing,
                 chromosome_recycling: builder.chromosome_recycling,
@@ -139,6 +144,34 @@ impl<T: Allele + Hash> MultiUnique<T> {
     fn mutation_type(&self) -> &MutationType<T> {
         &MutationType::Random
     }
+    /// The genes at the permutation index of the nested order, where the last set varies fastest.
+    /// The index within each set is decoded as Lehmer code into the permutation of the set
+    fn permutation_genes(&self, permutation_index: u64) -> Genes<T> {
+        let mut remainder = permutation_index;
+        let set_indices: Vec<u64> = self
+            .allele_list_sizes
+            .iter()
+            .rev()
+            .map(|allele_list_size| {
+                let set_size = (*allele_list_size as u64).factorial();
+                let set_index = remainder % set_size;
+                remainder /= set_size;
+                set_index
+            })
+            .collect();
+        let mut genes: Genes<T> = Vec::with_capacity(self.genes_size);
+        for (allele_list, mut set_index) in
+            self.allele_lists.iter().zip(set_indices.into_iter().rev())
+        {
+            let mut pool = allele_list.clone();
+            while !pool.is_empty() {
+                let radix = (pool.len() as u64 - 1).factorial();
+                genes.push(pool.remove((set_index / radix) as usize));
+                set_index %= radix;
+            }
+        }
+        genes
+    }
 }
 
 impl<T: Allele + Hash> Genotype for MultiUnique<T> {
@@ -239,6 +272,33 @@ impl<T: Allele + Hash> Genotype for MultiUnique<T> {
         }
         chromosome.reset_metadata(self.genes_hashing);
     }
+    /// Swaps the gene at each index with a random gene at another index in the same set, so the
+    /// gene always moves (no-op for a set of a single gene)
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        indices.iter().for_each(|index| {
+            let allele_list_index = self
+                .allele_list_index_offsets
+                .iter()
+                .rposition(|offset| *offset <= *index)
+                .unwrap();
+            let allele_list_size = self.allele_list_sizes[allele_list_index];
+            if allele_list_size < 2 {
+                return;
+            }
+            let allele_list_index_offset = self.allele_list_index_offsets[allele_list_index];
+            let mut other_index = allele_list_index_offset + rng.gen_range(0..allele_list_size - 1);
+            if other_index >= *index {
+                other_index += 1;
+            }
+            chromosome.genes.swap(*index, other_index);
+        });
+        chromosome.reset_metadata(self.genes_hashing);
+    }
     fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
         self.seed_genes_list = seed_genes_list;
     }
@@ -378,7 +438,28 @@ impl<T: Allele + Hash> PermutateGenotype for MultiUnique<T> {
         &'a self,
         _chromosome: Option<&Chromosome<Self::Allele>>,
     ) -> Box<dyn Iterator<Item = Chromosome<Self::Allele>> + Send + 'a> {
-        if self.seed_genes_list.is_empty() {
+        let shuffled_size = match self.permutation_order {
+            PermutationOrder::Shuffled(seed) => self
+                .chromosome_permutations_size()
+                .to_u64()
+                .map(|size| (size, seed)),
+            PermutationOrder::Nested => None,
+        };
+        if !self.seed_genes_list.is_empty() {
+            Box::new(
+                self.seed_genes_list
+                    .clone()
+                    .into_iter()
+                    .map(Chromosome::new),
+            )
+        } else if let Some((size, seed)) = shuffled_size {
+            let shuffle = PermutationIndexShuffle::new(size, seed);
+            Box::new(
+                (0..size).map(move |index| {
+                    Chromosome::new(self.permutation_genes(shuffle.index(index)))
+                }),
+            )
+        } else {
             Box::new(
                 self.allele_lists
                     .clone()
@@ -390,13 +471,6 @@ impl<T: Allele + Hash> PermutateGenotype for MultiUnique<T> {
                     .multi_cartesian_product()
                     .map(|gene_sets| Chromosome::new(gene_sets.into_iter().concat())),
             )
-        } else {
-            Box::new(
-                self.seed_genes_list
-                    .clone()
-                    .into_iter()
-                    .map(Chromosome::new),
-            )
         }
     }
 
@@ -437,6 +511,7 @@ impl<T: Allele + Hash> fmt::Display for MultiUnique<T> {
             "  expected_number_of_sampled_index_duplicates: {}",
             self.expected_number_of_sampled_index_duplicates_report()
         )?;
+        writeln!(f, "  permutation_order: {:?}", self.permutation_order)?;
         writeln!(f, "  seed_genes: {:?}", self.seed_genes_list.len())
     }
 }
@@ -1,4 +1,5 @@
-use crate::allele::Allele;
+use crate::allele::{Allele, RangeAllele};
+use rand::Rng;
 
 /// Controls mutation behavior for numeric genotypes (Range and MultiRange).
 ///
@@ -23,12 +24,14 @@ use crate::allele::Allele;
 /// - `Random`: Full range replacement
 /// - `Range(T)`: Fixed bandwidth range mutation (uniform sampling within ±bandwidth)
 /// - `Step(T)`: Fixed step mutation (exactly +step or -step)
+/// - `Gaussian(T)`: Normally distributed mutation with a fixed sigma
 /// - `Discrete`: Integer-only mutations for categorical data
 ///
 /// ## Scaled Mutations
 /// Progress through phases based on strategy-determined triggers:
 /// - `RangeScaled(Vec<T>)`: Range bandwidths that decrease through phases
 /// - `StepScaled(Vec<T>)`: Step sizes that decrease through phases
+/// - `GaussianScaled(Vec<T>)`: Gaussian sigmas that decrease through phases
 ///
 /// The strategy decides when to advance phases based on its own criteria:
 /// - Performance-based: Advance when `max_stale_generations` reached
@@ -132,6 +135,26 @@ use crate::allele::Allele;
 /// same bandwidth value. You could also alternate between exploration and exploitation several
 /// times (provide alternating high & low step sizes in the scales)
 ///
+/// ## `Gaussian(T)`
+/// Modifies the current gene value by adding a normally distributed delta with mean zero and
+/// the given standard deviation (sigma). The result is clamped to stay within the allele range.
+/// Like `Range` it preserves locality, but small adjustments are more likely than large ones,
+/// while occasional large jumps remain possible. For integer alleles the delta is rounded. A
+/// delta is at least the smallest increment, so the gene value always changes (unless clamped).
+///
+/// **Example:** With `Gaussian(1.0)` on a float range, about 68% of the mutations are within
+/// ±1.0 and about 95% within ±2.0.
+///
+/// **Use case:** Continuous optimization, fine-tuning real-valued parameters. The sigma can be
+/// set per gene with [MultiRangeGenotype](crate::genotype::MultiRangeGenotype).
+///
+/// ## `GaussianScaled(Vec<T>)`
+/// Multi-phase Gaussian mutation with strategy-controlled progression. Like `RangeScaled`, but
+/// each element is the sigma of the Gaussian delta for that phase. Always post-clamped.
+///
+/// **Example:** `GaussianScaled(vec![10.0, 1.0, 0.1])` provides three phases of progressively
+/// focused search, also for the [HillClimb](crate::strategy::hill_climb) neighbours.
+///
 /// ## `Discrete`
 /// Treats the numeric range as discrete integer values, useful for encoding
 /// categorical data or enum variants as numbers. Values are floored to integers
@@ -194,6 +217,8 @@ use crate::allele::Allele;
 /// * `Random`: Undersamples boundaries (infinitesimal probability)
 /// * `Range`: Post-clamped, slight boundary oversampling when near edges (assuming small bandwidth)
 /// * `Step`: Always clamped, slight boundary oversampling when near edges (assuming small step)
+/// * `Gaussian` and `GaussianScaled`: Post-clamped, slight boundary oversampling when near edges
+///   (assuming small sigma)
 /// * `RangeScaled`:
 ///   * Non-final phases: Pre-clamped, boundaries undersampled
 ///   * Final phase: Post-clamped, slight boundary oversampling (assuming small final bandwidth)
@@ -204,7 +229,7 @@ use crate::allele::Allele;
 ///
 /// # Phase Management
 ///
-/// For scaled mutations (`RangeScaled` and `StepScaled`), the current phase is
+/// For scaled mutations (`RangeScaled`, `StepScaled` and `GaussianScaled`), the current phase is
 /// determined by a `current_scale` index provided by the strategy. The strategy
 /// is responsible for:
 /// - Tracking when to advance phases
@@ -310,4 +335,31 @@ pub enum MutationType<T: Allele> {
     RangeScaled(Vec<T>),
     /// Step sizes for scaled mutations (strategy controls phase advancement)
     StepScaled(Vec<T>),
+    /// Gaussian mutation sigma (normally distributed delta, post-clamped)
+    Gaussian(T),
+    /// Gaussian sigmas for scaled mutations (strategy controls phase advancement)
+    GaussianScaled(Vec<T>),
+}
+
+/// Whether the Gaussian sigmas are positive (true for the other mutation types)
+pub(crate) fn positive_gaussian_sigmas<T: RangeAllele>(mutation_type: &MutationType<T>) -> bool {
+    match mutation_type {
+        MutationType::Gaussian(sigma) => *sigma > T::zero(),
+        MutationType::GaussianScaled(sigmas) => sigmas.iter().all(|sigma| *sigma > T::zero()),
+        _ => true,
+    }
+}
+
+/// Sample the magnitude of a Gaussian delta with the given sigma (Box-Muller transform), at
+/// least the smallest increment. The direction is left to the caller.
+pub(crate) fn sample_gaussian_delta<T: RangeAllele, R: Rng>(sigma: T, rng: &mut R) -> T {
+    let u1: f64 = 1.0 - rng.gen::<f64>(); // (0, 1], avoids ln(0)
+    let u2: f64 = rng.gen();
+    let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+    let delta = sigma.scale_by(z.abs());
+    if delta < T::smallest_increment() {
+        T::smallest_increment()
+    } else {
+        delta
+    }
 }
@@ -0,0 +1,450 @@
+use super::builder::{Builder, TryFromBuilderError};
+use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype};
+use crate::chromosome::{Chromosome, Genes};
+use crate::population::Population;
+use itertools::Itertools;
+use num::BigUint;
+use rand::distributions::{Distribution, Uniform};
+use rand::prelude::*;
+use std::fmt;
+
+/// Genes are a concatinated vector of booleans, grouped into one-hot encoded blocks, one for each
+/// categorical variable. Exactly one gene is active (true) in each group. The genes_size is
+/// derived to be the sum of the group_sizes. On random initialization, each group gets a random
+/// active gene. Each group has an equal probability of mutating. If a group mutates, its active
+/// gene moves to another random position within the group. Gene crossover swaps whole groups and
+/// point crossover only cuts on group boundaries, so the one-hot constraint always holds.
+///
+/// # Example:
+/// ```
+/// use genetic_algorithm::genotype::{Genotype, OneHotGenotype};
+///
+/// let genotype = OneHotGenotype::builder()
+///     .with_group_sizes(vec![3, 2, 4])
+///     .with_genes_hashing(true) // optional, defaults to true
+///     .with_chromosome_recycling(true) // optional, defaults to true
+///     .build()
+///     .unwrap();
+///
+/// // chromosome genes example: [false,true,false, true,false, false,false,false,true]
+/// // three one-hot groups, for categorical values 1, 0 and 3
+/// ```
+#[derive(Clone, Debug)]
+pub struct OneHot {
+    pub genes_size: usize,
+    pub group_sizes: Vec<usize>,
+    pub group_index_offsets: Vec<usize>,
+    group_index_sampler: Uniform<usize>,
+    gene_index_sampler: Uniform<usize>,
+    pub crossover_points: Vec<usize>,
+    crossover_point_index_sampler: Option<Uniform<usize>>,
+    pub seed_genes_list: Vec<Vec<bool>>,
+    pub genes_hashing: bool,
+    pub chromosome_recycling: bool,
+}
+
+impl TryFrom<Builder<Self>> for OneHot {
+    type Error = TryFromBuilderError;
+
+    fn try_from(builder: Builder<Self>) -> Result<Self, Self::Error> {
+        if builder.group_sizes.is_none() {
+            Err(TryFromBuilderError("OneHotGenotype requires a group_sizes"))
+        } else if builder.group_sizes.as_ref().map(|o| o.is_empty()).unwrap() {
+            Err(TryFromBuilderError(
+                "OneHotGenotype requires non-empty group_sizes",
+            ))
+        } else if builder
+            .group_sizes
+            .as_ref()
+            .map(|o| o.iter().any(|size| *size < 2))
+            .unwrap()
+        {
+            Err(TryFromBuilderError(
+                "OneHotGenotype requires all group_sizes > 1",
+            ))
+        } else if !builder
+            .seed_genes_list
+            .iter()
+            .all(|genes| Self::is_one_hot(genes, builder.group_sizes.as_ref().unwrap()))
+        {
+            Err(TryFromBuilderError(
+                "OneHotGenotype requires seed_genes of genes_size length with exactly one active gene per group",
+            ))
+        } else {
+            let group_sizes = builder.group_sizes.unwrap();
+            // has one last index too many, but robust for group lookup logic
+            let group_index_offsets = group_sizes.iter().fold(vec![0], |mut acc, size| {
+                acc.push(*acc.last().unwrap() + size);
+                acc
+            });
+
+            let mut crossover_points = group_index_offsets.clone();
+            crossover_points.remove(0);
+            crossover_points.pop();
+            let crossover_point_index_sampler = if crossover_points.is_empty() {
+                None
+            } else {
+                Some(Uniform::from(0..crossover_points.len()))
+            };
+            let genes_size = group_sizes.iter().sum();
+
+            Ok(Self {
+                genes_size,
+                group_sizes: group_sizes.clone(),
+                group_index_offsets,
+                group_index_sampler: Uniform::from(0..group_sizes.len()),
+                gene_index_sampler: Uniform::from(0..genes_size),
+                crossover_points,
+                crossover_point_index_sampler,
+                seed_genes_list: builder.seed_genes_list,
+                genes_hashing: builder.genes_hashing,
+                chromosome_recycling: builder.chromosome_recycling,
+            })
+        }
+    }
+}
+
+impl OneHot {
+    fn mutation_type(&self) -> &MutationType<bool> {
+        &MutationType::Random
+    }
+    fn group_index_for_gene_index(&self, index: usize) -> usize {
+        self.group_index_offsets
+            .iter()
+            .rposition(|offset| *offset <= index)
+            .unwrap()
+    }
+    /// Whether the genes hold exactly one active gene per group (and nothing more)
+    fn is_one_hot(genes: &[bool], group_sizes: &[usize]) -> bool {
+        genes.len() == group_sizes.iter().sum::<usize>()
+            && group_sizes
+                .iter()
+                .scan(0, |offset, size| {
+                    let group = &genes[*offset..*offset + size];
+                    *offset += size;
+                    Some(group)
+                })
+                .all(|group| group.iter().filter(|value| **value).count() == 1)
+    }
+    fn active_gene_index(&self, group_index: usize, genes: &[bool]) -> usize {
+        let offset = self.group_index_offsets[group_index];
+        offset
+            + genes[offset..offset + self.group_sizes[group_index]]
+                .iter()
+                .position(|value| *value)
+                .unwrap()
+    }
+    fn set_active_gene_index(&self, group_index: usize, index: usize, genes: &mut [bool]) {
+        let offset = self.group_index_offsets[group_index];
+        genes[offset..offset + self.group_sizes[group_index]].fill(false);
+        genes[index] = true;
+    }
+    /// Moves the active gene to another random position within the group
+    fn mutate_group<R: Rng>(&self, group_index: usize, genes: &mut [bool], rng: &mut R) {
+        let offset = self.group_index_offsets[group_index];
+        let active_index = self.active_gene_index(group_index, genes);
+        let mut new_index = offset + rng.gen_range(0..self.group_sizes[group_index] - 1);
+        if new_index >= active_index {
+            new_index += 1;
+        }
+        genes[active_index] = false;
+        genes[new_index] = true;
+    }
+    fn swap_group(
+        &self,
+        group_index: usize,
+        father: &mut Chromosome<bool>,
+        mother: &mut Chromosome<bool>,
+    ) {
+        let offset = self.group_index_offsets[group_index];
+        let end = offset + self.group_sizes[group_index];
+        father.genes[offset..end].swap_with_slice(&mut mother.genes[offset..end]);
+    }
+}
+
+impl Genotype for OneHot {
+    type Allele = bool;
+
+    fn genes_size(&self) -> usize {
+        self.genes_size
+    }
+    fn sample_gene_index<R: Rng>(&self, rng: &mut R) -> usize {
+        self.gene_index_sampler.sample(rng)
+    }
+    fn sample_gene_indices<R: Rng>(
+        &self,
+        count: usize,
+        allow_duplicates: bool,
+        rng: &mut R,
+    ) -> Vec<usize> {
+        if allow_duplicates {
+            rng.sample_iter(self.gene_index_sampler)
+                .take(count)
+                .collect()
+        } else {
+            rand::seq::index::sample(rng, self.genes_size, count.min(self.genes_size)).into_vec()
+        }
+    }
+
+    fn mutate_chromosome_genes<R: Rng>(
+        &self,
+        number_of_mutations: usize,
+        allow_duplicates: bool,
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        if allow_duplicates {
+            for _ in 0..number_of_mutations {
+                let group_index = self.group_index_sampler.sample(rng);
+                self.mutate_group(group_index, &mut chromosome.genes, rng);
+            }
+        } else {
+            rand::seq::index::sample(
+                rng,
+                self.group_sizes.len(),
+                number_of_mutations.min(self.group_sizes.len()),
+            )
+            .iter()
+            .for_each(|group_index| {
+                self.mutate_group(group_index, &mut chromosome.genes, rng);
+            });
+        }
+        chromosome.reset_metadata(self.genes_hashing);
+    }
+    /// Activates the gene at each index within its group. If it is already active, the active
+    /// gene moves to another random position within the group
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        indices.iter().for_each(|index| {
+            let group_index = self.group_index_for_gene_index(*index);
+            if chromosome.genes[*index] {
+                self.mutate_group(group_index, &mut chromosome.genes, rng);
+            } else {
+                self.set_active_gene_index(group_index, *index, &mut chromosome.genes);
+            }
+        });
+        chromosome.reset_metadata(self.genes_hashing);
+    }
+    fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
+        self.seed_genes_list = seed_genes_list;
+    }
+    fn seed_genes_list(&self) -> &Vec<Genes<Self::Allele>> {
+        &self.seed_genes_list
+    }
+    fn random_genes_factory<R: Rng>(&self, rng: &mut R) -> Vec<bool> {
+        if self.seed_genes_list.is_empty() {
+            self.group_sizes
+                .iter()
+                .flat_map(|group_size| {
+                    let mut genes = vec![false; *group_size];
+                    genes[rng.gen_range(0..*group_size)] = true;
+                    genes
+                })
+                .collect()
+        } else {
+            self.seed_genes_list.choose(rng).unwrap().clone()
+        }
+    }
+    fn genes_capacity(&self) -> usize {
+        self.genes_size
+    }
+    fn genes_hashing(&self) -> bool {
+        self.genes_hashing
+    }
+    fn chromosome_recycling(&self) -> bool {
+        self.chromosome_recycling
+    }
+}
+
+impl EvolveGenotype for OneHot {
+    /// Swaps whole groups instead of single genes
+    fn crossover_chromosome_genes<R: Rng>(
+        &self,
+        number_of_crossovers: usize,
+        allow_duplicates: bool,
+        father: &mut Chromosome<Self::Allele>,
+        mother: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        if allow_duplicates {
+            rng.sample_iter(self.group_index_sampler)
+                .take(number_of_crossovers)
+                .for_each(|group_index| {
+                    self.swap_group(group_index, father, mother);
+                });
+        } else {
+            rand::seq::index::sample(
+                rng,
+                self.group_sizes.len(),
+                number_of_crossovers.min(self.group_sizes.len()),
+            )
+            .iter()
+            .for_each(|group_index| {
+                self.swap_group(group_index, father, mother);
+            });
+        }
+        mother.reset_metadata(self.genes_hashing);
+        father.reset_metadata(self.genes_hashing);
+    }
+    /// Only cuts on group boundaries
+    fn crossover_chromosome_points<R: Rng>(
+        &self,
+        number_of_crossovers: usize,
+        allow_duplicates: bool,
+        father: &mut Chromosome<Self::Allele>,
+        mother: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        if let Some(crossover_point_index_sampler) = self.crossover_point_index_sampler {
+            if allow_duplicates {
+                rng.sample_iter(crossover_point_index_sampler)
+                    .take(number_of_crossovers)
+                    .for_each(|point_index| {
+                        let gene_index = self.crossover_points[point_index];
+                        let mother_back = &mut mother.genes[gene_index..];
+                        let father_back = &mut father.genes[gene_index..];
+                        father_back.swap_with_slice(mother_back);
+                    });
+            } else {
+                rand::seq::index::sample(
+                    rng,
+                    self.crossover_points.len(),
+                    number_of_crossovers.min(self.crossover_points.len()),
+                )
+                .iter()
+                .sorted_unstable()
+                .chunks(2)
+                .into_iter()
+                .for_each(|mut chunk| match (chunk.next(), chunk.next()) {
+                    (Some(start_point_index), Some(end_point_index)) => {
+                        let start_gene_index = self.crossover_points[start_point_index];
+                        let end_gene_index = self.crossover_points[end_point_index];
+                        let mother_back = &mut mother.genes[start_gene_index..end_gene_index];
+                        let father_back = &mut father.genes[start_gene_index..end_gene_index];
+                        father_back.swap_with_slice(mother_back);
+                    }
+                    (Some(start_point_index), _) => {
+                        let start_gene_index = self.crossover_points[start_point_index];
+                        let mother_back = &mut mother.genes[start_gene_index..];
+                        let father_back = &mut father.genes[start_gene_index..];
+                        father_back.swap_with_slice(mother_back);
+                    }
+                    _ => (),
+                });
+            }
+        }
+        mother.reset_metadata(self.genes_hashing);
+        father.reset_metadata(self.genes_hashing);
+    }
+
+    fn has_crossover_indexes(&self) -> bool {
+        true
+    }
+    fn has_crossover_points(&self) -> bool {
+        true
+    }
+}
+impl HillClimbGenotype for OneHot {
+    fn fill_neighbouring_population<R: Rng>(
+        &self,
+        chromosome: &Chromosome<Self::Allele>,
+        population: &mut Population<Self::Allele>,
+        _rng: &mut R,
+    ) {
+        (0..self.group_sizes.len()).for_each(|group_index| {
+            let offset = self.group_index_offsets[group_index];
+            let active_index = self.active_gene_index(group_index, &chromosome.genes);
+            (offset..offset + self.group_sizes[group_index])
+                .filter(|index| *index != active_index)
+                .for_each(|index| {
+                    let mut new_chromosome = population.new_chromosome(chromosome);
+                    new_chromosome.genes[active_index] = false;
+                    new_chromosome.genes[index] = true;
+                    new_chromosome.reset_metadata(self.genes_hashing);
+                    population.chromosomes.push(new_chromosome);
+                });
+        });
+    }
+
+    fn neighbouring_population_size(&self) -> BigUint {
+        BigUint::from(
+            self.group_sizes
+                .iter()
+                .map(|group_size| group_size - 1)
+                .sum::<usize>(),
+        )
+    }
+}
+
+impl PermutateGenotype for OneHot {
+    fn chromosome_permutations_into_iter<'a>(
+        &'a self,
+        _chromosome: Option<&Chromosome<Self::Allele>>,
+    ) -> Box<dyn Iterator<Item = Chromosome<Self::Allele>> + Send + 'a> {
+        if self.seed_genes_list.is_empty() {
+            Box::new(
+                self.group_sizes
+                    .iter()
+                    .map(|group_size| 0..*group_size)
+                    .multi_cartesian_product()
+                    .map(|active_indices| {
+                        Chromosome::new(
+                            self.group_sizes
+                                .iter()
+                                .zip(active_indices)
+                                .flat_map(|(group_size, active_index)| {
+                                    (0..*group_size).map(move |index| index == active_index)
+                                })
+                                .collect(),
+                        )
+                    }),
+            )
+        } else {
+            Box::new(
+                self.seed_genes_list
+                    .clone()
+                    .into_iter()
+                    .map(Chromosome::new),
+            )
+        }
+    }
+    fn chromosome_permutations_size(&self) -> BigUint {
+        if self.seed_genes_list.is_empty() {
+            self.group_sizes.iter().map(|v| BigUint::from(*v)).product()
+        } else {
+            self.seed_genes_list.len().into()
+        }
+    }
+    fn allows_permutation(&self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for OneHot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "genotype:")?;
+        writeln!(f, "  genes_size: {}", self.genes_size)?;
+        writeln!(f, "  group_sizes: {:?}", self.group_sizes)?;
+        writeln!(f, "  mutation_type: {:?}", self.mutation_type())?;
+        writeln!(
+            f,
+            "  chromosome_permutations_size: {}",
+            self.chromosome_permutations_size_report()
+        )?;
+        writeln!(
+            f,
+            "  neighbouring_population_size: {}",
+            self.neighbouring_population_size_report()
+        )?;
+        writeln!(
+            f,
+            "  expected_number_of_sampled_index_duplicates: {}",
+            self.expected_number_of_sampled_index_duplicates_report()
+        )?;
+        writeln!(f, "  seed_genes: {:?}", self.seed_genes_list.len())
+    }
+}
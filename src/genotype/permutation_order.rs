@@ -0,0 +1,70 @@
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+/// The order of the chromosome permutations for
+/// [Permutate](crate::strategy::permutate::Permutate), set with `with_permutation_order()` on the
+/// genotype builder. Only used by [MultiListGenotype](super::MultiListGenotype) and
+/// [MultiUniqueGenotype](super::MultiUniqueGenotype). Both orders are deterministic, so an
+/// interrupted Permutate can still be resumed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PermutationOrder {
+    /// The nested order of the cartesian product of the sets, where the last set varies fastest
+    #[default]
+    Nested,
+    /// A pseudo random order without replacement, seeded by the provided value. Consecutive
+    /// permutations are scattered across the search space, so an early terminated Permutate (e.g.
+    /// with a max_duration) has still explored diverse regions. Falls back to the nested order
+    /// if the chromosome_permutations_size exceeds u64
+    Shuffled(u64),
+}
+
+/// The pseudo random bijection on the permutation indices `0..size` for
+/// [PermutationOrder::Shuffled]. A balanced Feistel network on the smallest even number of bits
+/// covering the size, with round keys drawn from the seed. An index mapped outside of `0..size`
+/// is mapped again (cycle walking) until it lands inside, which keeps it a bijection on `0..size`.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct PermutationIndexShuffle {
+    size: u64,
+    half_bits: u32,
+    round_keys: [u64; 4],
+}
+
+impl PermutationIndexShuffle {
+    pub(crate) fn new(size: u64, seed: u64) -> Self {
+        let bits = (u64::BITS - size.saturating_sub(1).leading_zeros()).max(2);
+        let mut rng = SmallRng::seed_from_u64(seed);
+        Self {
+            size,
+            half_bits: (bits + 1) / 2,
+            round_keys: [rng.gen(), rng.gen(), rng.gen(), rng.gen()],
+        }
+    }
+
+    pub(crate) fn index(&self, index: u64) -> u64 {
+        let mut index = self.feistel(index);
+        while index >= self.size {
+            index = self.feistel(index);
+        }
+        index
+    }
+
+    fn feistel(&self, index: u64) -> u64 {
+        let mask = (1_u64 << self.half_bits) - 1;
+        let mut left = index >> self.half_bits;
+        let mut right = index & mask;
+        for key in self.round_keys {
+            let next = left ^ (Self::round(right, key) & mask);
+            left = right;
+            right = next;
+        }
+        (left << self.half_bits) | right
+    }
+
+    // splitmix64 finalizer
+    fn round(value: u64, key: u64) -> u64 {
+        let mut z = value ^ key;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
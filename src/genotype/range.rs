@@ -1,13 +1,19 @@
 use super::builder::{Builder, TryFromBuilderError};
-use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype};
+use super::mutation_type::{positive_gaussian_sigmas, sample_gaussian_delta};
+use super::{
+    euclidean_distance, genes_mutation_weighted_index, sample_weighted_gene_indices,
+    EvolveGenotype, Genotype, GenotypeDistance, HillClimbGenotype, MutationType, PermutateGenotype,
+};
 use crate::allele::RangeAllele;
-use crate::chromosome::{Chromosome, Genes};
+use crate::chromosome::{Chromosome, Genes, GenesHash};
 use crate::population::Population;
 use itertools::Itertools;
-use num::BigUint;
-use rand::distributions::{Distribution, Uniform};
+use num::{BigUint, ToPrimitive};
+use rand::distributions::{Distribution, Uniform, WeightedIndex};
 use rand::prelude::*;
+use rustc_hash::FxHasher;
 use std::fmt;
+use std::hash::Hasher;
 use std::ops::RangeInclusive;
 
 pub type DefaultAllele = f32;
@@ -36,6 +42,17 @@ pub type DefaultAllele = f32;
 /// The discrete mutations traverse all allowed values for every scale (see
 /// [MutationType::Discrete])
 ///
+/// # Allele step
+///
+/// With an allele_step (see
+/// [with_allele_step](crate::genotype::GenotypeBuilder::with_allele_step)), the genes are
+/// restricted to the grid `start + k * allele_step` within the allele_range, e.g. `0..=100` with
+/// step 5. The random initialization samples uniformly from the grid, the other mutation types
+/// and the neighbours are snapped to the nearest grid value (moving at least one grid value if
+/// the mutation is smaller than the allele_step). Permutation is supported for all mutation types
+/// and traverses the whole grid, except for [MutationType::StepScaled], which keeps the localized
+/// grid search on snapped values.
+///
 /// ** Note: ** When all parameters are discrete, prefer
 /// [ListGenotype](crate::genotype::ListGenotype) as this is more optimized.
 ///
@@ -51,7 +68,12 @@ pub type DefaultAllele = f32;
 ///     .with_mutation_type(MutationType::Range(0.1)) // optional, restricts mutations to a smaller relative range bandwidth: [-0.1..=0.1] uniformly sampled
 ///     .with_mutation_type(MutationType::StepScaled(vec![0.1, 0.01, 0.001])) // optional, restricts mutations to relative step up or down of each scale
 ///     .with_mutation_type(MutationType::RangeScaled(vec![1.0, 1.0, 0.1, 0.1, 0.01])) // optional, optional, restricts mutations to relative bandwidth up or down of each scale
+///     .with_mutation_type(MutationType::Gaussian(0.1)) // optional, normally distributed mutations with sigma 0.1 (post-clamped)
+///     .with_mutation_type(MutationType::GaussianScaled(vec![0.1, 0.01])) // optional, normally distributed mutations with the sigma of each scale
+///     .with_include_self_neighbour(false) // optional, defaults to false, add an unchanged copy to the neighbouring population for plateau detection in HillClimb
+///     .with_genes_mutation_weights(vec![1.0; 100]) // optional, defaults to None, relative mutation probability per gene
 ///     .with_genes_hashing(true) // optional, defaults to true
+///     .with_genes_key_precision(3) // optional, defaults to None, quantizes float genes (to 3 decimals) for the genes_hash
 ///     .with_chromosome_recycling(true) // optional, defaults to true
 ///     .build()
 ///     .unwrap();
@@ -64,6 +86,7 @@ pub type DefaultAllele = f32;
 /// let genotype = RangeGenotype::<i32>::builder()
 ///     .with_genes_size(100)
 ///     .with_allele_range(0..=100) // also default mutation range
+///     .with_allele_step(5) // optional, restricts the genes to 0, 5, 10, ..., 100
 ///     .with_mutation_type(MutationType::Random) // default
 ///     .with_mutation_type(MutationType::Step(2)) // optional, restricts mutations to a relative step up or down
 ///     .with_mutation_type(MutationType::Range(2)) // optional, restricts mutations to a smaller relative range bandwidth: [-2..=2] uniformly sampled
@@ -80,14 +103,19 @@ where
 {
     pub genes_size: usize,
     pub allele_range: RangeInclusive<T>,
+    pub allele_step: Option<T>,
     pub mutation_type: MutationType<T>,
     gene_index_sampler: Uniform<usize>,
+    pub genes_mutation_weights: Option<Vec<f32>>,
+    gene_mutation_index_sampler: Option<WeightedIndex<f32>>,
     allele_sampler: Uniform<T>,
     // post-clamped sampler, always positive to support unsigned
     allele_bandwidth_sampler: Option<Uniform<T>>,
     pub current_scale_index: usize,
+    pub include_self_neighbour: bool,
     pub seed_genes_list: Vec<Vec<T>>,
     pub genes_hashing: bool,
+    pub genes_key_precision: Option<u32>,
     pub chromosome_recycling: bool,
 }
 
@@ -104,6 +132,21 @@ where
             ))
         } else if builder.allele_range.is_none() {
             Err(TryFromBuilderError("RangeGenotype requires a allele_range"))
+        } else if builder
+            .allele_step
+            .is_some_and(|allele_step| allele_step <= T::zero())
+        {
+            Err(TryFromBuilderError(
+                "RangeGenotype requires an allele_step > 0",
+            ))
+        } else if builder
+            .mutation_type
+            .as_ref()
+            .is_some_and(|mutation_type| !positive_gaussian_sigmas(mutation_type))
+        {
+            Err(TryFromBuilderError(
+                "RangeGenotype requires Gaussian sigmas > 0",
+            ))
         } else {
             let genes_size = builder.genes_size.unwrap();
             let allele_range = builder.allele_range.unwrap();
@@ -136,19 +179,34 @@ where
                 }
                 _ => None,
             };
+            let gene_mutation_index_sampler =
+                genes_mutation_weighted_index(builder.genes_mutation_weights.as_ref(), genes_size)
+                    .map_err(|_| {
+                        TryFromBuilderError(
+                            "RangeGenotype requires non-negative genes_mutation_weights of genes_size length with a positive sum",
+                        )
+                    })?;
 
-            Ok(Self {
+            let mut genotype = Self {
                 genes_size,
                 allele_range: allele_range.clone(),
+                allele_step: builder.allele_step,
                 mutation_type,
                 gene_index_sampler: Uniform::from(0..genes_size),
+                genes_mutation_weights: builder.genes_mutation_weights,
+                gene_mutation_index_sampler,
                 allele_sampler,
                 allele_bandwidth_sampler,
                 current_scale_index: 0,
-                seed_genes_list: builder.seed_genes_list,
+                include_self_neighbour: builder.include_self_neighbour,
+                seed_genes_list: vec![],
                 genes_hashing: builder.genes_hashing,
+                genes_key_precision: builder.genes_key_precision,
                 chromosome_recycling: builder.chromosome_recycling,
-            })
+            };
+            // snap the seed genes to the allele_step grid
+            genotype.set_seed_genes_list(builder.seed_genes_list);
+            Ok(genotype)
         }
     }
 }
@@ -161,15 +219,93 @@ where
         &self.mutation_type
     }
     pub fn sample_gene_random<R: Rng>(&self, rng: &mut R) -> T {
-        match self.mutation_type {
-            MutationType::Discrete => self.allele_sampler.sample(rng).floor(),
-            _ => self.allele_sampler.sample(rng),
+        if let Some(allele_step) = self.allele_step {
+            let number_of_steps = rng.gen_range(0..self.allele_step_values_size(allele_step));
+            T::add_steps(*self.allele_range.start(), allele_step, number_of_steps)
+        } else {
+            match self.mutation_type {
+                MutationType::Discrete => self.allele_sampler.sample(rng).floor(),
+                _ => self.allele_sampler.sample(rng),
+            }
+        }
+    }
+
+    pub fn mutate_gene<R: Rng>(&self, chromosome: &mut Chromosome<T>, index: usize, rng: &mut R) {
+        if self.allele_step.is_some() {
+            let current_value = chromosome.genes[index];
+            match self.mutation_type {
+                MutationType::Random | MutationType::Discrete => {
+                    chromosome.genes[index] = self.sample_gene_random(rng);
+                }
+                _ => {
+                    self.mutate_gene_value(chromosome, index, rng);
+                    chromosome.genes[index] =
+                        self.snap_mutated_value(current_value, chromosome.genes[index]);
+                }
+            }
+        } else {
+            self.mutate_gene_value(chromosome, index, rng);
+        }
+    }
+
+    /// The number of values on the allele_step grid within the allele_range
+    fn allele_step_values_size(&self, allele_step: T) -> usize {
+        self.allele_range
+            .end()
+            .steps_from(*self.allele_range.start(), allele_step)
+            + 1
+    }
+
+    /// Snap the value to the nearest value on the allele_step grid (identity without allele_step)
+    pub fn snap_to_allele_step(&self, value: T) -> T {
+        if let Some(allele_step) = self.allele_step {
+            let allele_range_start = *self.allele_range.start();
+            let max_number_of_steps = self.allele_step_values_size(allele_step) - 1;
+            let number_of_steps = value
+                .steps_from(allele_range_start, allele_step)
+                .min(max_number_of_steps);
+            let lower_value = T::add_steps(allele_range_start, allele_step, number_of_steps);
+            if number_of_steps < max_number_of_steps && value > lower_value {
+                let upper_value =
+                    T::add_steps(allele_range_start, allele_step, number_of_steps + 1);
+                if upper_value - value < value - lower_value {
+                    return upper_value;
+                }
+            }
+            lower_value
+        } else {
+            value
+        }
+    }
+
+    /// Snap the mutated value to the allele_step grid. If the mutation is smaller than the
+    /// allele_step (snapping back to the current value), move to the neighbouring grid value in
+    /// the direction of the mutation instead
+    fn snap_mutated_value(&self, current_value: T, mutated_value: T) -> T {
+        let snapped_value = self.snap_to_allele_step(mutated_value);
+        match (self.allele_step, snapped_value == current_value) {
+            (Some(allele_step), true) if mutated_value != current_value => {
+                let allele_range_start = *self.allele_range.start();
+                let number_of_steps = current_value.steps_from(allele_range_start, allele_step);
+                if mutated_value > current_value {
+                    if number_of_steps + 1 < self.allele_step_values_size(allele_step) {
+                        T::add_steps(allele_range_start, allele_step, number_of_steps + 1)
+                    } else {
+                        current_value
+                    }
+                } else if number_of_steps > 0 {
+                    T::add_steps(allele_range_start, allele_step, number_of_steps - 1)
+                } else {
+                    current_value
+                }
+            }
+            _ => snapped_value,
         }
     }
 
     // all delta's are positive, because we support unsigned integers as RangeAllele
     // quite the overhead to make this work, but I think it is worth it
-    pub fn mutate_gene<R: Rng>(&self, chromosome: &mut Chromosome<T>, index: usize, rng: &mut R) {
+    fn mutate_gene_value<R: Rng>(&self, chromosome: &mut Chromosome<T>, index: usize, rng: &mut R) {
         match &self.mutation_type {
             MutationType::Random => {
                 chromosome.genes[index] = self.allele_sampler.sample(rng);
@@ -250,6 +386,30 @@ where
                         T::clamped_sub(current_value, delta, *self.allele_range.start());
                 }
             }
+            MutationType::Gaussian(sigma) => {
+                // post-clamp
+                let current_value = chromosome.genes[index];
+                let delta = sample_gaussian_delta(*sigma, rng);
+                if rng.gen() {
+                    chromosome.genes[index] =
+                        T::clamped_add(current_value, delta, *self.allele_range.end());
+                } else {
+                    chromosome.genes[index] =
+                        T::clamped_sub(current_value, delta, *self.allele_range.start());
+                }
+            }
+            MutationType::GaussianScaled(sigmas) => {
+                // post-clamp
+                let current_value = chromosome.genes[index];
+                let delta = sample_gaussian_delta(sigmas[self.current_scale_index], rng);
+                if rng.gen() {
+                    chromosome.genes[index] =
+                        T::clamped_add(current_value, delta, *self.allele_range.end());
+                } else {
+                    chromosome.genes[index] =
+                        T::clamped_sub(current_value, delta, *self.allele_range.start());
+                }
+            }
         }
     }
 }
@@ -264,7 +424,11 @@ where
         self.genes_size
     }
     fn sample_gene_index<R: Rng>(&self, rng: &mut R) -> usize {
-        self.gene_index_sampler.sample(rng)
+        if let Some(weighted_index) = self.gene_mutation_index_sampler.as_ref() {
+            weighted_index.sample(rng)
+        } else {
+            self.gene_index_sampler.sample(rng)
+        }
     }
     fn sample_gene_indices<R: Rng>(
         &self,
@@ -272,7 +436,12 @@ where
         allow_duplicates: bool,
         rng: &mut R,
     ) -> Vec<usize> {
-        if allow_duplicates {
+        if let (Some(weights), Some(weighted_index)) = (
+            self.genes_mutation_weights.as_ref(),
+            self.gene_mutation_index_sampler.as_ref(),
+        ) {
+            sample_weighted_gene_indices(weights, weighted_index, count, allow_duplicates, rng)
+        } else if allow_duplicates {
             rng.sample_iter(self.gene_index_sampler)
                 .take(count)
                 .collect()
@@ -288,7 +457,11 @@ where
         chromosome: &mut Chromosome<Self::Allele>,
         rng: &mut R,
     ) {
-        if allow_duplicates {
+        if self.genes_mutation_weights.is_some() {
+            self.sample_gene_indices(number_of_mutations, allow_duplicates, rng)
+                .into_iter()
+                .for_each(|index| self.mutate_gene(chromosome, index, rng));
+        } else if allow_duplicates {
             for _ in 0..number_of_mutations {
                 let index = self.gene_index_sampler.sample(rng);
                 self.mutate_gene(chromosome, index, rng);
@@ -302,27 +475,46 @@ where
             .iter()
             .for_each(|index| self.mutate_gene(chromosome, index, rng));
         }
-        chromosome.reset_metadata(self.genes_hashing);
+        self.reset_chromosome_metadata(chromosome);
+    }
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        indices
+            .iter()
+            .for_each(|index| self.mutate_gene(chromosome, *index, rng));
+        self.reset_chromosome_metadata(chromosome);
     }
     fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
-        self.seed_genes_list = seed_genes_list;
+        self.seed_genes_list = seed_genes_list
+            .into_iter()
+            .map(|genes| {
+                genes
+                    .into_iter()
+                    .map(|value| self.snap_to_allele_step(value))
+                    .collect()
+            })
+            .collect();
     }
     fn seed_genes_list(&self) -> &Vec<Genes<Self::Allele>> {
         &self.seed_genes_list
     }
     fn max_scale_index(&self) -> Option<usize> {
         match &self.mutation_type {
-            MutationType::RangeScaled(scales) | MutationType::StepScaled(scales) => {
-                Some(scales.len().saturating_sub(1))
-            }
+            MutationType::RangeScaled(scales)
+            | MutationType::StepScaled(scales)
+            | MutationType::GaussianScaled(scales) => Some(scales.len().saturating_sub(1)),
             _ => None,
         }
     }
     fn current_scale_index(&self) -> Option<usize> {
         match self.mutation_type {
-            MutationType::RangeScaled(_) | MutationType::StepScaled(_) => {
-                Some(self.current_scale_index)
-            }
+            MutationType::RangeScaled(_)
+            | MutationType::StepScaled(_)
+            | MutationType::GaussianScaled(_) => Some(self.current_scale_index),
             _ => None,
         }
     }
@@ -356,6 +548,18 @@ where
     fn genes_hashing(&self) -> bool {
         self.genes_hashing
     }
+    /// Quantized to the genes_key_precision (if set), so near-equal genes share the genes_hash
+    fn calculate_genes_hash(&self, chromosome: &Chromosome<Self::Allele>) -> GenesHash {
+        if let Some(decimals) = self.genes_key_precision {
+            let mut hasher = FxHasher::default();
+            chromosome.genes.iter().for_each(|gene| {
+                T::hash_slice(&[gene.round_to_decimals(decimals)], &mut hasher);
+            });
+            hasher.finish()
+        } else {
+            chromosome.calculate_hash()
+        }
+    }
     fn chromosome_recycling(&self) -> bool {
         self.chromosome_recycling
     }
@@ -390,8 +594,8 @@ where
                 std::mem::swap(&mut father.genes[index], &mut mother.genes[index]);
             });
         }
-        mother.reset_metadata(self.genes_hashing);
-        father.reset_metadata(self.genes_hashing);
+        self.reset_chromosome_metadata(mother);
+        self.reset_chromosome_metadata(father);
     }
     fn crossover_chromosome_points<R: Rng>(
         &self,
@@ -433,8 +637,8 @@ where
                 _ => (),
             });
         }
-        mother.reset_metadata(self.genes_hashing);
-        father.reset_metadata(self.genes_hashing);
+        self.reset_chromosome_metadata(mother);
+        self.reset_chromosome_metadata(father);
     }
 
     fn has_crossover_indexes(&self) -> bool {
@@ -443,6 +647,17 @@ where
     fn has_crossover_points(&self) -> bool {
         true
     }
+    fn has_raw_gene_swap(&self) -> bool {
+        true
+    }
+}
+impl<T: RangeAllele + ToPrimitive> GenotypeDistance for Range<T>
+where
+    Uniform<T>: Send + Sync,
+{
+    fn genes_distance(&self, genes_a: &[T], genes_b: &[T]) -> f64 {
+        euclidean_distance(genes_a, genes_b)
+    }
 }
 impl<T: RangeAllele> HillClimbGenotype for Range<T>
 where
@@ -454,6 +669,12 @@ where
         population: &mut Population<Self::Allele>,
         rng: &mut R,
     ) {
+        if self.include_self_neighbour {
+            // unchanged copy, keeps the fitness_score as well
+            let new_chromosome = population.new_chromosome(chromosome);
+            population.chromosomes.push(new_chromosome);
+        }
+        let neighbours_start_index = population.chromosomes.len();
         match &self.mutation_type {
             MutationType::Random => {
                 self.fill_neighbouring_population_random(chromosome, population, rng)
@@ -481,14 +702,35 @@ where
                     )
                 }
             }
+            MutationType::Gaussian(sigma) => {
+                self.fill_neighbouring_population_gaussian(chromosome, population, *sigma, rng)
+            }
+            MutationType::GaussianScaled(sigmas) => {
+                let sigma = sigmas[self.current_scale_index];
+                self.fill_neighbouring_population_gaussian(chromosome, population, sigma, rng)
+            }
             MutationType::Discrete => {
                 self.fill_neighbouring_population_discrete(chromosome, population)
             }
         }
+        if self.allele_step.is_some() {
+            population.chromosomes[neighbours_start_index..]
+                .iter_mut()
+                .for_each(|new_chromosome| {
+                    new_chromosome
+                        .genes
+                        .iter_mut()
+                        .zip(chromosome.genes.iter())
+                        .for_each(|(new_value, current_value)| {
+                            *new_value = self.snap_mutated_value(*current_value, *new_value);
+                        });
+                    self.reset_chromosome_metadata(new_chromosome);
+                });
+        }
     }
 
     fn neighbouring_population_size(&self) -> BigUint {
-        BigUint::from(2 * self.genes_size)
+        BigUint::from(2 * self.genes_size + usize::from(self.include_self_neighbour))
     }
 }
 
@@ -511,13 +753,13 @@ where
                 let mut new_chromosome = population.new_chromosome(chromosome);
                 new_chromosome.genes[index] =
                     T::clamped_sub(current_value, step, allele_range_start);
-                new_chromosome.reset_metadata(self.genes_hashing);
+                self.reset_chromosome_metadata(&mut new_chromosome);
                 population.chromosomes.push(new_chromosome);
             };
             if current_value < allele_range_end {
                 let mut new_chromosome = population.new_chromosome(chromosome);
                 new_chromosome.genes[index] = T::clamped_add(current_value, step, allele_range_end);
-                new_chromosome.reset_metadata(self.genes_hashing);
+                self.reset_chromosome_metadata(&mut new_chromosome);
                 population.chromosomes.push(new_chromosome);
             };
         });
@@ -540,19 +782,48 @@ where
                     let mut new_chromosome = population.new_chromosome(chromosome);
                     new_chromosome.genes[index] =
                         T::clamped_sub(current_value, delta, allele_range_start);
-                    new_chromosome.reset_metadata(self.genes_hashing);
+                    self.reset_chromosome_metadata(&mut new_chromosome);
                     population.chromosomes.push(new_chromosome);
                 };
                 if current_value < allele_range_end {
                     let mut new_chromosome = population.new_chromosome(chromosome);
                     new_chromosome.genes[index] =
                         T::clamped_add(current_value, delta, allele_range_end);
-                    new_chromosome.reset_metadata(self.genes_hashing);
+                    self.reset_chromosome_metadata(&mut new_chromosome);
                     population.chromosomes.push(new_chromosome);
                 };
             });
         }
     }
+    fn fill_neighbouring_population_gaussian<R: Rng>(
+        &self,
+        chromosome: &Chromosome<T>,
+        population: &mut Population<T>,
+        sigma: T,
+        rng: &mut R,
+    ) {
+        let allele_range_start = *self.allele_range.start();
+        let allele_range_end = *self.allele_range.end();
+
+        (0..self.genes_size).for_each(|index| {
+            let current_value = chromosome.genes[index];
+            let delta = sample_gaussian_delta(sigma, rng);
+            if allele_range_start < current_value {
+                let mut new_chromosome = population.new_chromosome(chromosome);
+                new_chromosome.genes[index] =
+                    T::clamped_sub(current_value, delta, allele_range_start);
+                self.reset_chromosome_metadata(&mut new_chromosome);
+                population.chromosomes.push(new_chromosome);
+            };
+            if current_value < allele_range_end {
+                let mut new_chromosome = population.new_chromosome(chromosome);
+                new_chromosome.genes[index] =
+                    T::clamped_add(current_value, delta, allele_range_end);
+                self.reset_chromosome_metadata(&mut new_chromosome);
+                population.chromosomes.push(new_chromosome);
+            };
+        });
+    }
     fn fill_neighbouring_population_range_pre_clamp<R: Rng>(
         &self,
         chromosome: &Chromosome<T>,
@@ -572,7 +843,7 @@ where
                 if working_delta_down >= T::smallest_increment() {
                     let delta = rng.gen_range(T::smallest_increment()..=working_delta_down);
                     new_chromosome.genes[index] -= delta; // no need to check again
-                    new_chromosome.reset_metadata(self.genes_hashing);
+                    self.reset_chromosome_metadata(&mut new_chromosome);
                     population.chromosomes.push(new_chromosome);
                 }
             };
@@ -583,7 +854,7 @@ where
                 if working_delta_up >= T::smallest_increment() {
                     let delta = rng.gen_range(T::smallest_increment()..=working_delta_up);
                     new_chromosome.genes[index] += delta; // no need to check again
-                    new_chromosome.reset_metadata(self.genes_hashing);
+                    self.reset_chromosome_metadata(&mut new_chromosome);
                     population.chromosomes.push(new_chromosome);
                 }
             };
@@ -604,7 +875,7 @@ where
             if allele_range_start < current_value {
                 let mut new_chromosome = population.new_chromosome(chromosome);
                 new_chromosome.genes[index] = rng.gen_range(allele_range_start..current_value);
-                new_chromosome.reset_metadata(self.genes_hashing);
+                self.reset_chromosome_metadata(&mut new_chromosome);
                 population.chromosomes.push(new_chromosome);
             };
             if current_value < allele_range_end {
@@ -612,7 +883,7 @@ where
                 let new_value =
                     rng.gen_range((current_value + T::smallest_increment())..=allele_range_end);
                 new_chromosome.genes[index] = new_value;
-                new_chromosome.reset_metadata(self.genes_hashing);
+                self.reset_chromosome_metadata(&mut new_chromosome);
                 population.chromosomes.push(new_chromosome);
             };
         });
@@ -632,7 +903,7 @@ where
                 if working_value != current_value {
                     let mut new_chromosome = population.new_chromosome(chromosome);
                     new_chromosome.genes[index] = working_value;
-                    new_chromosome.reset_metadata(self.genes_hashing);
+                    self.reset_chromosome_metadata(&mut new_chromosome);
                     population.chromosomes.push(new_chromosome);
                 }
                 working_value += T::one();
@@ -652,15 +923,24 @@ where
         if self.seed_genes_list.is_empty() {
             Box::new(
                 (0..self.genes_size())
-                    .map(|index| match &self.mutation_type {
-                        MutationType::Step(step) => {
+                    .map(|index| match (&self.mutation_type, self.allele_step) {
+                        (MutationType::Step(step), None) => {
                             // ineffecient but symmetrical
                             self.permutable_gene_values_step(*step)
                         }
-                        MutationType::StepScaled(steps) => {
+                        (MutationType::StepScaled(steps), None) => {
                             self.permutable_gene_values_step_scaled(index, chromosome, steps)
                         }
-                        MutationType::Discrete => self.permutable_gene_values_discrete(),
+                        (MutationType::StepScaled(steps), Some(_)) => self
+                            .permutable_gene_values_step_scaled(index, chromosome, steps)
+                            .into_iter()
+                            .map(|value| self.snap_to_allele_step(value))
+                            .dedup()
+                            .collect(),
+                        (_, Some(allele_step)) => {
+                            self.permutable_gene_values_allele_step(allele_step)
+                        }
+                        (MutationType::Discrete, None) => self.permutable_gene_values_discrete(),
                         _ => {
                             panic!(
                                 "RangeGenotype is not permutable for {:?}",
@@ -704,10 +984,11 @@ where
     }
 
     fn allows_permutation(&self) -> bool {
-        matches!(
-            self.mutation_type,
-            MutationType::Step(_) | MutationType::StepScaled(_) | MutationType::Discrete
-        )
+        self.allele_step.is_some()
+            || matches!(
+                self.mutation_type,
+                MutationType::Step(_) | MutationType::StepScaled(_) | MutationType::Discrete
+            )
     }
 }
 
@@ -770,6 +1051,14 @@ where
         .collect()
     }
 
+    pub fn permutable_gene_values_allele_step(&self, allele_step: T) -> Vec<T> {
+        (0..self.allele_step_values_size(allele_step))
+            .map(|number_of_steps| {
+                T::add_steps(*self.allele_range.start(), allele_step, number_of_steps)
+            })
+            .collect()
+    }
+
     pub fn permutable_gene_values_discrete(&self) -> Vec<T> {
         let allele_value_start = self.allele_range.start().floor();
         let allele_value_end = self.allele_range.end().floor();
@@ -807,8 +1096,8 @@ where
 
     pub fn chromosome_permutations_size_for_scale_index(&self, scale_index: usize) -> BigUint {
         BigUint::from(
-            match &self.mutation_type {
-                MutationType::Step(step) => {
+            match (&self.mutation_type, self.allele_step) {
+                (MutationType::Step(step), None) => {
                     let allele_range_start = *self.allele_range.start();
                     let allele_range_end = *self.allele_range.end();
                     std::iter::successors(Some(allele_range_start), |value| {
@@ -825,7 +1114,8 @@ where
                     })
                     .count()
                 }
-                MutationType::StepScaled(steps) => {
+                // an upper bound with an allele_step, as the snapped values are deduplicated
+                (MutationType::StepScaled(steps), _) => {
                     let (allele_value_start, allele_value_end) =
                         if let Some(previous_scale_index) = scale_index.checked_sub(1) {
                             let working_step = steps[previous_scale_index];
@@ -849,7 +1139,8 @@ where
                     })
                     .count()
                 }
-                MutationType::Discrete => {
+                (_, Some(allele_step)) => self.allele_step_values_size(allele_step),
+                (MutationType::Discrete, None) => {
                     let allele_value_start = self.allele_range.start().floor();
                     let allele_value_end = self.allele_range.end().floor();
 
@@ -919,13 +1210,18 @@ where
         Self {
             genes_size: self.genes_size,
             allele_range: self.allele_range.clone(),
+            allele_step: self.allele_step,
             mutation_type: self.mutation_type.clone(),
             gene_index_sampler: self.gene_index_sampler,
+            genes_mutation_weights: self.genes_mutation_weights.clone(),
+            gene_mutation_index_sampler: self.gene_mutation_index_sampler.clone(),
             allele_sampler,
             allele_bandwidth_sampler,
             current_scale_index: self.current_scale_index,
+            include_self_neighbour: self.include_self_neighbour,
             seed_genes_list: self.seed_genes_list.clone(),
             genes_hashing: self.genes_hashing,
+            genes_key_precision: self.genes_key_precision,
             chromosome_recycling: self.chromosome_recycling,
         }
     }
@@ -939,7 +1235,10 @@ where
         f.debug_struct("Point")
             .field("genes_size", &self.genes_size)
             .field("allele_range", &self.allele_range)
+            .field("allele_step", &self.allele_step)
             .field("mutation_type", &self.mutation_type)
+            .field("genes_mutation_weights", &self.genes_mutation_weights)
+            .field("include_self_neighbour", &self.include_self_neighbour)
             .field("seed_genes_list", &self.seed_genes_list)
             .finish()
     }
@@ -952,6 +1251,7 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "genotype:")?;
         writeln!(f, "  genes_size: {}", self.genes_size)?;
+        writeln!(f, "  allele_step: {:?}", self.allele_step)?;
         writeln!(f, "  mutation_type: {:?}", self.mutation_type())?;
 
         writeln!(
@@ -970,6 +1270,7 @@ where
             self.expected_number_of_sampled_index_duplicates_report()
         )?;
         writeln!(f, "  current scale index: {:?}", self.current_scale_index)?;
+        writeln!(f, "  genes_key_precision: {:?}", self.genes_key_precision)?;
         writeln!(f, "  seed_genes: {:?}", self.seed_genes_list.len())
     }
 }
@@ -0,0 +1,454 @@
+use super::builder::{Builder, TryFromBuilderError};
+use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType};
+use crate::allele::RangeAllele;
+use crate::chromosome::{Chromosome, Genes};
+use crate::population::Population;
+use itertools::Itertools;
+use num::{BigUint, Float, NumCast};
+use rand::distributions::{Distribution, Uniform};
+use rand::prelude::*;
+use std::fmt;
+
+pub type DefaultAllele = f32;
+
+/// Genes are a vector of non-negative float values, which always sum up to the `allele_sum`
+/// (default 1.0), a so called simplex. Suitable for portfolio and allocation problems, where every
+/// chromosome has to stay feasible. On random initialization, the genes are sampled uniformly on
+/// the simplex.
+///
+/// A mutation transfers an amount from one gene to another gene, preserving the sum. The
+/// transferred amount is taken uniformly from the donor gene's value, or capped by the bandwidth
+/// for [MutationType::Range]. Other mutation types are not supported.
+///
+/// Crossover exchanges genes (or gene segments) between parents like
+/// [RangeGenotype](crate::genotype::RangeGenotype) and re-normalizes both children to the
+/// `allele_sum` afterwards.
+///
+/// # Example (f64):
+/// ```
+/// use genetic_algorithm::genotype::{Genotype, SimplexGenotype, MutationType};
+///
+/// let genotype = SimplexGenotype::<f64>::builder()
+///     .with_genes_size(10)
+///     .with_allele_sum(1.0) // optional, defaults to 1.0
+///     .with_mutation_type(MutationType::Range(0.05)) // optional, restricts the transferred amount, defaults to Random
+///     .with_genes_hashing(true) // optional, defaults to true
+///     .with_chromosome_recycling(true) // optional, defaults to true
+///     .build()
+///     .unwrap();
+/// ```
+pub struct Simplex<T: RangeAllele + Float = DefaultAllele>
+where
+    Uniform<T>: Send + Sync,
+{
+    pub genes_size: usize,
+    pub allele_sum: T,
+    pub mutation_type: MutationType<T>,
+    gene_index_sampler: Uniform<usize>,
+    unit_sampler: Uniform<T>,
+    pub seed_genes_list: Vec<Vec<T>>,
+    pub genes_hashing: bool,
+    pub chromosome_recycling: bool,
+}
+
+impl<T: RangeAllele + Float> TryFrom<Builder<Self>> for Simplex<T>
+where
+    Uniform<T>: Send + Sync,
+{
+    type Error = TryFromBuilderError;
+
+    fn try_from(builder: Builder<Self>) -> Result<Self, Self::Error> {
+        if !builder.genes_size.is_some_and(|x| x > 1) {
+            Err(TryFromBuilderError(
+                "SimplexGenotype requires a genes_size > 1",
+            ))
+        } else if builder
+            .allele_sum
+            .is_some_and(|x| x <= <T as RangeAllele>::zero())
+        {
+            Err(TryFromBuilderError(
+                "SimplexGenotype requires a allele_sum > 0",
+            ))
+        } else if !matches!(
+            builder.mutation_type,
+            None | Some(MutationType::Random) | Some(MutationType::Range(_))
+        ) {
+            Err(TryFromBuilderError(
+                "SimplexGenotype only supports MutationType::Random and MutationType::Range",
+            ))
+        } else if !builder.seed_genes_list.iter().all(|genes| {
+            Self::is_on_simplex(
+                genes,
+                builder.genes_size.unwrap(),
+                builder.allele_sum.unwrap_or(<T as RangeAllele>::one()),
+            )
+        }) {
+            Err(TryFromBuilderError(
+                "SimplexGenotype requires non-negative seed_genes of genes_size length, which sum up to the allele_sum",
+            ))
+        } else {
+            let genes_size = builder.genes_size.unwrap();
+            Ok(Self {
+                genes_size,
+                allele_sum: builder.allele_sum.unwrap_or(<T as RangeAllele>::one()),
+                mutation_type: builder.mutation_type.unwrap_or(MutationType::Random),
+                gene_index_sampler: Uniform::from(0..genes_size),
+                unit_sampler: Uniform::new(<T as RangeAllele>::zero(), <T as RangeAllele>::one()),
+                seed_genes_list: builder.seed_genes_list,
+                genes_hashing: builder.genes_hashing,
+                chromosome_recycling: builder.chromosome_recycling,
+            })
+        }
+    }
+}
+
+impl<T: RangeAllele + Float> Simplex<T>
+where
+    Uniform<T>: Send + Sync,
+{
+    fn mutation_type(&self) -> &MutationType<T> {
+        &self.mutation_type
+    }
+
+    /// Uniform sample on the simplex, by normalizing exponentially distributed values
+    pub fn sample_genes_random<R: Rng>(&self, rng: &mut R) -> Vec<T> {
+        let mut genes: Vec<T> = (0..self.genes_size)
+            .map(|_| -(<T as RangeAllele>::one() - self.unit_sampler.sample(rng)).ln())
+            .collect();
+        self.normalize_genes(&mut genes);
+        genes
+    }
+
+    /// Rescale the genes to sum up to the allele_sum. Falls back to an even distribution when the
+    /// genes sum up to zero
+    pub fn normalize_genes(&self, genes: &mut [T]) {
+        let sum = genes
+            .iter()
+            .fold(<T as RangeAllele>::zero(), |acc, value| acc + *value);
+        if sum > <T as RangeAllele>::zero() {
+            let factor = self.allele_sum / sum;
+            genes.iter_mut().for_each(|value| *value = *value * factor);
+        } else {
+            let value = self.allele_sum / <T as NumCast>::from(genes.len()).unwrap();
+            genes.iter_mut().for_each(|gene| *gene = value);
+        }
+    }
+
+    /// Whether the genes are non-negative, of genes_size length and sum up to the allele_sum
+    /// (within a relative float tolerance)
+    fn is_on_simplex(genes: &[T], genes_size: usize, allele_sum: T) -> bool {
+        let tolerance = allele_sum * <T as NumCast>::from(1e-5).unwrap();
+        let sum = genes
+            .iter()
+            .fold(<T as RangeAllele>::zero(), |acc, gene| acc + *gene);
+        genes.len() == genes_size
+            && genes.iter().all(|gene| *gene >= <T as RangeAllele>::zero())
+            && (sum - allele_sum).abs() <= tolerance
+    }
+
+    /// Transfer an amount from the donor gene to the receiver gene, preserving the sum
+    pub fn mutate_gene_pair<R: Rng>(
+        &self,
+        chromosome: &mut Chromosome<T>,
+        donor_index: usize,
+        receiver_index: usize,
+        rng: &mut R,
+    ) {
+        let delta = self.sample_delta(chromosome.genes[donor_index], rng);
+        chromosome.genes[donor_index] -= delta;
+        chromosome.genes[receiver_index] += delta;
+    }
+
+    fn sample_delta<R: Rng>(&self, donor_value: T, rng: &mut R) -> T {
+        let max_delta = match self.mutation_type {
+            MutationType::Range(bandwidth) => <T as RangeAllele>::min(bandwidth, donor_value),
+            _ => donor_value,
+        };
+        if max_delta > <T as RangeAllele>::zero() {
+            self.unit_sampler.sample(rng) * max_delta
+        } else {
+            <T as RangeAllele>::zero()
+        }
+    }
+
+    fn sample_receiver_index<R: Rng>(&self, donor_index: usize, rng: &mut R) -> usize {
+        // shift over the donor_index to guarantee a different receiver_index
+        let index = rng.gen_range(0..self.genes_size - 1);
+        if index >= donor_index {
+            index + 1
+        } else {
+            index
+        }
+    }
+}
+
+impl<T: RangeAllele + Float> Genotype for Simplex<T>
+where
+    Uniform<T>: Send + Sync,
+{
+    type Allele = T;
+
+    fn genes_size(&self) -> usize {
+        self.genes_size
+    }
+    fn sample_gene_index<R: Rng>(&self, rng: &mut R) -> usize {
+        self.gene_index_sampler.sample(rng)
+    }
+    fn sample_gene_indices<R: Rng>(
+        &self,
+        count: usize,
+        allow_duplicates: bool,
+        rng: &mut R,
+    ) -> Vec<usize> {
+        if allow_duplicates {
+            rng.sample_iter(self.gene_index_sampler)
+                .take(count)
+                .collect()
+        } else {
+            rand::seq::index::sample(rng, self.genes_size, count.min(self.genes_size)).into_vec()
+        }
+    }
+
+    /// Each mutation transfers an amount between a pair of genes. Without duplicates, the donor
+    /// genes are unique
+    fn mutate_chromosome_genes<R: Rng>(
+        &self,
+        number_of_mutations: usize,
+        allow_duplicates: bool,
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        if allow_duplicates {
+            for _ in 0..number_of_mutations {
+                let donor_index = self.gene_index_sampler.sample(rng);
+                let receiver_index = self.sample_receiver_index(donor_index, rng);
+                self.mutate_gene_pair(chromosome, donor_index, receiver_index, rng);
+            }
+        } else {
+            rand::seq::index::sample(
+                rng,
+                self.genes_size,
+                number_of_mutations.min(self.genes_size),
+            )
+            .iter()
+            .for_each(|donor_index| {
+                let receiver_index = self.sample_receiver_index(donor_index, rng);
+                self.mutate_gene_pair(chromosome, donor_index, receiver_index, rng);
+            });
+        }
+        chromosome.reset_metadata(self.genes_hashing);
+    }
+    /// Each index is a donor, transferring an amount to a random other gene
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        indices.iter().for_each(|donor_index| {
+            let receiver_index = self.sample_receiver_index(*donor_index, rng);
+            self.mutate_gene_pair(chromosome, *donor_index, receiver_index, rng);
+        });
+        chromosome.reset_metadata(self.genes_hashing);
+    }
+    fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
+        self.seed_genes_list = seed_genes_list;
+    }
+    fn seed_genes_list(&self) -> &Vec<Genes<Self::Allele>> {
+        &self.seed_genes_list
+    }
+    fn random_genes_factory<R: Rng>(&self, rng: &mut R) -> Vec<T> {
+        if self.seed_genes_list.is_empty() {
+            self.sample_genes_random(rng)
+        } else {
+            self.seed_genes_list.choose(rng).unwrap().clone()
+        }
+    }
+    fn genes_capacity(&self) -> usize {
+        self.genes_size
+    }
+    fn genes_hashing(&self) -> bool {
+        self.genes_hashing
+    }
+    fn chromosome_recycling(&self) -> bool {
+        self.chromosome_recycling
+    }
+}
+
+impl<T: RangeAllele + Float> EvolveGenotype for Simplex<T>
+where
+    Uniform<T>: Send + Sync,
+{
+    fn crossover_chromosome_genes<R: Rng>(
+        &self,
+        number_of_crossovers: usize,
+        allow_duplicates: bool,
+        father: &mut Chromosome<Self::Allele>,
+        mother: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        if allow_duplicates {
+            rng.sample_iter(self.gene_index_sampler)
+                .take(number_of_crossovers)
+                .for_each(|index| {
+                    std::mem::swap(&mut father.genes[index], &mut mother.genes[index]);
+                });
+        } else {
+            rand::seq::index::sample(
+                rng,
+                self.genes_size(),
+                number_of_crossovers.min(self.genes_size()),
+            )
+            .iter()
+            .for_each(|index| {
+                std::mem::swap(&mut father.genes[index], &mut mother.genes[index]);
+            });
+        }
+        self.normalize_genes(&mut mother.genes);
+        self.normalize_genes(&mut father.genes);
+        mother.reset_metadata(self.genes_hashing);
+        father.reset_metadata(self.genes_hashing);
+    }
+    fn crossover_chromosome_points<R: Rng>(
+        &self,
+        number_of_crossovers: usize,
+        allow_duplicates: bool,
+        father: &mut Chromosome<Self::Allele>,
+        mother: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        if allow_duplicates {
+            rng.sample_iter(self.gene_index_sampler)
+                .take(number_of_crossovers)
+                .for_each(|index| {
+                    let mother_back = &mut mother.genes[index..];
+                    let father_back = &mut father.genes[index..];
+                    father_back.swap_with_slice(mother_back);
+                });
+        } else {
+            rand::seq::index::sample(
+                rng,
+                self.genes_size(),
+                number_of_crossovers.min(self.genes_size()),
+            )
+            .iter()
+            .sorted_unstable()
+            .chunks(2)
+            .into_iter()
+            .for_each(|mut chunk| match (chunk.next(), chunk.next()) {
+                (Some(start_index), Some(end_index)) => {
+                    let mother_back = &mut mother.genes[start_index..end_index];
+                    let father_back = &mut father.genes[start_index..end_index];
+                    father_back.swap_with_slice(mother_back);
+                }
+                (Some(start_index), _) => {
+                    let mother_back = &mut mother.genes[start_index..];
+                    let father_back = &mut father.genes[start_index..];
+                    father_back.swap_with_slice(mother_back);
+                }
+                _ => (),
+            });
+        }
+        self.normalize_genes(&mut mother.genes);
+        self.normalize_genes(&mut father.genes);
+        mother.reset_metadata(self.genes_hashing);
+        father.reset_metadata(self.genes_hashing);
+    }
+
+    fn has_crossover_indexes(&self) -> bool {
+        true
+    }
+    fn has_crossover_points(&self) -> bool {
+        true
+    }
+}
+
+impl<T: RangeAllele + Float> HillClimbGenotype for Simplex<T>
+where
+    Uniform<T>: Send + Sync,
+{
+    /// A neighbour for each ordered (donor, receiver) pair of genes, skipping empty donors
+    fn fill_neighbouring_population<R: Rng>(
+        &self,
+        chromosome: &Chromosome<Self::Allele>,
+        population: &mut Population<Self::Allele>,
+        rng: &mut R,
+    ) {
+        (0..self.genes_size)
+            .filter(|donor_index| chromosome.genes[*donor_index] > <T as RangeAllele>::zero())
+            .for_each(|donor_index| {
+                (0..self.genes_size)
+                    .filter(|receiver_index| *receiver_index != donor_index)
+                    .for_each(|receiver_index| {
+                        let mut new_chromosome = population.new_chromosome(chromosome);
+                        self.mutate_gene_pair(
+                            &mut new_chromosome,
+                            donor_index,
+                            receiver_index,
+                            rng,
+                        );
+                        new_chromosome.reset_metadata(self.genes_hashing);
+                        population.chromosomes.push(new_chromosome);
+                    });
+            });
+    }
+
+    fn neighbouring_population_size(&self) -> BigUint {
+        BigUint::from(self.genes_size * (self.genes_size - 1))
+    }
+}
+
+impl<T: RangeAllele + Float> Clone for Simplex<T>
+where
+    Uniform<T>: Send + Sync,
+{
+    fn clone(&self) -> Self {
+        Self {
+            genes_size: self.genes_size,
+            allele_sum: self.allele_sum,
+            mutation_type: self.mutation_type.clone(),
+            gene_index_sampler: self.gene_index_sampler,
+            unit_sampler: Uniform::new(<T as RangeAllele>::zero(), <T as RangeAllele>::one()),
+            seed_genes_list: self.seed_genes_list.clone(),
+            genes_hashing: self.genes_hashing,
+            chromosome_recycling: self.chromosome_recycling,
+        }
+    }
+}
+
+impl<T: RangeAllele + Float> fmt::Debug for Simplex<T>
+where
+    Uniform<T>: Send + Sync,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Simplex")
+            .field("genes_size", &self.genes_size)
+            .field("allele_sum", &self.allele_sum)
+            .field("mutation_type", &self.mutation_type)
+            .field("seed_genes_list", &self.seed_genes_list)
+            .finish()
+    }
+}
+
+impl<T: RangeAllele + Float> fmt::Display for Simplex<T>
+where
+    Uniform<T>: Send + Sync,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "genotype:")?;
+        writeln!(f, "  genes_size: {}", self.genes_size)?;
+        writeln!(f, "  allele_sum: {:?}", self.allele_sum)?;
+        writeln!(f, "  mutation_type: {:?}", self.mutation_type())?;
+        writeln!(
+            f,
+            "  neighbouring_population_size: {}",
+            self.neighbouring_population_size_report()
+        )?;
+        writeln!(
+            f,
+            "  expected_number_of_sampled_index_duplicates: {}",
+            self.expected_number_of_sampled_index_duplicates_report()
+        )?;
+        writeln!(f, "  seed_genes: {:?}", self.seed_genes_list.len())
+    }
+}
@@ -1,5 +1,8 @@
 use super::builder::{Builder, TryFromBuilderError};
-use super::{EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype};
+use super::{
+    CrossoverOrderedMethod, EvolveGenotype, Genotype, GenotypeDistance, HillClimbGenotype,
+    MutationType, PermutateGenotype,
+};
 use crate::allele::Allele;
 use crate::chromosome::{Chromosome, Genes};
 use crate::population::Population;
@@ -8,6 +11,7 @@ use itertools::Itertools;
 use num::BigUint;
 use rand::distributions::{Distribution, Uniform};
 use rand::prelude::*;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::hash::Hash;
 
@@ -21,8 +25,12 @@ pub type DefaultAllele = usize;
 ///
 /// # Panics
 ///
-/// Does not support gene or point crossover. Will panic when tried, but
-/// [EvolveBuilder](crate::strategy::evolve::EvolveBuilder) shouldn't allow this.
+/// Does not support gene or point crossover. Will panic when tried directly, but
+/// [EvolveBuilder](crate::strategy::evolve::EvolveBuilder) rejects these crossovers with a
+/// build error. Use the permutation preserving ordered crossovers instead
+/// ([CrossoverOrdered](crate::crossover::CrossoverOrdered),
+/// [CrossoverPartiallyMapped](crate::crossover::CrossoverPartiallyMapped) and
+/// [CrossoverCycle](crate::crossover::CrossoverCycle)).
 ///
 /// # Example (usize, default):
 /// ```
@@ -94,6 +102,100 @@ impl<T: Allele + Hash> Unique<T> {
     fn mutation_type(&self) -> &MutationType<T> {
         &MutationType::Random
     }
+
+    /// Sample a non-empty slice `start..end` within `0..genes_size`
+    fn sample_slice<R: Rng>(&self, rng: &mut R) -> (usize, usize) {
+        let start = rng.gen_range(0..self.genes_size);
+        let end = rng.gen_range(start + 1..=self.genes_size);
+        (start, end)
+    }
+}
+
+/// The ordered crossovers identify the genes by the alleles themselves, so they require Eq
+impl<T: Allele + Hash + Eq> Unique<T> {
+    /// Order Crossover (OX1): keep `genes[slice_start..slice_end]` and fill the other positions
+    /// (starting at slice_end and wrapping around) with the donor genes in order (also starting at
+    /// slice_end and wrapping around), skipping the genes in the kept slice
+    pub fn order_crossover(
+        genes: &[T],
+        donor: &[T],
+        slice_start: usize,
+        slice_end: usize,
+    ) -> Vec<T> {
+        let genes_size = genes.len();
+        let kept: HashSet<T> = genes[slice_start..slice_end].iter().copied().collect();
+        let mut donor_genes = (0..genes_size)
+            .map(|offset| donor[(slice_end + offset) % genes_size])
+            .filter(|gene| !kept.contains(gene));
+        let mut child = genes.to_vec();
+        for offset in 0..(genes_size - kept.len()) {
+            let index = (slice_end + offset) % genes_size;
+            child[index] = donor_genes.next().unwrap();
+        }
+        child
+    }
+
+    /// Partially Mapped Crossover (PMX): keep `genes[slice_start..slice_end]` and take the other
+    /// positions from the donor. A donor gene which is already in the kept slice is replaced by
+    /// following the mapping kept gene -> donor gene at the same position, until a gene outside of
+    /// the kept slice is found
+    pub fn partially_mapped_crossover(
+        genes: &[T],
+        donor: &[T],
+        slice_start: usize,
+        slice_end: usize,
+    ) -> Vec<T> {
+        let kept_positions: HashMap<T, usize> = (slice_start..slice_end)
+            .map(|index| (genes[index], index))
+            .collect();
+        let mut child = genes.to_vec();
+        (0..genes.len())
+            .filter(|index| *index < slice_start || *index >= slice_end)
+            .for_each(|index| {
+                let mut gene = donor[index];
+                while let Some(kept_index) = kept_positions.get(&gene) {
+                    gene = donor[*kept_index];
+                }
+                child[index] = gene;
+            });
+        child
+    }
+
+    /// Cycle Crossover (CX): partition the positions into cycles (following the position in the
+    /// father of the mother's gene at the same position). The first child takes the even cycles
+    /// from the father and the odd cycles from the mother, the second child the other way around
+    pub fn cycle_crossover(father: &[T], mother: &[T]) -> (Vec<T>, Vec<T>) {
+        let genes_size = father.len();
+        let father_positions: HashMap<T, usize> = father
+            .iter()
+            .enumerate()
+            .map(|(index, gene)| (*gene, index))
+            .collect();
+        let mut cycle_numbers: Vec<Option<usize>> = vec![None; genes_size];
+        let mut cycle_number = 0;
+        for start in 0..genes_size {
+            if cycle_numbers[start].is_some() {
+                continue;
+            }
+            let mut index = start;
+            while cycle_numbers[index].is_none() {
+                cycle_numbers[index] = Some(cycle_number);
+                index = father_positions[&mother[index]];
+            }
+            cycle_number += 1;
+        }
+        cycle_numbers
+            .into_iter()
+            .enumerate()
+            .map(|(index, cycle_number)| {
+                if cycle_number.unwrap() % 2 == 0 {
+                    (father[index], mother[index])
+                } else {
+                    (mother[index], father[index])
+                }
+            })
+            .unzip()
+    }
 }
 impl<T: Allele + Hash> Genotype for Unique<T> {
     type Allele = T;
@@ -144,6 +246,26 @@ impl<T: Allele + Hash> Genotype for Unique<T> {
         }
         chromosome.reset_metadata(self.genes_hashing);
     }
+    /// Swaps the gene at each index with a random gene at another index, so the gene always
+    /// moves (no-op for a single gene)
+    fn mutate_chromosome_genes_at<R: Rng>(
+        &self,
+        indices: &[usize],
+        chromosome: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        if self.genes_size < 2 {
+            return;
+        }
+        indices.iter().for_each(|index| {
+            let mut other_index = rng.gen_range(0..self.genes_size - 1);
+            if other_index >= *index {
+                other_index += 1;
+            }
+            chromosome.genes.swap(*index, other_index);
+        });
+        chromosome.reset_metadata(self.genes_hashing);
+    }
     fn set_seed_genes_list(&mut self, seed_genes_list: Vec<Genes<Self::Allele>>) {
         self.seed_genes_list = seed_genes_list;
     }
@@ -170,7 +292,7 @@ impl<T: Allele + Hash> Genotype for Unique<T> {
     }
 }
 
-impl<T: Allele + Hash> EvolveGenotype for Unique<T> {
+impl<T: Allele + Hash + Eq> EvolveGenotype for Unique<T> {
     fn crossover_chromosome_genes<R: Rng>(
         &self,
         _number_of_crossovers: usize,
@@ -191,6 +313,78 @@ impl<T: Allele + Hash> EvolveGenotype for Unique<T> {
     ) {
         panic!("UniqueGenotype does not support point crossover")
     }
+    fn crossover_chromosome_ordered<R: Rng>(
+        &self,
+        method: CrossoverOrderedMethod,
+        father: &mut Chromosome<Self::Allele>,
+        mother: &mut Chromosome<Self::Allele>,
+        rng: &mut R,
+    ) {
+        let (father_genes, mother_genes) = match method {
+            CrossoverOrderedMethod::Order => {
+                let (slice_start, slice_end) = self.sample_slice(rng);
+                (
+                    Self::order_crossover(&father.genes, &mother.genes, slice_start, slice_end),
+                    Self::order_crossover(&mother.genes, &father.genes, slice_start, slice_end),
+                )
+            }
+            CrossoverOrderedMethod::PartiallyMapped => {
+                let (slice_start, slice_end) = self.sample_slice(rng);
+                (
+                    Self::partially_mapped_crossover(
+                        &father.genes,
+                        &mother.genes,
+                        slice_start,
+                        slice_end,
+                    ),
+                    Self::partially_mapped_crossover(
+                        &mother.genes,
+                        &father.genes,
+                        slice_start,
+                        slice_end,
+                    ),
+                )
+            }
+            CrossoverOrderedMethod::Cycle => Self::cycle_crossover(&father.genes, &mother.genes),
+        };
+        father.genes.copy_from_slice(&father_genes);
+        mother.genes.copy_from_slice(&mother_genes);
+        mother.reset_metadata(self.genes_hashing);
+        father.reset_metadata(self.genes_hashing);
+    }
+    fn has_crossover_ordered(&self) -> bool {
+        true
+    }
+}
+impl<T: Allele + Hash + Eq> GenotypeDistance for Unique<T> {
+    /// The swap distance, the number of genes minus the number of cycles in the permutation
+    /// which maps the one set of genes onto the other
+    fn genes_distance(&self, genes_a: &[T], genes_b: &[T]) -> f64 {
+        let positions_b: HashMap<T, usize> = genes_b
+            .iter()
+            .enumerate()
+            .map(|(index, gene)| (*gene, index))
+            .collect();
+        let permutation: Vec<usize> = genes_a
+            .iter()
+            .enumerate()
+            .map(|(index, gene)| positions_b.get(gene).copied().unwrap_or(index))
+            .collect();
+        let mut visited = vec![false; permutation.len()];
+        let mut number_of_cycles = 0;
+        for start in 0..permutation.len() {
+            if visited[start] {
+                continue;
+            }
+            number_of_cycles += 1;
+            let mut index = start;
+            while !visited[index] {
+                visited[index] = true;
+                index = permutation[index];
+            }
+        }
+        (permutation.len() - number_of_cycles) as f64
+    }
 }
 impl<T: Allele + Hash> HillClimbGenotype for Unique<T> {
     fn fill_neighbouring_population<R: Rng>(
@@ -1,16 +1,20 @@
 //! The mutation strategy, very important for avoiding local optimum lock-in. But don't overdo it,
 //! as it degenerates the population too much if overused. Use a mutation probability generally between
 //! 5% and 20%.
+mod byte_flip;
 mod multi_gene;
 mod multi_gene_dynamic;
 mod multi_gene_range;
+mod multi_gene_stratified;
 mod single_gene;
 mod single_gene_dynamic;
 mod wrapper;
 
+pub use self::byte_flip::ByteFlip as MutateByteFlip;
 pub use self::multi_gene::MultiGene as MutateMultiGene;
 pub use self::multi_gene_dynamic::MultiGeneDynamic as MutateMultiGeneDynamic;
 pub use self::multi_gene_range::MultiGeneRange as MutateMultiGeneRange;
+pub use self::multi_gene_stratified::MultiGeneStratified as MutateMultiGeneStratified;
 pub use self::single_gene::SingleGene as MutateSingleGene;
 pub use self::single_gene_dynamic::SingleGeneDynamic as MutateSingleGeneDynamic;
 pub use self::wrapper::Wrapper as MutateWrapper;
@@ -97,6 +101,12 @@ pub trait Mutate: Clone + Send + Sync + std::fmt::Debug {
         reporter: &mut SR,
         rng: &mut R,
     );
+
+    /// the configured (fixed) mutation_probability, if any. Used to warn about a zero
+    /// mutation_probability (no mutations) in the builder's validate()
+    fn mutation_probability(&self) -> Option<f32> {
+        None
+    }
 }
 
 #[derive(Clone, Debug)]
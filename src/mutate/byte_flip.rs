@@ -0,0 +1,89 @@
+use super::Mutate;
+use crate::genotype::EvolveGenotype;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::{StrategyAction, StrategyReporter, StrategyState};
+use rand::distributions::{Bernoulli, Distribution};
+use rand::Rng;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// Selects [Chromosomes](crate::chromosome::Chromosome) in the
+/// [Population](crate::population::Population) with the provided mutation_probability. Then
+/// flips a single random bit in the byte representation of a single random gene. This enables a
+/// bit-level search over arbitrary alleles, without a bespoke genotype. The
+/// [Genotype](crate::genotype::Genotype) mutation logic (allele_list, allele_range, uniqueness)
+/// is bypassed, so the mutated value can be outside of the genotype's allele_list or
+/// allele_range.
+///
+/// Requires the Allele to be [bytemuck::Pod], as every bit pattern must be a valid value (e.g.
+/// the integer types, but not bool or char). Mind that flipping a float's exponent or sign bits
+/// makes very large jumps (and can produce NaN or infinity).
+///
+/// Not part of the [MutateWrapper](crate::mutate::MutateWrapper), because of the extra Allele
+/// bound.
+#[derive(Debug, Clone)]
+pub struct ByteFlip<G: EvolveGenotype>
+where
+    G::Allele: bytemuck::Pod,
+{
+    _phantom: PhantomData<G>,
+    pub mutation_probability: f32,
+    pub mutation_probability_sampler: Bernoulli,
+}
+
+impl<G: EvolveGenotype> Mutate for ByteFlip<G>
+where
+    G::Allele: bytemuck::Pod,
+{
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        genotype: &G,
+        state: &mut EvolveState<G>,
+        _config: &EvolveConfig,
+        _reporter: &mut SR,
+        rng: &mut R,
+    ) {
+        let now = Instant::now();
+        for chromosome in state
+            .population
+            .chromosomes
+            .iter_mut()
+            .filter(|c| c.is_offspring())
+        {
+            if !chromosome.genes.is_empty() && self.mutation_probability_sampler.sample(rng) {
+                let index = rng.gen_range(0..chromosome.genes.len());
+                Self::flip_bit(&mut chromosome.genes[index], rng);
+                genotype.reset_chromosome_metadata(chromosome);
+            }
+        }
+        state.add_duration(StrategyAction::Mutate, now.elapsed());
+    }
+    fn mutation_probability(&self) -> Option<f32> {
+        Some(self.mutation_probability)
+    }
+}
+
+impl<G: EvolveGenotype> ByteFlip<G>
+where
+    G::Allele: bytemuck::Pod,
+{
+    pub fn new(mutation_probability: f32) -> Self {
+        let mutation_probability_sampler = Bernoulli::new(mutation_probability as f64).unwrap();
+        Self {
+            _phantom: PhantomData,
+            mutation_probability,
+            mutation_probability_sampler,
+        }
+    }
+
+    /// Flip a single random bit in the byte representation of the gene
+    pub fn flip_bit<R: Rng>(gene: &mut G::Allele, rng: &mut R) {
+        let bytes = bytemuck::bytes_of_mut(gene);
+        if !bytes.is_empty() {
+            let bit_index = rng.gen_range(0..bytes.len() * 8);
+            bytes[bit_index / 8] ^= 1 << (bit_index % 8);
+        }
+    }
+}
@@ -27,6 +27,7 @@ pub struct MultiGeneDynamic<G: EvolveGenotype> {
     pub number_of_mutations: usize,
     pub mutation_probability: f32,
     pub mutation_probability_step: f32,
+    pub mutation_probability_floor: f32,
     pub target_cardinality: usize,
     pub number_of_mutations_sampler: Uniform<usize>,
 }
@@ -47,8 +48,9 @@ impl<G: EvolveGenotype> Mutate for MultiGeneDynamic<G> {
         if let Some(cardinality) = state.population_cardinality() {
             let changed = match cardinality.cmp(&self.target_cardinality) {
                 Ordering::Greater => {
-                    self.mutation_probability =
-                        (self.mutation_probability - self.mutation_probability_step).max(0.0);
+                    self.mutation_probability = (self.mutation_probability
+                        - self.mutation_probability_step)
+                        .max(self.mutation_probability_floor);
                     true
                 }
                 Ordering::Less => {
@@ -99,8 +101,18 @@ impl<G: EvolveGenotype> MultiGeneDynamic<G> {
             number_of_mutations,
             mutation_probability: 0.0,
             mutation_probability_step,
+            mutation_probability_floor: 0.0,
             target_cardinality,
             number_of_mutations_sampler,
         }
     }
+
+    /// Never let the mutation_probability drop below the floor (default 0.0), preventing total
+    /// convergence in long runs. Clamped to [0.0, 1.0], as it is a probability
+    pub fn with_mutation_floor(mut self, mutation_probability_floor: f32) -> Self {
+        let mutation_probability_floor = mutation_probability_floor.clamp(0.0, 1.0);
+        self.mutation_probability_floor = mutation_probability_floor;
+        self.mutation_probability = self.mutation_probability.max(mutation_probability_floor);
+        self
+    }
 }
@@ -54,6 +54,9 @@ impl<G: EvolveGenotype> Mutate for MultiGeneRange<G> {
         }
         state.add_duration(StrategyAction::Mutate, now.elapsed());
     }
+    fn mutation_probability(&self) -> Option<f32> {
+        Some(self.mutation_probability)
+    }
 }
 
 impl<G: EvolveGenotype> MultiGeneRange<G> {
@@ -0,0 +1,99 @@
+use super::Mutate;
+use crate::genotype::EvolveGenotype;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::{StrategyAction, StrategyReporter, StrategyState};
+use rand::distributions::{Bernoulli, Distribution};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// Selects [Chromosomes](crate::chromosome::Chromosome) in the
+/// [Population](crate::population::Population) with the provided mutation_probability. Then
+/// mutates the selected chromosomes the provided number of times, where the
+/// [Genotype](crate::genotype::Genotype) determines whether this is random, relative or scaled.
+///
+/// The mutated genes are not sampled independently per chromosome (like
+/// [MutateMultiGene](crate::mutate::MutateMultiGene)), but drawn without replacement from a
+/// shuffled queue of all gene indices, which is shared across the chromosomes and generations and
+/// refilled when exhausted. So every gene is mutated once per `genes_size` mutations. This
+/// prevents some genes never mutating in small populations.
+///
+/// Duplicate mutations of the same gene in a single chromosome are avoided.
+#[derive(Debug, Clone)]
+pub struct MultiGeneStratified<G: EvolveGenotype> {
+    _phantom: PhantomData<G>,
+    pub number_of_mutations: usize,
+    pub mutation_probability: f32,
+    pub mutation_probability_sampler: Bernoulli,
+    pub gene_index_queue: Vec<usize>,
+}
+
+impl<G: EvolveGenotype> Mutate for MultiGeneStratified<G> {
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        genotype: &G,
+        state: &mut EvolveState<G>,
+        _config: &EvolveConfig,
+        _reporter: &mut SR,
+        rng: &mut R,
+    ) {
+        let now = Instant::now();
+        let mut indices = Vec::with_capacity(self.number_of_mutations);
+        for chromosome in state
+            .population
+            .chromosomes
+            .iter_mut()
+            .filter(|c| c.is_offspring())
+        {
+            if self.mutation_probability_sampler.sample(rng) {
+                self.fill_gene_indices(&mut indices, genotype.genes_size(), rng);
+                genotype.mutate_chromosome_genes_at(&indices, chromosome, rng);
+            }
+        }
+        state.add_duration(StrategyAction::Mutate, now.elapsed());
+    }
+    fn mutation_probability(&self) -> Option<f32> {
+        Some(self.mutation_probability)
+    }
+}
+
+impl<G: EvolveGenotype> MultiGeneStratified<G> {
+    pub fn new(number_of_mutations: usize, mutation_probability: f32) -> Self {
+        let mutation_probability_sampler = Bernoulli::new(mutation_probability as f64).unwrap();
+        Self {
+            _phantom: PhantomData,
+            number_of_mutations,
+            mutation_probability,
+            mutation_probability_sampler,
+            gene_index_queue: vec![],
+        }
+    }
+
+    /// Take the next unique gene indices from the queue. Indices already taken for this
+    /// chromosome are skipped and put back in front of the queue for the next chromosome
+    fn fill_gene_indices<R: Rng>(
+        &mut self,
+        indices: &mut Vec<usize>,
+        genes_size: usize,
+        rng: &mut R,
+    ) {
+        indices.clear();
+        let mut skipped_indices = vec![];
+        while indices.len() < self.number_of_mutations.min(genes_size) {
+            if self.gene_index_queue.is_empty() {
+                self.gene_index_queue.extend(0..genes_size);
+                self.gene_index_queue.shuffle(rng);
+            }
+            let index = self.gene_index_queue.pop().unwrap();
+            if indices.contains(&index) {
+                skipped_indices.push(index);
+            } else {
+                indices.push(index);
+            }
+        }
+        self.gene_index_queue.extend(skipped_indices);
+    }
+}
@@ -42,6 +42,9 @@ impl<G: EvolveGenotype> Mutate for SingleGene<G> {
         }
         state.add_duration(StrategyAction::Mutate, now.elapsed());
     }
+    fn mutation_probability(&self) -> Option<f32> {
+        Some(self.mutation_probability)
+    }
 }
 
 impl<G: EvolveGenotype> SingleGene<G> {
@@ -19,6 +19,7 @@ pub struct SingleGeneDynamic<G: EvolveGenotype> {
     _phantom: PhantomData<G>,
     pub mutation_probability: f32,
     pub mutation_probability_step: f32,
+    pub mutation_probability_floor: f32,
     pub target_cardinality: usize,
 }
 
@@ -38,8 +39,9 @@ impl<G: EvolveGenotype> Mutate for SingleGeneDynamic<G> {
         if let Some(cardinality) = state.population_cardinality() {
             let changed = match cardinality.cmp(&self.target_cardinality) {
                 Ordering::Greater => {
-                    self.mutation_probability =
-                        (self.mutation_probability - self.mutation_probability_step).max(0.0);
+                    self.mutation_probability = (self.mutation_probability
+                        - self.mutation_probability_step)
+                        .max(self.mutation_probability_floor);
                     true
                 }
                 Ordering::Less => {
@@ -84,7 +86,17 @@ impl<G: EvolveGenotype> SingleGeneDynamic<G> {
             _phantom: PhantomData,
             mutation_probability: 0.0,
             mutation_probability_step,
+            mutation_probability_floor: 0.0,
             target_cardinality,
         }
     }
+
+    /// Never let the mutation_probability drop below the floor (default 0.0), preventing total
+    /// convergence in long runs. Clamped to [0.0, 1.0], as it is a probability
+    pub fn with_mutation_floor(mut self, mutation_probability_floor: f32) -> Self {
+        let mutation_probability_floor = mutation_probability_floor.clamp(0.0, 1.0);
+        self.mutation_probability_floor = mutation_probability_floor;
+        self.mutation_probability = self.mutation_probability.max(mutation_probability_floor);
+        self
+    }
 }
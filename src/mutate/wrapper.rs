@@ -1,6 +1,7 @@
 pub use super::multi_gene::MultiGene as MutateMultiGene;
 pub use super::multi_gene_dynamic::MultiGeneDynamic as MutateMultiGeneDynamic;
 pub use super::multi_gene_range::MultiGeneRange as MutateMultiGeneRange;
+pub use super::multi_gene_stratified::MultiGeneStratified as MutateMultiGeneStratified;
 pub use super::single_gene::SingleGene as MutateSingleGene;
 pub use super::single_gene_dynamic::SingleGeneDynamic as MutateSingleGeneDynamic;
 pub use super::Mutate;
@@ -15,6 +16,7 @@ pub enum Wrapper<G: EvolveGenotype> {
     MultiGene(MutateMultiGene<G>),
     MultiGeneDynamic(MutateMultiGeneDynamic<G>),
     MultiGeneRange(MutateMultiGeneRange<G>),
+    MultiGeneStratified(MutateMultiGeneStratified<G>),
     SingleGene(MutateSingleGene<G>),
     SingleGeneDynamic(MutateSingleGeneDynamic<G>),
 }
@@ -36,12 +38,25 @@ impl<G: EvolveGenotype> Mutate for Wrapper<G> {
                 mutate.call(genotype, state, config, reporter, rng)
             }
             Wrapper::MultiGeneRange(mutate) => mutate.call(genotype, state, config, reporter, rng),
+            Wrapper::MultiGeneStratified(mutate) => {
+                mutate.call(genotype, state, config, reporter, rng)
+            }
             Wrapper::SingleGene(mutate) => mutate.call(genotype, state, config, reporter, rng),
             Wrapper::SingleGeneDynamic(mutate) => {
                 mutate.call(genotype, state, config, reporter, rng)
             }
         }
     }
+    fn mutation_probability(&self) -> Option<f32> {
+        match self {
+            Wrapper::MultiGene(mutate) => mutate.mutation_probability(),
+            Wrapper::MultiGeneDynamic(mutate) => mutate.mutation_probability(),
+            Wrapper::MultiGeneRange(mutate) => mutate.mutation_probability(),
+            Wrapper::MultiGeneStratified(mutate) => mutate.mutation_probability(),
+            Wrapper::SingleGene(mutate) => mutate.mutation_probability(),
+            Wrapper::SingleGeneDynamic(mutate) => mutate.mutation_probability(),
+        }
+    }
 }
 
 impl<G: EvolveGenotype> From<MutateSingleGene<G>> for Wrapper<G> {
@@ -69,3 +84,8 @@ impl<G: EvolveGenotype> From<MutateMultiGeneRange<G>> for Wrapper<G> {
         Wrapper::MultiGeneRange(mutate)
     }
 }
+impl<G: EvolveGenotype> From<MutateMultiGeneStratified<G>> for Wrapper<G> {
+    fn from(mutate: MutateMultiGeneStratified<G>) -> Self {
+        Wrapper::MultiGeneStratified(mutate)
+    }
+}
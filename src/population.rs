@@ -4,9 +4,13 @@ use crate::chromosome::{Chromosome, GenesHash};
 use crate::fitness::{FitnessOrdering, FitnessValue};
 use cardinality_estimator::CardinalityEstimator;
 use itertools::Itertools;
+use num::ToPrimitive;
 use rand::prelude::*;
+use rayon::prelude::*;
+use rustc_hash::FxHasher;
 use std::cmp::Reverse;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hasher;
 
 #[derive(Clone, Debug)]
 pub struct Population<T: Allele> {
@@ -50,6 +54,16 @@ impl<T: Allele> Population<T> {
         self.chromosomes.shuffle(rng);
     }
 
+    /// Parallel iterator over the chromosomes, for custom analysis or operators
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, Chromosome<T>> {
+        self.chromosomes.par_iter()
+    }
+    /// Parallel mutable iterator over the chromosomes, for custom analysis or operators. Reset
+    /// the metadata of changed chromosomes, as the genes_hash and fitness_score are not updated
+    pub fn par_iter_mut(&mut self) -> rayon::slice::IterMut<'_, Chromosome<T>> {
+        self.chromosomes.par_iter_mut()
+    }
+
     pub fn reset_age(&mut self) {
         self.chromosomes.iter_mut().for_each(|c| c.reset_age())
     }
@@ -125,6 +139,41 @@ impl<T: Allele> Population<T> {
         }
     }
 
+    /// Extend population by amount, cycling over the first source_size chromosomes (so the amount
+    /// can exceed the source_size), reusing recycled chromosomes if available
+    pub fn extend_from_within_cycled(&mut self, source_size: usize, amount: usize) {
+        if amount <= source_size {
+            return self.extend_from_within(amount);
+        }
+        for i in 0..amount {
+            let source = &self.chromosomes[i % source_size];
+            let chromosome = if let Some(mut recycled) = self.recycling_bin.pop() {
+                recycled.copy_from(source);
+                recycled
+            } else {
+                source.clone()
+            };
+            self.chromosomes.push(chromosome);
+        }
+    }
+
+    /// Consume the population, returning all chromosomes sorted best first. Chromosomes without
+    /// fitness score are last, also when minimizing. The sort is stable, so equal fitness scores
+    /// keep their population order.
+    pub fn into_sorted_vec(self, fitness_ordering: FitnessOrdering) -> Vec<Chromosome<T>> {
+        let mut chromosomes = self.chromosomes;
+        match fitness_ordering {
+            FitnessOrdering::Maximize => {
+                // None is smaller than Some, so last in reverse
+                chromosomes.sort_by_key(|c| Reverse(c.fitness_score()));
+            }
+            FitnessOrdering::Minimize => {
+                chromosomes.sort_by_key(|c| (c.fitness_score().is_none(), c.fitness_score()));
+            }
+        }
+        chromosomes
+    }
+
     /// fitness_score is Option and None is least, but invalid as best_chromosome, so filter it out
     /// when minimizing the fitness score, otherwise None would end up as best.
     pub fn best_chromosome(&self, fitness_ordering: FitnessOrdering) -> Option<&Chromosome<T>> {
@@ -203,6 +252,43 @@ impl<T: Allele> Population<T> {
         data.into_values().sorted().collect()
     }
 
+    // Only works when genes_hash is stored on chromosome, as this is the uniqueness key.
+    // Exact count of the distinct genes_hash values (see genes_cardinality for an estimate).
+    // Returns None when no genes_hash is stored
+    pub fn unique_chromosome_count(&self) -> Option<usize> {
+        let genes_hashes: HashSet<GenesHash> = self
+            .chromosomes
+            .iter()
+            .filter_map(|c| c.genes_hash())
+            .collect();
+        if genes_hashes.is_empty() {
+            None
+        } else {
+            Some(genes_hashes.len())
+        }
+    }
+
+    // The genes_hash is the uniqueness key, calculated on demand when not stored on chromosome.
+    // Keeps the first occurence of a genes_hash and drops the duplicates (to the recycling bin).
+    // Returns the number of dropped chromosomes
+    pub fn deduplicate(&mut self) -> usize {
+        let mut genes_hashes: HashSet<GenesHash> = HashSet::new();
+        let chromosomes = std::mem::take(&mut self.chromosomes);
+        let mut number_of_duplicates = 0;
+        for chromosome in chromosomes {
+            let genes_hash = chromosome
+                .genes_hash()
+                .unwrap_or_else(|| chromosome.calculate_hash());
+            if genes_hashes.insert(genes_hash) {
+                self.chromosomes.push(chromosome);
+            } else {
+                number_of_duplicates += 1;
+                self.drop_chromosome(chromosome);
+            }
+        }
+        number_of_duplicates
+    }
+
     // Only works when genes_hash is stored on chromosome, as this is the uniqueness key.
     // Takes the first index occurence of a genes_hash. Returns indices in ascending order (irrespective of fitness)
     pub fn best_unique_chromosome_indices(
@@ -238,6 +324,118 @@ impl<T: Allele> Population<T> {
         }
     }
 
+    /// The non-dominated fronts of the chromosomes by their multi-objective
+    /// [objectives](Chromosome::objectives), with the best front first (the fast non-dominated
+    /// sorting of NSGA-II). All objectives share the fitness_ordering. Each front holds the
+    /// chromosome indices in ascending order. Chromosomes without objectives (e.g. invalid) are
+    /// collected in a separate last front.
+    pub fn non_dominated_fronts(&self, fitness_ordering: FitnessOrdering) -> Vec<Vec<usize>> {
+        let (valid_indices, invalid_indices): (Vec<usize>, Vec<usize>) =
+            (0..self.chromosomes.len())
+                .partition(|index| !self.chromosomes[*index].objectives.is_empty());
+
+        let mut dominated_counts = vec![0_usize; self.chromosomes.len()];
+        let mut dominated_indices: Vec<Vec<usize>> = vec![vec![]; self.chromosomes.len()];
+        for (position, &a) in valid_indices.iter().enumerate() {
+            for &b in &valid_indices[position + 1..] {
+                let objectives_a = &self.chromosomes[a].objectives;
+                let objectives_b = &self.chromosomes[b].objectives;
+                if fitness_ordering.dominates(objectives_a, objectives_b) {
+                    dominated_indices[a].push(b);
+                    dominated_counts[b] += 1;
+                } else if fitness_ordering.dominates(objectives_b, objectives_a) {
+                    dominated_indices[b].push(a);
+                    dominated_counts[a] += 1;
+                }
+            }
+        }
+
+        let mut fronts: Vec<Vec<usize>> = vec![];
+        let mut front: Vec<usize> = valid_indices
+            .into_iter()
+            .filter(|index| dominated_counts[*index] == 0)
+            .collect();
+        while !front.is_empty() {
+            let mut next_front: Vec<usize> = vec![];
+            for &a in &front {
+                for &b in &dominated_indices[a] {
+                    dominated_counts[b] -= 1;
+                    if dominated_counts[b] == 0 {
+                        next_front.push(b);
+                    }
+                }
+            }
+            next_front.sort_unstable();
+            fronts.push(front);
+            front = next_front;
+        }
+        if !invalid_indices.is_empty() {
+            fronts.push(invalid_indices);
+        }
+        fronts
+    }
+
+    /// The indices of the first non-dominated front (see
+    /// [non_dominated_fronts](Self::non_dominated_fronts)), in ascending order. Duplicate genes
+    /// (by genes_hash, calculated on demand when not stored) are only taken once. Empty when there
+    /// are no objectives
+    pub fn pareto_front_indices(&self, fitness_ordering: FitnessOrdering) -> Vec<usize> {
+        let mut genes_hashes: Vec<GenesHash> = vec![];
+        self.non_dominated_fronts(fitness_ordering)
+            .into_iter()
+            .next()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|index| !self.chromosomes[*index].objectives.is_empty())
+            .filter(|index| {
+                let chromosome = &self.chromosomes[*index];
+                let genes_hash = chromosome
+                    .genes_hash()
+                    .unwrap_or_else(|| chromosome.calculate_hash());
+                if genes_hashes.contains(&genes_hash) {
+                    false
+                } else {
+                    genes_hashes.push(genes_hash);
+                    true
+                }
+            })
+            .collect()
+    }
+
+    /// The crowding distance (NSGA-II) of each chromosome index in the front, in the same order.
+    /// Per objective, the boundary chromosomes get an infinite distance and the others the
+    /// normalized distance between their neighbours, summed over the objectives. A larger
+    /// distance means a less crowded region of the front.
+    pub fn crowding_distances(&self, front: &[usize]) -> Vec<f64> {
+        let mut distances = vec![0.0_f64; front.len()];
+        let number_of_objectives = front
+            .iter()
+            .map(|index| self.chromosomes[*index].objectives.len())
+            .max()
+            .unwrap_or(0);
+        let mut positions: Vec<usize> = (0..front.len()).collect();
+        for objective_index in 0..number_of_objectives {
+            let value = |position: usize| -> f64 {
+                self.chromosomes[front[position]]
+                    .objectives
+                    .get(objective_index)
+                    .map_or(0.0, |value| *value as f64)
+            };
+            positions.sort_by(|a, b| value(*a).total_cmp(&value(*b)));
+            if let (Some(&first), Some(&last)) = (positions.first(), positions.last()) {
+                distances[first] = f64::INFINITY;
+                distances[last] = f64::INFINITY;
+                let range = value(last) - value(first);
+                if range > 0.0 {
+                    for window in positions.windows(3) {
+                        distances[window[1]] += (value(window[2]) - value(window[0])) / range;
+                    }
+                }
+            }
+        }
+        distances
+    }
+
     pub fn age_mean(&self) -> f32 {
         stats::mean(self.chromosomes.iter().map(|c| c.age())) as f32
     }
@@ -256,6 +454,60 @@ impl<T: Allele> Population<T> {
     pub fn fitness_score_stddev(&self) -> f32 {
         stats::stddev(self.chromosomes.iter().filter_map(|c| c.fitness_score())) as f32
     }
+    pub fn fitness_score_min(&self) -> Option<FitnessValue> {
+        self.chromosomes
+            .iter()
+            .filter_map(|c| c.fitness_score())
+            .min()
+    }
+    pub fn fitness_score_max(&self) -> Option<FitnessValue> {
+        self.chromosomes
+            .iter()
+            .filter_map(|c| c.fitness_score())
+            .max()
+    }
+    /// The fitness score at the given fraction (0.0 to 1.0) of the ascending sorted fitness
+    /// scores, linearly interpolated between the neighbouring ranks. So `0.5` is the median and
+    /// `0.25` the first quartile. Chromosomes without fitness score are ignored
+    pub fn fitness_score_percentile(&self, percentile: f32) -> Option<f32> {
+        let mut values: Vec<FitnessValue> = self
+            .chromosomes
+            .iter()
+            .filter_map(|c| c.fitness_score())
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_unstable();
+        let position = (values.len() - 1) as f64 * percentile.clamp(0.0, 1.0) as f64;
+        let lower = values[position.floor() as usize] as f64;
+        let upper = values[position.ceil() as usize] as f64;
+        Some((lower + (upper - lower) * (position - position.floor())) as f32)
+    }
+    /// The number of fitness scores per bin, for number_of_bins equal width bins spanning the
+    /// fitness_score_min to the fitness_score_max (both inclusive). Chromosomes without fitness
+    /// score are ignored, returns an empty histogram if there are none
+    pub fn fitness_score_histogram(&self, number_of_bins: usize) -> Vec<usize> {
+        match (self.fitness_score_min(), self.fitness_score_max()) {
+            (Some(min), Some(max)) if number_of_bins > 0 => {
+                let mut histogram = vec![0; number_of_bins];
+                let width = (max - min) as f64 / number_of_bins as f64;
+                self.chromosomes
+                    .iter()
+                    .filter_map(|c| c.fitness_score())
+                    .for_each(|fitness_score| {
+                        let index = if width > 0.0 {
+                            ((fitness_score - min) as f64 / width) as usize
+                        } else {
+                            0
+                        };
+                        histogram[index.min(number_of_bins - 1)] += 1;
+                    });
+                histogram
+            }
+            _ => vec![],
+        }
+    }
     pub fn fitness_score_cardinality(&self) -> Option<usize> {
         let mut values = self
             .chromosomes
@@ -270,6 +522,32 @@ impl<T: Allele> Population<T> {
             None
         }
     }
+    /// The Shannon entropy (in bits) of the allele values per gene over the population, for
+    /// diagnosing premature convergence. Zero for a fully converged gene and at most one for a
+    /// boolean gene. Alleles are distinguished by their hash (bytes for floats). Empty for an
+    /// empty population
+    pub fn genes_entropy(&self) -> Vec<f64> {
+        let genes_size = self.chromosomes.first().map_or(0, |c| c.genes.len());
+        let population_size = self.chromosomes.len() as f64;
+        (0..genes_size)
+            .map(|index| {
+                let mut counts: HashMap<u64, usize> = HashMap::new();
+                self.chromosomes.iter().for_each(|c| {
+                    let mut hasher = FxHasher::default();
+                    T::hash_slice(&c.genes[index..=index], &mut hasher);
+                    *counts.entry(hasher.finish()).or_insert(0) += 1;
+                });
+                counts
+                    .values()
+                    .map(|count| {
+                        let probability = *count as f64 / population_size;
+                        -probability * probability.log2()
+                    })
+                    .sum::<f64>()
+                    .max(0.0)
+            })
+            .collect()
+    }
     pub fn genes_cardinality(&self) -> Option<usize> {
         let mut values = self
             .chromosomes
@@ -285,3 +563,15 @@ impl<T: Allele> Population<T> {
         }
     }
 }
+
+impl<T: Allele + ToPrimitive> Population<T> {
+    /// The variance of the allele values per gene over the population (for numeric alleles, e.g.
+    /// [RangeGenotype](crate::genotype::RangeGenotype)), for diagnosing premature convergence.
+    /// Zero for a fully converged gene. Empty for an empty population
+    pub fn genes_variance(&self) -> Vec<f64> {
+        let genes_size = self.chromosomes.first().map_or(0, |c| c.genes.len());
+        (0..genes_size)
+            .map(|index| stats::variance(self.chromosomes.iter().map(|c| c.genes[index])))
+            .collect()
+    }
+}
@@ -17,11 +17,27 @@
 //! `SelectTournament` where the best chromosome is not guaranteed to be
 //! selected for a tournament if the `population_size` is larger than the
 //! `target_population_size`
+//!
+//! The optional deduplication (`with_deduplication(true)` on `SelectElite` and
+//! `SelectTournament`) drops the chromosomes with duplicate genes before selection, to enforce
+//! genetic diversity. The stored genes_hash is used with `with_genes_hashing(true)` on the
+//! genotype, otherwise the genes are hashed on demand. Note the population size shrinks below the
+//! target_population_size if there are not enough unique chromosomes.
 mod elite;
+mod nsga2;
+mod percentile;
+mod proportionate;
+mod sharing;
 mod tournament;
 mod wrapper;
 
 pub use self::elite::Elite as SelectElite;
+pub use self::nsga2::Nsga2 as SelectNsga2;
+pub use self::percentile::Percentile as SelectPercentile;
+pub use self::proportionate::Proportionate as SelectProportionate;
+pub use self::proportionate::ProportionateSampling as SelectProportionateSampling;
+pub use self::proportionate::ProportionateScaling as SelectProportionateScaling;
+pub use self::sharing::Sharing as SelectSharing;
 pub use self::tournament::Tournament as SelectTournament;
 pub use self::wrapper::Wrapper as SelectWrapper;
 
@@ -156,6 +172,17 @@ pub trait Select: Clone + Send + Sync + std::fmt::Debug {
 
         (new_parents_size, new_offspring_size)
     }
+
+    /// the configured tournament_size, if any. Used to warn about a target_population_size
+    /// smaller than the tournament_size in the builder's validate()
+    fn tournament_size(&self) -> Option<usize> {
+        None
+    }
+    /// the configured winning_probability of a soft tournament, if any. Validated to be between
+    /// 0.0 and 1.0 in the builder
+    fn winning_probability(&self) -> Option<f32> {
+        None
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -18,6 +18,7 @@ pub struct Elite<G: EvolveGenotype> {
     _phantom: PhantomData<G>,
     pub replacement_rate: f32,
     pub elitism_rate: f32,
+    pub deduplicate: bool,
 }
 
 impl<G: EvolveGenotype> Select for Elite<G> {
@@ -32,6 +33,11 @@ impl<G: EvolveGenotype> Select for Elite<G> {
         _rng: &mut R,
     ) {
         let now = Instant::now();
+        if self.deduplicate {
+            state.population.deduplicate();
+        }
+        let pre_selection_mean = state.population.fitness_score_mean();
+        let pre_selection_stddev = state.population.fitness_score_stddev();
 
         let mut elite_chromosomes =
             self.extract_elite_chromosomes(state, config, self.elitism_rate);
@@ -80,6 +86,7 @@ impl<G: EvolveGenotype> Select for Elite<G> {
         );
         state.population.chromosomes = chromosomes;
 
+        state.update_selection_intensity(pre_selection_mean, pre_selection_stddev, config);
         state.add_duration(StrategyAction::Select, now.elapsed());
     }
 }
@@ -90,9 +97,17 @@ impl<G: EvolveGenotype> Elite<G> {
             _phantom: PhantomData,
             replacement_rate,
             elitism_rate,
+            deduplicate: false,
         }
     }
 
+    /// Drop the chromosomes with duplicate genes before selection, also without genes_hashing on
+    /// the genotype (see [select](crate::select))
+    pub fn with_deduplication(mut self, deduplicate: bool) -> Self {
+        self.deduplicate = deduplicate;
+        self
+    }
+
     pub fn selection(
         &self,
         chromosomes: &mut Vec<Chromosome<G::Allele>>,
@@ -0,0 +1,93 @@
+use super::Select;
+use crate::chromosome::Chromosome;
+use crate::genotype::EvolveGenotype;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::{StrategyAction, StrategyReporter, StrategyState};
+use rand::prelude::*;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// The (front index, crowding distance) rank of a chromosome
+type RankedChromosome<T> = ((usize, f64), Chromosome<T>);
+
+/// NSGA-II selection for multi-objective optimization, using the objectives of a
+/// [FitnessMultiObjective](crate::fitness::FitnessMultiObjective). Parents and offspring compete
+/// equally: the chromosomes are sorted by non-dominated front first (see
+/// [non_dominated_fronts](crate::population::Population::non_dominated_fronts)) and by crowding
+/// distance within the front (less crowded first), so the crossover pairs the best ranked
+/// chromosomes first. Then the target_population_size is kept and the excess dropped.
+///
+/// All objectives share the fitness_ordering. The fitness score itself is not used for the
+/// selection. Chromosomes without objectives are taken last. Use
+/// [Evolve::pareto_front](crate::strategy::evolve::Evolve::pareto_front) to extract the Pareto
+/// front of the final population.
+#[derive(Clone, Debug)]
+pub struct Nsga2<G: EvolveGenotype> {
+    _phantom: PhantomData<G>,
+}
+
+impl<G: EvolveGenotype> Select for Nsga2<G> {
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        _genotype: &G,
+        state: &mut EvolveState<G>,
+        config: &EvolveConfig,
+        _reporter: &mut SR,
+        _rng: &mut R,
+    ) {
+        let now = Instant::now();
+        let pre_selection_mean = state.population.fitness_score_mean();
+        let pre_selection_stddev = state.population.fitness_score_stddev();
+
+        let mut ranks: Vec<(usize, f64)> = vec![(0, 0.0); state.population.size()];
+        state
+            .population
+            .non_dominated_fronts(config.fitness_ordering)
+            .iter()
+            .enumerate()
+            .for_each(|(front_index, front)| {
+                front
+                    .iter()
+                    .zip(state.population.crowding_distances(front))
+                    .for_each(|(index, crowding_distance)| {
+                        ranks[*index] = (front_index, crowding_distance);
+                    });
+            });
+
+        let mut ranked_chromosomes: Vec<RankedChromosome<G::Allele>> = ranks
+            .into_iter()
+            .zip(state.population.chromosomes.drain(..))
+            .collect();
+        ranked_chromosomes.sort_by(|((front_a, distance_a), _), ((front_b, distance_b), _)| {
+            front_a
+                .cmp(front_b)
+                .then_with(|| distance_b.total_cmp(distance_a))
+        });
+        let mut chromosomes: Vec<Chromosome<G::Allele>> = ranked_chromosomes
+            .into_iter()
+            .map(|(_, chromosome)| chromosome)
+            .collect();
+        state
+            .population
+            .truncate_external(&mut chromosomes, config.target_population_size);
+        state.population.chromosomes = chromosomes;
+
+        state.update_selection_intensity(pre_selection_mean, pre_selection_stddev, config);
+        state.add_duration(StrategyAction::Select, now.elapsed());
+    }
+}
+
+impl<G: EvolveGenotype> Nsga2<G> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<G: EvolveGenotype> Default for Nsga2<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,116 @@
+use super::Select;
+use crate::chromosome::Chromosome;
+use crate::fitness::FitnessOrdering;
+use crate::genotype::EvolveGenotype;
+use crate::population::Population;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::{StrategyAction, StrategyReporter, StrategyState};
+use rand::prelude::*;
+use std::cmp::Reverse;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// Truncation selection expressed as a percentile. Sort the chromosomes with fittest first
+/// (chromosomes without fitness score last) and keep the top `percentile` fraction of the
+/// population (at least one chromosome), dropping the rest. The kept chromosomes are then cloned
+/// in ranking order to refill the population up to the target_population_size, so the better
+/// chromosomes get more crossover opportunities, while the population size stays stable. The
+/// kept size is capped by the target_population_size.
+///
+/// The cut-off position `percentile * population_size` is interpolated between the two
+/// neighbouring ranks: the fractional part is the probability of keeping the next chromosome as
+/// well. So `0.25` on a population of 10 keeps the best 2 chromosomes and the 3rd best one with a
+/// 50% chance.
+///
+/// In contrast to [SelectElite](crate::select::SelectElite) there is no replacement_rate or
+/// elitism_rate, parents and offspring compete equally.
+#[derive(Clone, Debug)]
+pub struct Percentile<G: EvolveGenotype> {
+    _phantom: PhantomData<G>,
+    pub percentile: f32,
+}
+
+impl<G: EvolveGenotype> Select for Percentile<G> {
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        _genotype: &G,
+        state: &mut EvolveState<G>,
+        config: &EvolveConfig,
+        _reporter: &mut SR,
+        rng: &mut R,
+    ) {
+        let now = Instant::now();
+        let pre_selection_mean = state.population.fitness_score_mean();
+        let pre_selection_stddev = state.population.fitness_score_stddev();
+
+        let population_size = state.population.size();
+        let selection_size = self
+            .selection_size(population_size, rng)
+            .max(population_size.min(1))
+            .min(config.target_population_size);
+
+        // detach and attach chromosomes for general reuse of selection method
+        let mut chromosomes = std::mem::take(&mut state.population.chromosomes);
+        self.selection(
+            &mut chromosomes,
+            selection_size,
+            &mut state.population,
+            config,
+        );
+        state.population.chromosomes = chromosomes;
+        if selection_size > 0 {
+            state.population.extend_from_within_cycled(
+                selection_size,
+                config.target_population_size - selection_size,
+            );
+        }
+
+        state.update_selection_intensity(pre_selection_mean, pre_selection_stddev, config);
+        state.add_duration(StrategyAction::Select, now.elapsed());
+    }
+}
+
+impl<G: EvolveGenotype> Percentile<G> {
+    /// The percentile is the fraction of the population to keep, clamped to 0.0..=1.0
+    pub fn new(percentile: f32) -> Self {
+        Self {
+            _phantom: PhantomData,
+            percentile: percentile.clamp(0.0, 1.0),
+        }
+    }
+
+    /// The interpolated number of chromosomes to keep
+    pub fn selection_size<R: Rng>(&self, population_size: usize, rng: &mut R) -> usize {
+        let position = population_size as f64 * self.percentile as f64;
+        let selection_size = position.floor() as usize;
+        let fraction = position - position.floor();
+        if fraction > 0.0 && rng.gen_bool(fraction) {
+            (selection_size + 1).min(population_size)
+        } else {
+            selection_size
+        }
+    }
+
+    pub fn selection(
+        &self,
+        chromosomes: &mut Vec<Chromosome<G::Allele>>,
+        selection_size: usize,
+        population: &mut Population<G::Allele>,
+        config: &EvolveConfig,
+    ) {
+        let selection_size = std::cmp::min(selection_size, chromosomes.len());
+        match config.fitness_ordering {
+            FitnessOrdering::Maximize => {
+                // None is smaller than Some, so last in reverse
+                chromosomes.sort_unstable_by_key(|c| Reverse(c.fitness_score()));
+            }
+            FitnessOrdering::Minimize => {
+                chromosomes
+                    .sort_unstable_by_key(|c| (c.fitness_score().is_none(), c.fitness_score()));
+            }
+        }
+        population.truncate_external(chromosomes, selection_size);
+    }
+}
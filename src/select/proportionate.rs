@@ -0,0 +1,313 @@
+use super::Select;
+use crate::chromosome::Chromosome;
+use crate::fitness::FitnessOrdering;
+use crate::genotype::EvolveGenotype;
+use crate::population::Population;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::{StrategyAction, StrategyReporter, StrategyState};
+use rand::prelude::*;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// The sampling of the [Proportionate] selection
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ProportionateSampling {
+    /// Spin the roulette wheel once per selected chromosome
+    #[default]
+    RouletteWheel,
+    /// Spin the wheel once with equally spaced pointers for all selected chromosomes (Stochastic
+    /// Universal Sampling), which minimizes the spread around the expected number of selections
+    StochasticUniversal,
+}
+
+/// The scaling of the fitness scores to selection weights of the [Proportionate] selection, both
+/// handle [FitnessOrdering::Minimize] and negative fitness scores
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ProportionateScaling {
+    /// The weight is the distance to the worst fitness score in the population plus one, so the
+    /// worst chromosome keeps a small chance
+    #[default]
+    Windowed,
+    /// The weight is the rank, from one for the worst to the population size for the best.
+    /// Insensitive to the magnitude of the fitness scores (and outliers)
+    Rank,
+}
+
+/// Fitness proportionate selection, where the chance of a chromosome to be selected is
+/// proportional to its weight, derived from the fitness score (see [ProportionateScaling]).
+/// Chromosomes without fitness score have zero weight and are only selected in shortage. Sample
+/// with the roulette wheel (see [new_roulette_wheel](Self::new_roulette_wheel)) or with
+/// Stochastic Universal Sampling (see [new_stochastic_universal](Self::new_stochastic_universal)).
+///
+/// As the chromosomes are moved and not cloned, they are sampled without replacement. For the
+/// roulette wheel this equals the sequential spinning of the wheel, where the selected chromosome
+/// is removed from the wheel each time. Stochastic Universal Sampling selects a chromosome once
+/// when multiple pointers hit it and spins again over the remaining chromosomes for the shortage.
+/// Like [SelectTournament](crate::select::SelectTournament), the selected chromosomes are
+/// ordered in the sampling order (shuffled for Stochastic Universal Sampling, as its pointers hit
+/// in population order), with the replacement_rate and elitism_rate.
+#[derive(Clone, Debug)]
+pub struct Proportionate<G: EvolveGenotype> {
+    _phantom: PhantomData<G>,
+    pub replacement_rate: f32,
+    pub elitism_rate: f32,
+    pub sampling: ProportionateSampling,
+    pub scaling: ProportionateScaling,
+}
+
+impl<G: EvolveGenotype> Select for Proportionate<G> {
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        _genotype: &G,
+        state: &mut EvolveState<G>,
+        config: &EvolveConfig,
+        _reporter: &mut SR,
+        rng: &mut R,
+    ) {
+        let now = Instant::now();
+        let pre_selection_mean = state.population.fitness_score_mean();
+        let pre_selection_stddev = state.population.fitness_score_stddev();
+
+        let mut elite_chromosomes =
+            self.extract_elite_chromosomes(state, config, self.elitism_rate);
+
+        #[allow(clippy::type_complexity)]
+        let (mut offspring, mut parents): (
+            Vec<Chromosome<G::Allele>>,
+            Vec<Chromosome<G::Allele>>,
+        ) = state
+            .population
+            .chromosomes
+            .drain(..)
+            .partition(|c| c.is_offspring());
+
+        let (new_parents_size, new_offspring_size) = self.parent_and_offspring_survival_sizes(
+            parents.len(),
+            offspring.len(),
+            config.target_population_size - elite_chromosomes.len(),
+            self.replacement_rate,
+        );
+
+        self.selection(
+            &mut parents,
+            new_parents_size,
+            &mut state.population,
+            config,
+            rng,
+        );
+        self.selection(
+            &mut offspring,
+            new_offspring_size,
+            &mut state.population,
+            config,
+            rng,
+        );
+
+        state.population.chromosomes.append(&mut elite_chromosomes);
+        state.population.chromosomes.append(&mut offspring);
+        state.population.chromosomes.append(&mut parents);
+
+        // detach and attach chromosomes for general reuse of selection method
+        let mut chromosomes = std::mem::take(&mut state.population.chromosomes);
+        self.selection(
+            &mut chromosomes,
+            config.target_population_size,
+            &mut state.population,
+            config,
+            rng,
+        );
+        state.population.chromosomes = chromosomes;
+
+        state.update_selection_intensity(pre_selection_mean, pre_selection_stddev, config);
+        state.add_duration(StrategyAction::Select, now.elapsed());
+    }
+}
+
+impl<G: EvolveGenotype> Proportionate<G> {
+    /// Roulette wheel selection with windowed scaling, see [Proportionate]
+    pub fn new_roulette_wheel(replacement_rate: f32, elitism_rate: f32) -> Self {
+        Self {
+            _phantom: PhantomData,
+            replacement_rate,
+            elitism_rate,
+            sampling: ProportionateSampling::RouletteWheel,
+            scaling: ProportionateScaling::Windowed,
+        }
+    }
+
+    /// Stochastic Universal Sampling with windowed scaling, see [Proportionate]
+    pub fn new_stochastic_universal(replacement_rate: f32, elitism_rate: f32) -> Self {
+        Self {
+            _phantom: PhantomData,
+            replacement_rate,
+            elitism_rate,
+            sampling: ProportionateSampling::StochasticUniversal,
+            scaling: ProportionateScaling::Windowed,
+        }
+    }
+
+    /// Override the scaling of the fitness scores to selection weights
+    pub fn with_scaling(mut self, scaling: ProportionateScaling) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
+    pub fn selection<R: Rng>(
+        &self,
+        chromosomes: &mut Vec<Chromosome<G::Allele>>,
+        selection_size: usize,
+        population: &mut Population<G::Allele>,
+        config: &EvolveConfig,
+        rng: &mut R,
+    ) {
+        let selection_size = std::cmp::min(selection_size, chromosomes.len());
+        let weights = self.weights(chromosomes, config.fitness_ordering);
+        let selected_indices = match self.sampling {
+            ProportionateSampling::RouletteWheel => {
+                Self::roulette_wheel_indices(&weights, selection_size, rng)
+            }
+            ProportionateSampling::StochasticUniversal => {
+                Self::stochastic_universal_indices(&weights, selection_size, rng)
+            }
+        };
+
+        let mut slots: Vec<Option<Chromosome<G::Allele>>> =
+            chromosomes.drain(..).map(Some).collect();
+        let selected_chromosomes: Vec<Chromosome<G::Allele>> = selected_indices
+            .into_iter()
+            .filter_map(|index| slots[index].take())
+            .collect();
+        // Recycle all non-selected chromosomes to population's recycling bin
+        let mut dropped_chromosomes: Vec<Chromosome<G::Allele>> =
+            slots.into_iter().flatten().collect();
+        population.truncate_external(&mut dropped_chromosomes, 0);
+        chromosomes.extend(selected_chromosomes);
+    }
+
+    /// The selection weight of each chromosome, zero for chromosomes without fitness score
+    pub fn weights(
+        &self,
+        chromosomes: &[Chromosome<G::Allele>],
+        fitness_ordering: FitnessOrdering,
+    ) -> Vec<f64> {
+        // higher is always better
+        let scores: Vec<Option<f64>> = chromosomes
+            .iter()
+            .map(|chromosome| {
+                chromosome
+                    .fitness_score()
+                    .map(|fitness_score| match fitness_ordering {
+                        FitnessOrdering::Maximize => fitness_score as f64,
+                        FitnessOrdering::Minimize => -(fitness_score as f64),
+                    })
+            })
+            .collect();
+        match self.scaling {
+            ProportionateScaling::Windowed => {
+                let worst_score = scores.iter().flatten().copied().reduce(f64::min);
+                scores
+                    .iter()
+                    .map(|score| match (score, worst_score) {
+                        (Some(score), Some(worst_score)) => score - worst_score + 1.0,
+                        _ => 0.0,
+                    })
+                    .collect()
+            }
+            ProportionateScaling::Rank => {
+                let mut ranked_indices: Vec<usize> = (0..scores.len())
+                    .filter(|index| scores[*index].is_some())
+                    .collect();
+                ranked_indices.sort_by(|a, b| {
+                    scores[*a]
+                        .unwrap_or_default()
+                        .total_cmp(&scores[*b].unwrap_or_default())
+                });
+                let mut weights = vec![0.0; scores.len()];
+                ranked_indices
+                    .into_iter()
+                    .enumerate()
+                    .for_each(|(rank, index)| weights[index] = (rank + 1) as f64);
+                weights
+            }
+        }
+    }
+
+    /// Sequential roulette wheel spins without replacement, sampled in one go with the
+    /// exponential keys `ln(u) / weight` (Efraimidis-Spirakis), highest keys first. Zero weights
+    /// come last, in the original order
+    fn roulette_wheel_indices<R: Rng>(
+        weights: &[f64],
+        selection_size: usize,
+        rng: &mut R,
+    ) -> Vec<usize> {
+        let mut keyed_indices: Vec<(f64, usize)> = weights
+            .iter()
+            .enumerate()
+            .map(|(index, weight)| {
+                if *weight > 0.0 {
+                    ((1.0 - rng.gen::<f64>()).ln() / weight, index)
+                } else {
+                    (f64::NEG_INFINITY, index)
+                }
+            })
+            .collect();
+        keyed_indices.sort_by(|(key_a, _), (key_b, _)| key_b.total_cmp(key_a));
+        keyed_indices
+            .into_iter()
+            .take(selection_size)
+            .map(|(_, index)| index)
+            .collect()
+    }
+
+    /// Spin with selection_size equally spaced pointers over the remaining chromosomes, select
+    /// each hit chromosome once and spin again for the shortage. Zero weights are only selected
+    /// when all remaining weights are zero, in the original order
+    fn stochastic_universal_indices<R: Rng>(
+        weights: &[f64],
+        selection_size: usize,
+        rng: &mut R,
+    ) -> Vec<usize> {
+        let mut selected = vec![false; weights.len()];
+        let mut selected_indices: Vec<usize> = Vec::with_capacity(selection_size);
+        while selected_indices.len() < selection_size {
+            let shortage = selection_size - selected_indices.len();
+            let total_weight: f64 = weights
+                .iter()
+                .zip(selected.iter())
+                .filter(|(_, selected)| !**selected)
+                .map(|(weight, _)| weight)
+                .sum();
+            if total_weight <= 0.0 {
+                let remaining_indices: Vec<usize> = (0..weights.len())
+                    .filter(|index| !selected[*index])
+                    .take(shortage)
+                    .collect();
+                selected_indices.extend(remaining_indices);
+                break;
+            }
+            let spacing = total_weight / shortage as f64;
+            let mut pointer = rng.gen::<f64>() * spacing;
+            let mut cumulative_weight = 0.0;
+            let mut hit_indices: Vec<usize> = Vec::with_capacity(shortage);
+            for (index, weight) in weights.iter().enumerate() {
+                if selected[index] || *weight <= 0.0 {
+                    continue;
+                }
+                cumulative_weight += weight;
+                if pointer < cumulative_weight && hit_indices.len() < shortage {
+                    hit_indices.push(index);
+                    while pointer < cumulative_weight {
+                        pointer += spacing;
+                    }
+                }
+            }
+            hit_indices.iter().for_each(|index| selected[*index] = true);
+            selected_indices.append(&mut hit_indices);
+        }
+        // the pointers hit in population order
+        selected_indices.shuffle(rng);
+        selected_indices
+    }
+}
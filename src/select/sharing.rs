@@ -0,0 +1,184 @@
+use super::Select;
+use crate::chromosome::Chromosome;
+use crate::fitness::FitnessOrdering;
+use crate::genotype::{EvolveGenotype, GenotypeDistance};
+use crate::population::Population;
+use crate::strategy::evolve::{EvolveConfig, EvolveState};
+use crate::strategy::{StrategyAction, StrategyReporter, StrategyState};
+use rand::prelude::*;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// Fitness sharing (niching), which reduces the fitness of chromosomes in crowded regions of the
+/// search space, to maintain multiple niches (sub-populations around different optima) instead of
+/// converging on a single optimum. Uses the genes distance of the
+/// [GenotypeDistance](crate::genotype::GenotypeDistance).
+///
+/// The niche count of a chromosome is the sum of the sharing function `1 - (d / sharing_radius)
+/// ^ sharing_alpha` over all chromosomes within the sharing_radius distance `d` (including
+/// itself, so the niche count is at least one). The shared fitness is the fitness score divided
+/// by the niche count (multiplied for [FitnessOrdering::Minimize], and vice versa for negative
+/// fitness scores), so it is always penalized towards worse. Then the chromosomes are sorted on
+/// the shared fitness, like [SelectElite](crate::select::SelectElite), with the same
+/// replacement_rate and elitism_rate. The elite is extracted on the raw fitness score. The
+/// fitness scores of the chromosomes themselves are not altered.
+///
+/// Note the niche count is quadratic in the population size. Not part of the
+/// [SelectWrapper](crate::select::SelectWrapper), as it requires a genotype distance.
+#[derive(Clone, Debug)]
+pub struct Sharing<G: EvolveGenotype + GenotypeDistance> {
+    _phantom: PhantomData<G>,
+    pub replacement_rate: f32,
+    pub elitism_rate: f32,
+    pub sharing_radius: f64,
+    pub sharing_alpha: f64,
+}
+
+impl<G: EvolveGenotype + GenotypeDistance> Select for Sharing<G> {
+    type Genotype = G;
+
+    fn call<R: Rng, SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        genotype: &G,
+        state: &mut EvolveState<G>,
+        config: &EvolveConfig,
+        _reporter: &mut SR,
+        _rng: &mut R,
+    ) {
+        let now = Instant::now();
+        let pre_selection_mean = state.population.fitness_score_mean();
+        let pre_selection_stddev = state.population.fitness_score_stddev();
+
+        let mut elite_chromosomes =
+            self.extract_elite_chromosomes(state, config, self.elitism_rate);
+
+        let shared_fitness_scores =
+            self.shared_fitness_scores(genotype, &state.population.chromosomes, config);
+
+        #[allow(clippy::type_complexity)]
+        let (offspring, parents): (
+            Vec<(f64, Chromosome<G::Allele>)>,
+            Vec<(f64, Chromosome<G::Allele>)>,
+        ) = shared_fitness_scores
+            .into_iter()
+            .zip(state.population.chromosomes.drain(..))
+            .partition(|(_, c)| c.is_offspring());
+
+        let (new_parents_size, new_offspring_size) = self.parent_and_offspring_survival_sizes(
+            parents.len(),
+            offspring.len(),
+            config.target_population_size - elite_chromosomes.len(),
+            self.replacement_rate,
+        );
+
+        let mut scored_chromosomes =
+            self.selection(offspring, new_offspring_size, &mut state.population);
+        scored_chromosomes.append(&mut self.selection(
+            parents,
+            new_parents_size,
+            &mut state.population,
+        ));
+        Self::sort_by_shared_fitness_score(&mut scored_chromosomes);
+
+        state.population.chromosomes.append(&mut elite_chromosomes);
+        state.population.chromosomes.extend(
+            scored_chromosomes
+                .into_iter()
+                .map(|(_, chromosome)| chromosome),
+        );
+
+        state.update_selection_intensity(pre_selection_mean, pre_selection_stddev, config);
+        state.add_duration(StrategyAction::Select, now.elapsed());
+    }
+}
+
+impl<G: EvolveGenotype + GenotypeDistance> Sharing<G> {
+    /// Fitness sharing with a linear sharing function (sharing_alpha of 1.0)
+    pub fn new(replacement_rate: f32, elitism_rate: f32, sharing_radius: f64) -> Self {
+        Self {
+            _phantom: PhantomData,
+            replacement_rate,
+            elitism_rate,
+            sharing_radius,
+            sharing_alpha: 1.0,
+        }
+    }
+
+    /// Override the shape of the sharing function, values above 1.0 share less between
+    /// chromosomes which are not that close (see [Sharing])
+    pub fn with_sharing_alpha(mut self, sharing_alpha: f64) -> Self {
+        self.sharing_alpha = sharing_alpha;
+        self
+    }
+
+    /// The niche count of each chromosome, at least one (the chromosome itself)
+    pub fn niche_counts(&self, genotype: &G, chromosomes: &[Chromosome<G::Allele>]) -> Vec<f64> {
+        let mut niche_counts = vec![1.0; chromosomes.len()];
+        if self.sharing_radius <= 0.0 {
+            return niche_counts;
+        }
+        for index_a in 0..chromosomes.len() {
+            for index_b in (index_a + 1)..chromosomes.len() {
+                let distance =
+                    genotype.chromosome_distance(&chromosomes[index_a], &chromosomes[index_b]);
+                if distance < self.sharing_radius {
+                    let share = 1.0 - (distance / self.sharing_radius).powf(self.sharing_alpha);
+                    niche_counts[index_a] += share;
+                    niche_counts[index_b] += share;
+                }
+            }
+        }
+        niche_counts
+    }
+
+    /// The shared fitness of each chromosome, where higher is always better (negated for
+    /// [FitnessOrdering::Minimize]). Chromosomes without fitness score are worst
+    fn shared_fitness_scores(
+        &self,
+        genotype: &G,
+        chromosomes: &[Chromosome<G::Allele>],
+        config: &EvolveConfig,
+    ) -> Vec<f64> {
+        self.niche_counts(genotype, chromosomes)
+            .into_iter()
+            .zip(chromosomes.iter())
+            .map(
+                |(niche_count, chromosome)| match chromosome.fitness_score() {
+                    Some(fitness_score) => {
+                        let fitness_score = match config.fitness_ordering {
+                            FitnessOrdering::Maximize => fitness_score as f64,
+                            FitnessOrdering::Minimize => -(fitness_score as f64),
+                        };
+                        if fitness_score >= 0.0 {
+                            fitness_score / niche_count
+                        } else {
+                            fitness_score * niche_count
+                        }
+                    }
+                    None => f64::NEG_INFINITY,
+                },
+            )
+            .collect()
+    }
+
+    fn selection(
+        &self,
+        mut scored_chromosomes: Vec<(f64, Chromosome<G::Allele>)>,
+        selection_size: usize,
+        population: &mut Population<G::Allele>,
+    ) -> Vec<(f64, Chromosome<G::Allele>)> {
+        Self::sort_by_shared_fitness_score(&mut scored_chromosomes);
+        let selection_size = std::cmp::min(selection_size, scored_chromosomes.len());
+        // Recycle all dropped chromosomes to population's recycling bin
+        let mut dropped_chromosomes: Vec<Chromosome<G::Allele>> = scored_chromosomes
+            .drain(selection_size..)
+            .map(|(_, chromosome)| chromosome)
+            .collect();
+        population.truncate_external(&mut dropped_chromosomes, 0);
+        scored_chromosomes
+    }
+
+    fn sort_by_shared_fitness_score(scored_chromosomes: &mut [(f64, Chromosome<G::Allele>)]) {
+        scored_chromosomes.sort_by(|(score_a, _), (score_b, _)| score_b.total_cmp(score_a));
+    }
+}
@@ -13,12 +13,26 @@ use std::time::Instant;
 /// target_population_size (or full population when in shortage) of the population is reached and
 /// drop excess chromosomes. This approach kind of sorts the fitness first, but not very strictly.
 /// This preserves a level of diversity, which avoids local optimum lock-in.
+///
+/// The soft tournament variant (see [new_soft](Self::new_soft)) reduces the selection pressure
+/// further. The participants (sampled without replacement) are ranked on fitness and the best
+/// wins with winning_probability `p`, the second with `p(1-p)`, the third with `p(1-p)^2`, etc.
+/// The last participant takes the remaining probability.
+///
+/// The participants of the standard variant are sampled with replacement (a chromosome can enter
+/// a tournament multiple times), those of the soft variant without replacement. Override with
+/// [with_sample_with_replacement](Self::with_sample_with_replacement). Sampling without
+/// replacement increases the selection pressure, as the tournament_size distinct participants
+/// always include the best chromosome when the tournament_size equals the population size.
 #[derive(Clone, Debug)]
 pub struct Tournament<G: EvolveGenotype> {
     _phantom: PhantomData<G>,
     pub replacement_rate: f32,
     pub elitism_rate: f32,
     pub tournament_size: usize,
+    pub winning_probability: Option<f32>,
+    pub sample_with_replacement: bool,
+    pub deduplicate: bool,
 }
 
 impl<G: EvolveGenotype> Select for Tournament<G> {
@@ -33,6 +47,11 @@ impl<G: EvolveGenotype> Select for Tournament<G> {
         rng: &mut R,
     ) {
         let now = Instant::now();
+        if self.deduplicate {
+            state.population.deduplicate();
+        }
+        let pre_selection_mean = state.population.fitness_score_mean();
+        let pre_selection_stddev = state.population.fitness_score_stddev();
 
         let mut elite_chromosomes =
             self.extract_elite_chromosomes(state, config, self.elitism_rate);
@@ -84,8 +103,15 @@ impl<G: EvolveGenotype> Select for Tournament<G> {
         );
         state.population.chromosomes = chromosomes;
 
+        state.update_selection_intensity(pre_selection_mean, pre_selection_stddev, config);
         state.add_duration(StrategyAction::Select, now.elapsed());
     }
+    fn tournament_size(&self) -> Option<usize> {
+        Some(self.tournament_size)
+    }
+    fn winning_probability(&self) -> Option<f32> {
+        self.winning_probability
+    }
 }
 
 impl<G: EvolveGenotype> Tournament<G> {
@@ -95,9 +121,46 @@ impl<G: EvolveGenotype> Tournament<G> {
             replacement_rate,
             elitism_rate,
             tournament_size,
+            winning_probability: None,
+            sample_with_replacement: true,
+            deduplicate: false,
+        }
+    }
+
+    /// Soft tournament, where the best participant only wins with the provided
+    /// winning_probability (< 1.0), see [Tournament]. The winning_probability must be between 0.0
+    /// and 1.0, which is validated in the builder
+    pub fn new_soft(
+        replacement_rate: f32,
+        elitism_rate: f32,
+        tournament_size: usize,
+        winning_probability: f32,
+    ) -> Self {
+        Self {
+            _phantom: PhantomData,
+            replacement_rate,
+            elitism_rate,
+            tournament_size,
+            winning_probability: Some(winning_probability),
+            sample_with_replacement: false,
+            deduplicate: false,
         }
     }
 
+    /// Override the sampling of the tournament participants, with replacement (default for
+    /// [new](Self::new)) or without replacement (default for [new_soft](Self::new_soft))
+    pub fn with_sample_with_replacement(mut self, sample_with_replacement: bool) -> Self {
+        self.sample_with_replacement = sample_with_replacement;
+        self
+    }
+
+    /// Drop the chromosomes with duplicate genes before selection, also without genes_hashing on
+    /// the genotype (see [select](crate::select))
+    pub fn with_deduplication(mut self, deduplicate: bool) -> Self {
+        self.deduplicate = deduplicate;
+        self
+    }
+
     pub fn selection<R: Rng>(
         &self,
         chromosomes: &mut Vec<Chromosome<G::Allele>>,
@@ -106,6 +169,16 @@ impl<G: EvolveGenotype> Tournament<G> {
         config: &EvolveConfig,
         rng: &mut R,
     ) {
+        if self.winning_probability.is_some() || !self.sample_with_replacement {
+            return self.ranked_selection(
+                chromosomes,
+                selection_size,
+                population,
+                config,
+                self.winning_probability.unwrap_or(1.0),
+                rng,
+            );
+        }
         let mut working_population_size = chromosomes.len();
         let tournament_size = std::cmp::min(self.tournament_size, working_population_size);
         let selection_size = std::cmp::min(selection_size, working_population_size);
@@ -165,4 +238,67 @@ impl<G: EvolveGenotype> Tournament<G> {
         population.truncate_external(chromosomes, 0);
         chromosomes.append(&mut selected_chromosomes);
     }
+
+    /// Rank the participants and pick the winner with the (geometric) winning_probability, a
+    /// winning_probability of 1.0 always picks the best participant
+    fn ranked_selection<R: Rng>(
+        &self,
+        chromosomes: &mut Vec<Chromosome<G::Allele>>,
+        selection_size: usize,
+        population: &mut crate::population::Population<G::Allele>,
+        config: &EvolveConfig,
+        winning_probability: f32,
+        rng: &mut R,
+    ) {
+        let mut working_population_size = chromosomes.len();
+        let tournament_size = std::cmp::min(self.tournament_size.max(1), working_population_size);
+        let selection_size = std::cmp::min(selection_size, working_population_size);
+        let winning_sampler =
+            rand::distributions::Bernoulli::new(winning_probability as f64).unwrap();
+
+        let mut selected_chromosomes: Vec<Chromosome<G::Allele>> =
+            Vec::with_capacity(selection_size);
+        let mut sample_indices: Vec<usize> = Vec::with_capacity(tournament_size);
+
+        for _ in 0..selection_size {
+            sample_indices.clear();
+            if self.sample_with_replacement {
+                sample_indices.extend(
+                    (0..tournament_size).map(|_| rng.gen_range(0..working_population_size)),
+                );
+            } else {
+                sample_indices.extend(
+                    rand::seq::index::sample(rng, working_population_size, tournament_size).iter(),
+                );
+            }
+            // rank participants best first, invalid fitness scores last
+            match config.fitness_ordering {
+                FitnessOrdering::Maximize => sample_indices.sort_unstable_by_key(|index| {
+                    std::cmp::Reverse(
+                        chromosomes[*index]
+                            .fitness_score()
+                            .unwrap_or(FitnessValue::MIN),
+                    )
+                }),
+                FitnessOrdering::Minimize => sample_indices.sort_unstable_by_key(|index| {
+                    chromosomes[*index]
+                        .fitness_score()
+                        .unwrap_or(FitnessValue::MAX)
+                }),
+            }
+            let winning_index = sample_indices
+                .iter()
+                .take(tournament_size - 1)
+                .find(|_| winning_sampler.sample(rng))
+                .copied()
+                .unwrap_or(sample_indices[tournament_size - 1]);
+
+            let chromosome = chromosomes.swap_remove(winning_index);
+            selected_chromosomes.push(chromosome);
+            working_population_size -= 1;
+        }
+        // Recycle all losing chromosomes to population's recycling bin
+        population.truncate_external(chromosomes, 0);
+        chromosomes.append(&mut selected_chromosomes);
+    }
 }
@@ -1,4 +1,7 @@
 pub use super::elite::Elite as SelectElite;
+pub use super::nsga2::Nsga2 as SelectNsga2;
+pub use super::percentile::Percentile as SelectPercentile;
+pub use super::proportionate::Proportionate as SelectProportionate;
 pub use super::tournament::Tournament as SelectTournament;
 pub use super::Select;
 
@@ -12,6 +15,9 @@ use rand::prelude::*;
 #[derive(Clone, Debug)]
 pub enum Wrapper<G: EvolveGenotype> {
     Elite(SelectElite<G>),
+    Nsga2(SelectNsga2<G>),
+    Percentile(SelectPercentile<G>),
+    Proportionate(SelectProportionate<G>),
     Tournament(SelectTournament<G>),
 }
 
@@ -28,6 +34,9 @@ impl<G: EvolveGenotype> Select for Wrapper<G> {
     ) {
         match self {
             Wrapper::Elite(select) => select.call(genotype, state, config, reporter, rng),
+            Wrapper::Nsga2(select) => select.call(genotype, state, config, reporter, rng),
+            Wrapper::Percentile(select) => select.call(genotype, state, config, reporter, rng),
+            Wrapper::Proportionate(select) => select.call(genotype, state, config, reporter, rng),
             Wrapper::Tournament(select) => select.call(genotype, state, config, reporter, rng),
         }
     }
@@ -40,6 +49,13 @@ impl<G: EvolveGenotype> Select for Wrapper<G> {
     ) -> Vec<Chromosome<G::Allele>> {
         match self {
             Wrapper::Elite(select) => select.extract_elite_chromosomes(state, config, elitism_rate),
+            Wrapper::Nsga2(select) => select.extract_elite_chromosomes(state, config, elitism_rate),
+            Wrapper::Percentile(select) => {
+                select.extract_elite_chromosomes(state, config, elitism_rate)
+            }
+            Wrapper::Proportionate(select) => {
+                select.extract_elite_chromosomes(state, config, elitism_rate)
+            }
             Wrapper::Tournament(select) => {
                 select.extract_elite_chromosomes(state, config, elitism_rate)
             }
@@ -60,6 +76,24 @@ impl<G: EvolveGenotype> Select for Wrapper<G> {
                 target_population_size,
                 replacement_rate,
             ),
+            Wrapper::Nsga2(select) => select.parent_and_offspring_survival_sizes(
+                parents_size,
+                offspring_size,
+                target_population_size,
+                replacement_rate,
+            ),
+            Wrapper::Percentile(select) => select.parent_and_offspring_survival_sizes(
+                parents_size,
+                offspring_size,
+                target_population_size,
+                replacement_rate,
+            ),
+            Wrapper::Proportionate(select) => select.parent_and_offspring_survival_sizes(
+                parents_size,
+                offspring_size,
+                target_population_size,
+                replacement_rate,
+            ),
             Wrapper::Tournament(select) => select.parent_and_offspring_survival_sizes(
                 parents_size,
                 offspring_size,
@@ -68,6 +102,24 @@ impl<G: EvolveGenotype> Select for Wrapper<G> {
             ),
         }
     }
+    fn tournament_size(&self) -> Option<usize> {
+        match self {
+            Wrapper::Elite(select) => select.tournament_size(),
+            Wrapper::Nsga2(select) => select.tournament_size(),
+            Wrapper::Percentile(select) => select.tournament_size(),
+            Wrapper::Proportionate(select) => select.tournament_size(),
+            Wrapper::Tournament(select) => select.tournament_size(),
+        }
+    }
+    fn winning_probability(&self) -> Option<f32> {
+        match self {
+            Wrapper::Elite(select) => select.winning_probability(),
+            Wrapper::Nsga2(select) => select.winning_probability(),
+            Wrapper::Percentile(select) => select.winning_probability(),
+            Wrapper::Proportionate(select) => select.winning_probability(),
+            Wrapper::Tournament(select) => select.winning_probability(),
+        }
+    }
 }
 impl<G: EvolveGenotype> From<SelectElite<G>> for Wrapper<G> {
     fn from(select: SelectElite<G>) -> Self {
@@ -79,3 +131,18 @@ impl<G: EvolveGenotype> From<SelectTournament<G>> for Wrapper<G> {
         Wrapper::Tournament(select)
     }
 }
+impl<G: EvolveGenotype> From<SelectPercentile<G>> for Wrapper<G> {
+    fn from(select: SelectPercentile<G>) -> Self {
+        Wrapper::Percentile(select)
+    }
+}
+impl<G: EvolveGenotype> From<SelectNsga2<G>> for Wrapper<G> {
+    fn from(select: SelectNsga2<G>) -> Self {
+        Wrapper::Nsga2(select)
+    }
+}
+impl<G: EvolveGenotype> From<SelectProportionate<G>> for Wrapper<G> {
+    fn from(select: SelectProportionate<G>) -> Self {
+        Wrapper::Proportionate(select)
+    }
+}
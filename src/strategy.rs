@@ -49,6 +49,7 @@
 //!     .with_fitness_ordering(FitnessOrdering::Minimize)       // (E,H,P) aim for the least true values
 //!     .with_fitness_cache(1000)                               // (E) enable caching of fitness values, only works when genes_hash is stored in chromosome.
 //!     .with_par_fitness(true)                                 // (E,H,P) optional, defaults to false, use parallel fitness calculation
+//!     .with_fitness_chunk_size(10)                            // (E,H) optional, defaults to dynamic chunking, number of chromosomes per parallel fitness work unit
 //!     .with_target_population_size(100)                       // (E) evolve with 100 chromosomes
 //!     .with_target_fitness_score(0)                           // (E,H) ending condition if 0 times true in the best chromosome
 //!     .with_valid_fitness_score(1)                            // (E,H) block ending conditions until at most a 1 times true in the best chromosome
@@ -93,17 +94,21 @@ use crate::population::Population;
 use crate::select::SelectEvent;
 use std::collections::HashMap;
 use std::fmt::Display;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 pub use self::builder::{
     Builder as StrategyBuilder, TryFromBuilderError as TryFromStrategyBuilderError,
 };
 
+pub use self::reporter::Csv as StrategyReporterCsv;
 pub use self::reporter::Duration as StrategyReporterDuration;
+pub use self::reporter::Json as StrategyReporterJson;
 pub use self::reporter::Noop as StrategyReporterNoop;
 pub use self::reporter::Simple as StrategyReporterSimple;
 
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StrategyAction {
     SetupAndCleanup,
     Extension,
@@ -125,6 +130,105 @@ pub const STRATEGY_ACTIONS: [StrategyAction; 8] = [
     StrategyAction::Other,
 ];
 
+/// Why a strategy run stopped, see [Strategy::termination_reason]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StrategyTerminationReason {
+    TargetFitnessScore,
+    /// [Fitness::should_terminate](crate::fitness::Fitness::should_terminate) returned true
+    FitnessShouldTerminate,
+    MaxGenerations,
+    StopAfter,
+    MaxStaleGenerations,
+    /// The max_duration or deadline was reached
+    MaxDuration,
+    /// The [StrategyTerminationPredicate] returned true
+    TerminationPredicate,
+    /// All permutations are evaluated, for [Permutate](self::permutate::Permutate)
+    Exhausted,
+}
+
+/// User predicate on the [StrategyState] to stop the run on external conditions (e.g. file
+/// existence, a channel message, memory pressure or the convergence of an external metric). It is
+/// checked once per generation alongside the ending conditions, as a hard stop like the
+/// max_duration. See `with_termination_predicate` on the builders. Clones share the same closure,
+/// also across repeated runs.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct StrategyTerminationPredicate<G: Genotype>(
+    Arc<dyn Fn(&dyn StrategyState<G>) -> bool + Send + Sync>,
+);
+impl<G: Genotype> StrategyTerminationPredicate<G> {
+    pub fn new<P>(predicate: P) -> Self
+    where
+        P: Fn(&dyn StrategyState<G>) -> bool + Send + Sync + 'static,
+    {
+        Self(Arc::new(predicate))
+    }
+    pub fn is_met(&self, state: &dyn StrategyState<G>) -> bool {
+        (self.0)(state)
+    }
+}
+impl<G: Genotype> std::fmt::Debug for StrategyTerminationPredicate<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StrategyTerminationPredicate")
+    }
+}
+
+/// The source of the current time for the max_duration and deadline ending conditions. Defaults
+/// to the system clock (`Instant::now()`). Inject a mock clock which advances deterministically to
+/// test the duration based termination without depending on the wall-clock, see `with_clock` on
+/// the builders. The reported action durations always use the system clock. Clones share the
+/// same closure, also across repeated runs.
+#[derive(Clone)]
+pub struct StrategyClock(Arc<dyn Fn() -> Instant + Send + Sync>);
+impl StrategyClock {
+    pub fn new<N>(now: N) -> Self
+    where
+        N: Fn() -> Instant + Send + Sync + 'static,
+    {
+        Self(Arc::new(now))
+    }
+    pub fn now(&self) -> Instant {
+        (self.0)()
+    }
+}
+impl Default for StrategyClock {
+    fn default() -> Self {
+        Self::new(Instant::now)
+    }
+}
+impl std::fmt::Debug for StrategyClock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StrategyClock")
+    }
+}
+
+/// The outcome of a strategy run bundled as plain data, see [Strategy::result]. Useful for
+/// pipelines which pass along or store the results, instead of the strategy itself. Serializable
+/// with the optional `serde` feature.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "G::Allele: serde::Serialize",
+        deserialize = "G::Allele: serde::Deserialize<'de>"
+    ))
+)]
+pub struct StrategyResult<G: Genotype> {
+    pub best_chromosome: Option<Chromosome<G::Allele>>,
+    pub best_fitness_score: Option<FitnessValue>,
+    pub best_generation: usize,
+    pub generations_run: usize,
+    /// The number of calculated chromosomes (fitness cache hits excluded)
+    pub fitness_evaluations: usize,
+    pub durations: HashMap<StrategyAction, Duration>,
+    pub total_duration: Duration,
+    /// None if the strategy is not called yet
+    pub termination_reason: Option<StrategyTerminationReason>,
+}
+
 #[derive(Copy, Clone, Debug)]
 pub enum StrategyVariant {
     Evolve(EvolveVariant),
@@ -135,6 +239,12 @@ impl Display for StrategyVariant {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             StrategyVariant::Evolve(EvolveVariant::Standard) => write!(f, "evolve"),
+            StrategyVariant::Evolve(EvolveVariant::Generational) => {
+                write!(f, "evolve/generational")
+            }
+            StrategyVariant::Evolve(EvolveVariant::SteadyState) => {
+                write!(f, "evolve/steady_state")
+            }
             StrategyVariant::HillClimb(HillClimbVariant::Stochastic) => {
                 write!(f, "hill_climb/stochastic")
             }
@@ -158,6 +268,25 @@ pub trait Strategy<G: Genotype> {
             None
         }
     }
+    /// The number of generations run in the main loop (setup excluded), for all strategies
+    fn generations_run(&self) -> usize;
+    /// The number of completed iterations. This is 0 before and 1 after a single call. For the
+    /// best run of `call_repeatedly` and `call_par_repeatedly` it is the number of completed runs
+    /// (which stop early when the target_fitness_score is reached)
+    fn iterations_run(&self) -> usize;
+    /// The genes of the final population, for continuing in a next run (see
+    /// [EvolveBuilder::with_seed_population_from](crate::strategy::evolve::EvolveBuilder::with_seed_population_from)).
+    /// Empty for strategies without a population to continue from
+    fn final_population(&self) -> Vec<Genes<G::Allele>> {
+        Vec::new()
+    }
+    /// Why the run stopped, None if not called yet. Defaults to None for strategies which don't
+    /// track it
+    fn termination_reason(&self) -> Option<StrategyTerminationReason> {
+        None
+    }
+    /// The outcome of the run bundled in a single struct, cloning the best chromosome
+    fn result(&self) -> StrategyResult<G>;
     /// strategy can be boxed, need a way to get to the reporter
     fn flush_reporter(&mut self, _output: &mut Vec<u8>);
 }
@@ -170,7 +299,40 @@ pub trait StrategyConfig: Display {
         None
     }
     fn par_fitness(&self) -> bool;
+    /// The minimum number of chromosomes per rayon work unit in the parallel fitness calculation,
+    /// None for the dynamic (rayon default) chunking
+    fn fitness_chunk_size(&self) -> Option<usize> {
+        None
+    }
     fn replace_on_equal_fitness(&self) -> bool;
+    /// The time budget per `call()`, see [deadline_from](Self::deadline_from)
+    fn max_duration(&self) -> Option<Duration> {
+        None
+    }
+    /// The absolute point in time to stop at, see [deadline_from](Self::deadline_from)
+    fn deadline(&self) -> Option<Instant> {
+        None
+    }
+    /// The effective deadline of a `call()` started at the given instant, the earliest of the
+    /// deadline and the start plus the max_duration
+    fn deadline_from(&self, start: Instant) -> Option<Instant> {
+        let max_duration_deadline = self
+            .max_duration()
+            .and_then(|max_duration| start.checked_add(max_duration));
+        match (max_duration_deadline, self.deadline()) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+/// The seed of the run with the given index, derived from the base seed with SplitMix64 of
+/// `seed ^ index`, so each run gets a distinct, well mixed seed
+pub(crate) fn derive_seed(seed: u64, index: usize) -> u64 {
+    let mut z = (seed ^ index as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
 }
 
 /// Stores the state of the strategy.
@@ -189,14 +351,26 @@ pub trait StrategyState<G: Genotype>: Display {
     fn best_fitness_score(&self) -> Option<FitnessValue>;
     fn best_generation(&self) -> usize;
     fn best_genes(&self) -> Option<Genes<G::Allele>>;
+    /// Borrow the best chromosome, without cloning the genes
+    fn best_chromosome_as_ref(&self) -> Option<&Chromosome<G::Allele>>;
     fn current_generation(&self) -> usize;
     fn current_iteration(&self) -> usize;
     fn stale_generations(&self) -> usize;
     fn scale_generation(&self) -> usize;
     fn population_cardinality(&self) -> Option<usize>;
+    fn selection_intensity(&self) -> Option<f32>;
     fn durations(&self) -> &HashMap<StrategyAction, Duration>;
     fn add_duration(&mut self, action: StrategyAction, duration: Duration);
     fn total_duration(&self) -> Duration;
+    /// The number of calculated chromosomes so far (fitness cache hits excluded)
+    fn fitness_evaluations(&self) -> usize;
+    fn add_fitness_evaluations(&mut self, number_of_evaluations: usize);
+    /// Why the run loop stopped, set just before `on_finish` of the [StrategyReporter] (None
+    /// before and when not finished, e.g. a chunked [Permutate](self::permutate::Permutate)).
+    /// Defaults to None for states which don't track it
+    fn termination_reason(&self) -> Option<StrategyTerminationReason> {
+        None
+    }
     fn close_duration(&mut self, total_duration: Duration) {
         if let Some(other_duration) = total_duration.checked_sub(self.total_duration()) {
             self.add_duration(StrategyAction::Other, other_duration);
@@ -226,38 +400,17 @@ pub trait StrategyState<G: Genotype>: Display {
         fitness_ordering: &FitnessOrdering,
         replace_on_equal_fitness: bool,
     ) -> (bool, bool) {
-        match (
-            self.best_fitness_score(),
-            contending_chromosome.fitness_score(),
-        ) {
-            (None, None) => (false, false),
-            (Some(_), None) => (false, false),
-            (None, Some(_)) => (true, true),
-            (Some(current_fitness_score), Some(contending_fitness_score)) => match fitness_ordering
-            {
-                FitnessOrdering::Maximize => {
-                    if contending_fitness_score > current_fitness_score {
-                        (true, true)
-                    } else if replace_on_equal_fitness
-                        && contending_fitness_score == current_fitness_score
-                    {
-                        (true, false)
-                    } else {
-                        (false, false)
-                    }
-                }
-                FitnessOrdering::Minimize => {
-                    if contending_fitness_score < current_fitness_score {
-                        (true, true)
-                    } else if replace_on_equal_fitness
-                        && contending_fitness_score == current_fitness_score
-                    {
-                        (true, false)
-                    } else {
-                        (false, false)
-                    }
-                }
-            },
+        let current_fitness_score = self.best_fitness_score();
+        let contending_fitness_score = contending_chromosome.fitness_score();
+        if fitness_ordering.is_better(contending_fitness_score, current_fitness_score) {
+            (true, true)
+        } else if replace_on_equal_fitness
+            && contending_fitness_score.is_some()
+            && contending_fitness_score == current_fitness_score
+        {
+            (true, false)
+        } else {
+            (false, false)
         }
     }
 }
@@ -409,6 +562,25 @@ pub trait StrategyReporter: Clone + Send + Sync {
         _config: &C,
     ) {
     }
+    /// Called after each generation without fitness improvement, the
+    /// [stale_generations](StrategyState::stale_generations) is already incremented
+    fn on_stale_generation<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        _state: &S,
+        _config: &C,
+    ) {
+    }
+    /// Called when a scaled genotype moves to the next (smaller) scale, after max_generations or
+    /// max_stale_generations within the current scale (or the exhausted permutations). The
+    /// stale_generations and scale_generation are already reset
+    fn on_scale_down<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        _state: &S,
+        _config: &C,
+    ) {
+    }
     fn on_select_event<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
         &mut self,
         _event: SelectEvent,
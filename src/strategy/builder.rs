@@ -1,5 +1,6 @@
 use crate::crossover::Crossover;
 pub use crate::errors::TryFromStrategyBuilderError as TryFromBuilderError;
+use crate::errors::{BuilderError, BuilderValidation};
 use crate::extension::{Extension, ExtensionNoop};
 use crate::fitness::{Fitness, FitnessCache, FitnessOrdering, FitnessValue};
 use crate::genotype::{EvolveGenotype, HillClimbGenotype, PermutateGenotype};
@@ -8,7 +9,11 @@ use crate::select::Select;
 use crate::strategy::evolve::EvolveBuilder;
 use crate::strategy::hill_climb::HillClimbBuilder;
 use crate::strategy::permutate::PermutateBuilder;
-use crate::strategy::{Strategy, StrategyReporter, StrategyReporterNoop, StrategyVariant};
+use crate::strategy::{
+    Strategy, StrategyClock, StrategyReporter, StrategyReporterNoop, StrategyState,
+    StrategyTerminationPredicate, StrategyVariant,
+};
+use std::time::{Duration, Instant};
 
 /// The superset builder for all strategies.
 ///
@@ -36,13 +41,23 @@ pub struct Builder<
     pub max_generations: Option<usize>,
     pub mutate: Option<M>,
     pub par_fitness: bool,
+    pub fitness_chunk_size: Option<usize>,
     pub replace_on_equal_fitness: bool,
+    pub evaluate_initial_population: bool,
+    pub unique_population: bool,
+    pub neighbour_sample_size: Option<usize>,
+    pub speciation_merge_interval: Option<usize>,
     pub reporter: SR,
     pub rng_seed: Option<u64>,
     pub select: Option<C>,
     pub target_fitness_score: Option<FitnessValue>,
     pub target_population_size: usize,
     pub valid_fitness_score: Option<FitnessValue>,
+    pub stop_after: Option<(usize, bool)>,
+    pub max_duration: Option<Duration>,
+    pub deadline: Option<Instant>,
+    pub termination_predicate: Option<StrategyTerminationPredicate<G>>,
+    pub clock: StrategyClock,
 }
 
 impl<
@@ -63,10 +78,20 @@ impl<
             max_chromosome_age: None,
             target_fitness_score: None,
             valid_fitness_score: None,
+            stop_after: None,
+            max_duration: None,
+            deadline: None,
+            termination_predicate: None,
+            clock: StrategyClock::default(),
             fitness_ordering: FitnessOrdering::Maximize,
             fitness_cache: None,
             par_fitness: false,
+            fitness_chunk_size: None,
             replace_on_equal_fitness: false,
+            evaluate_initial_population: true,
+            unique_population: false,
+            neighbour_sample_size: None,
+            speciation_merge_interval: None,
             mutate: None,
             fitness: None,
             crossover: None,
@@ -165,6 +190,49 @@ impl<
         self.valid_fitness_score = valid_fitness_score_option;
         self
     }
+    /// Run at least the given number of generations, ignoring the max_stale_generations ending
+    /// condition until then. When require_no_improvement is false, stop at the given number of
+    /// generations (unless finished earlier by the target_fitness_score). When true, continue
+    /// after the given number of generations until finished by the other ending conditions.
+    /// Note the other ending conditions remain required.
+    pub fn with_stop_after(mut self, generations: usize, require_no_improvement: bool) -> Self {
+        self.stop_after = Some((generations, require_no_improvement));
+        self
+    }
+    /// Stop when the given duration since the start of the `call()` has passed (checked once per
+    /// generation). This is a hard stop, regardless of the valid_fitness_score and stop_after.
+    /// Applies per run for the repeated and speciated calls
+    pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+    /// Stop when the given point in time has passed (checked once per generation). This is a hard
+    /// stop, regardless of the valid_fitness_score and stop_after. Shared by all runs for the
+    /// repeated and speciated calls
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+    /// Stop when the predicate on the state returns true (checked once per generation), e.g. for
+    /// external conditions. This is a hard stop, regardless of the valid_fitness_score and
+    /// stop_after. Shared by all runs for the repeated and speciated calls, see
+    /// [StrategyTerminationPredicate]
+    pub fn with_termination_predicate<P>(mut self, termination_predicate: P) -> Self
+    where
+        P: Fn(&dyn StrategyState<G>) -> bool + Send + Sync + 'static,
+    {
+        self.termination_predicate = Some(StrategyTerminationPredicate::new(termination_predicate));
+        self
+    }
+    /// The source of the current time for the max_duration and deadline, defaults to the system
+    /// clock. Shared by all runs for the repeated and speciated calls, see [StrategyClock]
+    pub fn with_clock<N>(mut self, now: N) -> Self
+    where
+        N: Fn() -> Instant + Send + Sync + 'static,
+    {
+        self.clock = StrategyClock::new(now);
+        self
+    }
     pub fn with_fitness_ordering(mut self, fitness_ordering: FitnessOrdering) -> Self {
         self.fitness_ordering = fitness_ordering;
         self
@@ -183,10 +251,39 @@ impl<
         self.par_fitness = par_fitness;
         self
     }
+    /// The number of consecutive chromosomes per rayon work unit for the parallel fitness
+    /// calculation (only used with `with_par_fitness(true)`), each chunk is calculated by a single
+    /// thread. Larger chunks reduce the scheduling overhead for cheap fitness functions, smaller
+    /// chunks balance uneven fitness costs better. When not set, the chunking is dynamic (rayon's
+    /// default splitting)
+    pub fn with_fitness_chunk_size(mut self, fitness_chunk_size: usize) -> Self {
+        self.fitness_chunk_size = Some(fitness_chunk_size);
+        self
+    }
     pub fn with_replace_on_equal_fitness(mut self, replace_on_equal_fitness: bool) -> Self {
         self.replace_on_equal_fitness = replace_on_equal_fitness;
         self
     }
+    /// Only used by Evolve, see [EvolveBuilder::with_evaluate_initial_population]
+    pub fn with_evaluate_initial_population(mut self, evaluate_initial_population: bool) -> Self {
+        self.evaluate_initial_population = evaluate_initial_population;
+        self
+    }
+    /// Only used by Evolve, see [EvolveBuilder::with_unique_population]
+    pub fn with_unique_population(mut self, unique_population: bool) -> Self {
+        self.unique_population = unique_population;
+        self
+    }
+    /// Only used by HillClimb, see [HillClimbBuilder::with_neighbour_sample_size]
+    pub fn with_neighbour_sample_size(mut self, neighbour_sample_size: usize) -> Self {
+        self.neighbour_sample_size = Some(neighbour_sample_size);
+        self
+    }
+    /// Only used by Evolve's speciated calls, see [EvolveBuilder::with_speciation_merge_interval]
+    pub fn with_speciation_merge_interval(mut self, generations: usize) -> Self {
+        self.speciation_merge_interval = Some(generations);
+        self
+    }
     pub fn with_mutate(mut self, mutate: M) -> Self {
         self.mutate = Some(mutate);
         self
@@ -216,10 +313,20 @@ impl<
             max_chromosome_age: self.max_chromosome_age,
             target_fitness_score: self.target_fitness_score,
             valid_fitness_score: self.valid_fitness_score,
+            stop_after: self.stop_after,
+            max_duration: self.max_duration,
+            deadline: self.deadline,
+            termination_predicate: self.termination_predicate,
+            clock: self.clock,
             fitness_ordering: self.fitness_ordering,
             fitness_cache: self.fitness_cache,
             par_fitness: self.par_fitness,
+            fitness_chunk_size: self.fitness_chunk_size,
             replace_on_equal_fitness: self.replace_on_equal_fitness,
+            evaluate_initial_population: self.evaluate_initial_population,
+            unique_population: self.unique_population,
+            neighbour_sample_size: self.neighbour_sample_size,
+            speciation_merge_interval: self.speciation_merge_interval,
             mutate: self.mutate,
             fitness: self.fitness,
             crossover: self.crossover,
@@ -242,10 +349,20 @@ impl<
             max_chromosome_age: self.max_chromosome_age,
             target_fitness_score: self.target_fitness_score,
             valid_fitness_score: self.valid_fitness_score,
+            stop_after: self.stop_after,
+            max_duration: self.max_duration,
+            deadline: self.deadline,
+            termination_predicate: self.termination_predicate,
+            clock: self.clock,
             fitness_ordering: self.fitness_ordering,
             fitness_cache: self.fitness_cache,
             par_fitness: self.par_fitness,
+            fitness_chunk_size: self.fitness_chunk_size,
             replace_on_equal_fitness: self.replace_on_equal_fitness,
+            evaluate_initial_population: self.evaluate_initial_population,
+            unique_population: self.unique_population,
+            neighbour_sample_size: self.neighbour_sample_size,
+            speciation_merge_interval: self.speciation_merge_interval,
             mutate: self.mutate,
             fitness: self.fitness,
             crossover: self.crossover,
@@ -282,13 +399,41 @@ impl<
             Some(StrategyVariant::Permutate(_)) => {
                 Ok(Box::new(self.to_permutate_builder().build()?))
             }
-            Some(StrategyVariant::Evolve(_)) => Ok(Box::new(self.to_evolve_builder().build()?)),
+            Some(StrategyVariant::Evolve(evolve_variant)) => Ok(Box::new(
+                self.to_evolve_builder()
+                    .with_variant(evolve_variant)
+                    .build()?,
+            )),
             Some(StrategyVariant::HillClimb(hill_climb_variant)) => Ok(Box::new(
                 self.to_hill_climb_builder()
                     .with_variant(hill_climb_variant)
                     .build()?,
             )),
-            None => Err(TryFromBuilderError("StrategyVariant is required")),
+            None => Err(TryFromBuilderError(
+                "StrategyVariant is required",
+                BuilderError::MissingVariant,
+            )),
+        }
+    }
+    /// Dry-run validation of the full configuration for the variant, without building. See
+    /// [EvolveBuilder::validate], [HillClimbBuilder::validate] and [PermutateBuilder::validate]
+    pub fn validate(&self) -> BuilderValidation {
+        match self.variant {
+            Some(StrategyVariant::Permutate(_)) => self.clone().to_permutate_builder().validate(),
+            Some(StrategyVariant::Evolve(evolve_variant)) => self
+                .clone()
+                .to_evolve_builder()
+                .with_variant(evolve_variant)
+                .validate(),
+            Some(StrategyVariant::HillClimb(hill_climb_variant)) => self
+                .clone()
+                .to_hill_climb_builder()
+                .with_variant(hill_climb_variant)
+                .validate(),
+            None => BuilderValidation {
+                errors: vec!["StrategyVariant is required".to_string()],
+                warnings: vec![],
+            },
         }
     }
     pub fn to_permutate_builder(self) -> PermutateBuilder<G, F, SR> {
@@ -296,7 +441,14 @@ impl<
             genotype: self.genotype,
             fitness_ordering: self.fitness_ordering,
             par_fitness: self.par_fitness,
+            permutation_chunk_size: None,
             replace_on_equal_fitness: self.replace_on_equal_fitness,
+            chunk_size: None,
+            start_permutation_index: 0,
+            max_duration: self.max_duration,
+            deadline: self.deadline,
+            termination_predicate: self.termination_predicate,
+            clock: self.clock,
             fitness: self.fitness,
             reporter: self.reporter,
         }
@@ -304,16 +456,41 @@ impl<
     pub fn to_evolve_builder(self) -> EvolveBuilder<G, M, F, S, C, E, SR> {
         EvolveBuilder {
             genotype: self.genotype,
+            variant: None,
+            generation_gap: None,
             target_population_size: self.target_population_size,
             max_stale_generations: self.max_stale_generations,
             max_generations: self.max_generations,
             max_chromosome_age: self.max_chromosome_age,
+            elitism_rate: None,
+            offspring_rate: None,
+            age_layers: None,
             target_fitness_score: self.target_fitness_score,
             valid_fitness_score: self.valid_fitness_score,
+            stop_after: self.stop_after,
+            max_duration: self.max_duration,
+            deadline: self.deadline,
+            termination_predicate: self.termination_predicate,
+            clock: self.clock,
             fitness_ordering: self.fitness_ordering,
             fitness_cache: self.fitness_cache,
+            fitness_resampling: None,
+            fitness_resampling_aggregation: None,
+            fitness_resampling_best_interval: None,
             par_fitness: self.par_fitness,
+            fitness_chunk_size: self.fitness_chunk_size,
             replace_on_equal_fitness: self.replace_on_equal_fitness,
+            evaluate_initial_population: self.evaluate_initial_population,
+            unique_population: self.unique_population,
+            seed_population: Vec::new(),
+            seed_chromosomes: Vec::new(),
+            seed_genes: Vec::new(),
+            checkpoint: None,
+            speciation_merge_interval: self.speciation_merge_interval,
+            migration: None,
+            local_search: None,
+            local_search_top_k: None,
+            population_snapshot: None,
             mutate: self.mutate,
             fitness: self.fitness,
             crossover: self.crossover,
@@ -331,10 +508,21 @@ impl<
             max_generations: self.max_generations,
             target_fitness_score: self.target_fitness_score,
             valid_fitness_score: self.valid_fitness_score,
+            stop_after: self.stop_after,
+            max_duration: self.max_duration,
+            deadline: self.deadline,
+            termination_predicate: self.termination_predicate,
+            clock: self.clock,
             fitness_ordering: self.fitness_ordering,
             fitness_cache: self.fitness_cache,
             par_fitness: self.par_fitness,
+            fitness_chunk_size: self.fitness_chunk_size,
             replace_on_equal_fitness: self.replace_on_equal_fitness,
+            neighbour_sample_size: self.neighbour_sample_size,
+            seed_genes: Vec::new(),
+            acceptance: None,
+            record_trajectory: false,
+            trajectory_limit: None,
             fitness: self.fitness,
             reporter: self.reporter,
             rng_seed: self.rng_seed,
@@ -359,6 +547,19 @@ impl<
         strategy.call();
         Ok(strategy)
     }
+    /// Call a clone of the builder with the fitness_ordering overridden for this run only, so the
+    /// same configured builder can be reused for both orderings. All ordering dependent logic of
+    /// the strategy (e.g. best chromosome tracking and target_fitness_score) reads the overridden
+    /// ordering. The fitness itself is not overridden, so a fitness with its own ordering (e.g.
+    /// [FitnessDuplicatePenalty](crate::fitness::FitnessDuplicatePenalty), the non-finite policy
+    /// of [SumGenes](crate::fitness::placeholders::SumGenes) or the always minimizing
+    /// [TourDistance](crate::fitness::placeholders::TourDistance)) must match the passed ordering.
+    pub fn call_with_ordering(
+        &self,
+        fitness_ordering: FitnessOrdering,
+    ) -> Result<Box<dyn Strategy<G> + 'a>, TryFromBuilderError> {
+        self.clone().with_fitness_ordering(fitness_ordering).call()
+    }
 
     /// Permutate: call (once)
     /// Evolve: call_repeatedly
@@ -373,8 +574,11 @@ impl<
                 let run = self.to_permutate_builder().call()?;
                 Ok((Box::new(run), vec![]))
             }
-            Some(StrategyVariant::Evolve(_)) => {
-                let (run, runs) = self.to_evolve_builder().call_repeatedly(max_repeats)?;
+            Some(StrategyVariant::Evolve(evolve_variant)) => {
+                let (run, runs) = self
+                    .to_evolve_builder()
+                    .with_variant(evolve_variant)
+                    .call_repeatedly(max_repeats)?;
                 Ok((
                     Box::new(run),
                     runs.into_iter().map(|r| Box::new(r) as _).collect(),
@@ -390,7 +594,10 @@ impl<
                     runs.into_iter().map(|r| Box::new(r) as _).collect(),
                 ))
             }
-            None => Err(TryFromBuilderError("StrategyVariant is required")),
+            None => Err(TryFromBuilderError(
+                "StrategyVariant is required",
+                BuilderError::MissingVariant,
+            )),
         }
     }
 
@@ -407,8 +614,11 @@ impl<
                 let run = self.to_permutate_builder().with_par_fitness(true).call()?;
                 Ok((Box::new(run), vec![]))
             }
-            Some(StrategyVariant::Evolve(_)) => {
-                let (run, runs) = self.to_evolve_builder().call_par_repeatedly(max_repeats)?;
+            Some(StrategyVariant::Evolve(evolve_variant)) => {
+                let (run, runs) = self
+                    .to_evolve_builder()
+                    .with_variant(evolve_variant)
+                    .call_par_repeatedly(max_repeats)?;
                 Ok((
                     Box::new(run),
                     runs.into_iter().map(|r| Box::new(r) as _).collect(),
@@ -424,7 +634,10 @@ impl<
                     runs.into_iter().map(|r| Box::new(r) as _).collect(),
                 ))
             }
-            None => Err(TryFromBuilderError("StrategyVariant is required")),
+            None => Err(TryFromBuilderError(
+                "StrategyVariant is required",
+                BuilderError::MissingVariant,
+            )),
         }
     }
 
@@ -441,8 +654,11 @@ impl<
                 let run = self.to_permutate_builder().call()?;
                 Ok((Box::new(run), vec![]))
             }
-            Some(StrategyVariant::Evolve(_)) => {
-                let (run, runs) = self.to_evolve_builder().call_speciated(number_of_species)?;
+            Some(StrategyVariant::Evolve(evolve_variant)) => {
+                let (run, runs) = self
+                    .to_evolve_builder()
+                    .with_variant(evolve_variant)
+                    .call_speciated(number_of_species)?;
                 Ok((
                     Box::new(run),
                     runs.into_iter().map(|r| Box::new(r) as _).collect(),
@@ -458,7 +674,10 @@ impl<
                     runs.into_iter().map(|r| Box::new(r) as _).collect(),
                 ))
             }
-            None => Err(TryFromBuilderError("StrategyVariant is required")),
+            None => Err(TryFromBuilderError(
+                "StrategyVariant is required",
+                BuilderError::MissingVariant,
+            )),
         }
     }
 
@@ -475,9 +694,10 @@ impl<
                 let run = self.to_permutate_builder().with_par_fitness(true).call()?;
                 Ok((Box::new(run), vec![]))
             }
-            Some(StrategyVariant::Evolve(_)) => {
+            Some(StrategyVariant::Evolve(evolve_variant)) => {
                 let (run, runs) = self
                     .to_evolve_builder()
+                    .with_variant(evolve_variant)
                     .call_par_speciated(number_of_species)?;
                 Ok((
                     Box::new(run),
@@ -494,7 +714,10 @@ impl<
                     runs.into_iter().map(|r| Box::new(r) as _).collect(),
                 ))
             }
-            None => Err(TryFromBuilderError("StrategyVariant is required")),
+            None => Err(TryFromBuilderError(
+                "StrategyVariant is required",
+                BuilderError::MissingVariant,
+            )),
         }
     }
 }
@@ -1,44 +1,133 @@
 //! A solution strategy for finding the best chromosome using evolution
 mod builder;
+mod local_search;
+mod population_snapshot;
 pub mod prelude;
 mod reporter;
 
 pub use self::builder::{
     Builder as EvolveBuilder, TryFromBuilderError as TryFromEvolveBuilderError,
 };
+pub use self::local_search::LocalSearch as EvolveLocalSearch;
+pub use self::population_snapshot::PopulationSnapshot as EvolvePopulationSnapshot;
 
 use super::{
-    Strategy, StrategyAction, StrategyConfig, StrategyReporter, StrategyReporterNoop,
-    StrategyState, StrategyVariant,
+    Strategy, StrategyAction, StrategyClock, StrategyConfig, StrategyReporter,
+    StrategyReporterNoop, StrategyResult, StrategyState, StrategyTerminationPredicate,
+    StrategyTerminationReason, StrategyVariant,
 };
-use crate::chromosome::{Chromosome, Genes};
+use crate::chromosome::{Chromosome, Genes, GenesHash};
 use crate::crossover::Crossover;
 use crate::extension::{Extension, ExtensionNoop};
-use crate::fitness::{Fitness, FitnessCache, FitnessOrdering, FitnessValue};
+use crate::fitness::{
+    Fitness, FitnessCache, FitnessContext, FitnessOrdering, FitnessResampling, FitnessValue,
+};
 use crate::genotype::EvolveGenotype;
 use crate::mutate::Mutate;
 use crate::population::Population;
 use crate::select::Select;
 use rand::rngs::SmallRng;
+use rand::Rng;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::time::{Duration, Instant};
 use thread_local::ThreadLocal;
 
 pub use self::reporter::Simple as EvolveReporterSimple;
+pub use crate::strategy::reporter::Csv as EvolveReporterCsv;
 pub use crate::strategy::reporter::Duration as EvolveReporterDuration;
+pub use crate::strategy::reporter::Json as EvolveReporterJson;
 pub use crate::strategy::reporter::Noop as EvolveReporterNoop;
 
+/// The replacement of parents by offspring each generation:
+/// * [EvolveVariant::Standard]: as configured by the [select](crate::select) strategy (through its
+///   replacement_rate)
+/// * [EvolveVariant::Generational]: the offspring replace the parents. Parents are only kept
+///   (fittest first) to fill the population up to the target_population_size, excess offspring
+///   are dropped (worst first)
+/// * [EvolveVariant::SteadyState]: the offspring only replace the worst members of the population,
+///   parents and offspring compete equally for the target_population_size. Typically combined with
+///   a low crossover selection_rate or a
+///   [generation_gap](crate::strategy::evolve::EvolveBuilder::with_generation_gap), so each
+///   generation only produces (and evaluates) a few offspring
+///
+/// The replacement of the Generational and SteadyState variants takes the place of the select
+/// strategy, which is not called, so the survivors are selected once per generation.
 #[derive(Copy, Clone, Debug, Default)]
 pub enum EvolveVariant {
     #[default]
     Standard,
+    Generational,
+    SteadyState,
+}
+
+/// The migration topology between the islands of
+/// [call_islands](crate::strategy::evolve::EvolveBuilder::call_islands) and
+/// [call_par_islands](crate::strategy::evolve::EvolveBuilder::call_par_islands):
+/// * [EvolveMigrationTopology::Ring]: each island receives the best chromosome of its
+///   predecessor (the last island feeds the first)
+/// * [EvolveMigrationTopology::FullyConnected]: each island receives the best chromosomes of all
+///   other islands
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum EvolveMigrationTopology {
+    #[default]
+    Ring,
+    FullyConnected,
+}
+
+impl EvolveMigrationTopology {
+    /// The island indices sending their best chromosome to the given island
+    pub fn source_indices(&self, island_index: usize, number_of_islands: usize) -> Vec<usize> {
+        if number_of_islands < 2 {
+            return vec![];
+        }
+        match self {
+            EvolveMigrationTopology::Ring => {
+                vec![(island_index + number_of_islands - 1) % number_of_islands]
+            }
+            EvolveMigrationTopology::FullyConnected => (0..number_of_islands)
+                .filter(|index| *index != island_index)
+                .collect(),
+        }
+    }
+}
+
+/// A snapshot of a called [Evolve] run as plain data, for resuming the run later with
+/// [EvolveBuilder::with_checkpoint] (e.g. after an interruption of a long optimization). Take it
+/// with [Evolve::checkpoint]. Serializable with the optional `serde` feature, the storage format
+/// is left to the user.
+///
+/// The final population is stored by genes only, so it is re-evaluated on resume. The internal
+/// state of the plugins (e.g. a dynamic mutation probability or a fitness cache) and the current
+/// scale of a scaled [MutationType](crate::genotype::MutationType) are not part of the checkpoint. As the [SmallRng] can't be serialized, the checkpoint holds a fresh rng_seed
+/// instead, so resuming the same checkpoint is reproducible.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "G::Allele: serde::Serialize",
+        deserialize = "G::Allele: serde::Deserialize<'de>"
+    ))
+)]
+pub struct EvolveCheckpoint<G: EvolveGenotype> {
+    pub population: Vec<Genes<G::Allele>>,
+    pub best_chromosome: Option<Chromosome<G::Allele>>,
+    pub current_generation: usize,
+    pub scale_generation: usize,
+    pub best_generation: usize,
+    pub stale_generations: usize,
+    pub rng_seed: u64,
 }
 
 /// The Evolve strategy initializes with a random population of chromosomes (unless the genotype
 /// seeds specific genes to sample from), calculates [fitness](crate::fitness) for all chromosomes
-/// and sets a first best chromosome (if any).
+/// and sets a first best chromosome (if any). This initial population is generation 0, so the best
+/// chromosome can originate from it. Disable this initial evaluation with
+/// `with_evaluate_initial_population(false)`, then the initial population is only scored in the
+/// first generation, after selection, crossover and mutation.
 ///
 /// Then the Evolve strategy runs through generations of chromosomes in a loop:
 /// * [select](crate::select) and pair up chromosomes for crossover
@@ -53,6 +142,10 @@ pub enum EvolveVariant {
 /// * max_stale_generations: when the ultimate goal in terms of fitness score is unknown and one depends on some convergion
 ///   threshold, or one wants a duration limitation next to the target_fitness_score
 /// * max_generations: when the ultimate goal in terms of fitness score is unknown and there is a effort constraint
+/// * max_duration or deadline: when there is a time budget, this is a hard stop which ignores the
+///   valid_fitness_score and stop_after
+/// * termination_predicate: a user predicate on the state for external conditions, also a hard
+///   stop, see [StrategyTerminationPredicate]
 /// * With a scaled [crate::genotype::MutationType]:
 ///   * Scale down after max_generations or max_stale_generations is reached and reset scale_generations and stale_generations to zero
 ///   * Only trigger max_generations or max_stale_generations ending condition when already reached the smallest scale
@@ -78,6 +171,13 @@ pub enum EvolveVariant {
 ///   parents are not replaced yet. Value should typically be between 0.4 and
 ///   0.8. High values risk of premature convergence. Low values reduce diversity
 ///   if overused.
+/// * `offspring_rate` (crossover, optional): the number of offspring per selected parent, 1.0 by
+///   default. Above 1.0 the parent pairs produce multiple children (crossed with different random
+///   points), which improves exploration for small populations without increasing the parent
+///   pool, see `with_offspring_rate()`.
+/// * `age_layers` (optional): the number_of_layers and age_gap of the age-layered population
+///   structure (ALPS), where chromosomes only select and crossover within their age layer and new
+///   random chromosomes are injected periodically, see `with_age_layers()`.
 /// * `crossover_rate (or recombination-rate)` (crossover): the fraction of selected parents
 ///   to crossover, the remaining parents just clone as offspring. Value should
 ///   typically be between 0.5 and 0.8. High values converge faster, but risk
@@ -88,6 +188,10 @@ pub enum EvolveVariant {
 ///   ability. Low have a risk of stagnation.
 ///
 ///
+/// The replacement of parents by offspring is configured by the select strategy by default, but
+/// can be forced to generational or steady-state replacement with `with_variant()`, see
+/// [EvolveVariant].
+///
 /// There are optional mutation distance limitations for
 /// [RangeGenotype](crate::genotype::RangeGenotype) and
 /// [MultiRangeGenotype](crate::genotype::MultiRangeGenotype) chromosomes, see [crate::genotype::MutationType].
@@ -150,6 +254,7 @@ pub enum EvolveVariant {
 ///     .with_fitness_ordering(FitnessOrdering::Minimize)       // aim for the least true values
 ///     .with_fitness_cache(1000)                               // enable caching of fitness values (LRU size 1000), only works when genes_hash is stored in chromosome. Only useful for long stale runs, but better to increase population diversity
 ///     .with_par_fitness(true)                                 // optional, defaults to false, use parallel fitness calculation
+///     .with_fitness_chunk_size(10)                            // optional, defaults to dynamic chunking, number of chromosomes per parallel fitness work unit
 ///     .with_target_population_size(100)                       // evolve with 100 chromosomes
 ///     .with_target_fitness_score(0)                           // ending condition if 0 times true in the best chromosome
 ///     .with_valid_fitness_score(10)                           // block ending conditions until at most a 10 times true in the best chromosome
@@ -179,6 +284,10 @@ pub struct Evolve<
     pub genotype: G,
     pub fitness: F,
     pub plugins: EvolvePlugins<M, S, C, E>,
+    pub local_search: Option<EvolveLocalSearch<G>>,
+    pub termination_predicate: Option<StrategyTerminationPredicate<G>>,
+    pub clock: StrategyClock,
+    pub population_snapshot: Option<EvolvePopulationSnapshot<G>>,
     pub config: EvolveConfig,
     pub state: EvolveState<G>,
     pub reporter: SR,
@@ -194,24 +303,37 @@ pub struct EvolvePlugins<M: Mutate, S: Crossover, C: Select, E: Extension> {
 
 pub struct EvolveConfig {
     pub variant: EvolveVariant,
+    pub generation_gap: Option<usize>,
     pub fitness_ordering: FitnessOrdering,
     pub par_fitness: bool,
+    pub fitness_chunk_size: Option<usize>,
     pub replace_on_equal_fitness: bool,
+    pub evaluate_initial_population: bool,
+    pub unique_population: bool,
 
     pub target_fitness_score: Option<FitnessValue>,
     pub max_stale_generations: Option<usize>,
     pub max_generations: Option<usize>,
     pub valid_fitness_score: Option<FitnessValue>,
+    pub stop_after: Option<(usize, bool)>,
+    pub max_duration: Option<Duration>,
+    pub deadline: Option<Instant>,
     pub fitness_cache: Option<FitnessCache>,
+    pub fitness_resampling: Option<FitnessResampling>,
 
     pub target_population_size: usize,
     pub max_chromosome_age: Option<usize>,
+    pub elitism_rate: Option<f32>,
+    pub offspring_rate: Option<f32>,
+    pub age_layers: Option<(usize, usize)>,
 }
 
 /// Stores the state of the Evolve strategy.
 #[derive(Clone)]
 pub struct EvolveState<G: EvolveGenotype> {
     pub current_iteration: usize,
+    /// The number of completed iterations, see [Strategy::iterations_run]
+    pub iterations_run: usize,
     pub current_generation: usize,
     pub stale_generations: usize,
     pub scale_generation: usize,
@@ -221,7 +343,23 @@ pub struct EvolveState<G: EvolveGenotype> {
     pub chromosome: Option<Chromosome<G::Allele>>,
     pub population: Population<G::Allele>,
     pub durations: HashMap<StrategyAction, Duration>,
+    pub fitness_evaluations: usize,
+    /// The effective deadline of the current `call()`, from the max_duration and deadline
+    pub deadline: Option<Instant>,
+    pub deadline_reached: bool,
+    pub termination_predicate_met: bool,
+    /// Set just before `on_finish`, see [Strategy::termination_reason]
+    pub termination_reason: Option<StrategyTerminationReason>,
     pub population_cardinality: Option<usize>,
+    pub selection_intensity: Option<f32>,
+    pub current_scale_index: Option<usize>,
+    pub scale_index_reset_requested: bool,
+    /// The genes of the population at the end of the run, kept on cleanup
+    pub final_population: Vec<Genes<G::Allele>>,
+    /// The Pareto front of the population at the end of the run, kept on cleanup. Only filled
+    /// when the chromosomes have objectives (see
+    /// [FitnessMultiObjective](crate::fitness::FitnessMultiObjective))
+    pub pareto_front: Vec<Chromosome<G::Allele>>,
 }
 
 impl<
@@ -236,6 +374,10 @@ impl<
 {
     fn call(&mut self) {
         let now = Instant::now();
+        self.state.deadline = self.config.deadline_from(self.clock.now());
+        self.state.deadline_reached = false;
+        self.state.termination_predicate_met = false;
+        self.state.termination_reason = None;
         self.reporter
             .on_enter(&self.genotype, &self.state, &self.config);
         let mut fitness_thread_local: Option<ThreadLocal<RefCell<F>>> = None;
@@ -246,18 +388,15 @@ impl<
 
         self.reporter
             .on_start(&self.genotype, &self.state, &self.config);
+        self.update_deadline_reached();
+        self.update_termination_predicate_met();
         while !self.is_finished() {
             self.state.increment_generation();
             self.state
                 .population_filter_age(&self.genotype, &self.config);
+            let elite_chromosomes = self.state.population_elite(&self.config);
 
-            self.plugins.select.call(
-                &self.genotype,
-                &mut self.state,
-                &self.config,
-                &mut self.reporter,
-                &mut self.rng,
-            );
+            self.call_select();
             self.state
                 .update_population_cardinality(&self.genotype, &self.config);
             self.reporter
@@ -271,14 +410,11 @@ impl<
                 &mut self.rng,
             );
 
+            self.state
+                .population_inject_age_layer(&self.genotype, &self.config, &mut self.rng);
+
             self.state.population.increment_age();
-            self.plugins.crossover.call(
-                &self.genotype,
-                &mut self.state,
-                &self.config,
-                &mut self.reporter,
-                &mut self.rng,
-            );
+            self.call_crossover();
             self.plugins.mutate.call(
                 &self.genotype,
                 &mut self.state,
@@ -286,22 +422,41 @@ impl<
                 &mut self.reporter,
                 &mut self.rng,
             );
-            self.fitness.call_for_state_population(
-                &self.genotype,
-                &mut self.state,
-                &self.config,
-                fitness_thread_local.as_ref(),
-            );
+            self.state
+                .population_unique_offspring(&self.genotype, &self.config, &mut self.rng);
+            self.call_fitness(fitness_thread_local.as_ref());
+            if let Some(local_search) = self.local_search.as_ref() {
+                local_search.call(
+                    &self.genotype,
+                    &mut self.fitness,
+                    &mut self.state,
+                    &self.config,
+                    &mut self.rng,
+                );
+            }
+            self.state
+                .reevaluate_best_chromosome(&mut self.fitness, &self.genotype, &self.config);
             self.state.update_best_chromosome_and_report(
                 &self.genotype,
                 &self.config,
                 &mut self.reporter,
             );
+            self.state.population_replace(&self.config);
+            self.state
+                .population_restore_elite(&self.genotype, elite_chromosomes);
+            if let Some(population_snapshot) = self.population_snapshot.as_ref() {
+                population_snapshot.call(&self.genotype, &mut self.state);
+            }
 
             self.reporter
                 .on_generation_complete(&self.genotype, &self.state, &self.config);
-            self.state.scale(&mut self.genotype, &self.config);
+            self.state
+                .scale(&mut self.genotype, &self.config, &mut self.reporter);
+            self.update_deadline_reached();
+            self.update_termination_predicate_met();
         }
+        self.state.termination_reason = self.termination_reason();
+        self.state.iterations_run = self.state.iterations_run.max(1);
         self.reporter
             .on_finish(&self.genotype, &self.state, &self.config);
         self.cleanup(fitness_thread_local.as_mut());
@@ -321,6 +476,46 @@ impl<
             .as_ref()
             .map(|c| c.genes().clone())
     }
+    fn generations_run(&self) -> usize {
+        self.state.current_generation
+    }
+    fn iterations_run(&self) -> usize {
+        self.state.iterations_run
+    }
+    fn final_population(&self) -> Vec<Genes<G::Allele>> {
+        self.state.final_population.clone()
+    }
+    fn termination_reason(&self) -> Option<StrategyTerminationReason> {
+        if !self.is_finished() {
+            None
+        } else if self.is_finished_by_target_fitness_score() {
+            Some(StrategyTerminationReason::TargetFitnessScore)
+        } else if self.is_finished_by_fitness() {
+            Some(StrategyTerminationReason::FitnessShouldTerminate)
+        } else if self.is_finished_by_max_generations() {
+            Some(StrategyTerminationReason::MaxGenerations)
+        } else if self.is_finished_by_stop_after() {
+            Some(StrategyTerminationReason::StopAfter)
+        } else if self.is_finished_by_max_duration() {
+            Some(StrategyTerminationReason::MaxDuration)
+        } else if self.is_finished_by_termination_predicate() {
+            Some(StrategyTerminationReason::TerminationPredicate)
+        } else {
+            Some(StrategyTerminationReason::MaxStaleGenerations)
+        }
+    }
+    fn result(&self) -> StrategyResult<G> {
+        StrategyResult {
+            best_chromosome: self.state.best_chromosome.clone(),
+            best_fitness_score: self.best_fitness_score(),
+            best_generation: self.best_generation(),
+            generations_run: self.generations_run(),
+            fitness_evaluations: self.state.fitness_evaluations,
+            durations: self.state.durations.clone(),
+            total_duration: self.state.total_duration(),
+            termination_reason: self.termination_reason(),
+        }
+    }
     fn flush_reporter(&mut self, output: &mut Vec<u8>) {
         self.reporter.flush(output);
     }
@@ -344,6 +539,30 @@ impl<
             None
         }
     }
+    /// Borrow the best chromosome, without cloning the genes like
+    /// [best_chromosome](Self::best_chromosome). Useful when polling frequently
+    pub fn best_chromosome_ref(&self) -> Option<&Chromosome<G::Allele>> {
+        self.state.best_chromosome.as_ref()
+    }
+    /// The non-dominated chromosomes of the final population, with their objectives (see
+    /// [FitnessMultiObjective](crate::fitness::FitnessMultiObjective)). Deduplicated by genes_hash,
+    /// empty for a single objective fitness
+    pub fn pareto_front(&self) -> &[Chromosome<G::Allele>] {
+        &self.state.pareto_front
+    }
+    /// Snapshot the called run for resuming it later with [EvolveBuilder::with_checkpoint], see
+    /// [EvolveCheckpoint]. The rng_seed is drawn from the rng of the run
+    pub fn checkpoint(&mut self) -> EvolveCheckpoint<G> {
+        EvolveCheckpoint {
+            population: self.state.final_population.clone(),
+            best_chromosome: self.state.best_chromosome.clone(),
+            current_generation: self.state.current_generation,
+            scale_generation: self.state.scale_generation,
+            best_generation: self.state.best_generation,
+            stale_generations: self.state.stale_generations,
+            rng_seed: self.rng.gen(),
+        }
+    }
 }
 
 impl<
@@ -371,23 +590,22 @@ impl<
 {
     pub fn setup(&mut self, fitness_thread_local: Option<&ThreadLocal<RefCell<F>>>) {
         let now = Instant::now();
-        self.state.population = self
-            .genotype
-            .population_constructor(self.config.target_population_size, &mut self.rng);
+        if self.state.population.chromosomes.is_empty() {
+            self.state.population = self
+                .genotype
+                .population_constructor(self.config.target_population_size, &mut self.rng);
+        }
         self.state
             .add_duration(StrategyAction::SetupAndCleanup, now.elapsed());
 
-        self.fitness.call_for_state_population(
-            &self.genotype,
-            &mut self.state,
-            &self.config,
-            fitness_thread_local,
-        );
-        self.state.update_best_chromosome_and_report(
-            &self.genotype,
-            &self.config,
-            &mut self.reporter,
-        );
+        if self.config.evaluate_initial_population {
+            self.call_fitness(fitness_thread_local);
+            self.state.update_best_chromosome_and_report(
+                &self.genotype,
+                &self.config,
+                &mut self.reporter,
+            );
+        }
 
         if self.state.best_fitness_score().is_none() {
             let chromosome = &self.state.population.chromosomes[0];
@@ -399,10 +617,44 @@ impl<
         }
     }
 
+    /// Calculate the fitness of the chromosomes without fitness score, with the
+    /// fitness_resampling if set
+    fn call_fitness(&mut self, fitness_thread_local: Option<&ThreadLocal<RefCell<F>>>) {
+        if let Some(fitness_resampling) = self.config.fitness_resampling.as_ref() {
+            fitness_resampling.call_for_state_population(
+                &mut self.fitness,
+                &self.genotype,
+                &mut self.state,
+                &self.config,
+                fitness_thread_local,
+            );
+        } else {
+            self.fitness.call_for_state_population(
+                &self.genotype,
+                &mut self.state,
+                &self.config,
+                fitness_thread_local,
+            );
+        }
+    }
+
     pub fn cleanup(&mut self, fitness_thread_local: Option<&mut ThreadLocal<RefCell<F>>>) {
         let now = Instant::now();
         self.state.chromosome.take();
-        self.state.population.chromosomes.clear();
+        self.state.pareto_front = self
+            .state
+            .population
+            .pareto_front_indices(self.config.fitness_ordering)
+            .into_iter()
+            .map(|index| self.state.population.chromosomes[index].clone())
+            .collect();
+        self.state.final_population = self
+            .state
+            .population
+            .chromosomes
+            .drain(..)
+            .map(|chromosome| chromosome.genes)
+            .collect();
         if let Some(thread_local) = fitness_thread_local {
             thread_local.clear();
         }
@@ -410,16 +662,141 @@ impl<
             .add_duration(StrategyAction::SetupAndCleanup, now.elapsed());
     }
 
+    /// Call the select strategy, within each age layer if configured (see
+    /// [EvolveBuilder::with_age_layers]). Skipped for [EvolveVariant::Generational] and
+    /// [EvolveVariant::SteadyState], as the replacement of the previous generation already selected
+    /// the survivors
+    fn call_select(&mut self) {
+        if !matches!(self.config.variant, EvolveVariant::Standard) {
+            return;
+        }
+        if self.config.age_layers.is_none() {
+            self.plugins.select.call(
+                &self.genotype,
+                &mut self.state,
+                &self.config,
+                &mut self.reporter,
+                &mut self.rng,
+            );
+            return;
+        }
+        let target_population_size = self.config.target_population_size;
+        let mut chromosomes = Vec::with_capacity(self.state.population.chromosomes.capacity());
+        let layers = self.state.population_split_age_layers(&self.config);
+        let oldest_layer_index = layers.len().saturating_sub(1);
+        let mut remaining_population_size = target_population_size;
+        for (layer_index, layer_chromosomes) in layers.into_iter().enumerate() {
+            if layer_chromosomes.is_empty() {
+                continue;
+            }
+            // the oldest populated layer takes the share of the unpopulated layers as well, so
+            // the initial population (all in the youngest layer) is not cut down to a layer share
+            let layer_size = if layer_index == oldest_layer_index {
+                remaining_population_size
+            } else {
+                self.config.age_layer_size(layer_index)
+            };
+            remaining_population_size = remaining_population_size.saturating_sub(layer_size);
+            self.state.population.chromosomes = layer_chromosomes;
+            self.config.target_population_size = layer_size;
+            self.plugins.select.call(
+                &self.genotype,
+                &mut self.state,
+                &self.config,
+                &mut self.reporter,
+                &mut self.rng,
+            );
+            // restore before the next layer, as the layer shares derive from it
+            self.config.target_population_size = target_population_size;
+            chromosomes.append(&mut self.state.population.chromosomes);
+        }
+        self.state.population.chromosomes = chromosomes;
+    }
+
+    /// Call the crossover strategy, within each age layer if configured (see
+    /// [EvolveBuilder::with_age_layers]). The offspring inherit the lineage_age of their parents,
+    /// so they stay in the layer
+    fn call_crossover(&mut self) {
+        if self.config.age_layers.is_none() {
+            self.plugins.crossover.call(
+                &self.genotype,
+                &mut self.state,
+                &self.config,
+                &mut self.reporter,
+                &mut self.rng,
+            );
+            return;
+        }
+        let mut chromosomes = Vec::with_capacity(self.state.population.chromosomes.capacity());
+        for layer_chromosomes in self.state.population_split_age_layers(&self.config) {
+            if layer_chromosomes.is_empty() {
+                continue;
+            }
+            self.state.population.chromosomes = layer_chromosomes;
+            self.plugins.crossover.call(
+                &self.genotype,
+                &mut self.state,
+                &self.config,
+                &mut self.reporter,
+                &mut self.rng,
+            );
+            chromosomes.append(&mut self.state.population.chromosomes);
+        }
+        self.state.population.chromosomes = chromosomes;
+    }
+
     fn is_finished(&self) -> bool {
-        self.allow_finished_by_valid_fitness_score()
-            && (self.is_finished_by_max_stale_generations()
-                || self.is_finished_by_max_generations()
-                || self.is_finished_by_target_fitness_score())
+        self.is_finished_by_max_duration()
+            || self.is_finished_by_termination_predicate()
+            || (self.allow_finished_by_valid_fitness_score()
+                && (self.is_finished_by_max_stale_generations()
+                    || self.is_finished_by_max_generations()
+                    || self.is_finished_by_target_fitness_score()
+                    || self.is_finished_by_stop_after()
+                    || self.is_finished_by_fitness()))
+    }
+
+    /// A hard stop, regardless of the valid_fitness_score and stop_after
+    fn is_finished_by_max_duration(&self) -> bool {
+        self.state.deadline_reached
+    }
+
+    fn update_deadline_reached(&mut self) {
+        if let Some(deadline) = self.state.deadline {
+            self.state.deadline_reached = self.clock.now() >= deadline;
+        }
+    }
+
+    /// A hard stop, regardless of the valid_fitness_score and stop_after
+    fn is_finished_by_termination_predicate(&self) -> bool {
+        self.state.termination_predicate_met
+    }
+
+    fn update_termination_predicate_met(&mut self) {
+        if let Some(termination_predicate) = self.termination_predicate.as_ref() {
+            self.state.termination_predicate_met = termination_predicate.is_met(&self.state);
+        }
+    }
+
+    fn is_finished_by_fitness(&self) -> bool {
+        self.state
+            .best_chromosome
+            .as_ref()
+            .is_some_and(|chromosome| self.fitness.should_terminate(chromosome))
     }
 
     fn is_finished_by_max_stale_generations(&self) -> bool {
         if let Some(max_stale_generations) = self.config.max_stale_generations {
-            self.state.stale_generations >= max_stale_generations
+            self.allow_finished_by_stop_after()
+                && self.state.stale_generations >= max_stale_generations
+        } else {
+            false
+        }
+    }
+
+    fn is_finished_by_stop_after(&self) -> bool {
+        if let Some((generations, false)) = self.config.stop_after {
+            self.state.current_generation >= generations
         } else {
             false
         }
@@ -448,6 +825,14 @@ impl<
         }
     }
 
+    fn allow_finished_by_stop_after(&self) -> bool {
+        if let Some((generations, _)) = self.config.stop_after {
+            self.state.current_generation >= generations
+        } else {
+            true
+        }
+    }
+
     fn allow_finished_by_valid_fitness_score(&self) -> bool {
         if let Some(valid_fitness_score) = self.config.valid_fitness_score {
             if let Some(fitness_score) = self.best_fitness_score() {
@@ -474,9 +859,18 @@ impl StrategyConfig for EvolveConfig {
     fn par_fitness(&self) -> bool {
         self.par_fitness
     }
+    fn fitness_chunk_size(&self) -> Option<usize> {
+        self.fitness_chunk_size
+    }
     fn replace_on_equal_fitness(&self) -> bool {
         self.replace_on_equal_fitness
     }
+    fn max_duration(&self) -> Option<Duration> {
+        self.max_duration
+    }
+    fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
     fn variant(&self) -> StrategyVariant {
         StrategyVariant::Evolve(self.variant)
     }
@@ -529,6 +923,9 @@ impl<G: EvolveGenotype> StrategyState<G> for EvolveState<G> {
     fn population_cardinality(&self) -> Option<usize> {
         self.population_cardinality
     }
+    fn selection_intensity(&self) -> Option<f32> {
+        self.selection_intensity
+    }
     fn durations(&self) -> &HashMap<StrategyAction, Duration> {
         &self.durations
     }
@@ -538,9 +935,78 @@ impl<G: EvolveGenotype> StrategyState<G> for EvolveState<G> {
     fn total_duration(&self) -> Duration {
         self.durations.values().sum()
     }
+    fn fitness_evaluations(&self) -> usize {
+        self.fitness_evaluations
+    }
+    fn add_fitness_evaluations(&mut self, number_of_evaluations: usize) {
+        self.fitness_evaluations += number_of_evaluations;
+    }
+    fn termination_reason(&self) -> Option<StrategyTerminationReason> {
+        self.termination_reason
+    }
     fn best_genes(&self) -> Option<Genes<G::Allele>> {
         self.best_chromosome.as_ref().map(|c| c.genes().clone())
     }
+    fn best_chromosome_as_ref(&self) -> Option<&Chromosome<G::Allele>> {
+        self.best_chromosome.as_ref()
+    }
+}
+
+/// Public accessors for custom [Select](crate::select::Select),
+/// [Crossover](crate::crossover::Crossover), [Mutate](crate::mutate::Mutate) and
+/// [Extension](crate::extension::Extension) implementations in other crates.
+///
+/// # Example
+/// ```rust
+/// use genetic_algorithm::strategy::evolve::prelude::*;
+/// use rand::Rng;
+///
+/// #[derive(Clone, Debug)]
+/// struct FlipFirstGene;
+/// impl Mutate for FlipFirstGene {
+///     type Genotype = BinaryGenotype;
+///
+///     fn call<R: Rng, SR: StrategyReporter<Genotype = Self::Genotype>>(
+///         &mut self,
+///         genotype: &Self::Genotype,
+///         state: &mut EvolveState<Self::Genotype>,
+///         _config: &EvolveConfig,
+///         _reporter: &mut SR,
+///         _rng: &mut R,
+///     ) {
+///         let _generation = state.current_generation();
+///         let _scale_index = state.current_scale_index();
+///         let _best_fitness_score = state.best_chromosome().and_then(|c| c.fitness_score());
+///         for chromosome in state
+///             .population_mut()
+///             .chromosomes
+///             .iter_mut()
+///             .filter(|c| c.is_offspring())
+///         {
+///             chromosome.genes[0] = !chromosome.genes[0];
+///             chromosome.reset_metadata(genotype.genes_hashing());
+///         }
+///     }
+/// }
+/// ```
+impl<G: EvolveGenotype> EvolveState<G> {
+    pub fn population(&self) -> &Population<G::Allele> {
+        &self.population
+    }
+    pub fn population_mut(&mut self) -> &mut Population<G::Allele> {
+        &mut self.population
+    }
+    pub fn best_chromosome(&self) -> Option<&Chromosome<G::Allele>> {
+        self.best_chromosome.as_ref()
+    }
+    pub fn current_generation(&self) -> usize {
+        self.current_generation
+    }
+    /// Scale index of the genotype at the end of the previous generation, `None` if the genotype
+    /// has no scaled [MutationType](crate::genotype::MutationType)
+    pub fn current_scale_index(&self) -> Option<usize> {
+        self.current_scale_index
+    }
 }
 
 impl<G: EvolveGenotype> EvolveState<G> {
@@ -570,27 +1036,47 @@ impl<G: EvolveGenotype> EvolveState<G> {
                     self.best_chromosome = Some(contending_chromosome.clone());
                     reporter.on_new_best_chromosome_equal_fitness(genotype, self, config);
                     self.increment_stale_generations();
+                    reporter.on_stale_generation(genotype, self, config);
+                }
+                _ => {
+                    self.increment_stale_generations();
+                    reporter.on_stale_generation(genotype, self, config);
                 }
-                _ => self.increment_stale_generations(),
             }
         } else {
             self.increment_stale_generations();
+            reporter.on_stale_generation(genotype, self, config);
         }
         self.add_duration(StrategyAction::UpdateBestChromosome, now.elapsed());
     }
-    fn scale(&mut self, genotype: &mut G, config: &EvolveConfig) {
+    fn scale<SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        genotype: &mut G,
+        config: &EvolveConfig,
+        reporter: &mut SR,
+    ) {
+        if std::mem::take(&mut self.scale_index_reset_requested) {
+            genotype.reset_scale_index();
+            self.reset_scale_generation();
+            self.reset_stale_generations();
+        }
         if let Some(max_generations) = config.max_generations {
             if self.scale_generation >= max_generations && genotype.increment_scale_index() {
                 self.reset_scale_generation();
                 self.reset_stale_generations();
+                self.current_scale_index = genotype.current_scale_index();
+                reporter.on_scale_down(genotype, self, config);
             }
         }
         if let Some(max_stale_generations) = config.max_stale_generations {
             if self.stale_generations >= max_stale_generations && genotype.increment_scale_index() {
                 self.reset_scale_generation();
                 self.reset_stale_generations();
+                self.current_scale_index = genotype.current_scale_index();
+                reporter.on_scale_down(genotype, self, config);
             }
         }
+        self.current_scale_index = genotype.current_scale_index();
     }
 
     fn population_filter_age(&mut self, _genotype: &G, config: &EvolveConfig) {
@@ -604,6 +1090,230 @@ impl<G: EvolveGenotype> EvolveState<G> {
             }
         }
     }
+    /// Move the chromosomes out of the population into their age layers, youngest first
+    fn population_split_age_layers(
+        &mut self,
+        config: &EvolveConfig,
+    ) -> Vec<Vec<Chromosome<G::Allele>>> {
+        let mut layers: Vec<Vec<Chromosome<G::Allele>>> = Vec::new();
+        for chromosome in self.population.chromosomes.drain(..) {
+            let layer_index = config.age_layer_index(chromosome.lineage_age());
+            if layers.len() <= layer_index {
+                layers.resize_with(layer_index + 1, Vec::new);
+            }
+            layers[layer_index].push(chromosome);
+        }
+        layers
+    }
+    /// Inject an age layer share of new random chromosomes into the youngest age layer, every
+    /// age_gap generations (not at the start, as the initial population is the youngest layer).
+    /// The previous youngest cohort has aged into the next layer by then
+    fn population_inject_age_layer<R: Rng>(
+        &mut self,
+        genotype: &G,
+        config: &EvolveConfig,
+        rng: &mut R,
+    ) {
+        if let Some((_, age_gap)) = config.age_layers {
+            if self.current_generation > 0 && self.current_generation % age_gap == 0 {
+                let now = Instant::now();
+                let layer_size = config.age_layer_size(0);
+                self.population
+                    .chromosomes
+                    .extend((0..layer_size).map(|_| genotype.chromosome_constructor_random(rng)));
+                self.add_duration(StrategyAction::Other, now.elapsed());
+            }
+        }
+    }
+    /// Clone the best chromosomes for the elitism_rate, to restore them after the replacement
+    fn population_elite(&mut self, config: &EvolveConfig) -> Vec<Chromosome<G::Allele>> {
+        if let Some(elitism_rate) = config.elitism_rate {
+            let now = Instant::now();
+            let elitism_size =
+                (config.target_population_size as f32 * elitism_rate).ceil() as usize;
+            let elite_chromosomes = self
+                .population
+                .best_chromosome_indices(elitism_size, config.fitness_ordering)
+                .into_iter()
+                .map(|index| self.population.chromosomes[index].clone())
+                .collect();
+            self.add_duration(StrategyAction::Select, now.elapsed());
+            elite_chromosomes
+        } else {
+            Vec::new()
+        }
+    }
+    /// Add the elite chromosomes which did not survive the generation (unchanged) to the
+    /// population. The genes are compared by hash, also without genes_hashing
+    fn population_restore_elite(
+        &mut self,
+        genotype: &G,
+        elite_chromosomes: Vec<Chromosome<G::Allele>>,
+    ) {
+        if elite_chromosomes.is_empty() {
+            return;
+        }
+        let now = Instant::now();
+        let genes_hashes: HashSet<GenesHash> = self
+            .population
+            .chromosomes
+            .iter()
+            .filter(|c| c.fitness_score().is_some())
+            .map(|c| genotype.calculate_genes_hash(c))
+            .collect();
+        elite_chromosomes.into_iter().for_each(|chromosome| {
+            if !genes_hashes.contains(&genotype.calculate_genes_hash(&chromosome)) {
+                self.population.chromosomes.push(chromosome);
+            }
+        });
+        self.add_duration(StrategyAction::Select, now.elapsed());
+    }
+    /// Recalculate the best chromosome every best_reevaluation_interval generations of the
+    /// fitness_resampling, so a lucky fitness score doesn't lock in a false optimum. The fresh
+    /// fitness score replaces the one of the best chromosome and, with genes_hashing, of its
+    /// copies in the population (recognized by the stored genes_hash)
+    fn reevaluate_best_chromosome<F: Fitness<Genotype = G>>(
+        &mut self,
+        fitness: &mut F,
+        genotype: &G,
+        config: &EvolveConfig,
+    ) {
+        if let Some(fitness_resampling) = config.fitness_resampling.as_ref() {
+            if fitness_resampling
+                .best_reevaluation_interval
+                .is_some_and(|interval| self.current_generation % interval == 0)
+            {
+                let now = Instant::now();
+                let context = FitnessContext::from_state(self);
+                if let Some(best_chromosome) = self.best_chromosome.as_mut() {
+                    fitness_resampling.call_for_chromosome(
+                        fitness,
+                        best_chromosome,
+                        genotype,
+                        &context,
+                    );
+                    let fitness_score = best_chromosome.fitness_score();
+                    if let Some(genes_hash) = best_chromosome
+                        .genes_hash()
+                        .filter(|_| genotype.genes_hashing())
+                    {
+                        self.population
+                            .chromosomes
+                            .iter_mut()
+                            .filter(|c| c.genes_hash() == Some(genes_hash))
+                            .for_each(|c| c.set_fitness_score(fitness_score));
+                    }
+                    self.best_fitness_score = fitness_score;
+                    self.add_fitness_evaluations(fitness_resampling.samples);
+                }
+                self.add_duration(StrategyAction::Fitness, now.elapsed());
+            }
+        }
+    }
+    /// Resample the offspring whose genes already exist in the population (the parents or the
+    /// earlier offspring) by mutating a single gene, up to genes_size times, and drop the ones
+    /// which remain a duplicate, before the fitness is calculated. The genes are compared by hash,
+    /// also without genes_hashing
+    fn population_unique_offspring<R: Rng>(
+        &mut self,
+        genotype: &G,
+        config: &EvolveConfig,
+        rng: &mut R,
+    ) {
+        if !config.unique_population {
+            return;
+        }
+        let now = Instant::now();
+        let mut genes_hashes: HashSet<GenesHash> = self
+            .population
+            .chromosomes
+            .iter()
+            .filter(|c| !c.is_offspring())
+            .map(|c| genotype.calculate_genes_hash(c))
+            .collect();
+        let mut duplicate_indices: Vec<usize> = Vec::new();
+        for (index, chromosome) in self.population.chromosomes.iter_mut().enumerate() {
+            if !chromosome.is_offspring() {
+                continue;
+            }
+            let mut genes_hash = genotype.calculate_genes_hash(chromosome);
+            let mut attempts = 0;
+            while genes_hashes.contains(&genes_hash) && attempts < genotype.genes_size() {
+                genotype.mutate_chromosome_genes(1, true, chromosome, rng);
+                genes_hash = genotype.calculate_genes_hash(chromosome);
+                attempts += 1;
+            }
+            if !genes_hashes.insert(genes_hash) {
+                duplicate_indices.push(index);
+            }
+        }
+        duplicate_indices.into_iter().rev().for_each(|index| {
+            let chromosome = self.population.chromosomes.remove(index);
+            self.population.drop_chromosome(chromosome);
+        });
+        self.add_duration(StrategyAction::Mutate, now.elapsed());
+    }
+    /// Replace parents by offspring according to the [EvolveVariant], after the fitness is
+    /// calculated. This is the only selection of the generation, the select strategy is skipped.
+    /// Noop for [EvolveVariant::Standard], where the select strategy handles it.
+    fn population_replace(&mut self, config: &EvolveConfig) {
+        let now = Instant::now();
+        let fitness_ordering = config.fitness_ordering;
+        let fittest_first = |a: &Chromosome<G::Allele>, b: &Chromosome<G::Allele>| {
+            if fitness_ordering.is_better(a.fitness_score(), b.fitness_score()) {
+                Ordering::Less
+            } else if fitness_ordering.is_better(b.fitness_score(), a.fitness_score()) {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        };
+        match config.variant {
+            EvolveVariant::Standard => return,
+            EvolveVariant::Generational => {
+                self.population.chromosomes.sort_by(|a, b| {
+                    b.is_offspring()
+                        .cmp(&a.is_offspring())
+                        .then_with(|| fittest_first(a, b))
+                });
+            }
+            EvolveVariant::SteadyState => {
+                self.population.chromosomes.sort_by(fittest_first);
+            }
+        }
+        let pre_selection_mean = self.population.fitness_score_mean();
+        let pre_selection_stddev = self.population.fitness_score_stddev();
+        let keep_size = config.target_population_size;
+        self.population
+            .truncate(keep_size.min(self.population.size()));
+        self.update_selection_intensity(pre_selection_mean, pre_selection_stddev, config);
+        self.add_duration(StrategyAction::Select, now.elapsed());
+    }
+    /// Set the selection_intensity, the standardized fitness gain of the Select step:
+    /// `(mean_selected - mean_pre) / stddev_pre`, where the pre-selection fitness_score_mean and
+    /// fitness_score_stddev of the population are taken at the start of the Select step. The sign
+    /// is flipped when minimizing, so a positive value is always a gain. None when the
+    /// pre-selection stddev is zero or undefined (no fitness scores).
+    ///
+    /// Called by the provided Select implementations, custom implementations should call this as
+    /// well to report the metric.
+    pub fn update_selection_intensity(
+        &mut self,
+        pre_selection_mean: f32,
+        pre_selection_stddev: f32,
+        config: &EvolveConfig,
+    ) {
+        self.selection_intensity = if pre_selection_stddev > 0.0 {
+            let gain = self.population.fitness_score_mean() - pre_selection_mean;
+            let gain = match config.fitness_ordering {
+                FitnessOrdering::Maximize => gain,
+                FitnessOrdering::Minimize => -gain,
+            };
+            Some(gain / pre_selection_stddev).filter(|v| v.is_finite())
+        } else {
+            None
+        };
+    }
     fn update_population_cardinality(&mut self, genotype: &G, _config: &EvolveConfig) {
         self.population_cardinality = if genotype.genes_hashing() {
             self.population.genes_cardinality()
@@ -626,68 +1336,58 @@ impl<
     type Error = TryFromEvolveBuilderError;
 
     fn try_from(builder: EvolveBuilder<G, M, F, S, C, E, SR>) -> Result<Self, Self::Error> {
-        if builder.genotype.is_none() {
-            Err(TryFromEvolveBuilderError(
-                "Evolve requires a EvolveGenotype",
-            ))
-        } else if builder.fitness.is_none() {
-            Err(TryFromEvolveBuilderError("Evolve requires a Fitness"))
-        } else if builder.mutate.is_none() {
-            Err(TryFromEvolveBuilderError(
-                "Evolve requires a Mutate strategy",
-            ))
-        } else if builder.crossover.is_none() {
-            Err(TryFromEvolveBuilderError(
-                "Evolve requires a Crossover strategy",
-            ))
-        } else if builder.select.is_none() {
-            Err(TryFromEvolveBuilderError(
-                "Evolve requires a Select strategy",
-            ))
-        } else if builder
-            .crossover
-            .as_ref()
-            .map(|o| o.require_crossover_indexes())
-            .unwrap()
-            && builder
-                .genotype
-                .as_ref()
-                .map(|o| !o.has_crossover_indexes())
-                .unwrap()
-        {
-            Err(TryFromEvolveBuilderError(
-                "The provided Crossover strategy requires crossover_indexes, which the provided EvolveGenotype does not provide",
-            ))
-        } else if builder
-            .crossover
-            .as_ref()
-            .map(|o| o.require_crossover_points())
-            .unwrap()
-            && builder
-                .genotype
-                .as_ref()
-                .map(|o| !o.has_crossover_points())
-                .unwrap()
-        {
-            Err(TryFromEvolveBuilderError(
-                "The provided Crossover strategy requires crossover_points, which the provided EvolveGenotype does not provide",
-            ))
-        } else if builder.target_population_size == 0 {
-            Err(TryFromEvolveBuilderError(
-                "Evolve requires a target_population_size > 0",
-            ))
-        } else if builder.max_stale_generations.is_none()
-            && builder.max_generations.is_none()
-            && builder.target_fitness_score.is_none()
-        {
-            Err(TryFromEvolveBuilderError(
-                "Evolve requires at least a max_stale_generations, max_generations or target_fitness_score ending condition",
-            ))
+        if let Some(error) = builder.validation_errors().into_iter().next() {
+            Err(error)
         } else {
-            let rng = builder.rng();
+            let mut rng = builder.rng();
             let genotype = builder.genotype.unwrap();
-            let state = EvolveState::new(&genotype);
+            let mut state = EvolveState::new(&genotype);
+            if let Some(checkpoint) = builder.checkpoint.as_ref() {
+                state.current_generation = checkpoint.current_generation;
+                state.scale_generation = checkpoint.scale_generation;
+                state.best_generation = checkpoint.best_generation;
+                state.stale_generations = checkpoint.stale_generations;
+                state.best_fitness_score = checkpoint
+                    .best_chromosome
+                    .as_ref()
+                    .and_then(|chromosome| chromosome.fitness_score());
+                state
+                    .best_chromosome
+                    .clone_from(&checkpoint.best_chromosome);
+            }
+            state.population.chromosomes = builder
+                .seed_population
+                .iter()
+                .map(|genes| genotype.chromosome_constructor_genes(genes))
+                .collect();
+            // keep the provided fitness, so only the unevaluated seed chromosomes are calculated
+            state
+                .population
+                .chromosomes
+                .extend(builder.seed_chromosomes.iter().map(|seed_chromosome| {
+                    let mut chromosome =
+                        genotype.chromosome_constructor_genes(&seed_chromosome.genes);
+                    chromosome.set_fitness_score(seed_chromosome.fitness_score());
+                    chromosome
+                        .objectives
+                        .clone_from(&seed_chromosome.objectives);
+                    chromosome
+                }));
             let target_population_size = builder.target_population_size;
+            // the seed genes only take part of a new population from the genotype
+            if state.population.chromosomes.is_empty() && !builder.seed_genes.is_empty() {
+                let seed_size = builder.seed_genes.len().min(target_population_size);
+                let mut population =
+                    genotype.population_constructor(target_population_size - seed_size, &mut rng);
+                population.chromosomes.extend(
+                    builder
+                        .seed_genes
+                        .iter()
+                        .take(seed_size)
+                        .map(|genes| genotype.chromosome_constructor_genes(genes)),
+                );
+                state.population = population;
+            }
 
             Ok(Self {
                 genotype,
@@ -698,18 +1398,44 @@ impl<
                     select: builder.select.unwrap(),
                     extension: builder.extension,
                 },
+                local_search: builder.local_search.map(|mut local_search| {
+                    local_search.top_k = builder.local_search_top_k.or(local_search.top_k);
+                    local_search
+                }),
+                termination_predicate: builder.termination_predicate,
+                clock: builder.clock,
+                population_snapshot: builder.population_snapshot,
                 config: EvolveConfig {
+                    variant: builder.variant.unwrap_or_default(),
+                    generation_gap: builder.generation_gap,
                     target_population_size,
                     max_stale_generations: builder.max_stale_generations,
                     max_generations: builder.max_generations,
                     max_chromosome_age: builder.max_chromosome_age,
+                    elitism_rate: builder.elitism_rate,
+                    offspring_rate: builder.offspring_rate,
+                    age_layers: builder.age_layers,
                     target_fitness_score: builder.target_fitness_score,
                     valid_fitness_score: builder.valid_fitness_score,
+                    stop_after: builder.stop_after,
+                    max_duration: builder.max_duration,
+                    deadline: builder.deadline,
                     fitness_ordering: builder.fitness_ordering,
                     fitness_cache: builder.fitness_cache,
+                    fitness_resampling: builder.fitness_resampling.map(|mut fitness_resampling| {
+                        if let Some(aggregation) = builder.fitness_resampling_aggregation {
+                            fitness_resampling.aggregation = aggregation;
+                        }
+                        if let Some(interval) = builder.fitness_resampling_best_interval {
+                            fitness_resampling.best_reevaluation_interval = Some(interval);
+                        }
+                        fitness_resampling
+                    }),
                     par_fitness: builder.par_fitness,
+                    fitness_chunk_size: builder.fitness_chunk_size,
                     replace_on_equal_fitness: builder.replace_on_equal_fitness,
-                    ..Default::default()
+                    evaluate_initial_population: builder.evaluate_initial_population,
+                    unique_population: builder.unique_population,
                 },
                 state,
                 reporter: builder.reporter,
@@ -723,16 +1449,27 @@ impl Default for EvolveConfig {
     fn default() -> Self {
         Self {
             variant: Default::default(),
+            generation_gap: None,
             target_population_size: 0,
             max_stale_generations: None,
             max_generations: None,
             max_chromosome_age: None,
+            elitism_rate: None,
+            offspring_rate: None,
+            age_layers: None,
             target_fitness_score: None,
             valid_fitness_score: None,
+            stop_after: None,
+            max_duration: None,
+            deadline: None,
             fitness_ordering: FitnessOrdering::Maximize,
             fitness_cache: None,
+            fitness_resampling: None,
             par_fitness: false,
+            fitness_chunk_size: None,
             replace_on_equal_fitness: false,
+            evaluate_initial_population: true,
+            unique_population: false,
         }
     }
 }
@@ -740,12 +1477,53 @@ impl EvolveConfig {
     pub fn new() -> Self {
         Self::default()
     }
+    /// The number of offspring for the selected parents in the crossover phase, see
+    /// [EvolveBuilder::with_offspring_rate]. Capped at the generation_gap for
+    /// [EvolveVariant::SteadyState], see [EvolveBuilder::with_generation_gap]
+    pub fn offspring_size(&self, selected_population_size: usize) -> usize {
+        let offspring_size = match self.offspring_rate {
+            Some(offspring_rate) => {
+                (selected_population_size as f32 * offspring_rate).ceil() as usize
+            }
+            None => selected_population_size,
+        };
+        match (self.variant, self.generation_gap) {
+            (EvolveVariant::SteadyState, Some(generation_gap)) => {
+                offspring_size.min(generation_gap)
+            }
+            _ => offspring_size,
+        }
+    }
+    /// The age layer index of the lineage_age, where the last layer has no age limit. Always zero
+    /// without age layers, see [EvolveBuilder::with_age_layers]
+    pub fn age_layer_index(&self, lineage_age: usize) -> usize {
+        match self.age_layers {
+            Some((number_of_layers, age_gap)) => (lineage_age / age_gap).min(number_of_layers - 1),
+            None => 0,
+        }
+    }
+    /// The share of the target_population_size of the age layer, the last layer takes the
+    /// remainder. The full target_population_size without age layers
+    pub fn age_layer_size(&self, layer_index: usize) -> usize {
+        match self.age_layers {
+            Some((number_of_layers, _)) => {
+                let layer_size = self.target_population_size / number_of_layers;
+                if layer_index + 1 == number_of_layers {
+                    layer_size + self.target_population_size % number_of_layers
+                } else {
+                    layer_size
+                }
+            }
+            None => self.target_population_size,
+        }
+    }
 }
 
 impl<G: EvolveGenotype> EvolveState<G> {
     pub fn new(genotype: &G) -> Self {
         Self {
             current_iteration: 0,
+            iterations_run: 0,
             current_generation: 0,
             stale_generations: 0,
             scale_generation: 0,
@@ -755,7 +1533,17 @@ impl<G: EvolveGenotype> EvolveState<G> {
             chromosome: None,
             population: Population::new_empty(genotype.chromosome_recycling()),
             population_cardinality: None,
+            selection_intensity: None,
+            current_scale_index: genotype.current_scale_index(),
+            scale_index_reset_requested: false,
+            final_population: Vec::new(),
+            pareto_front: Vec::new(),
             durations: HashMap::new(),
+            fitness_evaluations: 0,
+            deadline: None,
+            deadline_reached: false,
+            termination_predicate_met: false,
+            termination_reason: None,
         }
     }
 }
@@ -795,6 +1583,8 @@ impl<M: Mutate, S: Crossover, C: Select, E: Extension> fmt::Display for EvolvePl
 impl fmt::Display for EvolveConfig {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "evolve_config:")?;
+        writeln!(f, "  variant: {:?}", self.variant)?;
+        writeln!(f, "  generation_gap: {:?}", self.generation_gap)?;
         writeln!(
             f,
             "  target_population_size: {}",
@@ -807,10 +1597,24 @@ impl fmt::Display for EvolveConfig {
         )?;
         writeln!(f, "  max_generations: {:?}", self.max_generations)?;
         writeln!(f, "  max_chromosome_age: {:?}", self.max_chromosome_age)?;
+        writeln!(f, "  elitism_rate: {:?}", self.elitism_rate)?;
+        writeln!(f, "  offspring_rate: {:?}", self.offspring_rate)?;
+        writeln!(f, "  age_layers: {:?}", self.age_layers)?;
         writeln!(f, "  valid_fitness_score: {:?}", self.valid_fitness_score)?;
+        writeln!(f, "  stop_after: {:?}", self.stop_after)?;
+        writeln!(f, "  max_duration: {:?}", self.max_duration)?;
+        writeln!(f, "  deadline: {:?}", self.deadline)?;
         writeln!(f, "  target_fitness_score: {:?}", self.target_fitness_score)?;
         writeln!(f, "  fitness_ordering: {:?}", self.fitness_ordering)?;
-        writeln!(f, "  par_fitness: {:?}", self.par_fitness)
+        writeln!(f, "  par_fitness: {:?}", self.par_fitness)?;
+        writeln!(f, "  fitness_chunk_size: {:?}", self.fitness_chunk_size)?;
+        writeln!(f, "  fitness_resampling: {:?}", self.fitness_resampling)?;
+        writeln!(
+            f,
+            "  evaluate_initial_population: {:?}",
+            self.evaluate_initial_population
+        )?;
+        writeln!(f, "  unique_population: {:?}", self.unique_population)
     }
 }
 
@@ -825,6 +1629,8 @@ impl<G: EvolveGenotype> fmt::Display for EvolveState<G> {
             "  population cardinality: {:?}",
             self.population_cardinality
         )?;
+        writeln!(f, "  selection intensity: {:?}", self.selection_intensity)?;
+        writeln!(f, "  fitness evaluations: {:?}", self.fitness_evaluations)?;
         writeln!(f, "  best fitness score: {:?}", self.best_fitness_score())
     }
 }
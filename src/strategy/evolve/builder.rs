@@ -1,16 +1,31 @@
-use super::Evolve;
+use super::{
+    Evolve, EvolveCheckpoint, EvolveLocalSearch, EvolveMigrationTopology, EvolvePopulationSnapshot,
+    EvolveVariant,
+};
+use crate::chromosome::{Chromosome, Genes};
 use crate::crossover::Crossover;
 pub use crate::errors::TryFromStrategyBuilderError as TryFromBuilderError;
+use crate::errors::{BuilderError, BuilderValidation};
 use crate::extension::{Extension, ExtensionNoop};
-use crate::fitness::{Fitness, FitnessCache, FitnessOrdering, FitnessValue};
-use crate::genotype::EvolveGenotype;
+use crate::fitness::{
+    Fitness, FitnessCache, FitnessOrdering, FitnessResampling, FitnessResamplingAggregation,
+    FitnessValue,
+};
+use crate::genotype::{EvolveGenotype, Genotype, HillClimbGenotype};
 use crate::mutate::Mutate;
+use crate::population::Population;
 use crate::select::Select;
-use crate::strategy::{Strategy, StrategyReporter, StrategyReporterNoop};
+use crate::strategy::hill_climb::HillClimbVariant;
+use crate::strategy::{
+    derive_seed, Strategy, StrategyClock, StrategyReporter, StrategyReporterNoop, StrategyState,
+    StrategyTerminationPredicate,
+};
 use rand::rngs::SmallRng;
 use rand::SeedableRng;
 use rayon::prelude::*;
+use std::io::Write;
 use std::sync::mpsc::channel;
+use std::time::{Duration, Instant};
 
 /// The builder for an Evolve struct.
 #[derive(Clone, Debug)]
@@ -24,16 +39,41 @@ pub struct Builder<
     SR: StrategyReporter<Genotype = G>,
 > {
     pub genotype: Option<G>,
+    pub variant: Option<EvolveVariant>,
+    pub generation_gap: Option<usize>,
     pub target_population_size: usize,
     pub max_stale_generations: Option<usize>,
     pub max_generations: Option<usize>,
     pub max_chromosome_age: Option<usize>,
+    pub elitism_rate: Option<f32>,
+    pub offspring_rate: Option<f32>,
+    pub age_layers: Option<(usize, usize)>,
     pub target_fitness_score: Option<FitnessValue>,
     pub valid_fitness_score: Option<FitnessValue>,
+    pub stop_after: Option<(usize, bool)>,
+    pub max_duration: Option<Duration>,
+    pub deadline: Option<Instant>,
+    pub termination_predicate: Option<StrategyTerminationPredicate<G>>,
+    pub clock: StrategyClock,
     pub fitness_ordering: FitnessOrdering,
     pub fitness_cache: Option<FitnessCache>,
+    pub fitness_resampling: Option<FitnessResampling>,
+    pub fitness_resampling_aggregation: Option<FitnessResamplingAggregation>,
+    pub fitness_resampling_best_interval: Option<usize>,
     pub par_fitness: bool,
+    pub fitness_chunk_size: Option<usize>,
     pub replace_on_equal_fitness: bool,
+    pub evaluate_initial_population: bool,
+    pub unique_population: bool,
+    pub seed_population: Vec<Genes<G::Allele>>,
+    pub seed_chromosomes: Vec<Chromosome<G::Allele>>,
+    pub seed_genes: Vec<Genes<G::Allele>>,
+    pub checkpoint: Option<EvolveCheckpoint<G>>,
+    pub speciation_merge_interval: Option<usize>,
+    pub migration: Option<(EvolveMigrationTopology, usize)>,
+    pub local_search: Option<EvolveLocalSearch<G>>,
+    pub local_search_top_k: Option<usize>,
+    pub population_snapshot: Option<EvolvePopulationSnapshot<G>>,
     pub mutate: Option<M>,
     pub fitness: Option<F>,
     pub crossover: Option<S>,
@@ -54,16 +94,41 @@ impl<
     fn default() -> Self {
         Self {
             genotype: None,
+            variant: None,
+            generation_gap: None,
             target_population_size: 0,
             max_stale_generations: None,
             max_generations: None,
             max_chromosome_age: None,
+            elitism_rate: None,
+            offspring_rate: None,
+            age_layers: None,
             target_fitness_score: None,
             valid_fitness_score: None,
+            stop_after: None,
+            max_duration: None,
+            deadline: None,
+            termination_predicate: None,
+            clock: StrategyClock::default(),
             fitness_ordering: FitnessOrdering::Maximize,
             fitness_cache: None,
+            fitness_resampling: None,
+            fitness_resampling_aggregation: None,
+            fitness_resampling_best_interval: None,
             par_fitness: false,
+            fitness_chunk_size: None,
             replace_on_equal_fitness: false,
+            evaluate_initial_population: true,
+            unique_population: false,
+            seed_population: Vec::new(),
+            seed_chromosomes: Vec::new(),
+            seed_genes: Vec::new(),
+            checkpoint: None,
+            speciation_merge_interval: None,
+            migration: None,
+            local_search: None,
+            local_search_top_k: None,
+            population_snapshot: None,
             mutate: None,
             fitness: None,
             crossover: None,
@@ -102,10 +167,331 @@ impl<
         self.try_into()
     }
 
+    /// Dry-run validation of the full configuration, without building. Returns all errors at
+    /// once, where `build()` and `call()` only return the first one. The warnings are returned
+    /// separately, as they don't fail the build, but are likely misconfigurations. Therefore a
+    /// [BuilderValidation] is returned instead of a `Result<(), Vec<String>>`, which can't carry
+    /// the warnings of a valid configuration. Use [is_valid](BuilderValidation::is_valid) to check
+    /// for errors.
+    pub fn validate(&self) -> BuilderValidation {
+        BuilderValidation {
+            errors: self
+                .validation_errors()
+                .into_iter()
+                .map(|error| error.0.to_string())
+                .collect(),
+            warnings: self.validation_warnings(),
+        }
+    }
+
+    pub(crate) fn validation_errors(&self) -> Vec<TryFromBuilderError> {
+        let mut errors = vec![];
+        if self.genotype.is_none() {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a EvolveGenotype",
+                BuilderError::MissingGenotype,
+            ));
+        }
+        if self.fitness.is_none() {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a Fitness",
+                BuilderError::MissingFitness,
+            ));
+        }
+        if self.mutate.is_none() {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a Mutate strategy",
+                BuilderError::MissingMutate,
+            ));
+        }
+        if self.crossover.is_none() {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a Crossover strategy",
+                BuilderError::MissingCrossover,
+            ));
+        }
+        if self.select.is_none() {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a Select strategy",
+                BuilderError::MissingSelect,
+            ));
+        }
+        if let (Some(genotype), Some(crossover)) = (self.genotype.as_ref(), self.crossover.as_ref())
+        {
+            if crossover.require_crossover_indexes() && !genotype.has_crossover_indexes() {
+                errors.push(TryFromBuilderError(
+                    "The provided Crossover strategy requires crossover_indexes, which the provided EvolveGenotype does not provide",
+                    BuilderError::IncompatibleCrossover {
+                        requires: "crossover_indexes",
+                    },
+                ));
+            }
+            if crossover.require_crossover_points() && !genotype.has_crossover_points() {
+                errors.push(TryFromBuilderError(
+                    "The provided Crossover strategy requires crossover_points, which the provided EvolveGenotype does not provide",
+                    BuilderError::IncompatibleCrossover {
+                        requires: "crossover_points",
+                    },
+                ));
+            }
+            if crossover.require_crossover_ordered() && !genotype.has_crossover_ordered() {
+                errors.push(TryFromBuilderError(
+                    "The provided Crossover strategy requires crossover_ordered, which the provided EvolveGenotype does not provide",
+                    BuilderError::IncompatibleCrossover {
+                        requires: "crossover_ordered",
+                    },
+                ));
+            }
+            if crossover.require_raw_gene_swap() && !genotype.has_raw_gene_swap() {
+                errors.push(TryFromBuilderError(
+                    "The provided Crossover strategy requires raw_gene_swap, which the provided EvolveGenotype does not provide",
+                    BuilderError::IncompatibleCrossover {
+                        requires: "raw_gene_swap",
+                    },
+                ));
+            }
+            if crossover
+                .mask_size()
+                .is_some_and(|mask_size| mask_size != genotype.genes_size())
+            {
+                errors.push(TryFromBuilderError(
+                    "The provided Crossover strategy's mask size does not match the genes_size of the provided EvolveGenotype",
+                    BuilderError::IncompatibleCrossover {
+                        requires: "mask_size",
+                    },
+                ));
+            }
+        }
+        if self.target_population_size == 0 {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a target_population_size > 0",
+                BuilderError::InvalidTargetPopulationSize,
+            ));
+        }
+        if self
+            .elitism_rate
+            .is_some_and(|elitism_rate| !(0.0..=1.0).contains(&elitism_rate))
+        {
+            errors.push(TryFromBuilderError(
+                "Evolve requires an elitism_rate between 0.0 and 1.0",
+                BuilderError::InvalidParameter,
+            ));
+        }
+        if self
+            .offspring_rate
+            .is_some_and(|offspring_rate| offspring_rate.is_nan() || offspring_rate <= 0.0)
+        {
+            errors.push(TryFromBuilderError(
+                "Evolve requires an offspring_rate > 0.0",
+                BuilderError::InvalidParameter,
+            ));
+        }
+        if let Some((number_of_layers, age_gap)) = self.age_layers {
+            if number_of_layers < 2 || age_gap == 0 {
+                errors.push(TryFromBuilderError(
+                    "Evolve requires age_layers with a number_of_layers > 1 and an age_gap > 0",
+                    BuilderError::InvalidParameter,
+                ));
+            } else if self.target_population_size < 2 * number_of_layers {
+                errors.push(TryFromBuilderError(
+                    "Evolve requires a target_population_size of at least 2 per age layer",
+                    BuilderError::InvalidTargetPopulationSize,
+                ));
+            }
+            if !matches!(self.variant, None | Some(EvolveVariant::Standard)) {
+                errors.push(TryFromBuilderError(
+                    "Evolve age_layers require the EvolveVariant::Standard",
+                    BuilderError::InvalidParameter,
+                ));
+            }
+        }
+        if let Some(genotype) = self.genotype.as_ref() {
+            if self
+                .seed_chromosomes
+                .iter()
+                .any(|chromosome| chromosome.genes.len() != genotype.genes_size())
+            {
+                errors.push(TryFromBuilderError(
+                    "Evolve requires seed_population chromosomes with the genotype's genes_size",
+                    BuilderError::IncompatibleSeedGenes,
+                ));
+            }
+            if self
+                .seed_genes
+                .iter()
+                .any(|genes| genes.len() != genotype.genes_size())
+            {
+                errors.push(TryFromBuilderError(
+                    "Evolve requires seed_genes_from genes with the genotype's genes_size",
+                    BuilderError::IncompatibleSeedGenes,
+                ));
+            }
+        }
+        if self.generation_gap == Some(0) {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a generation_gap > 0",
+                BuilderError::InvalidParameter,
+            ));
+        }
+        if self
+            .local_search
+            .as_ref()
+            .is_some_and(|local_search| local_search.steps == 0)
+        {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a local_search with steps > 0",
+                BuilderError::InvalidParameter,
+            ));
+        }
+        if self.local_search_top_k == Some(0)
+            || self
+                .local_search
+                .as_ref()
+                .is_some_and(|local_search| local_search.top_k == Some(0))
+        {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a local_search with top_k > 0",
+                BuilderError::InvalidParameter,
+            ));
+        }
+        if self.local_search_top_k.is_some() && self.local_search.is_none() {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a local_search for the local_search_top_k",
+                BuilderError::InvalidParameter,
+            ));
+        }
+        if self
+            .population_snapshot
+            .as_ref()
+            .is_some_and(|population_snapshot| population_snapshot.interval == 0)
+        {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a population_snapshot with interval > 0",
+                BuilderError::InvalidParameter,
+            ));
+        }
+        if self
+            .select
+            .as_ref()
+            .and_then(|select| select.winning_probability())
+            .is_some_and(|winning_probability| !(0.0..=1.0).contains(&winning_probability))
+        {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a Select strategy with a winning_probability between 0.0 and 1.0",
+                BuilderError::InvalidParameter,
+            ));
+        }
+        if let Some(fitness_resampling) = self.fitness_resampling.as_ref() {
+            if fitness_resampling.samples == 0 {
+                errors.push(TryFromBuilderError(
+                    "Evolve requires a fitness_resampling with samples > 0",
+                    BuilderError::InvalidParameter,
+                ));
+            }
+            if self.fitness_resampling_best_interval == Some(0)
+                || fitness_resampling.best_reevaluation_interval == Some(0)
+            {
+                errors.push(TryFromBuilderError(
+                    "Evolve requires a fitness_resampling with best_reevaluation_interval > 0",
+                    BuilderError::InvalidParameter,
+                ));
+            }
+            if self.fitness_cache.is_some() {
+                errors.push(TryFromBuilderError(
+                    "Evolve does not support a fitness_cache with fitness_resampling",
+                    BuilderError::InvalidParameter,
+                ));
+            }
+        } else if self.fitness_resampling_aggregation.is_some()
+            || self.fitness_resampling_best_interval.is_some()
+        {
+            errors.push(TryFromBuilderError(
+                "Evolve requires a fitness_resampling for the fitness_resampling_aggregation and fitness_resampling_best_interval",
+                BuilderError::InvalidParameter,
+            ));
+        }
+        if self.max_stale_generations.is_none()
+            && self.max_generations.is_none()
+            && self.target_fitness_score.is_none()
+            && self.max_duration.is_none()
+            && self.deadline.is_none()
+        {
+            errors.push(TryFromBuilderError(
+                "Evolve requires at least a max_stale_generations, max_generations, target_fitness_score, max_duration or deadline ending condition",
+                BuilderError::MissingEndingCondition,
+            ));
+        }
+        errors
+    }
+
+    pub(crate) fn validation_warnings(&self) -> Vec<String> {
+        let mut warnings = vec![];
+        if let (Some(genotype), Some(crossover)) = (self.genotype.as_ref(), self.crossover.as_ref())
+        {
+            if let Some(number_of_crossovers) = crossover.number_of_crossovers() {
+                if number_of_crossovers > genotype.genes_size() {
+                    warnings.push(format!(
+                        "The provided Crossover strategy's number_of_crossovers ({}) exceeds the genes_size ({}) and is silently clamped, derive it from the genes_size instead",
+                        number_of_crossovers,
+                        genotype.genes_size()
+                    ));
+                }
+            }
+        }
+        if let Some(selection_rate) = self.crossover.as_ref().and_then(|c| c.selection_rate()) {
+            if selection_rate <= 0.0 {
+                warnings.push(
+                    "The provided Crossover strategy's selection_rate is zero, so no offspring are produced".to_string(),
+                );
+            }
+        }
+        if let Some(mutation_probability) =
+            self.mutate.as_ref().and_then(|m| m.mutation_probability())
+        {
+            if mutation_probability <= 0.0 {
+                warnings.push(
+                    "The provided Mutate strategy's mutation_probability is zero, so the offspring are never mutated".to_string(),
+                );
+            }
+        }
+        if matches!(
+            self.variant,
+            Some(EvolveVariant::Generational) | Some(EvolveVariant::SteadyState)
+        ) {
+            warnings.push(
+                "The provided Select strategy is ignored, as the replacement of the Generational and SteadyState EvolveVariant takes its place".to_string(),
+            );
+        } else if let Some(tournament_size) = self.select.as_ref().and_then(|s| s.tournament_size())
+        {
+            if self.target_population_size < tournament_size {
+                warnings.push(format!(
+                    "The target_population_size ({}) is smaller than the provided Select strategy's tournament_size ({}), so the tournaments are clamped to the population size",
+                    self.target_population_size, tournament_size
+                ));
+            }
+        }
+        warnings
+    }
+
     pub fn with_genotype(mut self, genotype: G) -> Self {
         self.genotype = Some(genotype);
         self
     }
+    /// The Generational and SteadyState variants replace the select strategy, which is still
+    /// required by the builder, but ignored (see [EvolveVariant])
+    pub fn with_variant(mut self, variant: EvolveVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+    /// The maximum number of offspring per generation for [EvolveVariant::SteadyState], which caps
+    /// the offspring created by the crossover (see
+    /// [offspring_size](crate::strategy::evolve::EvolveConfig::offspring_size)). So each
+    /// generation only a few offspring are crossed, mutated and evaluated, which replace the worst
+    /// members of the population. Ignored for the other variants.
+    pub fn with_generation_gap(mut self, generation_gap: usize) -> Self {
+        self.generation_gap = Some(generation_gap);
+        self
+    }
     pub fn with_target_population_size(mut self, target_population_size: usize) -> Self {
         self.target_population_size = target_population_size;
         self
@@ -133,6 +519,44 @@ impl<
         self.max_chromosome_age = Some(max_chromosome_age);
         self
     }
+    /// Guarantee the best `ceil(target_population_size * elitism_rate)` chromosomes of each
+    /// generation survive unchanged into the next generation, regardless of the select, crossover,
+    /// mutate and variant configuration. The elite is taken at the start of the generation (after
+    /// the max_chromosome_age filter) and restored after the replacement if it was lost, so the
+    /// population can exceed the target_population_size by the restored elite. Orthogonal to the
+    /// elitism_rate of the select strategies, which only guards the select step.
+    pub fn with_elitism_rate(mut self, elitism_rate: f32) -> Self {
+        self.elitism_rate = Some(elitism_rate);
+        self
+    }
+    /// Generate `ceil(selected parents * offspring_rate)` offspring in the crossover phase,
+    /// instead of one child per selected parent (rate 1.0, the default). Above 1.0 the parent
+    /// pairs are crossed multiple times (with different random crossover points/genes), which
+    /// improves the exploration for small populations without increasing the parent pool. Below
+    /// 1.0 only part of the parent pairs produce offspring. Ignored by
+    /// [CrossoverNoop](crate::crossover::CrossoverNoop) and
+    /// [CrossoverRejuvenate](crate::crossover::CrossoverRejuvenate).
+    pub fn with_offspring_rate(mut self, offspring_rate: f32) -> Self {
+        self.offspring_rate = Some(offspring_rate);
+        self
+    }
+    /// Segment the population into age layers (ALPS, the age-layered population structure), to
+    /// keep exploring without premature convergence. The layers are based on the lineage_age of
+    /// the chromosomes (see [Chromosome](crate::chromosome::Chromosome)), the number of
+    /// generations since the genetic material was randomly introduced, which the offspring
+    /// inherit from their parents. Layer `i` holds the lineage ages below `(i + 1) * age_gap`, the
+    /// last layer has no age limit. Each layer has an equal share of the target_population_size,
+    /// and the chromosomes only compete (select) and crossover within their own layer. Every
+    /// age_gap generations, a layer share of new random chromosomes is injected into the youngest
+    /// layer, while the previous youngest cohort ages into the next layer, competing with the
+    /// chromosomes already there. The initial population starts in the youngest layer, and
+    /// until the older layers are populated, the oldest populated layer takes their share.
+    /// Requires the [EvolveVariant::Standard]. The extension and mutate strategies still act on
+    /// the whole population.
+    pub fn with_age_layers(mut self, number_of_layers: usize, age_gap: usize) -> Self {
+        self.age_layers = Some((number_of_layers, age_gap));
+        self
+    }
     pub fn with_max_chromosome_age_option(
         mut self,
         max_chromosome_age_option: Option<usize>,
@@ -162,6 +586,50 @@ impl<
         self.valid_fitness_score = valid_fitness_score_option;
         self
     }
+    /// Run at least the given number of generations, ignoring the max_stale_generations ending
+    /// condition until then. When require_no_improvement is false, stop at the given number of
+    /// generations (unless finished earlier by the target_fitness_score). When true, continue
+    /// after the given number of generations until finished by the other ending conditions.
+    /// Note the other ending conditions remain required.
+    pub fn with_stop_after(mut self, generations: usize, require_no_improvement: bool) -> Self {
+        self.stop_after = Some((generations, require_no_improvement));
+        self
+    }
+    /// Stop when the given duration since the start of the `call()` has passed (checked once per
+    /// generation). This is a hard stop, regardless of the valid_fitness_score and stop_after.
+    /// Applies per run for the repeated and speciated calls, but to all epochs together for the
+    /// islands and the speciated calls with merging
+    pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+    /// Stop when the given point in time has passed (checked once per generation). This is a hard
+    /// stop, regardless of the valid_fitness_score and stop_after. Shared by all runs for the
+    /// repeated and speciated calls
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+    /// Stop when the predicate on the state returns true (checked once per generation), e.g. for
+    /// external conditions. This is a hard stop, regardless of the valid_fitness_score and
+    /// stop_after. Shared by all runs for the repeated and speciated calls, see
+    /// [StrategyTerminationPredicate]
+    pub fn with_termination_predicate<P>(mut self, termination_predicate: P) -> Self
+    where
+        P: Fn(&dyn StrategyState<G>) -> bool + Send + Sync + 'static,
+    {
+        self.termination_predicate = Some(StrategyTerminationPredicate::new(termination_predicate));
+        self
+    }
+    /// The source of the current time for the max_duration and deadline, defaults to the system
+    /// clock. Shared by all runs for the repeated and speciated calls, see [StrategyClock]
+    pub fn with_clock<N>(mut self, now: N) -> Self
+    where
+        N: Fn() -> Instant + Send + Sync + 'static,
+    {
+        self.clock = StrategyClock::new(now);
+        self
+    }
     pub fn with_fitness_ordering(mut self, fitness_ordering: FitnessOrdering) -> Self {
         self.fitness_ordering = fitness_ordering;
         self
@@ -180,10 +648,119 @@ impl<
         self.par_fitness = par_fitness;
         self
     }
+    /// The number of consecutive chromosomes per rayon work unit for the parallel fitness
+    /// calculation (only used with `with_par_fitness(true)`), each chunk is calculated by a single
+    /// thread. Larger chunks reduce the scheduling overhead for cheap fitness functions, smaller
+    /// chunks balance uneven fitness costs better. When not set, the chunking is dynamic (rayon's
+    /// default splitting)
+    pub fn with_fitness_chunk_size(mut self, fitness_chunk_size: usize) -> Self {
+        self.fitness_chunk_size = Some(fitness_chunk_size);
+        self
+    }
     pub fn with_replace_on_equal_fitness(mut self, replace_on_equal_fitness: bool) -> Self {
         self.replace_on_equal_fitness = replace_on_equal_fitness;
         self
     }
+    /// Defaults to true, calculate fitness for the initial population (generation 0) and set the
+    /// first best chromosome from it. When false, the initial population is only scored in the
+    /// first generation
+    pub fn with_evaluate_initial_population(mut self, evaluate_initial_population: bool) -> Self {
+        self.evaluate_initial_population = evaluate_initial_population;
+        self
+    }
+    /// Defaults to false. When true, the offspring whose genes already exist in the population
+    /// (compared by genes hash, also without genes_hashing) are resampled by mutating a single
+    /// gene, up to genes_size times, before the fitness is calculated. The offspring which remain
+    /// a duplicate are dropped. Keeps the effective diversity up for small search spaces (e.g. a
+    /// [UniqueGenotype](crate::genotype::UniqueGenotype) with few genes), where the population
+    /// otherwise collapses to identical chromosomes
+    pub fn with_unique_population(mut self, unique_population: bool) -> Self {
+        self.unique_population = unique_population;
+        self
+    }
+    /// Start from the final population of a previous run (see [Strategy::final_population]),
+    /// instead of a new population from the genotype. This continues the previous run (e.g.
+    /// with a lower mutation probability). The full final population is taken as generation 0,
+    /// regardless of the target_population_size. Ignored if the previous run has no final
+    /// population
+    pub fn with_seed_population_from<S2: Strategy<G>>(mut self, strategy: &S2) -> Self {
+        self.seed_population = strategy.final_population();
+        self
+    }
+    /// Start from an already evaluated population (e.g. from a previous run or an external
+    /// heuristic), instead of a new population from the genotype. The provided fitness scores are
+    /// reused, only the chromosomes without a fitness score are calculated. The full population
+    /// is taken as generation 0, regardless of the target_population_size. The genes_size of the
+    /// chromosomes must match the genotype. Combines with
+    /// [with_seed_population_from](Self::with_seed_population_from)
+    pub fn with_seed_population(mut self, population: Population<G::Allele>) -> Self {
+        self.seed_chromosomes = population.chromosomes;
+        self
+    }
+    /// Resume a run from its [EvolveCheckpoint] (see [Evolve::checkpoint]). Seeds the population
+    /// with the final population of the checkpoint (like
+    /// [with_seed_population_from](Self::with_seed_population_from)) and continues the
+    /// generation counters and best chromosome, so the ending conditions (e.g. max_generations)
+    /// count from the start of the original run. Also sets the rng_seed of the checkpoint
+    pub fn with_checkpoint(mut self, checkpoint: EvolveCheckpoint<G>) -> Self {
+        self.seed_population.clone_from(&checkpoint.population);
+        self.rng_seed = Some(checkpoint.rng_seed);
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+    /// Only used by `call_speciated` and `call_par_speciated`. Instead of fully isolated species,
+    /// the species run in epochs of the given number of generations. After each epoch the best
+    /// chromosome of each species is contributed to a shared pool, which reseeds the lagging
+    /// species (the pool chromosomes at least as good as its own best are added to its final
+    /// population for the next epoch, so no species loses its own best either). The epochs stop
+    /// when a species reaches the target_fitness_score, the max_generations is reached (summed
+    /// over the epochs) or an epoch doesn't improve the overall best fitness score. Then the
+    /// final speciated run is as without merging.
+    pub fn with_speciation_merge_interval(mut self, generations: usize) -> Self {
+        self.speciation_merge_interval = Some(generations);
+        self
+    }
+    /// Only used by `call_islands` and `call_par_islands`. The islands run in epochs of the
+    /// migration_interval generations, after which the best chromosome of each island migrates
+    /// to the other islands according to the topology (see [EvolveMigrationTopology]). Defaults
+    /// to a Ring topology with a migration_interval of 10 generations.
+    pub fn with_migration(
+        mut self,
+        topology: EvolveMigrationTopology,
+        migration_interval: usize,
+    ) -> Self {
+        self.migration = Some((topology, migration_interval));
+        self
+    }
+    /// Only search locally from the top_k best offspring each generation, instead of from all
+    /// offspring. Requires [with_local_search](Self::with_local_search), in any order
+    pub fn with_local_search_top_k(mut self, top_k: usize) -> Self {
+        self.local_search_top_k = Some(top_k);
+        self
+    }
+    /// For a stochastic fitness (e.g. simulation based), calculate each chromosome samples times
+    /// and store the mean of the fitness scores, see [FitnessResampling]. Doesn't combine with the
+    /// fitness_cache
+    pub fn with_fitness_resampling(mut self, samples: usize) -> Self {
+        self.fitness_resampling = Some(FitnessResampling::new(samples));
+        self
+    }
+    /// Store the median (or mean) of the fitness samples instead of the default mean. Requires
+    /// [with_fitness_resampling](Self::with_fitness_resampling), in any order
+    pub fn with_fitness_resampling_aggregation(
+        mut self,
+        aggregation: FitnessResamplingAggregation,
+    ) -> Self {
+        self.fitness_resampling_aggregation = Some(aggregation);
+        self
+    }
+    /// Recalculate the best chromosome every interval generations, so a lucky fitness score
+    /// doesn't lock in a false optimum. Requires
+    /// [with_fitness_resampling](Self::with_fitness_resampling), in any order
+    pub fn with_fitness_resampling_best_interval(mut self, interval: usize) -> Self {
+        self.fitness_resampling_best_interval = Some(interval);
+        self
+    }
     pub fn with_mutate(mut self, mutate: M) -> Self {
         self.mutate = Some(mutate);
         self
@@ -196,6 +773,7 @@ impl<
         self.crossover = Some(crossover);
         self
     }
+    /// Ignored for the Generational and SteadyState variants (see [EvolveVariant])
     pub fn with_select(mut self, select: C) -> Self {
         self.select = Some(select);
         self
@@ -206,16 +784,41 @@ impl<
     ) -> Builder<G, M, F, S, C, E2, SR> {
         Builder {
             genotype: self.genotype,
+            variant: self.variant,
+            generation_gap: self.generation_gap,
             target_population_size: self.target_population_size,
             max_stale_generations: self.max_stale_generations,
             max_generations: self.max_generations,
             max_chromosome_age: self.max_chromosome_age,
+            elitism_rate: self.elitism_rate,
+            offspring_rate: self.offspring_rate,
+            age_layers: self.age_layers,
             target_fitness_score: self.target_fitness_score,
             valid_fitness_score: self.valid_fitness_score,
+            stop_after: self.stop_after,
+            max_duration: self.max_duration,
+            deadline: self.deadline,
+            termination_predicate: self.termination_predicate,
+            clock: self.clock,
             fitness_ordering: self.fitness_ordering,
             fitness_cache: self.fitness_cache,
+            fitness_resampling: self.fitness_resampling,
+            fitness_resampling_aggregation: self.fitness_resampling_aggregation,
+            fitness_resampling_best_interval: self.fitness_resampling_best_interval,
             par_fitness: self.par_fitness,
+            fitness_chunk_size: self.fitness_chunk_size,
             replace_on_equal_fitness: self.replace_on_equal_fitness,
+            evaluate_initial_population: self.evaluate_initial_population,
+            unique_population: self.unique_population,
+            seed_population: self.seed_population,
+            seed_chromosomes: self.seed_chromosomes,
+            seed_genes: self.seed_genes,
+            checkpoint: self.checkpoint,
+            speciation_merge_interval: self.speciation_merge_interval,
+            migration: self.migration,
+            local_search: self.local_search,
+            local_search_top_k: self.local_search_top_k,
+            population_snapshot: self.population_snapshot,
             mutate: self.mutate,
             fitness: self.fitness,
             crossover: self.crossover,
@@ -231,16 +834,41 @@ impl<
     ) -> Builder<G, M, F, S, C, E, SR2> {
         Builder {
             genotype: self.genotype,
+            variant: self.variant,
+            generation_gap: self.generation_gap,
             target_population_size: self.target_population_size,
             max_stale_generations: self.max_stale_generations,
             max_generations: self.max_generations,
             max_chromosome_age: self.max_chromosome_age,
+            elitism_rate: self.elitism_rate,
+            offspring_rate: self.offspring_rate,
+            age_layers: self.age_layers,
             target_fitness_score: self.target_fitness_score,
             valid_fitness_score: self.valid_fitness_score,
+            stop_after: self.stop_after,
+            max_duration: self.max_duration,
+            deadline: self.deadline,
+            termination_predicate: self.termination_predicate,
+            clock: self.clock,
             fitness_ordering: self.fitness_ordering,
             fitness_cache: self.fitness_cache,
+            fitness_resampling: self.fitness_resampling,
+            fitness_resampling_aggregation: self.fitness_resampling_aggregation,
+            fitness_resampling_best_interval: self.fitness_resampling_best_interval,
             par_fitness: self.par_fitness,
+            fitness_chunk_size: self.fitness_chunk_size,
             replace_on_equal_fitness: self.replace_on_equal_fitness,
+            evaluate_initial_population: self.evaluate_initial_population,
+            unique_population: self.unique_population,
+            seed_population: self.seed_population,
+            seed_chromosomes: self.seed_chromosomes,
+            seed_genes: self.seed_genes,
+            checkpoint: self.checkpoint,
+            speciation_merge_interval: self.speciation_merge_interval,
+            migration: self.migration,
+            local_search: self.local_search,
+            local_search_top_k: self.local_search_top_k,
+            population_snapshot: self.population_snapshot,
             mutate: self.mutate,
             fitness: self.fitness,
             crossover: self.crossover,
@@ -250,6 +878,23 @@ impl<
             rng_seed: self.rng_seed,
         }
     }
+    /// Add the best genes of an earlier strategy run to the initial population, e.g. to refine a
+    /// coarse HillClimb or Permutate with Evolve. The genotypes must be compatible (same allele
+    /// and genes_size, validated at build). Can be called multiple times, each seed takes a
+    /// single place in the initial population and the rest of the population is constructed by
+    /// the genotype as usual, which keeps the diversity. Ignored when starting from a
+    /// [with_seed_population_from](Self::with_seed_population_from) or
+    /// [with_seed_population](Self::with_seed_population). Only the best genes are seeded, as
+    /// the strategies don't keep their final population.
+    pub fn with_seed_genes_from<G2: Genotype<Allele = G::Allele>, S2: Strategy<G2>>(
+        mut self,
+        strategy: &S2,
+    ) -> Self {
+        if let Some(best_genes) = strategy.best_genes() {
+            self.seed_genes.push(best_genes);
+        }
+        self
+    }
     pub fn with_rng_seed_from_u64(mut self, rng_seed: u64) -> Self {
         self.rng_seed = Some(rng_seed);
         self
@@ -260,6 +905,55 @@ impl<
     }
 }
 
+#[allow(clippy::type_complexity)]
+impl<
+        G: EvolveGenotype + HillClimbGenotype,
+        M: Mutate<Genotype = G>,
+        F: Fitness<Genotype = G>,
+        S: Crossover<Genotype = G>,
+        C: Select<Genotype = G>,
+        E: Extension<Genotype = G>,
+        SR: StrategyReporter<Genotype = G>,
+    > Builder<G, M, F, S, C, E, SR>
+{
+    /// Turn Evolve into a memetic algorithm: after the fitness calculation of the offspring, each
+    /// offspring climbs the hill for the given number of steps with the given
+    /// [HillClimbVariant], keeping the improved genes. See [EvolveLocalSearch]. Limit to the
+    /// best offspring with [with_local_search_top_k](Self::with_local_search_top_k). Requires a
+    /// genotype which also implements [HillClimbGenotype] (all provided genotypes do)
+    pub fn with_local_search(mut self, variant: HillClimbVariant, steps: usize) -> Self {
+        self.local_search = Some(EvolveLocalSearch::new(variant, steps));
+        self
+    }
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        G: EvolveGenotype,
+        M: Mutate<Genotype = G>,
+        F: Fitness<Genotype = G>,
+        S: Crossover<Genotype = G>,
+        C: Select<Genotype = G>,
+        E: Extension<Genotype = G>,
+        SR: StrategyReporter<Genotype = G>,
+    > Builder<G, M, F, S, C, E, SR>
+where
+    G::Allele: bytemuck::NoUninit,
+{
+    /// Dump the full population (genes and fitness score) every interval generations to the
+    /// writer (e.g. a `File`), in a compact binary format for an external dashboard. See
+    /// [EvolvePopulationSnapshot] for the format. Requires an Allele implementing
+    /// [bytemuck::NoUninit], which all primitive alleles do
+    pub fn with_population_snapshot<W: Write + Send + 'static>(
+        mut self,
+        interval: usize,
+        writer: W,
+    ) -> Self {
+        self.population_snapshot = Some(EvolvePopulationSnapshot::new(interval, writer));
+        self
+    }
+}
+
 #[allow(clippy::type_complexity)]
 impl<
         G: EvolveGenotype,
@@ -279,11 +973,44 @@ impl<
             SmallRng::from_rng(rand::thread_rng()).unwrap()
         }
     }
+    /// The rng seed of the run with the given index, for reproducing a single run externally
+    /// with `with_rng_seed_from_u64`. Each run of a seeded builder gets its own seed, derived
+    /// from the builder's rng_seed (SplitMix64 of `rng_seed ^ index`). The index is:
+    /// * the iteration for `call_repeatedly` and `call_par_repeatedly`
+    /// * the species for `call_speciated` and `call_par_speciated`
+    /// * `epoch * number_of_islands + island` for `call_islands` and `call_par_islands`, and
+    ///   likewise for the speciated calls with merging
+    ///
+    /// The final run of the speciated calls uses the builder's rng_seed unchanged.
+    /// Only meaningful for a seeded builder (taking zero as rng_seed otherwise), as each run of an
+    /// unseeded builder seeds from entropy.
+    pub fn derived_seed(&self, index: usize) -> u64 {
+        derive_seed(self.rng_seed.unwrap_or_default(), index)
+    }
+    fn with_derived_seed(mut self, index: usize) -> Self {
+        if let Some(rng_seed) = self.rng_seed {
+            self.rng_seed = Some(derive_seed(rng_seed, index));
+        }
+        self
+    }
     pub fn call(self) -> Result<Evolve<G, M, F, S, C, E, SR>, TryFromBuilderError> {
         let mut evolve: Evolve<G, M, F, S, C, E, SR> = self.try_into()?;
         evolve.call();
         Ok(evolve)
     }
+    /// Call a clone of the builder with the fitness_ordering overridden for this run only, so the
+    /// same configured builder can be reused for both orderings. All ordering dependent logic of
+    /// the strategy (e.g. best chromosome tracking and target_fitness_score) reads the overridden
+    /// ordering. The fitness itself is not overridden, so a fitness with its own ordering (e.g.
+    /// [FitnessDuplicatePenalty](crate::fitness::FitnessDuplicatePenalty), the non-finite policy
+    /// of [SumGenes](crate::fitness::placeholders::SumGenes) or the always minimizing
+    /// [TourDistance](crate::fitness::placeholders::TourDistance)) must match the passed ordering.
+    pub fn call_with_ordering(
+        &self,
+        fitness_ordering: FitnessOrdering,
+    ) -> Result<Evolve<G, M, F, S, C, E, SR>, TryFromBuilderError> {
+        self.clone().with_fitness_ordering(fitness_ordering).call()
+    }
     pub fn call_repeatedly(
         self,
         max_repeats: usize,
@@ -298,7 +1025,7 @@ impl<
         (0..max_repeats)
             .filter_map(|iteration| {
                 let mut contending_run: Evolve<G, M, F, S, C, E, SR> =
-                    self.clone().try_into().ok()?;
+                    self.clone().with_derived_seed(iteration).try_into().ok()?;
                 contending_run.state.current_iteration = iteration;
                 Some(contending_run)
             })
@@ -333,8 +1060,11 @@ impl<
             s.spawn(move |_| {
                 (0..max_repeats)
                     .filter_map(|iteration| {
-                        let mut contending_run: Evolve<G, M, F, S, C, E, SR> =
-                            builder.clone().try_into().ok()?;
+                        let mut contending_run: Evolve<G, M, F, S, C, E, SR> = builder
+                            .clone()
+                            .with_derived_seed(iteration)
+                            .try_into()
+                            .ok()?;
                         contending_run.state.current_iteration = iteration;
                         Some(contending_run)
                     })
@@ -366,10 +1096,18 @@ impl<
         TryFromBuilderError,
     > {
         let _valid_builder: Evolve<G, M, F, S, C, E, SR> = self.clone().try_into()?;
+        if let Some(speciation_merge_interval) = self.speciation_merge_interval {
+            return self.with_epochs_deadline().call_speciated_with_merging(
+                number_of_species,
+                speciation_merge_interval,
+                false,
+            );
+        }
         let mut species_runs: Vec<Evolve<G, M, F, S, C, E, SR>> = vec![];
         (0..number_of_species)
             .filter_map(|iteration| {
-                let mut species_run: Evolve<G, M, F, S, C, E, SR> = self.clone().try_into().ok()?;
+                let mut species_run: Evolve<G, M, F, S, C, E, SR> =
+                    self.clone().with_derived_seed(iteration).try_into().ok()?;
                 species_run.state.current_iteration = iteration;
                 Some(species_run)
             })
@@ -381,25 +1119,7 @@ impl<
             })
             .any(|x| x);
 
-        let final_run = if let Some(index_finished_by_target_fitness_score) = species_runs
-            .iter()
-            .position(|species_run| species_run.is_finished_by_target_fitness_score())
-        {
-            species_runs.remove(index_finished_by_target_fitness_score)
-        } else {
-            let seed_genes_list = species_runs
-                .iter()
-                .filter_map(|species_run| species_run.best_genes())
-                .collect();
-            let mut final_genotype = self.genotype.clone().unwrap();
-            final_genotype.reset(); // not needed, clone is unused
-            final_genotype.set_seed_genes_list(seed_genes_list);
-            let mut final_run: Evolve<G, M, F, S, C, E, SR> =
-                self.clone().with_genotype(final_genotype).try_into()?;
-
-            final_run.call();
-            final_run
-        };
+        let final_run = self.call_final_species_run(&mut species_runs)?;
         Ok((final_run, species_runs))
     }
 
@@ -414,6 +1134,13 @@ impl<
         TryFromBuilderError,
     > {
         let _valid_builder: Evolve<G, M, F, S, C, E, SR> = self.clone().try_into()?;
+        if let Some(speciation_merge_interval) = self.speciation_merge_interval {
+            return self.with_epochs_deadline().call_speciated_with_merging(
+                number_of_species,
+                speciation_merge_interval,
+                true,
+            );
+        }
         let mut species_runs: Vec<Evolve<G, M, F, S, C, E, SR>> = vec![];
         rayon::scope(|s| {
             let builder = &self;
@@ -422,8 +1149,11 @@ impl<
             s.spawn(move |_| {
                 (0..number_of_species)
                     .filter_map(|iteration| {
-                        let mut species_run: Evolve<G, M, F, S, C, E, SR> =
-                            builder.clone().try_into().ok()?;
+                        let mut species_run: Evolve<G, M, F, S, C, E, SR> = builder
+                            .clone()
+                            .with_derived_seed(iteration)
+                            .try_into()
+                            .ok()?;
                         species_run.state.current_iteration = iteration;
                         Some(species_run)
                     })
@@ -442,11 +1172,286 @@ impl<
             });
         });
 
-        let final_run = if let Some(index_finished_by_target_fitness_score) = species_runs
+        let final_run = self.call_final_species_run(&mut species_runs)?;
+        Ok((final_run, species_runs))
+    }
+
+    fn call_speciated_with_merging(
+        self,
+        number_of_species: usize,
+        speciation_merge_interval: usize,
+        par: bool,
+    ) -> Result<
+        (
+            Evolve<G, M, F, S, C, E, SR>,
+            Vec<Evolve<G, M, F, S, C, E, SR>>,
+        ),
+        TryFromBuilderError,
+    > {
+        let fitness_ordering = self.fitness_ordering;
+        let epoch_generations = speciation_merge_interval.max(1);
+        let mut seed_populations: Vec<Vec<Genes<G::Allele>>> = vec![vec![]; number_of_species];
+        let mut species_runs: Vec<Evolve<G, M, F, S, C, E, SR>>;
+        let mut best_fitness_score: Option<FitnessValue> = None;
+        let mut generations_run: usize = 0;
+        let mut epochs_run: usize = 0;
+        loop {
+            let max_epoch_generations = self
+                .max_generations
+                .map_or(epoch_generations, |max_generations| {
+                    (max_generations - generations_run).min(epoch_generations)
+                });
+            species_runs = seed_populations
+                .drain(..)
+                .enumerate()
+                .map(|(iteration, seed_population)| {
+                    let mut epoch_builder = self
+                        .clone()
+                        .with_max_generations(max_epoch_generations)
+                        .with_derived_seed(epochs_run * number_of_species + iteration);
+                    epoch_builder.seed_population = seed_population;
+                    epoch_builder.seed_chromosomes = Vec::new();
+                    let mut species_run: Evolve<G, M, F, S, C, E, SR> = epoch_builder.try_into()?;
+                    species_run.state.current_iteration = iteration;
+                    Ok(species_run)
+                })
+                .collect::<Result<_, TryFromBuilderError>>()?;
+            if par {
+                species_runs
+                    .par_iter_mut()
+                    .for_each(|species_run| species_run.call());
+            } else {
+                species_runs
+                    .iter_mut()
+                    .for_each(|species_run| species_run.call());
+            }
+            generations_run += max_epoch_generations;
+            epochs_run += 1;
+
+            let epoch_best_fitness_score =
+                species_runs
+                    .iter()
+                    .fold(None, |best_fitness_score, species_run| {
+                        fitness_ordering
+                            .better_of(best_fitness_score, species_run.best_fitness_score())
+                    });
+            if species_runs
+                .iter()
+                .any(|species_run| species_run.is_finished_by_target_fitness_score())
+                || self
+                    .max_generations
+                    .is_some_and(|max_generations| generations_run >= max_generations)
+                || self.is_epochs_deadline_reached()
+                || !fitness_ordering.is_better(epoch_best_fitness_score, best_fitness_score)
+            {
+                break;
+            }
+            best_fitness_score = epoch_best_fitness_score;
+
+            let pool: Vec<(Option<FitnessValue>, Genes<G::Allele>)> = species_runs
+                .iter()
+                .filter_map(|species_run| {
+                    species_run
+                        .best_genes()
+                        .map(|genes| (species_run.best_fitness_score(), genes))
+                })
+                .collect();
+            seed_populations = species_runs
+                .iter()
+                .map(|species_run| {
+                    let mut seed_population = species_run.final_population();
+                    pool.iter()
+                        .filter(|(fitness_score, _)| {
+                            !fitness_ordering
+                                .is_better(species_run.best_fitness_score(), *fitness_score)
+                        })
+                        .for_each(|(_, genes)| seed_population.push(genes.clone()));
+                    seed_population
+                })
+                .collect();
+        }
+
+        let final_run = self.call_final_species_run(&mut species_runs)?;
+        Ok((final_run, species_runs))
+    }
+
+    /// Run the number_of_islands as separate subpopulations, which periodically exchange their
+    /// best chromosomes (see [with_migration](Self::with_migration)). Each island continues
+    /// from its own final population of the previous epoch, extended with its own best
+    /// chromosome and the immigrants, so no island loses its best. The epochs stop when an
+    /// island reaches the target_fitness_score, the max_generations is reached (summed over the
+    /// epochs) or the overall best fitness score is stale for max_stale_generations (also summed
+    /// over the epochs). Returns the best island run and the other island runs.
+    ///
+    /// In contrast to `call_speciated` there is no final run seeded with the best of each
+    /// species, the islands share the best chromosomes continuously instead.
+    pub fn call_islands(
+        self,
+        number_of_islands: usize,
+    ) -> Result<
+        (
+            Evolve<G, M, F, S, C, E, SR>,
+            Vec<Evolve<G, M, F, S, C, E, SR>>,
+        ),
+        TryFromBuilderError,
+    > {
+        self.with_epochs_deadline()
+            .call_islands_with_migration(number_of_islands, false)
+    }
+
+    /// As [call_islands](Self::call_islands), but the islands of each epoch run in parallel
+    /// threads
+    pub fn call_par_islands(
+        self,
+        number_of_islands: usize,
+    ) -> Result<
+        (
+            Evolve<G, M, F, S, C, E, SR>,
+            Vec<Evolve<G, M, F, S, C, E, SR>>,
+        ),
+        TryFromBuilderError,
+    > {
+        self.with_epochs_deadline()
+            .call_islands_with_migration(number_of_islands, true)
+    }
+
+    fn call_islands_with_migration(
+        self,
+        number_of_islands: usize,
+        par: bool,
+    ) -> Result<
+        (
+            Evolve<G, M, F, S, C, E, SR>,
+            Vec<Evolve<G, M, F, S, C, E, SR>>,
+        ),
+        TryFromBuilderError,
+    > {
+        let _valid_builder: Evolve<G, M, F, S, C, E, SR> = self.clone().try_into()?;
+        let fitness_ordering = self.fitness_ordering;
+        let (topology, migration_interval) = self
+            .migration
+            .unwrap_or((EvolveMigrationTopology::Ring, 10));
+        let epoch_generations = migration_interval.max(1);
+        let mut seed_populations: Vec<Vec<Genes<G::Allele>>> = vec![vec![]; number_of_islands];
+        let mut island_runs: Vec<Evolve<G, M, F, S, C, E, SR>>;
+        let mut best_fitness_score: Option<FitnessValue> = None;
+        let mut generations_run: usize = 0;
+        let mut stale_generations_run: usize = 0;
+        let mut epochs_run: usize = 0;
+        loop {
+            let max_epoch_generations = self
+                .max_generations
+                .map_or(epoch_generations, |max_generations| {
+                    (max_generations - generations_run).min(epoch_generations)
+                });
+            island_runs = seed_populations
+                .drain(..)
+                .enumerate()
+                .map(|(iteration, seed_population)| {
+                    let mut epoch_builder = self
+                        .clone()
+                        .with_max_generations(max_epoch_generations)
+                        .with_derived_seed(epochs_run * number_of_islands + iteration);
+                    epoch_builder.seed_population = seed_population;
+                    epoch_builder.seed_chromosomes = Vec::new();
+                    let mut island_run: Evolve<G, M, F, S, C, E, SR> = epoch_builder.try_into()?;
+                    island_run.state.current_iteration = iteration;
+                    Ok(island_run)
+                })
+                .collect::<Result<_, TryFromBuilderError>>()?;
+            if par {
+                island_runs
+                    .par_iter_mut()
+                    .for_each(|island_run| island_run.call());
+            } else {
+                island_runs
+                    .iter_mut()
+                    .for_each(|island_run| island_run.call());
+            }
+            generations_run += max_epoch_generations;
+            epochs_run += 1;
+
+            let epoch_best_fitness_score =
+                island_runs
+                    .iter()
+                    .fold(None, |best_fitness_score, island_run| {
+                        fitness_ordering
+                            .better_of(best_fitness_score, island_run.best_fitness_score())
+                    });
+            if fitness_ordering.is_better(epoch_best_fitness_score, best_fitness_score) {
+                best_fitness_score = epoch_best_fitness_score;
+                stale_generations_run = 0;
+            } else {
+                stale_generations_run += max_epoch_generations;
+            }
+            if island_runs
+                .iter()
+                .any(|island_run| island_run.is_finished_by_target_fitness_score())
+                || self
+                    .max_generations
+                    .is_some_and(|max_generations| generations_run >= max_generations)
+                || self
+                    .max_stale_generations
+                    .is_some_and(|max_stale_generations| {
+                        stale_generations_run >= max_stale_generations
+                    })
+                || self.is_epochs_deadline_reached()
+            {
+                break;
+            }
+
+            let best_genes_per_island: Vec<Option<Genes<G::Allele>>> = island_runs
+                .iter()
+                .map(|island_run| island_run.best_genes())
+                .collect();
+            seed_populations = island_runs
+                .iter()
+                .enumerate()
+                .map(|(island_index, island_run)| {
+                    let mut seed_population = island_run.final_population();
+                    std::iter::once(island_index)
+                        .chain(topology.source_indices(island_index, number_of_islands))
+                        .filter_map(|source_index| best_genes_per_island[source_index].clone())
+                        .for_each(|genes| seed_population.push(genes));
+                    seed_population
+                })
+                .collect();
+        }
+
+        let best_run = self.extract_best_run(&mut island_runs);
+        Ok((best_run, island_runs))
+    }
+
+    /// Replace the max_duration by an absolute deadline from now, so the epochs of the islands
+    /// and the speciated calls with merging share a single time budget, instead of each epoch
+    /// starting a fresh max_duration
+    fn with_epochs_deadline(mut self) -> Self {
+        let now = self.clock.now();
+        let max_duration_deadline = self
+            .max_duration
+            .take()
+            .and_then(|max_duration| now.checked_add(max_duration));
+        self.deadline = match (max_duration_deadline, self.deadline) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self
+    }
+
+    fn is_epochs_deadline_reached(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| self.clock.now() >= deadline)
+    }
+
+    fn call_final_species_run(
+        &self,
+        species_runs: &mut Vec<Evolve<G, M, F, S, C, E, SR>>,
+    ) -> Result<Evolve<G, M, F, S, C, E, SR>, TryFromBuilderError> {
+        if let Some(index_finished_by_target_fitness_score) = species_runs
             .iter()
             .position(|species_run| species_run.is_finished_by_target_fitness_score())
         {
-            species_runs.remove(index_finished_by_target_fitness_score)
+            Ok(species_runs.remove(index_finished_by_target_fitness_score))
         } else {
             let seed_genes_list = species_runs
                 .iter()
@@ -459,9 +1464,8 @@ impl<
                 self.clone().with_genotype(final_genotype).try_into()?;
 
             final_run.call();
-            final_run
-        };
-        Ok((final_run, species_runs))
+            Ok(final_run)
+        }
     }
 
     pub fn extract_best_run(
@@ -497,6 +1501,9 @@ impl<
                 }
             }
         });
-        runs.remove(best_index)
+        let mut best_run = runs.remove(best_index);
+        // all runs are completed, including the best one
+        best_run.state.iterations_run = runs.len() + 1;
+        best_run
     }
 }
@@ -0,0 +1,158 @@
+use super::{EvolveConfig, EvolveState};
+use crate::chromosome::Chromosome;
+use crate::fitness::{is_cached, Fitness, FitnessContext};
+use crate::genotype::{EvolveGenotype, Genotype, HillClimbGenotype};
+use crate::population::Population;
+use crate::strategy::hill_climb::HillClimbVariant;
+use crate::strategy::{StrategyAction, StrategyState};
+use rand::rngs::SmallRng;
+use std::cmp::Ordering;
+use std::fmt;
+use std::time::Instant;
+
+/// The neighbouring population of the [HillClimbGenotype], captured when the local search is
+/// set, so the Evolve strategy itself doesn't require a HillClimbGenotype
+type FillNeighbouringPopulation<G> = fn(
+    &G,
+    &Chromosome<<G as Genotype>::Allele>,
+    &mut Population<<G as Genotype>::Allele>,
+    &mut SmallRng,
+);
+
+/// Local search on the offspring after the fitness calculation, which turns Evolve into a
+/// memetic algorithm. Each offspring (or only the top_k best offspring) climbs the hill for the
+/// given number of steps, using the neighbouring machinery of the genotype like
+/// [HillClimb](crate::strategy::hill_climb::HillClimb):
+/// * [HillClimbVariant::Stochastic]: mutate a single random gene per step, keep it if better
+/// * [HillClimbVariant::SteepestAscent]: move to the best of all neighbours per step if better,
+///   stop early in a local optimum
+///
+/// The improved genes replace the offspring in place (Lamarckian). The fitness is calculated
+/// sequentially (with the fitness_cache or fitness_resampling if set) and the duration is
+/// accounted as Fitness. Set with
+/// [EvolveBuilder::with_local_search](crate::strategy::evolve::EvolveBuilder::with_local_search).
+#[derive(Clone)]
+pub struct LocalSearch<G: EvolveGenotype> {
+    pub variant: HillClimbVariant,
+    pub steps: usize,
+    pub top_k: Option<usize>,
+    fill_neighbouring_population: FillNeighbouringPopulation<G>,
+}
+
+impl<G: EvolveGenotype + HillClimbGenotype> LocalSearch<G> {
+    pub fn new(variant: HillClimbVariant, steps: usize) -> Self {
+        Self {
+            variant,
+            steps,
+            top_k: None,
+            fill_neighbouring_population: G::fill_neighbouring_population::<SmallRng>,
+        }
+    }
+}
+
+impl<G: EvolveGenotype> LocalSearch<G> {
+    pub fn call<F: Fitness<Genotype = G>>(
+        &self,
+        genotype: &G,
+        fitness: &mut F,
+        state: &mut EvolveState<G>,
+        config: &EvolveConfig,
+        rng: &mut SmallRng,
+    ) {
+        let now = Instant::now();
+        let mut offspring_indices: Vec<usize> = state
+            .population
+            .chromosomes
+            .iter()
+            .enumerate()
+            .filter(|(_, chromosome)| chromosome.is_offspring())
+            .map(|(index, _)| index)
+            .collect();
+        if let Some(top_k) = self.top_k {
+            let chromosomes = &state.population.chromosomes;
+            offspring_indices.sort_by(|a, b| {
+                let (a, b) = (
+                    chromosomes[*a].fitness_score(),
+                    chromosomes[*b].fitness_score(),
+                );
+                if config.fitness_ordering.is_better(a, b) {
+                    Ordering::Less
+                } else if config.fitness_ordering.is_better(b, a) {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            });
+            offspring_indices.truncate(top_k);
+        }
+
+        let mut neighbours: Population<G::Allele> =
+            Population::new_empty(genotype.chromosome_recycling());
+        for index in offspring_indices {
+            for _ in 0..self.steps {
+                let chromosome = &state.population.chromosomes[index];
+                match self.variant {
+                    HillClimbVariant::Stochastic => {
+                        let mut neighbour = neighbours.new_chromosome(chromosome);
+                        genotype.mutate_chromosome_genes(1, true, &mut neighbour, rng);
+                        neighbours.chromosomes.push(neighbour);
+                    }
+                    HillClimbVariant::SteepestAscent => {
+                        (self.fill_neighbouring_population)(
+                            genotype,
+                            chromosome,
+                            &mut neighbours,
+                            rng,
+                        );
+                    }
+                }
+                if let Some(fitness_resampling) = config.fitness_resampling.as_ref() {
+                    let context = FitnessContext::default();
+                    neighbours.chromosomes.iter_mut().for_each(|neighbour| {
+                        fitness_resampling
+                            .call_for_chromosome(fitness, neighbour, genotype, &context)
+                    });
+                    state.add_fitness_evaluations(neighbours.size() * fitness_resampling.samples);
+                } else {
+                    let cache = config.fitness_cache.as_ref();
+                    let mut number_of_evaluations = 0;
+                    neighbours.chromosomes.iter_mut().for_each(|neighbour| {
+                        if !is_cached(cache, neighbour) {
+                            number_of_evaluations += 1;
+                        }
+                        fitness.call_for_chromosome(neighbour, genotype, cache)
+                    });
+                    state.add_fitness_evaluations(number_of_evaluations);
+                }
+
+                let improved = match neighbours.best_chromosome(config.fitness_ordering) {
+                    Some(best_neighbour)
+                        if config.fitness_ordering.is_better(
+                            best_neighbour.fitness_score(),
+                            state.population.chromosomes[index].fitness_score(),
+                        ) =>
+                    {
+                        state.population.chromosomes[index].copy_from(best_neighbour);
+                        true
+                    }
+                    _ => false,
+                };
+                neighbours.truncate(0);
+                if !improved && matches!(self.variant, HillClimbVariant::SteepestAscent) {
+                    break;
+                }
+            }
+        }
+        state.add_duration(StrategyAction::Fitness, now.elapsed());
+    }
+}
+
+impl<G: EvolveGenotype> fmt::Debug for LocalSearch<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalSearch")
+            .field("variant", &self.variant)
+            .field("steps", &self.steps)
+            .field("top_k", &self.top_k)
+            .finish()
+    }
+}
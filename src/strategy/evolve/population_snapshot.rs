@@ -0,0 +1,98 @@
+use super::EvolveState;
+use crate::genotype::{EvolveGenotype, Genotype};
+use crate::strategy::{StrategyAction, StrategyState};
+use std::fmt;
+use std::io::{self, BufWriter, Write};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+type SharedWriter = Arc<Mutex<dyn Write + Send>>;
+type WriteGenes<G> = fn(&[<G as Genotype>::Allele], &mut dyn Write) -> io::Result<()>;
+
+/// Dumps the full population (genes and fitness score) every interval generations to a
+/// [Write](std::io::Write) (e.g. a [File](std::fs::File)), for an external dashboard. Set with
+/// [EvolveBuilder::with_population_snapshot](crate::strategy::evolve::EvolveBuilder::with_population_snapshot).
+///
+/// Each snapshot is a self-contained frame in a compact binary format, streamed chromosome by
+/// chromosome (through a [BufWriter]) and flushed at the end of the frame:
+/// * header: the [MAGIC](Self::MAGIC) bytes `GAPS`, followed by the iteration, generation,
+///   population_size, genes_size and allele_size (bytes per gene), each as little-endian u64
+/// * population_size records of `9 + genes_size * allele_size` bytes: a fitness flag (u8, 0 for
+///   no fitness score), the fitness score (little-endian i64, 0 without fitness score) and the
+///   raw genes (in native byte order, see [bytemuck])
+///
+/// The writer is shared between the clones, so `call_repeatedly()` and `call_speciated()` write
+/// all runs to the same sink, distinguished by the iteration. Write errors are ignored, so a
+/// failing dashboard doesn't stop the run.
+#[derive(Clone)]
+pub struct PopulationSnapshot<G: EvolveGenotype> {
+    pub interval: usize,
+    writer: SharedWriter,
+    write_genes: WriteGenes<G>,
+}
+
+impl<G: EvolveGenotype> PopulationSnapshot<G>
+where
+    G::Allele: bytemuck::NoUninit,
+{
+    pub fn new<W: Write + Send + 'static>(interval: usize, writer: W) -> Self {
+        Self {
+            interval,
+            writer: Arc::new(Mutex::new(BufWriter::new(writer))),
+            write_genes: write_genes::<G::Allele>,
+        }
+    }
+}
+
+impl<G: EvolveGenotype> PopulationSnapshot<G> {
+    pub const MAGIC: [u8; 4] = *b"GAPS";
+
+    pub fn call(&self, genotype: &G, state: &mut EvolveState<G>) {
+        if state.current_generation % self.interval != 0 {
+            return;
+        }
+        let now = Instant::now();
+        if let Ok(mut writer) = self.writer.lock() {
+            self.write_frame(genotype, state, &mut *writer)
+                .unwrap_or(());
+        }
+        state.add_duration(StrategyAction::Other, now.elapsed());
+    }
+
+    fn write_frame(
+        &self,
+        genotype: &G,
+        state: &EvolveState<G>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        writer.write_all(&Self::MAGIC)?;
+        for value in [
+            state.current_iteration,
+            state.current_generation,
+            state.population.size(),
+            genotype.genes_size(),
+            std::mem::size_of::<G::Allele>(),
+        ] {
+            writer.write_all(&(value as u64).to_le_bytes())?;
+        }
+        for chromosome in &state.population.chromosomes {
+            let fitness_score = chromosome.fitness_score();
+            writer.write_all(&[fitness_score.is_some() as u8])?;
+            writer.write_all(&(fitness_score.unwrap_or(0) as i64).to_le_bytes())?;
+            (self.write_genes)(&chromosome.genes, writer)?;
+        }
+        writer.flush()
+    }
+}
+
+fn write_genes<T: bytemuck::NoUninit>(genes: &[T], writer: &mut dyn Write) -> io::Result<()> {
+    writer.write_all(bytemuck::cast_slice(genes))
+}
+
+impl<G: EvolveGenotype> fmt::Debug for PopulationSnapshot<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PopulationSnapshot")
+            .field("interval", &self.interval)
+            .finish()
+    }
+}
@@ -2,45 +2,76 @@
 pub use crate::chromosome::{Chromosome, GenesHash};
 #[doc(no_inline)]
 pub use crate::crossover::{
-    Crossover, CrossoverClone, CrossoverEvent, CrossoverMultiGene, CrossoverMultiPoint,
-    CrossoverRejuvenate, CrossoverSingleGene, CrossoverSinglePoint, CrossoverUniform,
-    CrossoverWrapper,
+    Crossover, CrossoverClone, CrossoverCycle, CrossoverEvent, CrossoverGroupPoint,
+    CrossoverMasked, CrossoverMultiGene, CrossoverMultiPoint, CrossoverMultiUniqueOrder,
+    CrossoverNoop, CrossoverOrdered, CrossoverPartiallyMapped, CrossoverRejuvenate,
+    CrossoverSingleGene, CrossoverSinglePoint, CrossoverUniform, CrossoverWrapper,
 };
 #[doc(no_inline)]
+pub use crate::errors::{BuilderError, BuilderValidation};
+#[doc(no_inline)]
 pub use crate::extension::{
-    Extension, ExtensionEvent, ExtensionMassDeduplication, ExtensionMassDegeneration,
-    ExtensionMassExtinction, ExtensionMassGenesis, ExtensionNoop, ExtensionWrapper,
+    Extension, ExtensionChain, ExtensionEvent, ExtensionMassDeduplication,
+    ExtensionMassDegeneration, ExtensionMassExtinction, ExtensionMassGenesis, ExtensionNoop,
+    ExtensionScaleReset, ExtensionWrapper,
 };
 #[doc(no_inline)]
 pub use crate::fitness::{
-    Fitness, FitnessChromosome, FitnessGenes, FitnessGenotype, FitnessOrdering, FitnessPopulation,
-    FitnessValue,
+    Fitness, FitnessAllele, FitnessChromosome, FitnessDuplicatePenalty, FitnessGenes,
+    FitnessGenotype, FitnessMultiObjective, FitnessNonFinitePolicy, FitnessOrdering,
+    FitnessPopulation, FitnessResampling, FitnessResamplingAggregation, FitnessValue,
+    FitnessWeightedObjectives, MultiObjectiveFitness,
 };
 #[doc(no_inline)]
 pub use crate::genotype::{
-    Allele, BinaryGenotype, EvolveGenotype, Genotype, GenotypeBuilder, ListGenotype,
-    MultiListGenotype, MultiRangeGenotype, MultiUniqueGenotype, MutationType, RangeAllele,
-    RangeGenotype, TryFromGenotypeBuilderError, UniqueGenotype,
+    Allele, BinaryGenotype, CrossoverOrderedMethod, EvolveGenotype, Genotype, GenotypeBuilder,
+    GenotypeDistance, GrayCodeGenotype, LatinSquareGenotype, ListGenotype, MaskedValueGenotype,
+    MultiListGenotype, MultiRangeGenotype, MultiUniqueGenotype, MutationType, OneHotGenotype,
+    RangeAllele, RangeGenotype, SimplexGenotype, TryFromGenotypeBuilderError, UniqueGenotype,
 };
 #[doc(no_inline)]
 pub use crate::impl_allele;
 #[doc(no_inline)]
 pub use crate::mutate::{
-    Mutate, MutateEvent, MutateMultiGene, MutateMultiGeneDynamic, MutateMultiGeneRange,
-    MutateSingleGene, MutateSingleGeneDynamic, MutateWrapper,
+    Mutate, MutateByteFlip, MutateEvent, MutateMultiGene, MutateMultiGeneDynamic,
+    MutateMultiGeneRange, MutateMultiGeneStratified, MutateSingleGene, MutateSingleGeneDynamic,
+    MutateWrapper,
 };
 #[doc(no_inline)]
 pub use crate::population::Population;
 #[doc(no_inline)]
-pub use crate::select::{Select, SelectElite, SelectEvent, SelectTournament, SelectWrapper};
+pub use crate::select::{
+    Select, SelectElite, SelectEvent, SelectNsga2, SelectPercentile, SelectProportionate,
+    SelectProportionateSampling, SelectProportionateScaling, SelectSharing, SelectTournament,
+    SelectWrapper,
+};
 #[doc(no_inline)]
 pub use crate::strategy::evolve::{
-    Evolve, EvolveBuilder, EvolveConfig, EvolveReporterDuration, EvolveReporterNoop,
-    EvolveReporterSimple, EvolveState, EvolveVariant, TryFromEvolveBuilderError,
+    Evolve, EvolveBuilder, EvolveCheckpoint, EvolveConfig, EvolveLocalSearch,
+    EvolveMigrationTopology, EvolvePopulationSnapshot, EvolveReporterCsv, EvolveReporterDuration,
+    EvolveReporterJson, EvolveReporterNoop, EvolveReporterSimple, EvolveState, EvolveVariant,
+    TryFromEvolveBuilderError,
 };
 #[doc(no_inline)]
+pub use crate::strategy::hill_climb::HillClimbVariant;
+#[doc(no_inline)]
 pub use crate::strategy::{
-    Strategy, StrategyAction, StrategyBuilder, StrategyConfig, StrategyReporter,
-    StrategyReporterDuration, StrategyReporterNoop, StrategyReporterSimple, StrategyState,
-    TryFromStrategyBuilderError, STRATEGY_ACTIONS,
+    Strategy, StrategyAction, StrategyBuilder, StrategyClock, StrategyConfig, StrategyReporter,
+    StrategyReporterCsv, StrategyReporterDuration, StrategyReporterJson, StrategyReporterNoop,
+    StrategyReporterSimple, StrategyResult, StrategyState, StrategyTerminationPredicate,
+    StrategyTerminationReason, TryFromStrategyBuilderError, STRATEGY_ACTIONS,
 };
+
+/// Re-exports every Select, Crossover, Mutate and Extension implementation (including the
+/// traits, events and wrappers) by glob, so newly added operators are covered as well. Useful
+/// next to another prelude, e.g. `use genetic_algorithm::strategy::evolve::prelude::operators::*;`
+pub mod operators {
+    #[doc(no_inline)]
+    pub use crate::crossover::*;
+    #[doc(no_inline)]
+    pub use crate::extension::*;
+    #[doc(no_inline)]
+    pub use crate::mutate::*;
+    #[doc(no_inline)]
+    pub use crate::select::*;
+}
@@ -1,8 +1,10 @@
 use crate::crossover::CrossoverEvent;
 use crate::extension::ExtensionEvent;
+use crate::fitness::FitnessValue;
 use crate::genotype::EvolveGenotype;
 use crate::mutate::MutateEvent;
 use crate::select::SelectEvent;
+use crate::strategy::reporter::{finish_line, show_genes_on_improvement, ReportedGenes};
 use crate::strategy::{StrategyConfig, StrategyReporter, StrategyState, STRATEGY_ACTIONS};
 use std::fmt::Arguments;
 use std::io::Write;
@@ -26,17 +28,17 @@ use std::marker::PhantomData;
 /// new best - generation: 27, fitness_score: Some(-561), scale_index: None, genes: None
 /// new best - generation: 37, fitness_score: Some(-559), scale_index: None, genes: None
 /// new best - generation: 40, fitness_score: Some(-553), scale_index: None, genes: None
-/// periodic - current_generation: 50, stale_generations: 9, best_generation: 40, scale_index: None, population_cardinality: Some(13), current_population_size: 1000 (517p/483o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/0/0/0
+/// periodic - current_generation: 50, stale_generations: 9, best_generation: 40, scale_index: None, population_cardinality: Some(13), selection_intensity: Some(0.71), current_population_size: 1000 (517p/483o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/0/0/0
 /// new best - generation: 53, fitness_score: Some(-549), scale_index: None, genes: None
 /// new best - generation: 91, fitness_score: Some(-548), scale_index: None, genes: None
 /// new best - generation: 92, fitness_score: Some(-141), scale_index: None, genes: None
-/// periodic - current_generation: 100, stale_generations: 7, best_generation: 92, scale_index: None, population_cardinality: Some(3), current_population_size: 1000 (517p/483o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/4/0/0
+/// periodic - current_generation: 100, stale_generations: 7, best_generation: 92, scale_index: None, population_cardinality: Some(3), selection_intensity: Some(0.08), current_population_size: 1000 (517p/483o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/4/0/0
 /// new best - generation: 142, fitness_score: Some(-130), scale_index: None, genes: None
-/// periodic - current_generation: 150, stale_generations: 7, best_generation: 142, scale_index: None, population_cardinality: Some(3), current_population_size: 1000 (517p/483o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/5/0/0
-/// periodic - current_generation: 200, stale_generations: 57, best_generation: 142, scale_index: None, population_cardinality: Some(702), current_population_size: 1000 (516p/484o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/7/0/0
-/// periodic - current_generation: 250, stale_generations: 107, best_generation: 142, scale_index: None, population_cardinality: Some(549), current_population_size: 1000 (515p/485o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/7/0/0
-/// periodic - current_generation: 300, stale_generations: 157, best_generation: 142, scale_index: None, population_cardinality: Some(347), current_population_size: 1000 (517p/483o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/7/0/0
-/// periodic - current_generation: 350, stale_generations: 207, best_generation: 142, scale_index: None, population_cardinality: Some(147), current_population_size: 1000 (516p/484o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/7/0/0
+/// periodic - current_generation: 150, stale_generations: 7, best_generation: 142, scale_index: None, population_cardinality: Some(3), selection_intensity: Some(0.08), current_population_size: 1000 (517p/483o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/5/0/0
+/// periodic - current_generation: 200, stale_generations: 57, best_generation: 142, scale_index: None, population_cardinality: Some(702), selection_intensity: Some(0.94), current_population_size: 1000 (516p/484o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/7/0/0
+/// periodic - current_generation: 250, stale_generations: 107, best_generation: 142, scale_index: None, population_cardinality: Some(549), selection_intensity: Some(0.87), current_population_size: 1000 (515p/485o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/7/0/0
+/// periodic - current_generation: 300, stale_generations: 157, best_generation: 142, scale_index: None, population_cardinality: Some(347), selection_intensity: Some(0.65), current_population_size: 1000 (517p/483o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/7/0/0
+/// periodic - current_generation: 350, stale_generations: 207, best_generation: 142, scale_index: None, population_cardinality: Some(147), selection_intensity: Some(0.42), current_population_size: 1000 (516p/484o,700r), fitness_cache_hit_miss_ratio: None, #events(S/E/C/M): 0/7/0/0
 /// exit - evolve, iteration: 8
 ///   SetupAndCleanup: 145.999µs
 ///   Extension: 4.771ms
@@ -55,10 +57,14 @@ pub struct Simple<G: EvolveGenotype> {
     pub period: usize,
     pub show_genes: bool,
     pub show_equal_fitness: bool,
+    pub show_genes_min_improvement: Option<FitnessValue>,
+    pub show_genes_truncate_size: Option<usize>,
+    last_shown_genes_fitness_score: Option<FitnessValue>,
     pub show_select_event: bool,
     pub show_extension_event: bool,
     pub show_crossover_event: bool,
     pub show_mutate_event: bool,
+    pub show_population_statistics: bool,
     number_of_select_events: usize,
     number_of_extension_events: usize,
     number_of_crossover_events: usize,
@@ -72,10 +78,14 @@ impl<G: EvolveGenotype> Default for Simple<G> {
             period: 1,
             show_genes: false,
             show_equal_fitness: false,
+            show_genes_min_improvement: None,
+            show_genes_truncate_size: None,
+            last_shown_genes_fitness_score: None,
             show_select_event: false,
             show_extension_event: false,
             show_crossover_event: false,
             show_mutate_event: false,
+            show_population_statistics: false,
             number_of_select_events: 0,
             number_of_extension_events: 0,
             number_of_crossover_events: 0,
@@ -121,6 +131,23 @@ impl<G: EvolveGenotype> Simple<G> {
             ..Default::default()
         }
     }
+    /// Only show the genes when the fitness score changed at least min_improvement since the
+    /// last shown genes (the first new best always shows the genes). Requires show_genes.
+    pub fn with_show_genes_min_improvement(mut self, min_improvement: FitnessValue) -> Self {
+        self.show_genes_min_improvement = Some(min_improvement);
+        self
+    }
+    /// Only show the first and last truncate_size genes, for large genotypes. Requires show_genes.
+    pub fn with_show_genes_truncate_size(mut self, truncate_size: usize) -> Self {
+        self.show_genes_truncate_size = Some(truncate_size);
+        self
+    }
+    /// Add a periodic line with the fitness score distribution and the mean genes entropy of the
+    /// selected population, for diagnosing premature convergence
+    pub fn with_show_population_statistics(mut self, show_population_statistics: bool) -> Self {
+        self.show_population_statistics = show_population_statistics;
+        self
+    }
     fn writeln(&mut self, args: Arguments<'_>) {
         if let Some(buffer) = self.buffer.as_mut() {
             buffer.write_fmt(args).unwrap_or(());
@@ -130,6 +157,13 @@ impl<G: EvolveGenotype> Simple<G> {
             println!()
         }
     }
+    fn show_genes_on_improvement(&mut self, fitness_score: Option<FitnessValue>) -> bool {
+        show_genes_on_improvement(
+            &mut self.last_shown_genes_fitness_score,
+            fitness_score,
+            self.show_genes_min_improvement,
+        )
+    }
 }
 impl<G: EvolveGenotype> StrategyReporter for Simple<G> {
     type Genotype = G;
@@ -145,6 +179,7 @@ impl<G: EvolveGenotype> StrategyReporter for Simple<G> {
         state: &S,
         config: &C,
     ) {
+        self.last_shown_genes_fitness_score = None;
         let number_of_seed_genes = genotype.seed_genes_list().len();
         if number_of_seed_genes > 0 {
             self.writeln(format_args!(
@@ -161,6 +196,16 @@ impl<G: EvolveGenotype> StrategyReporter for Simple<G> {
             ));
         }
     }
+    fn on_finish<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        config: &C,
+    ) {
+        if let Some(finish_line) = finish_line(state, config) {
+            self.writeln(format_args!("{}", finish_line));
+        }
+    }
     fn on_exit<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
         &mut self,
         _genotype: &Self::Genotype,
@@ -215,12 +260,13 @@ impl<G: EvolveGenotype> StrategyReporter for Simple<G> {
                 state.population_as_ref().parents_and_offspring_size();
 
             self.writeln(format_args!(
-                "periodic - current_generation: {}, stale_generations: {}, best_generation: {}, scale_index: {:?}, population_cardinality: {:?}, current_population_size: {} ({}p/{}o,{}r), fitness_cache_hit_miss_ratio: {:.2?}, #events(S/E/C/M): {}/{}/{}/{}",
+                "periodic - current_generation: {}, stale_generations: {}, best_generation: {}, scale_index: {:?}, population_cardinality: {:?}, selection_intensity: {:.2?}, current_population_size: {} ({}p/{}o,{}r), fitness_cache_hit_miss_ratio: {:.2?}, #events(S/E/C/M): {}/{}/{}/{}",
                 state.current_generation(),
                 state.stale_generations(),
                 state.best_generation(),
                 genotype.current_scale_index(),
                 state.population_cardinality(),
+                state.selection_intensity(),
                 state.population_as_ref().size(),
                 parents_size,
                 offspring_size,
@@ -232,6 +278,24 @@ impl<G: EvolveGenotype> StrategyReporter for Simple<G> {
                 number_of_mutate_events,
             ));
 
+            if self.show_population_statistics {
+                let population = state.population_as_ref();
+                let genes_entropy = population.genes_entropy();
+                let genes_entropy_mean =
+                    genes_entropy.iter().sum::<f64>() / genes_entropy.len().max(1) as f64;
+                self.writeln(format_args!(
+                    "statistics - fitness_score min/p25/median/p75/max: {:?}/{:.1?}/{:.1?}/{:.1?}/{:?}, mean: {:.2}, stddev: {:.2}, genes_entropy_mean: {:.3}",
+                    population.fitness_score_min(),
+                    population.fitness_score_percentile(0.25),
+                    population.fitness_score_percentile(0.5),
+                    population.fitness_score_percentile(0.75),
+                    population.fitness_score_max(),
+                    population.fitness_score_mean(),
+                    population.fitness_score_stddev(),
+                    genes_entropy_mean,
+                ));
+            }
+
             // reset event counters
             self.number_of_select_events = 0;
             self.number_of_extension_events = 0;
@@ -246,13 +310,19 @@ impl<G: EvolveGenotype> StrategyReporter for Simple<G> {
         state: &S,
         _config: &C,
     ) {
+        let show_genes =
+            self.show_genes && self.show_genes_on_improvement(state.best_fitness_score());
+        let truncate_size = self.show_genes_truncate_size;
         self.writeln(format_args!(
             "new best - generation: {}, fitness_score: {:?}, scale_index: {:?}, genes: {:?}",
             state.current_generation(),
             state.best_fitness_score(),
             genotype.current_scale_index(),
-            if self.show_genes {
-                state.best_genes()
+            if show_genes {
+                state.best_genes().map(|genes| ReportedGenes {
+                    genes,
+                    truncate_size,
+                })
             } else {
                 None
             },
@@ -266,13 +336,19 @@ impl<G: EvolveGenotype> StrategyReporter for Simple<G> {
         _config: &C,
     ) {
         if self.show_equal_fitness {
+            let show_genes =
+                self.show_genes && self.show_genes_on_improvement(state.best_fitness_score());
+            let truncate_size = self.show_genes_truncate_size;
             self.writeln(format_args!(
                 "equal best - generation: {}, fitness_score: {:?}, scale_index: {:?}, genes: {:?}",
                 state.current_generation(),
                 state.best_fitness_score(),
                 genotype.current_scale_index(),
-                if self.show_genes {
-                    state.best_genes()
+                if show_genes {
+                    state.best_genes().map(|genes| ReportedGenes {
+                        genes,
+                        truncate_size,
+                    })
                 } else {
                     None
                 },
@@ -307,9 +383,11 @@ impl<G: EvolveGenotype> StrategyReporter for Simple<G> {
         self.number_of_extension_events += 1;
         if self.show_extension_event {
             self.writeln(format_args!(
-                "extension event - generation {} - {}",
+                "extension event - generation {} - {} (cardinality: {:?}, threshold: {:?})",
                 state.current_generation(),
-                event.0,
+                event.name,
+                event.population_cardinality,
+                event.cardinality_threshold,
             ));
         }
     }
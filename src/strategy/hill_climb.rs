@@ -8,8 +8,9 @@ pub use self::builder::{
 };
 
 use super::{
-    Strategy, StrategyAction, StrategyConfig, StrategyReporter, StrategyReporterNoop,
-    StrategyState, StrategyVariant,
+    Strategy, StrategyAction, StrategyClock, StrategyConfig, StrategyReporter,
+    StrategyReporterNoop, StrategyResult, StrategyState, StrategyTerminationPredicate,
+    StrategyTerminationReason, StrategyVariant,
 };
 use crate::chromosome::{Chromosome, Genes};
 use crate::fitness::{Fitness, FitnessCache, FitnessOrdering, FitnessValue};
@@ -20,11 +21,14 @@ use rand::rngs::SmallRng;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use thread_local::ThreadLocal;
 
 pub use self::reporter::Simple as HillClimbReporterSimple;
+pub use crate::strategy::reporter::Csv as HillClimbReporterCsv;
 pub use crate::strategy::reporter::Duration as HillClimbReporterDuration;
+pub use crate::strategy::reporter::Json as HillClimbReporterJson;
 pub use crate::strategy::reporter::Noop as HillClimbReporterNoop;
 
 #[derive(Copy, Clone, Debug, Default)]
@@ -34,6 +38,36 @@ pub enum HillClimbVariant {
     SteepestAscent,
 }
 
+/// Custom acceptance criterion, called with the contending and the current fitness score, returning
+/// whether to move the current position to the contending chromosome. See
+/// [with_acceptance](HillClimbBuilder::with_acceptance). Clones share the same closure (and its
+/// state), also across repeated runs.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct HillClimbAcceptance(
+    Arc<Mutex<dyn FnMut(Option<FitnessValue>, Option<FitnessValue>) -> bool + Send>>,
+);
+impl HillClimbAcceptance {
+    pub fn new<A>(acceptance: A) -> Self
+    where
+        A: FnMut(Option<FitnessValue>, Option<FitnessValue>) -> bool + Send + 'static,
+    {
+        Self(Arc::new(Mutex::new(acceptance)))
+    }
+    pub fn accepts(
+        &self,
+        contending_fitness_score: Option<FitnessValue>,
+        current_fitness_score: Option<FitnessValue>,
+    ) -> bool {
+        (self.0.lock().unwrap())(contending_fitness_score, current_fitness_score)
+    }
+}
+impl fmt::Debug for HillClimbAcceptance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HillClimbAcceptance")
+    }
+}
+
 /// The HillClimb strategy is an iterative algorithm that starts with a single arbitrary solution
 /// to a problem (unless the genotype seeds specific genes to sample a single starting point from),
 /// then attempts to find a better solution by making an incremental change to the solution
@@ -45,6 +79,17 @@ pub enum HillClimbVariant {
 /// * [HillClimbVariant::SteepestAscent]: all neighbours are compared and the one with the best
 ///   improvement is chosen.
 ///
+/// For huge neighbourhoods, the builder step `with_neighbour_sample_size(n)` limits the
+/// [HillClimbVariant::SteepestAscent] to a random sample of n neighbours per generation, the best
+/// of which is chosen. This is a middle ground between the two variants.
+///
+/// By default the search always continues from the best chromosome. The builder step
+/// `with_acceptance(f)` sets a custom acceptance criterion instead (e.g. threshold accepting or
+/// great deluge). The search then continues from a separate current chromosome, which moves to the
+/// contending chromosome (the mutated one for [HillClimbVariant::Stochastic] or the best neighbour
+/// for [HillClimbVariant::SteepestAscent]) when `f(contending_fitness_score,
+/// current_fitness_score)` returns true. The best chromosome is still tracked as usual.
+///
 /// The ending conditions are one or more of the following:
 /// * target_fitness_score: when the ultimate goal in terms of fitness score is known and reached
 /// * max_stale_generations: when the ultimate goal in terms of fitness score is unknown and one depends on some convergion
@@ -54,6 +99,10 @@ pub enum HillClimbVariant {
 ///   there is a replace_on_equal_fitness consideration or some remaining randomness in the neighbouring population (see RangeGenotype
 ///   below)
 /// * max_generations: when the ultimate goal in terms of fitness score is unknown and there is a effort constraint
+/// * max_duration or deadline: when there is a time budget, this is a hard stop which ignores the
+///   valid_fitness_score and stop_after
+/// * termination_predicate: a user predicate on the state for external conditions, also a hard
+///   stop, see [StrategyTerminationPredicate]
 /// * With a scaled [crate::genotype::MutationType]:
 ///   * Scale down after max_generations or max_stale_generations is reached and reset scale_generations and stale_generations to zero
 ///   * Only trigger max_generations or max_stale_generations ending condition when already reached the smallest scale
@@ -81,6 +130,11 @@ pub enum HillClimbVariant {
 ///         * Pick random edge for [HillClimbVariant::Stochastic]
 ///         * Take both edges per gene for [HillClimbVariant::SteepestAscent]
 ///     * max_stale_generations should be set somewhat higher than 1 as there is some remaining randomness
+/// * With MutationType::Gaussian or MutationType::GaussianScaled
+///     * Mutation distance normally distributed with the (current scale) sigma
+///         * Pick random edge for [HillClimbVariant::Stochastic]
+///         * Take both edges per gene for [HillClimbVariant::SteepestAscent]
+///     * max_stale_generations should be set somewhat higher than 1 as there is some remaining randomness
 /// * With MutationType::Random (not advised for hill climbing):
 ///     * Mutate uniformly over the complete allele range
 ///         * Sample single random value for [HillClimbVariant::Stochastic]
@@ -140,6 +194,7 @@ pub enum HillClimbVariant {
 ///     .with_fitness_ordering(FitnessOrdering::Minimize) // aim for the lowest sum
 ///     .with_fitness_cache(1000)                         // enable caching of fitness values (LRU size 1000), only works when genes_hash is stored in chromosome. Only useful for long stale runs
 ///     .with_par_fitness(true)                           // optional, defaults to false, use parallel fitness calculation
+///     .with_fitness_chunk_size(10)                      // optional, defaults to dynamic chunking, number of chromosomes per parallel fitness work unit
 ///     .with_target_fitness_score(0)                     // ending condition if sum of genes is <= 0.00001 in the best chromosome
 ///     .with_valid_fitness_score(100)                    // block ending conditions until at least the sum of genes <= 0.00100 is reached in the best chromosome
 ///     .with_max_stale_generations(1000)                 // stop searching if there is no improvement in fitness score for 1000 generations (per scaled_range)
@@ -162,6 +217,8 @@ pub struct HillClimb<
 > {
     pub genotype: G,
     pub fitness: F,
+    pub termination_predicate: Option<StrategyTerminationPredicate<G>>,
+    pub clock: StrategyClock,
     pub config: HillClimbConfig,
     pub state: HillClimbState<G>,
     pub reporter: SR,
@@ -172,27 +229,48 @@ pub struct HillClimbConfig {
     pub variant: HillClimbVariant,
     pub fitness_ordering: FitnessOrdering,
     pub par_fitness: bool,
+    pub fitness_chunk_size: Option<usize>,
     pub replace_on_equal_fitness: bool,
+    pub neighbour_sample_size: Option<usize>,
+    pub acceptance: Option<HillClimbAcceptance>,
+    pub record_trajectory: bool,
+    pub trajectory_limit: Option<usize>,
 
     pub target_fitness_score: Option<FitnessValue>,
     pub max_stale_generations: Option<usize>,
     pub max_generations: Option<usize>,
     pub valid_fitness_score: Option<FitnessValue>,
+    pub stop_after: Option<(usize, bool)>,
+    pub max_duration: Option<Duration>,
+    pub deadline: Option<Instant>,
     pub fitness_cache: Option<FitnessCache>,
 }
 
 /// Stores the state of the HillClimb strategy.
 pub struct HillClimbState<G: HillClimbGenotype> {
     pub current_iteration: usize,
+    /// The number of completed iterations, see [Strategy::iterations_run]
+    pub iterations_run: usize,
     pub current_generation: usize,
     pub stale_generations: usize,
     pub scale_generation: usize,
     pub best_generation: usize,
     pub best_fitness_score: Option<FitnessValue>,
     pub best_chromosome: Option<Chromosome<G::Allele>>,
+    /// The accepted current position, only used with a custom acceptance criterion
+    pub current_chromosome: Option<Chromosome<G::Allele>>,
+    /// The accepted chromosomes, only recorded with record_trajectory
+    pub trajectory: Vec<Chromosome<G::Allele>>,
     pub chromosome: Option<Chromosome<G::Allele>>,
     pub population: Population<G::Allele>,
     pub durations: HashMap<StrategyAction, Duration>,
+    pub fitness_evaluations: usize,
+    /// The effective deadline of the current `call()`, from the max_duration and deadline
+    pub deadline: Option<Instant>,
+    pub deadline_reached: bool,
+    pub termination_predicate_met: bool,
+    /// Set just before `on_finish`, see [Strategy::termination_reason]
+    pub termination_reason: Option<StrategyTerminationReason>,
 }
 
 impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genotype = G>> Strategy<G>
@@ -200,6 +278,10 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
 {
     fn call(&mut self) {
         let now = Instant::now();
+        self.state.deadline = self.config.deadline_from(self.clock.now());
+        self.state.deadline_reached = false;
+        self.state.termination_predicate_met = false;
+        self.state.termination_reason = None;
         self.reporter
             .on_enter(&self.genotype, &self.state, &self.config);
         let mut fitness_thread_local: Option<ThreadLocal<RefCell<F>>> = None;
@@ -210,13 +292,21 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
         self.setup();
         self.reporter
             .on_start(&self.genotype, &self.state, &self.config);
+        self.update_deadline_reached();
+        self.update_termination_predicate_met();
         while !self.is_finished() {
             self.state.increment_generation();
             match self.config.variant {
                 HillClimbVariant::Stochastic => {
-                    self.state
-                        .chromosome
-                        .clone_from(&self.state.best_chromosome);
+                    if self.config.acceptance.is_some() {
+                        self.state
+                            .chromosome
+                            .clone_from(&self.state.current_chromosome);
+                    } else {
+                        self.state
+                            .chromosome
+                            .clone_from(&self.state.best_chromosome);
+                    }
                     self.genotype.mutate_chromosome_genes(
                         1,
                         true,
@@ -228,40 +318,70 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
                         &mut self.state,
                         &self.config,
                     );
-                    self.state.update_best_chromosome_from_state_chromosome(
+                    let improved = self.state.update_best_chromosome_from_state_chromosome(
                         &self.genotype,
                         &self.config,
                         &mut self.reporter,
                     );
+                    let accepted = self
+                        .state
+                        .update_current_chromosome_from_state_chromosome(&self.config);
+                    self.state
+                        .update_trajectory(improved, accepted, &self.config);
                 }
                 HillClimbVariant::SteepestAscent => {
-                    self.state
-                        .chromosome
-                        .clone_from(&self.state.best_chromosome);
+                    if self.config.acceptance.is_some() {
+                        self.state
+                            .chromosome
+                            .clone_from(&self.state.current_chromosome);
+                    } else {
+                        self.state
+                            .chromosome
+                            .clone_from(&self.state.best_chromosome);
+                    }
                     self.state.population.truncate(0);
-                    self.genotype.fill_neighbouring_population(
-                        self.state.chromosome.as_ref().unwrap(),
-                        &mut self.state.population,
-                        &mut self.rng,
-                    );
+                    if let Some(neighbour_sample_size) = self.config.neighbour_sample_size {
+                        self.genotype.fill_sampled_neighbouring_population(
+                            self.state.chromosome.as_ref().unwrap(),
+                            &mut self.state.population,
+                            neighbour_sample_size,
+                            &mut self.rng,
+                        );
+                    } else {
+                        self.genotype.fill_neighbouring_population(
+                            self.state.chromosome.as_ref().unwrap(),
+                            &mut self.state.population,
+                            &mut self.rng,
+                        );
+                    }
                     self.fitness.call_for_state_population(
                         &self.genotype,
                         &mut self.state,
                         &self.config,
                         fitness_thread_local.as_ref(),
                     );
-                    self.state.update_best_chromosome_from_state_population(
+                    let improved = self.state.update_best_chromosome_from_state_population(
                         &self.genotype,
                         &self.config,
                         &mut self.reporter,
                         &mut self.rng,
                     );
+                    let accepted = self
+                        .state
+                        .update_current_chromosome_from_state_population(&self.config);
+                    self.state
+                        .update_trajectory(improved, accepted, &self.config);
                 }
             }
             self.reporter
                 .on_generation_complete(&self.genotype, &self.state, &self.config);
-            self.state.scale(&mut self.genotype, &self.config);
+            self.state
+                .scale(&mut self.genotype, &self.config, &mut self.reporter);
+            self.update_deadline_reached();
+            self.update_termination_predicate_met();
         }
+        self.state.termination_reason = self.termination_reason();
+        self.state.iterations_run = self.state.iterations_run.max(1);
         self.reporter
             .on_finish(&self.genotype, &self.state, &self.config);
         self.cleanup(fitness_thread_local.as_mut());
@@ -281,6 +401,43 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
             .as_ref()
             .map(|c| c.genes().clone())
     }
+    fn generations_run(&self) -> usize {
+        self.state.current_generation
+    }
+    fn iterations_run(&self) -> usize {
+        self.state.iterations_run
+    }
+    fn termination_reason(&self) -> Option<StrategyTerminationReason> {
+        if !self.is_finished() {
+            None
+        } else if self.is_finished_by_target_fitness_score() {
+            Some(StrategyTerminationReason::TargetFitnessScore)
+        } else if self.is_finished_by_fitness() {
+            Some(StrategyTerminationReason::FitnessShouldTerminate)
+        } else if self.is_finished_by_max_generations() {
+            Some(StrategyTerminationReason::MaxGenerations)
+        } else if self.is_finished_by_stop_after() {
+            Some(StrategyTerminationReason::StopAfter)
+        } else if self.is_finished_by_max_duration() {
+            Some(StrategyTerminationReason::MaxDuration)
+        } else if self.is_finished_by_termination_predicate() {
+            Some(StrategyTerminationReason::TerminationPredicate)
+        } else {
+            Some(StrategyTerminationReason::MaxStaleGenerations)
+        }
+    }
+    fn result(&self) -> StrategyResult<G> {
+        StrategyResult {
+            best_chromosome: self.state.best_chromosome.clone(),
+            best_fitness_score: self.best_fitness_score(),
+            best_generation: self.best_generation(),
+            generations_run: self.generations_run(),
+            fitness_evaluations: self.state.fitness_evaluations,
+            durations: self.state.durations.clone(),
+            total_duration: self.state.total_duration(),
+            termination_reason: self.termination_reason(),
+        }
+    }
     fn flush_reporter(&mut self, output: &mut Vec<u8>) {
         self.reporter.flush(output);
     }
@@ -297,6 +454,16 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
             None
         }
     }
+    /// Borrow the best chromosome, without cloning the genes like
+    /// [best_chromosome](Self::best_chromosome). Useful when polling frequently
+    pub fn best_chromosome_ref(&self) -> Option<&Chromosome<G::Allele>> {
+        self.state.best_chromosome.as_ref()
+    }
+    /// The accepted chromosome of each generation with an accepting move, in order. Only
+    /// recorded with [with_record_trajectory](HillClimbBuilder::with_record_trajectory).
+    pub fn trajectory(&self) -> &[Chromosome<G::Allele>] {
+        &self.state.trajectory
+    }
 }
 
 impl<G: HillClimbGenotype, F: Fitness<Genotype = G>> HillClimb<G, F, StrategyReporterNoop<G>> {
@@ -359,10 +526,16 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
                 .on_new_best_chromosome(&self.genotype, &self.state, &self.config);
             self.state.reset_stale_generations();
         }
+        if self.config.acceptance.is_some() {
+            self.state
+                .current_chromosome
+                .clone_from(&self.state.best_chromosome);
+        }
     }
     pub fn cleanup(&mut self, fitness_thread_local: Option<&mut ThreadLocal<RefCell<F>>>) {
         let now = Instant::now();
         self.state.chromosome.take();
+        self.state.current_chromosome.take();
         self.state.population.chromosomes.clear();
         if let Some(thread_local) = fitness_thread_local {
             thread_local.clear();
@@ -371,15 +544,57 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
             .add_duration(StrategyAction::SetupAndCleanup, now.elapsed());
     }
     fn is_finished(&self) -> bool {
-        self.allow_finished_by_valid_fitness_score()
-            && (self.is_finished_by_max_stale_generations()
-                || self.is_finished_by_max_generations()
-                || self.is_finished_by_target_fitness_score())
+        self.is_finished_by_max_duration()
+            || self.is_finished_by_termination_predicate()
+            || (self.allow_finished_by_valid_fitness_score()
+                && (self.is_finished_by_max_stale_generations()
+                    || self.is_finished_by_max_generations()
+                    || self.is_finished_by_target_fitness_score()
+                    || self.is_finished_by_stop_after()
+                    || self.is_finished_by_fitness()))
+    }
+
+    /// A hard stop, regardless of the valid_fitness_score and stop_after
+    fn is_finished_by_max_duration(&self) -> bool {
+        self.state.deadline_reached
+    }
+
+    fn update_deadline_reached(&mut self) {
+        if let Some(deadline) = self.state.deadline {
+            self.state.deadline_reached = self.clock.now() >= deadline;
+        }
+    }
+
+    /// A hard stop, regardless of the valid_fitness_score and stop_after
+    fn is_finished_by_termination_predicate(&self) -> bool {
+        self.state.termination_predicate_met
+    }
+
+    fn update_termination_predicate_met(&mut self) {
+        if let Some(termination_predicate) = self.termination_predicate.as_ref() {
+            self.state.termination_predicate_met = termination_predicate.is_met(&self.state);
+        }
+    }
+
+    fn is_finished_by_fitness(&self) -> bool {
+        self.state
+            .best_chromosome
+            .as_ref()
+            .is_some_and(|chromosome| self.fitness.should_terminate(chromosome))
     }
 
     fn is_finished_by_max_stale_generations(&self) -> bool {
         if let Some(max_stale_generations) = self.config.max_stale_generations {
-            self.state.stale_generations >= max_stale_generations
+            self.allow_finished_by_stop_after()
+                && self.state.stale_generations >= max_stale_generations
+        } else {
+            false
+        }
+    }
+
+    fn is_finished_by_stop_after(&self) -> bool {
+        if let Some((generations, false)) = self.config.stop_after {
+            self.state.current_generation >= generations
         } else {
             false
         }
@@ -408,6 +623,14 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
         }
     }
 
+    fn allow_finished_by_stop_after(&self) -> bool {
+        if let Some((generations, _)) = self.config.stop_after {
+            self.state.current_generation >= generations
+        } else {
+            true
+        }
+    }
+
     fn allow_finished_by_valid_fitness_score(&self) -> bool {
         if let Some(valid_fitness_score) = self.config.valid_fitness_score {
             if let Some(fitness_score) = self.best_fitness_score() {
@@ -434,9 +657,18 @@ impl StrategyConfig for HillClimbConfig {
     fn par_fitness(&self) -> bool {
         self.par_fitness
     }
+    fn fitness_chunk_size(&self) -> Option<usize> {
+        self.fitness_chunk_size
+    }
     fn replace_on_equal_fitness(&self) -> bool {
         self.replace_on_equal_fitness
     }
+    fn max_duration(&self) -> Option<Duration> {
+        self.max_duration
+    }
+    fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
     fn variant(&self) -> StrategyVariant {
         StrategyVariant::HillClimb(self.variant)
     }
@@ -489,6 +721,9 @@ impl<G: HillClimbGenotype> StrategyState<G> for HillClimbState<G> {
     fn population_cardinality(&self) -> Option<usize> {
         None
     }
+    fn selection_intensity(&self) -> Option<f32> {
+        None
+    }
     fn durations(&self) -> &HashMap<StrategyAction, Duration> {
         &self.durations
     }
@@ -498,21 +733,104 @@ impl<G: HillClimbGenotype> StrategyState<G> for HillClimbState<G> {
     fn total_duration(&self) -> Duration {
         self.durations.values().sum()
     }
+    fn fitness_evaluations(&self) -> usize {
+        self.fitness_evaluations
+    }
+    fn add_fitness_evaluations(&mut self, number_of_evaluations: usize) {
+        self.fitness_evaluations += number_of_evaluations;
+    }
+    fn termination_reason(&self) -> Option<StrategyTerminationReason> {
+        self.termination_reason
+    }
     fn best_genes(&self) -> Option<Genes<G::Allele>> {
         self.best_chromosome.as_ref().map(|c| c.genes().clone())
     }
+    fn best_chromosome_as_ref(&self) -> Option<&Chromosome<G::Allele>> {
+        self.best_chromosome.as_ref()
+    }
 }
 
 impl<G: HillClimbGenotype> HillClimbState<G> {
+    /// Copy into the existing best or current chromosome buffer, only allocates the first time
+    fn store_chromosome(
+        target_chromosome: &mut Option<Chromosome<G::Allele>>,
+        chromosome: &Chromosome<G::Allele>,
+    ) {
+        match target_chromosome {
+            Some(target_chromosome) => target_chromosome.copy_from(chromosome),
+            None => *target_chromosome = Some(chromosome.clone()),
+        }
+    }
+    /// Returns whether the current chromosome moved (only with a custom acceptance criterion)
+    fn update_current_chromosome_from_state_chromosome(
+        &mut self,
+        config: &HillClimbConfig,
+    ) -> bool {
+        if let (Some(acceptance), Some(chromosome)) =
+            (config.acceptance.as_ref(), self.chromosome.as_ref())
+        {
+            let current_fitness_score = self
+                .current_chromosome
+                .as_ref()
+                .and_then(|c| c.fitness_score());
+            if acceptance.accepts(chromosome.fitness_score(), current_fitness_score) {
+                Self::store_chromosome(&mut self.current_chromosome, chromosome);
+                return true;
+            }
+        }
+        false
+    }
+    /// Returns whether the current chromosome moved (only with a custom acceptance criterion)
+    fn update_current_chromosome_from_state_population(
+        &mut self,
+        config: &HillClimbConfig,
+    ) -> bool {
+        if let Some(acceptance) = config.acceptance.as_ref() {
+            if let Some(contending_chromosome) =
+                self.population.best_chromosome(config.fitness_ordering)
+            {
+                let current_fitness_score = self
+                    .current_chromosome
+                    .as_ref()
+                    .and_then(|c| c.fitness_score());
+                if acceptance.accepts(contending_chromosome.fitness_score(), current_fitness_score)
+                {
+                    Self::store_chromosome(&mut self.current_chromosome, contending_chromosome);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+    /// Record the accepted position of the generation: the current chromosome when accepted by a
+    /// custom acceptance criterion, otherwise the best chromosome when replaced
+    fn update_trajectory(&mut self, improved: bool, accepted: bool, config: &HillClimbConfig) {
+        if !config.record_trajectory
+            || config
+                .trajectory_limit
+                .is_some_and(|limit| self.trajectory.len() >= limit)
+        {
+            return;
+        }
+        let position = if config.acceptance.is_some() {
+            self.current_chromosome.as_ref().filter(|_| accepted)
+        } else {
+            self.best_chromosome.as_ref().filter(|_| improved)
+        };
+        if let Some(position) = position {
+            self.trajectory.push(position.clone());
+        }
+    }
+    /// Returns whether the best chromosome was replaced
     fn update_best_chromosome_from_state_chromosome<SR: StrategyReporter<Genotype = G>>(
         &mut self,
         genotype: &G,
         config: &HillClimbConfig,
         reporter: &mut SR,
-    ) {
+    ) -> bool {
         if let Some(chromosome) = self.chromosome.as_ref() {
             let now = Instant::now();
-            match self.is_better_chromosome(
+            let replaced = match self.is_better_chromosome(
                 chromosome,
                 &config.fitness_ordering,
                 config.replace_on_equal_fitness,
@@ -520,33 +838,44 @@ impl<G: HillClimbGenotype> HillClimbState<G> {
                 (true, true) => {
                     self.best_generation = self.current_generation;
                     self.best_fitness_score = chromosome.fitness_score();
-                    self.best_chromosome = Some(chromosome.clone());
+                    Self::store_chromosome(&mut self.best_chromosome, chromosome);
                     reporter.on_new_best_chromosome(genotype, self, config);
                     self.reset_stale_generations();
+                    true
                 }
                 (true, false) => {
-                    self.best_chromosome = Some(chromosome.clone());
+                    Self::store_chromosome(&mut self.best_chromosome, chromosome);
                     reporter.on_new_best_chromosome_equal_fitness(genotype, self, config);
-                    self.increment_stale_generations()
+                    self.increment_stale_generations();
+                    reporter.on_stale_generation(genotype, self, config);
+                    true
                 }
-                _ => self.increment_stale_generations(),
-            }
+                _ => {
+                    self.increment_stale_generations();
+                    reporter.on_stale_generation(genotype, self, config);
+                    false
+                }
+            };
             self.add_duration(StrategyAction::UpdateBestChromosome, now.elapsed());
+            replaced
+        } else {
+            false
         }
     }
+    /// Returns whether the best chromosome was replaced
     fn update_best_chromosome_from_state_population<SR: StrategyReporter<Genotype = G>>(
         &mut self,
         genotype: &G,
         config: &HillClimbConfig,
         reporter: &mut SR,
         rng: &mut SmallRng,
-    ) {
+    ) -> bool {
         let now = Instant::now();
         if config.replace_on_equal_fitness {
             // shuffle, so we don't repeatedly take the same best chromosome in sideways move
             self.population.chromosomes.shuffle(rng);
         }
-        if let Some(contending_chromosome) =
+        let replaced = if let Some(contending_chromosome) =
             self.population.best_chromosome(config.fitness_ordering)
         {
             match self.is_better_chromosome(
@@ -557,33 +886,50 @@ impl<G: HillClimbGenotype> HillClimbState<G> {
                 (true, true) => {
                     self.best_generation = self.current_generation;
                     self.best_fitness_score = contending_chromosome.fitness_score();
-                    self.best_chromosome = Some(contending_chromosome.clone());
+                    Self::store_chromosome(&mut self.best_chromosome, contending_chromosome);
                     reporter.on_new_best_chromosome(genotype, self, config);
                     self.reset_stale_generations();
+                    true
                 }
                 (true, false) => {
-                    self.best_chromosome = Some(contending_chromosome.clone());
+                    Self::store_chromosome(&mut self.best_chromosome, contending_chromosome);
                     reporter.on_new_best_chromosome_equal_fitness(genotype, self, config);
-                    self.increment_stale_generations()
+                    self.increment_stale_generations();
+                    reporter.on_stale_generation(genotype, self, config);
+                    true
+                }
+                _ => {
+                    self.increment_stale_generations();
+                    reporter.on_stale_generation(genotype, self, config);
+                    false
                 }
-                _ => self.increment_stale_generations(),
             }
         } else {
             self.increment_stale_generations();
-        }
+            reporter.on_stale_generation(genotype, self, config);
+            false
+        };
         self.add_duration(StrategyAction::UpdateBestChromosome, now.elapsed());
+        replaced
     }
-    fn scale(&mut self, genotype: &mut G, config: &HillClimbConfig) {
+    fn scale<SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        genotype: &mut G,
+        config: &HillClimbConfig,
+        reporter: &mut SR,
+    ) {
         if let Some(max_generations) = config.max_generations {
             if self.scale_generation >= max_generations && genotype.increment_scale_index() {
                 self.reset_scale_generation();
                 self.reset_stale_generations();
+                reporter.on_scale_down(genotype, self, config);
             }
         }
         if let Some(max_stale_generations) = config.max_stale_generations {
             if self.stale_generations >= max_stale_generations && genotype.increment_scale_index() {
                 self.reset_scale_generation();
                 self.reset_stale_generations();
+                reporter.on_scale_down(genotype, self, config);
             }
         }
     }
@@ -595,37 +941,41 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
     type Error = TryFromHillClimbBuilderError;
 
     fn try_from(builder: HillClimbBuilder<G, F, SR>) -> Result<Self, Self::Error> {
-        if builder.genotype.is_none() {
-            Err(TryFromHillClimbBuilderError(
-                "HillClimb requires a HillClimbGenotype",
-            ))
-        } else if builder.fitness.is_none() {
-            Err(TryFromHillClimbBuilderError("HillClimb requires a Fitness"))
-        } else if builder.max_stale_generations.is_none()
-            && builder.max_generations.is_none()
-            && builder.target_fitness_score.is_none()
-        {
-            Err(TryFromHillClimbBuilderError(
-                "HillClimb requires at least a max_stale_generations, max_generations or target_fitness_score ending condition",
-            ))
+        if let Some(error) = builder.validation_errors().into_iter().next() {
+            Err(error)
         } else {
             let rng = builder.rng();
-            let genotype = builder.genotype.unwrap();
+            let mut genotype = builder.genotype.unwrap();
+            if !builder.seed_genes.is_empty() {
+                let mut seed_genes_list = genotype.seed_genes_list().clone();
+                seed_genes_list.extend(builder.seed_genes);
+                genotype.set_seed_genes_list(seed_genes_list);
+            }
             let state = HillClimbState::new(&genotype);
 
             Ok(Self {
                 genotype,
                 fitness: builder.fitness.unwrap(),
+                termination_predicate: builder.termination_predicate,
+                clock: builder.clock,
                 config: HillClimbConfig {
                     variant: builder.variant.unwrap_or_default(),
                     fitness_ordering: builder.fitness_ordering,
                     fitness_cache: builder.fitness_cache,
                     par_fitness: builder.par_fitness,
+                    fitness_chunk_size: builder.fitness_chunk_size,
                     max_stale_generations: builder.max_stale_generations,
                     max_generations: builder.max_generations,
                     target_fitness_score: builder.target_fitness_score,
                     valid_fitness_score: builder.valid_fitness_score,
+                    stop_after: builder.stop_after,
+                    max_duration: builder.max_duration,
+                    deadline: builder.deadline,
                     replace_on_equal_fitness: builder.replace_on_equal_fitness,
+                    neighbour_sample_size: builder.neighbour_sample_size,
+                    acceptance: builder.acceptance,
+                    record_trajectory: builder.record_trajectory,
+                    trajectory_limit: builder.trajectory_limit,
                 },
                 state,
                 reporter: builder.reporter,
@@ -642,11 +992,19 @@ impl Default for HillClimbConfig {
             fitness_ordering: FitnessOrdering::Maximize,
             fitness_cache: None,
             par_fitness: false,
+            fitness_chunk_size: None,
             max_stale_generations: None,
             max_generations: None,
             target_fitness_score: None,
             valid_fitness_score: None,
+            stop_after: None,
+            max_duration: None,
+            deadline: None,
             replace_on_equal_fitness: false,
+            neighbour_sample_size: None,
+            acceptance: None,
+            record_trajectory: false,
+            trajectory_limit: None,
         }
     }
 }
@@ -660,6 +1018,7 @@ impl<G: HillClimbGenotype> HillClimbState<G> {
     pub fn new(genotype: &G) -> Self {
         Self {
             current_iteration: 0,
+            iterations_run: 0,
             current_generation: 0,
             stale_generations: 0,
             scale_generation: 0,
@@ -668,7 +1027,14 @@ impl<G: HillClimbGenotype> HillClimbState<G> {
             chromosome: None,
             population: Population::new_empty(genotype.chromosome_recycling()),
             durations: HashMap::new(),
+            fitness_evaluations: 0,
+            deadline: None,
+            deadline_reached: false,
+            termination_predicate_met: false,
+            termination_reason: None,
             best_chromosome: None,
+            current_chromosome: None,
+            trajectory: vec![],
         }
     }
 }
@@ -691,6 +1057,14 @@ impl fmt::Display for HillClimbConfig {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "hill_climb_config:")?;
         writeln!(f, "  variant: {:?}", self.variant)?;
+        writeln!(
+            f,
+            "  neighbour_sample_size: {:?}",
+            self.neighbour_sample_size
+        )?;
+        writeln!(f, "  acceptance: {:?}", self.acceptance)?;
+        writeln!(f, "  record_trajectory: {:?}", self.record_trajectory)?;
+        writeln!(f, "  trajectory_limit: {:?}", self.trajectory_limit)?;
 
         writeln!(
             f,
@@ -699,9 +1073,13 @@ impl fmt::Display for HillClimbConfig {
         )?;
         writeln!(f, "  max_generations: {:?}", self.max_generations)?;
         writeln!(f, "  valid_fitness_score: {:?}", self.valid_fitness_score)?;
+        writeln!(f, "  stop_after: {:?}", self.stop_after)?;
+        writeln!(f, "  max_duration: {:?}", self.max_duration)?;
+        writeln!(f, "  deadline: {:?}", self.deadline)?;
         writeln!(f, "  target_fitness_score: {:?}", self.target_fitness_score)?;
         writeln!(f, "  fitness_ordering: {:?}", self.fitness_ordering)?;
-        writeln!(f, "  par_fitness: {:?}", self.par_fitness)
+        writeln!(f, "  par_fitness: {:?}", self.par_fitness)?;
+        writeln!(f, "  fitness_chunk_size: {:?}", self.fitness_chunk_size)
     }
 }
 
@@ -711,6 +1089,7 @@ impl<G: HillClimbGenotype> fmt::Display for HillClimbState<G> {
         writeln!(f, "  current iteration: {:?}", self.current_iteration)?;
         writeln!(f, "  current generation: {:?}", self.current_generation)?;
         writeln!(f, "  stale generations: {:?}", self.stale_generations)?;
+        writeln!(f, "  fitness evaluations: {:?}", self.fitness_evaluations)?;
         writeln!(f, "  best fitness score: {:?}", self.best_fitness_score())
     }
 }
@@ -1,13 +1,16 @@
-use super::{HillClimb, HillClimbVariant};
+use super::{HillClimb, HillClimbAcceptance, HillClimbVariant};
+use crate::chromosome::Genes;
 pub use crate::errors::TryFromStrategyBuilderError as TryFromBuilderError;
+use crate::errors::{BuilderError, BuilderValidation};
 use crate::fitness::{Fitness, FitnessCache, FitnessOrdering, FitnessValue};
-use crate::genotype::HillClimbGenotype;
-use crate::strategy::Strategy;
+use crate::genotype::{Genotype, HillClimbGenotype};
+use crate::strategy::{derive_seed, Strategy, StrategyClock, StrategyTerminationPredicate};
 pub use crate::strategy::{StrategyReporter, StrategyReporterNoop, StrategyState};
 use rand::rngs::SmallRng;
 use rand::SeedableRng;
 use rayon::prelude::*;
 use std::sync::mpsc::channel;
+use std::time::{Duration, Instant};
 
 /// The builder for an HillClimb struct.
 #[derive(Clone, Debug)]
@@ -22,11 +25,22 @@ pub struct Builder<
     pub fitness_ordering: FitnessOrdering,
     pub fitness_cache: Option<FitnessCache>,
     pub par_fitness: bool,
+    pub fitness_chunk_size: Option<usize>,
     pub max_stale_generations: Option<usize>,
     pub max_generations: Option<usize>,
     pub target_fitness_score: Option<FitnessValue>,
     pub valid_fitness_score: Option<FitnessValue>,
+    pub stop_after: Option<(usize, bool)>,
+    pub max_duration: Option<Duration>,
+    pub deadline: Option<Instant>,
+    pub termination_predicate: Option<StrategyTerminationPredicate<G>>,
+    pub clock: StrategyClock,
     pub replace_on_equal_fitness: bool,
+    pub neighbour_sample_size: Option<usize>,
+    pub seed_genes: Vec<Genes<G::Allele>>,
+    pub acceptance: Option<HillClimbAcceptance>,
+    pub record_trajectory: bool,
+    pub trajectory_limit: Option<usize>,
     pub reporter: SR,
     pub rng_seed: Option<u64>,
 }
@@ -42,11 +56,22 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>> Default
             fitness_ordering: FitnessOrdering::Maximize,
             fitness_cache: None,
             par_fitness: false,
+            fitness_chunk_size: None,
             max_stale_generations: None,
             max_generations: None,
             target_fitness_score: None,
             valid_fitness_score: None,
+            stop_after: None,
+            max_duration: None,
+            deadline: None,
+            termination_predicate: None,
+            clock: StrategyClock::default(),
             replace_on_equal_fitness: true,
+            neighbour_sample_size: None,
+            seed_genes: Vec::new(),
+            acceptance: None,
+            record_trajectory: false,
+            trajectory_limit: None,
             reporter: StrategyReporterNoop::new(),
             rng_seed: None,
         }
@@ -64,6 +89,81 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
     pub fn build(self) -> Result<HillClimb<G, F, SR>, TryFromBuilderError> {
         self.try_into()
     }
+
+    /// Dry-run validation of the full configuration, without building. Returns all errors at
+    /// once, where `build()` and `call()` only return the first one. The warnings are returned
+    /// separately, as they don't fail the build, but are likely misconfigurations.
+    pub fn validate(&self) -> BuilderValidation {
+        BuilderValidation {
+            errors: self
+                .validation_errors()
+                .into_iter()
+                .map(|error| error.0.to_string())
+                .collect(),
+            warnings: self.validation_warnings(),
+        }
+    }
+
+    pub(crate) fn validation_errors(&self) -> Vec<TryFromBuilderError> {
+        let mut errors = vec![];
+        if self.genotype.is_none() {
+            errors.push(TryFromBuilderError(
+                "HillClimb requires a HillClimbGenotype",
+                BuilderError::MissingGenotype,
+            ));
+        }
+        if self.fitness.is_none() {
+            errors.push(TryFromBuilderError(
+                "HillClimb requires a Fitness",
+                BuilderError::MissingFitness,
+            ));
+        }
+        if self.max_stale_generations.is_none()
+            && self.max_generations.is_none()
+            && self.target_fitness_score.is_none()
+            && self.max_duration.is_none()
+            && self.deadline.is_none()
+        {
+            errors.push(TryFromBuilderError(
+                "HillClimb requires at least a max_stale_generations, max_generations, target_fitness_score, max_duration or deadline ending condition",
+                BuilderError::MissingEndingCondition,
+            ));
+        }
+        if let Some(genotype) = self.genotype.as_ref() {
+            if self
+                .seed_genes
+                .iter()
+                .any(|genes| genes.len() != genotype.genes_size())
+            {
+                errors.push(TryFromBuilderError(
+                    "HillClimb requires seed_genes_from genes with the genotype's genes_size",
+                    BuilderError::IncompatibleSeedGenes,
+                ));
+            }
+        }
+        if self.neighbour_sample_size == Some(0) {
+            errors.push(TryFromBuilderError(
+                "HillClimb requires a neighbour_sample_size > 0",
+                BuilderError::InvalidParameter,
+            ));
+        }
+        errors
+    }
+
+    pub(crate) fn validation_warnings(&self) -> Vec<String> {
+        let mut warnings = vec![];
+        if self.neighbour_sample_size.is_some()
+            && matches!(
+                self.variant.unwrap_or_default(),
+                HillClimbVariant::Stochastic
+            )
+        {
+            warnings.push(
+                "The neighbour_sample_size is only used by the HillClimbVariant::SteepestAscent, so it is ignored".to_string(),
+            );
+        }
+        warnings
+    }
     pub fn with_genotype(mut self, genotype: G) -> Self {
         self.genotype = Some(genotype);
         self
@@ -90,6 +190,15 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
         self.par_fitness = par_fitness;
         self
     }
+    /// The number of consecutive chromosomes per rayon work unit for the parallel fitness
+    /// calculation (only used with `with_par_fitness(true)`), each chunk is calculated by a single
+    /// thread. Larger chunks reduce the scheduling overhead for cheap fitness functions, smaller
+    /// chunks balance uneven fitness costs better. When not set, the chunking is dynamic (rayon's
+    /// default splitting)
+    pub fn with_fitness_chunk_size(mut self, fitness_chunk_size: usize) -> Self {
+        self.fitness_chunk_size = Some(fitness_chunk_size);
+        self
+    }
     pub fn with_fitness(mut self, fitness: F) -> Self {
         self.fitness = Some(fitness);
         self
@@ -135,10 +244,85 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
         self.valid_fitness_score = valid_fitness_score_option;
         self
     }
+    /// Run at least the given number of generations, ignoring the max_stale_generations ending
+    /// condition until then. When require_no_improvement is false, stop at the given number of
+    /// generations (unless finished earlier by the target_fitness_score). When true, continue
+    /// after the given number of generations until finished by the other ending conditions.
+    /// Note the other ending conditions remain required.
+    pub fn with_stop_after(mut self, generations: usize, require_no_improvement: bool) -> Self {
+        self.stop_after = Some((generations, require_no_improvement));
+        self
+    }
+    /// Stop when the given duration since the start of the `call()` has passed (checked once per
+    /// generation). This is a hard stop, regardless of the valid_fitness_score and stop_after.
+    /// Applies per run for the repeated and speciated calls
+    pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+    /// Stop when the given point in time has passed (checked once per generation). This is a hard
+    /// stop, regardless of the valid_fitness_score and stop_after. Shared by all runs for the
+    /// repeated and speciated calls
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+    /// Stop when the predicate on the state returns true (checked once per generation), e.g. for
+    /// external conditions. This is a hard stop, regardless of the valid_fitness_score and
+    /// stop_after. Shared by all runs for the repeated and speciated calls, see
+    /// [StrategyTerminationPredicate]
+    pub fn with_termination_predicate<P>(mut self, termination_predicate: P) -> Self
+    where
+        P: Fn(&dyn StrategyState<G>) -> bool + Send + Sync + 'static,
+    {
+        self.termination_predicate = Some(StrategyTerminationPredicate::new(termination_predicate));
+        self
+    }
+    /// The source of the current time for the max_duration and deadline, defaults to the system
+    /// clock. Shared by all runs for the repeated and speciated calls, see [StrategyClock]
+    pub fn with_clock<N>(mut self, now: N) -> Self
+    where
+        N: Fn() -> Instant + Send + Sync + 'static,
+    {
+        self.clock = StrategyClock::new(now);
+        self
+    }
     pub fn with_replace_on_equal_fitness(mut self, replace_on_equal_fitness: bool) -> Self {
         self.replace_on_equal_fitness = replace_on_equal_fitness;
         self
     }
+    pub fn with_neighbour_sample_size(mut self, neighbour_sample_size: usize) -> Self {
+        self.neighbour_sample_size = Some(neighbour_sample_size);
+        self
+    }
+    pub fn with_neighbour_sample_size_option(
+        mut self,
+        neighbour_sample_size_option: Option<usize>,
+    ) -> Self {
+        self.neighbour_sample_size = neighbour_sample_size_option;
+        self
+    }
+    /// Set a custom acceptance criterion, called with the contending and the current fitness
+    /// score. See [HillClimb](crate::strategy::hill_climb::HillClimb) for details.
+    pub fn with_acceptance<A>(mut self, acceptance: A) -> Self
+    where
+        A: FnMut(Option<FitnessValue>, Option<FitnessValue>) -> bool + Send + 'static,
+    {
+        self.acceptance = Some(HillClimbAcceptance::new(acceptance));
+        self
+    }
+    /// Record the accepted chromosome of each generation with an accepting move (not all
+    /// neighbours), see [HillClimb::trajectory](crate::strategy::hill_climb::HillClimb::trajectory).
+    /// Memory heavy, so opt-in and optionally capped with `with_trajectory_limit`.
+    pub fn with_record_trajectory(mut self, record_trajectory: bool) -> Self {
+        self.record_trajectory = record_trajectory;
+        self
+    }
+    /// Stop recording the trajectory after the given number of entries
+    pub fn with_trajectory_limit(mut self, trajectory_limit: usize) -> Self {
+        self.trajectory_limit = Some(trajectory_limit);
+        self
+    }
     pub fn with_reporter<SR2: StrategyReporter<Genotype = G>>(
         self,
         reporter: SR2,
@@ -150,15 +334,39 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
             fitness_ordering: self.fitness_ordering,
             fitness_cache: self.fitness_cache,
             par_fitness: self.par_fitness,
+            fitness_chunk_size: self.fitness_chunk_size,
             max_stale_generations: self.max_stale_generations,
             max_generations: self.max_generations,
             target_fitness_score: self.target_fitness_score,
             valid_fitness_score: self.valid_fitness_score,
+            stop_after: self.stop_after,
+            max_duration: self.max_duration,
+            deadline: self.deadline,
+            termination_predicate: self.termination_predicate,
+            clock: self.clock,
             replace_on_equal_fitness: self.replace_on_equal_fitness,
+            neighbour_sample_size: self.neighbour_sample_size,
+            seed_genes: self.seed_genes,
+            acceptance: self.acceptance,
+            record_trajectory: self.record_trajectory,
+            trajectory_limit: self.trajectory_limit,
             reporter,
             rng_seed: self.rng_seed,
         }
     }
+    /// Add the best genes of an earlier strategy run to the seed genes of the genotype at build,
+    /// e.g. to continue a coarse run. The genotypes must be compatible (same allele and
+    /// genes_size, validated at build). Can be called multiple times. Only the best genes are
+    /// seeded, as the strategies don't keep their final population.
+    pub fn with_seed_genes_from<G2: Genotype<Allele = G::Allele>, S2: Strategy<G2>>(
+        mut self,
+        strategy: &S2,
+    ) -> Self {
+        if let Some(best_genes) = strategy.best_genes() {
+            self.seed_genes.push(best_genes);
+        }
+        self
+    }
     pub fn with_rng_seed_from_u64(mut self, rng_seed: u64) -> Self {
         self.rng_seed = Some(rng_seed);
         self
@@ -181,11 +389,38 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
             SmallRng::from_rng(rand::thread_rng()).unwrap()
         }
     }
+    /// The rng seed of the run with the given iteration index in `call_repeatedly` and
+    /// `call_par_repeatedly`, for reproducing a single run externally with
+    /// `with_rng_seed_from_u64`. Each run of a seeded builder gets its own seed, derived from the
+    /// builder's rng_seed (SplitMix64 of `rng_seed ^ index`). Only meaningful for a seeded builder
+    /// (taking zero as rng_seed otherwise), as each run of an unseeded builder seeds from entropy.
+    pub fn derived_seed(&self, index: usize) -> u64 {
+        derive_seed(self.rng_seed.unwrap_or_default(), index)
+    }
+    fn with_derived_seed(mut self, index: usize) -> Self {
+        if let Some(rng_seed) = self.rng_seed {
+            self.rng_seed = Some(derive_seed(rng_seed, index));
+        }
+        self
+    }
     pub fn call(self) -> Result<HillClimb<G, F, SR>, TryFromBuilderError> {
         let mut hill_climb: HillClimb<G, F, SR> = self.try_into()?;
         hill_climb.call();
         Ok(hill_climb)
     }
+    /// Call a clone of the builder with the fitness_ordering overridden for this run only, so the
+    /// same configured builder can be reused for both orderings. All ordering dependent logic of
+    /// the strategy (e.g. best chromosome tracking and target_fitness_score) reads the overridden
+    /// ordering. The fitness itself is not overridden, so a fitness with its own ordering (e.g.
+    /// [FitnessDuplicatePenalty](crate::fitness::FitnessDuplicatePenalty), the non-finite policy
+    /// of [SumGenes](crate::fitness::placeholders::SumGenes) or the always minimizing
+    /// [TourDistance](crate::fitness::placeholders::TourDistance)) must match the passed ordering.
+    pub fn call_with_ordering(
+        &self,
+        fitness_ordering: FitnessOrdering,
+    ) -> Result<HillClimb<G, F, SR>, TryFromBuilderError> {
+        self.clone().with_fitness_ordering(fitness_ordering).call()
+    }
 
     pub fn call_repeatedly(
         self,
@@ -194,7 +429,8 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
         let mut runs: Vec<HillClimb<G, F, SR>> = vec![];
         (0..max_repeats)
             .filter_map(|iteration| {
-                let mut contending_run: HillClimb<G, F, SR> = self.clone().try_into().ok()?;
+                let mut contending_run: HillClimb<G, F, SR> =
+                    self.clone().with_derived_seed(iteration).try_into().ok()?;
                 contending_run.state.current_iteration = iteration;
                 Some(contending_run)
             })
@@ -223,8 +459,11 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
             s.spawn(move |_| {
                 (0..max_repeats)
                     .filter_map(|iteration| {
-                        let mut contending_run: HillClimb<G, F, SR> =
-                            builder.clone().try_into().ok()?;
+                        let mut contending_run: HillClimb<G, F, SR> = builder
+                            .clone()
+                            .with_derived_seed(iteration)
+                            .try_into()
+                            .ok()?;
                         contending_run.state.current_iteration = iteration;
                         Some(contending_run)
                     })
@@ -276,6 +515,9 @@ impl<G: HillClimbGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
                 }
             }
         });
-        runs.remove(best_index)
+        let mut best_run = runs.remove(best_index);
+        // all runs are completed, including the best one
+        best_run.state.iterations_run = runs.len() + 1;
+        best_run
     }
 }
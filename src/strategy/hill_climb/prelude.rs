@@ -1,26 +1,32 @@
 #[doc(no_inline)]
 pub use crate::chromosome::{Chromosome, GenesHash};
 #[doc(no_inline)]
+pub use crate::errors::{BuilderError, BuilderValidation};
+#[doc(no_inline)]
 pub use crate::fitness::{
-    Fitness, FitnessChromosome, FitnessGenes, FitnessGenotype, FitnessOrdering, FitnessPopulation,
-    FitnessValue,
+    Fitness, FitnessAllele, FitnessChromosome, FitnessDuplicatePenalty, FitnessGenes,
+    FitnessGenotype, FitnessMultiObjective, FitnessNonFinitePolicy, FitnessOrdering,
+    FitnessPopulation, FitnessValue, FitnessWeightedObjectives, MultiObjectiveFitness,
 };
 #[doc(no_inline)]
 pub use crate::genotype::{
-    Allele, BinaryGenotype, Genotype, GenotypeBuilder, HillClimbGenotype, ListGenotype,
-    MultiListGenotype, MultiRangeGenotype, MultiUniqueGenotype, MutationType, RangeAllele,
-    RangeGenotype, TryFromGenotypeBuilderError, UniqueGenotype,
+    Allele, BinaryGenotype, Genotype, GenotypeBuilder, GrayCodeGenotype, HillClimbGenotype,
+    LatinSquareGenotype, ListGenotype, MaskedValueGenotype, MultiListGenotype, MultiRangeGenotype,
+    MultiUniqueGenotype, MutationType, OneHotGenotype, RangeAllele, RangeGenotype, SimplexGenotype,
+    TryFromGenotypeBuilderError, UniqueGenotype,
 };
 #[doc(no_inline)]
 pub use crate::impl_allele;
 #[doc(no_inline)]
 pub use crate::strategy::hill_climb::{
-    HillClimb, HillClimbBuilder, HillClimbConfig, HillClimbReporterDuration, HillClimbReporterNoop,
+    HillClimb, HillClimbAcceptance, HillClimbBuilder, HillClimbConfig, HillClimbReporterCsv,
+    HillClimbReporterDuration, HillClimbReporterJson, HillClimbReporterNoop,
     HillClimbReporterSimple, HillClimbState, HillClimbVariant, TryFromHillClimbBuilderError,
 };
 #[doc(no_inline)]
 pub use crate::strategy::{
-    Strategy, StrategyBuilder, StrategyConfig, StrategyReporter, StrategyReporterDuration,
-    StrategyReporterNoop, StrategyReporterSimple, StrategyState, TryFromStrategyBuilderError,
-    STRATEGY_ACTIONS,
+    Strategy, StrategyBuilder, StrategyClock, StrategyConfig, StrategyReporter,
+    StrategyReporterCsv, StrategyReporterDuration, StrategyReporterJson, StrategyReporterNoop,
+    StrategyReporterSimple, StrategyResult, StrategyState, StrategyTerminationPredicate,
+    StrategyTerminationReason, TryFromStrategyBuilderError, STRATEGY_ACTIONS,
 };
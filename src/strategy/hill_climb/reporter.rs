@@ -1,5 +1,7 @@
 use super::HillClimbVariant;
+use crate::fitness::FitnessValue;
 use crate::genotype::HillClimbGenotype;
+use crate::strategy::reporter::{finish_line, show_genes_on_improvement, ReportedGenes};
 use crate::strategy::{
     StrategyConfig, StrategyReporter, StrategyState, StrategyVariant, STRATEGY_ACTIONS,
 };
@@ -15,6 +17,9 @@ pub struct Simple<G: HillClimbGenotype> {
     pub period: usize,
     pub show_genes: bool,
     pub show_equal_fitness: bool,
+    pub show_genes_min_improvement: Option<FitnessValue>,
+    pub show_genes_truncate_size: Option<usize>,
+    last_shown_genes_fitness_score: Option<FitnessValue>,
     _phantom: PhantomData<G>,
 }
 impl<G: HillClimbGenotype> Default for Simple<G> {
@@ -24,6 +29,9 @@ impl<G: HillClimbGenotype> Default for Simple<G> {
             period: 1,
             show_genes: false,
             show_equal_fitness: false,
+            show_genes_min_improvement: None,
+            show_genes_truncate_size: None,
+            last_shown_genes_fitness_score: None,
             _phantom: PhantomData,
         }
     }
@@ -56,6 +64,17 @@ impl<G: HillClimbGenotype> Simple<G> {
             ..Default::default()
         }
     }
+    /// Only show the genes when the fitness score changed at least min_improvement since the
+    /// last shown genes (the first new best always shows the genes). Requires show_genes.
+    pub fn with_show_genes_min_improvement(mut self, min_improvement: FitnessValue) -> Self {
+        self.show_genes_min_improvement = Some(min_improvement);
+        self
+    }
+    /// Only show the first and last truncate_size genes, for large genotypes. Requires show_genes.
+    pub fn with_show_genes_truncate_size(mut self, truncate_size: usize) -> Self {
+        self.show_genes_truncate_size = Some(truncate_size);
+        self
+    }
     fn writeln(&mut self, args: Arguments<'_>) {
         if let Some(buffer) = self.buffer.as_mut() {
             buffer.write_fmt(args).unwrap_or(());
@@ -65,16 +84,29 @@ impl<G: HillClimbGenotype> Simple<G> {
             println!()
         }
     }
+    fn show_genes_on_improvement(&mut self, fitness_score: Option<FitnessValue>) -> bool {
+        show_genes_on_improvement(
+            &mut self.last_shown_genes_fitness_score,
+            fitness_score,
+            self.show_genes_min_improvement,
+        )
+    }
 }
 impl<G: HillClimbGenotype> StrategyReporter for Simple<G> {
     type Genotype = G;
 
+    fn flush(&mut self, output: &mut Vec<u8>) {
+        if let Some(buffer) = self.buffer.as_mut() {
+            output.append(buffer);
+        }
+    }
     fn on_enter<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
         &mut self,
         genotype: &Self::Genotype,
         state: &S,
         config: &C,
     ) {
+        self.last_shown_genes_fitness_score = None;
         let number_of_seed_genes = genotype.seed_genes_list().len();
         if number_of_seed_genes > 0 {
             self.writeln(format_args!(
@@ -97,6 +129,16 @@ impl<G: HillClimbGenotype> StrategyReporter for Simple<G> {
             ))
         }
     }
+    fn on_finish<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        config: &C,
+    ) {
+        if let Some(finish_line) = finish_line(state, config) {
+            self.writeln(format_args!("{}", finish_line));
+        }
+    }
     fn on_exit<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
         &mut self,
         _genotype: &Self::Genotype,
@@ -158,13 +200,19 @@ impl<G: HillClimbGenotype> StrategyReporter for Simple<G> {
         state: &S,
         _config: &C,
     ) {
+        let show_genes =
+            self.show_genes && self.show_genes_on_improvement(state.best_fitness_score());
+        let truncate_size = self.show_genes_truncate_size;
         self.writeln(format_args!(
             "new best - generation: {}, fitness_score: {:?}, scale_index: {:?}, genes: {:?}",
             state.current_generation(),
             state.best_fitness_score(),
             genotype.current_scale_index(),
-            if self.show_genes {
-                Some(state.best_genes())
+            if show_genes {
+                Some(state.best_genes().map(|genes| ReportedGenes {
+                    genes,
+                    truncate_size,
+                }))
             } else {
                 None
             },
@@ -178,13 +226,19 @@ impl<G: HillClimbGenotype> StrategyReporter for Simple<G> {
         _config: &C,
     ) {
         if self.show_equal_fitness {
+            let show_genes =
+                self.show_genes && self.show_genes_on_improvement(state.best_fitness_score());
+            let truncate_size = self.show_genes_truncate_size;
             self.writeln(format_args!(
                 "equal best - generation: {}, fitness_score: {:?}, scale_index: {:?}, genes: {:?}",
                 state.current_generation(),
                 state.best_fitness_score(),
                 genotype.current_scale_index(),
-                if self.show_genes {
-                    Some(state.best_genes())
+                if show_genes {
+                    Some(state.best_genes().map(|genes| ReportedGenes {
+                        genes,
+                        truncate_size,
+                    }))
                 } else {
                     None
                 },
@@ -8,13 +8,16 @@ pub use self::builder::{
 };
 
 use super::{
-    Strategy, StrategyAction, StrategyConfig, StrategyReporter, StrategyReporterNoop,
-    StrategyState, StrategyVariant,
+    Strategy, StrategyAction, StrategyClock, StrategyConfig, StrategyReporter,
+    StrategyReporterNoop, StrategyResult, StrategyState, StrategyTerminationPredicate,
+    StrategyTerminationReason, StrategyVariant,
 };
 use crate::chromosome::{Chromosome, Genes};
-use crate::fitness::{Fitness, FitnessOrdering, FitnessValue};
+use crate::fitness::{Fitness, FitnessContext, FitnessOrdering, FitnessValue};
 use crate::genotype::PermutateGenotype;
 use crate::population::Population;
+use num::rational::Ratio;
+use num::{BigUint, One, Zero};
 use rayon::prelude::*;
 use std::collections::HashMap;
 use std::fmt;
@@ -22,7 +25,9 @@ use std::sync::mpsc::sync_channel;
 use std::time::{Duration, Instant};
 
 pub use self::reporter::Simple as PermutateReporterSimple;
+pub use crate::strategy::reporter::Csv as PermutateReporterCsv;
 pub use crate::strategy::reporter::Duration as PermutateReporterDuration;
+pub use crate::strategy::reporter::Json as PermutateReporterJson;
 pub use crate::strategy::reporter::Noop as PermutateReporterNoop;
 
 #[derive(Copy, Clone, Debug, Default)]
@@ -80,6 +85,7 @@ pub enum PermutateVariant {
 ///     .with_fitness(CountTrue)                          // count the number of true values in the chromosomes
 ///     .with_fitness_ordering(FitnessOrdering::Minimize) // aim for the least true values
 ///     .with_par_fitness(true)                           // optional, defaults to false, use parallel fitness calculation
+///     .with_permutation_chunk_size(100)                 // optional, defaults to None, fully parallel in chunks of 100 permutations (only per window reporting)
 ///     .with_reporter(PermutateReporterSimple::new(100)) // optional builder step, report every 100 generations
 ///     .call()
 ///     .unwrap();
@@ -96,6 +102,8 @@ pub struct Permutate<
 > {
     pub genotype: G,
     pub fitness: F,
+    pub termination_predicate: Option<StrategyTerminationPredicate<G>>,
+    pub clock: StrategyClock,
     pub config: PermutateConfig,
     pub state: PermutateState<G>,
     pub reporter: SR,
@@ -105,12 +113,18 @@ pub struct PermutateConfig {
     pub variant: PermutateVariant,
     pub fitness_ordering: FitnessOrdering,
     pub par_fitness: bool,
+    pub permutation_chunk_size: Option<usize>,
     pub replace_on_equal_fitness: bool,
+    pub chunk_size: Option<usize>,
+    pub max_duration: Option<Duration>,
+    pub deadline: Option<Instant>,
 }
 
 /// Stores the state of the Permutate strategy
 pub struct PermutateState<G: PermutateGenotype> {
     pub current_iteration: usize,
+    /// The number of completed iterations, see [Strategy::iterations_run]
+    pub iterations_run: usize,
     pub current_generation: usize,
     pub stale_generations: usize,
     pub scale_generation: usize,
@@ -120,6 +134,15 @@ pub struct PermutateState<G: PermutateGenotype> {
     pub chromosome: Option<Chromosome<G::Allele>>,
     pub population: Population<G::Allele>,
     pub durations: HashMap<StrategyAction, Duration>,
+    pub fitness_evaluations: usize,
+    /// The effective deadline of the current `call()`, from the max_duration and deadline
+    pub deadline: Option<Instant>,
+    pub deadline_reached: bool,
+    pub termination_predicate_met: bool,
+    /// Set just before `on_finish`, see [Strategy::termination_reason]
+    pub termination_reason: Option<StrategyTerminationReason>,
+    /// The index of the next permutation in the current scale, for resuming a chunked run
+    pub permutation_index: usize,
 }
 
 impl<G: PermutateGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genotype = G>> Strategy<G>
@@ -127,19 +150,38 @@ impl<G: PermutateGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
 {
     fn call(&mut self) {
         let now = Instant::now();
+        self.state.deadline = self.config.deadline_from(self.clock.now());
+        self.state.deadline_reached = false;
+        self.state.termination_predicate_met = false;
+        self.state.termination_reason = None;
         self.reporter
             .on_enter(&self.genotype, &self.state, &self.config);
-        self.setup();
+        if self.state.best_chromosome.is_none() {
+            self.setup();
+        }
         self.reporter
             .on_start(&self.genotype, &self.state, &self.config);
         while !self.is_finished() {
-            if self.config.par_fitness {
-                self.call_parallel()
+            let scale_exhausted = if self.config.par_fitness {
+                match self.config.permutation_chunk_size {
+                    Some(permutation_chunk_size) => {
+                        self.call_parallel_chunked(permutation_chunk_size)
+                    }
+                    None => self.call_parallel(),
+                }
             } else {
                 self.call_sequential()
+            };
+            if !scale_exhausted {
+                // chunk_size, deadline or termination_predicate reached, resume in the next call
+                break;
             }
-            self.state.scale(&mut self.genotype, &self.config);
+            self.state.permutation_index = 0;
+            self.state
+                .scale(&mut self.genotype, &self.config, &mut self.reporter);
         }
+        self.state.termination_reason = self.termination_reason();
+        self.state.iterations_run = self.state.iterations_run.max(1);
         self.reporter
             .on_finish(&self.genotype, &self.state, &self.config);
         self.cleanup();
@@ -159,6 +201,35 @@ impl<G: PermutateGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
             .as_ref()
             .map(|c| c.genes().clone())
     }
+    fn generations_run(&self) -> usize {
+        self.state.current_generation
+    }
+    fn iterations_run(&self) -> usize {
+        self.state.iterations_run
+    }
+    fn termination_reason(&self) -> Option<StrategyTerminationReason> {
+        if self.is_finished() {
+            Some(StrategyTerminationReason::Exhausted)
+        } else if self.is_finished_by_max_duration() {
+            Some(StrategyTerminationReason::MaxDuration)
+        } else if self.is_finished_by_termination_predicate() {
+            Some(StrategyTerminationReason::TerminationPredicate)
+        } else {
+            None
+        }
+    }
+    fn result(&self) -> StrategyResult<G> {
+        StrategyResult {
+            best_chromosome: self.state.best_chromosome.clone(),
+            best_fitness_score: self.best_fitness_score(),
+            best_generation: self.best_generation(),
+            generations_run: self.generations_run(),
+            fitness_evaluations: self.state.fitness_evaluations,
+            durations: self.state.durations.clone(),
+            total_duration: self.state.total_duration(),
+            termination_reason: self.termination_reason(),
+        }
+    }
     fn flush_reporter(&mut self, output: &mut Vec<u8>) {
         self.reporter.flush(output);
     }
@@ -175,6 +246,49 @@ impl<G: PermutateGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
             None
         }
     }
+    /// Borrow the best chromosome, without cloning the genes like
+    /// [best_chromosome](Self::best_chromosome). Useful when polling frequently
+    pub fn best_chromosome_ref(&self) -> Option<&Chromosome<G::Allele>> {
+        self.state.best_chromosome.as_ref()
+    }
+
+    /// The index of the next permutation in the current scale. Persist it to resume a chunked run
+    /// later with [with_start_permutation_index](PermutateBuilder::with_start_permutation_index)
+    pub fn permutation_index(&self) -> usize {
+        self.state.permutation_index
+    }
+
+    /// The fraction of the permutations iterated over (all scales), for tracking the completion
+    /// of a long running or chunked permutation. Equals one when finished
+    pub fn progress(&self) -> Ratio<BigUint> {
+        let total = self.genotype.chromosome_permutations_size();
+        if total.is_zero() || self.is_finished() {
+            Ratio::one()
+        } else {
+            let done = BigUint::from(self.state.current_generation).min(total.clone());
+            Ratio::new(done, total)
+        }
+    }
+
+    /// Parallel iterator over all permutation chromosomes (without fitness score) and their
+    /// index, for custom parallel evaluation or aggregation in rayon pipelines. The built-in best
+    /// chromosome tracking, scaling and reporting are not involved.
+    ///
+    /// The index is the deterministic position in the sequential permutation order (the order of
+    /// [chromosome_permutations_into_iter](PermutateGenotype::chromosome_permutations_into_iter)),
+    /// but the items arrive unordered as the sequential iterator is bridged to rayon. This is not
+    /// an [IndexedParallelIterator](rayon::iter::IndexedParallelIterator) (no `zip`,
+    /// `enumerate` or ordered `collect`), as the genotypes have no direct mapping from a
+    /// permutation index to its chromosome to split on. Sort on the index to restore the order,
+    /// or collect into a `Vec` and call `into_par_iter()` when the permutations fit in memory.
+    pub fn par_iter_chromosomes(
+        &self,
+    ) -> impl ParallelIterator<Item = (usize, Chromosome<G::Allele>)> + '_ {
+        self.genotype
+            .chromosome_permutations_into_iter(None)
+            .enumerate()
+            .par_bridge()
+    }
 }
 
 impl<G: PermutateGenotype, F: Fitness<Genotype = G>> Permutate<G, F, StrategyReporterNoop<G>> {
@@ -217,57 +331,100 @@ impl<G: PermutateGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
             .add_duration(StrategyAction::SetupAndCleanup, now.elapsed());
     }
     fn is_finished(&self) -> bool {
-        self.is_finished_by_max_scale_generation()
+        // a chunked scale is only partially iterated over, when the permutation_index is set
+        self.state.permutation_index == 0 && self.is_finished_by_max_scale_generation()
     }
     fn is_finished_by_max_scale_generation(&self) -> bool {
         self.state.scale_generation > 0
     }
+    /// Stopped early, the permutation is not exhausted
+    fn is_finished_by_max_duration(&self) -> bool {
+        self.state.deadline_reached
+    }
+    /// Stopped early, the permutation is not exhausted
+    fn is_finished_by_termination_predicate(&self) -> bool {
+        self.state.termination_predicate_met
+    }
+    /// Returns true when stopped early by the deadline or the termination_predicate
+    fn update_stopped_early(&mut self) -> bool {
+        if let Some(deadline) = self.state.deadline {
+            self.state.deadline_reached = self.clock.now() >= deadline;
+        }
+        if let Some(termination_predicate) = self.termination_predicate.as_ref() {
+            self.state.termination_predicate_met = termination_predicate.is_met(&self.state);
+        }
+        self.state.deadline_reached || self.state.termination_predicate_met
+    }
 
-    fn call_sequential(&mut self) {
-        self.genotype
-            .clone()
+    /// Returns true when all permutations of the current scale are iterated over
+    fn call_sequential(&mut self) -> bool {
+        let chunk_size = self.config.chunk_size.unwrap_or(usize::MAX);
+        let mut number_of_permutations = 0;
+        let genotype = self.genotype.clone();
+        let chromosomes = genotype
             .chromosome_permutations_into_iter(self.state.best_chromosome.as_ref())
-            .for_each(|chromosome| {
-                self.state.increment_generation();
-                self.state.chromosome.replace(chromosome);
-                self.fitness.call_for_state_chromosome(
-                    &self.genotype,
-                    &mut self.state,
-                    &self.config,
-                );
-                self.state.update_best_chromosome_and_report(
-                    &self.genotype,
-                    &self.config,
-                    &mut self.reporter,
-                );
-                self.reporter
-                    .on_generation_complete(&self.genotype, &self.state, &self.config);
-            });
+            .skip(self.state.permutation_index)
+            .take(chunk_size);
+        for chromosome in chromosomes {
+            if self.update_stopped_early() {
+                return false;
+            }
+            number_of_permutations += 1;
+            self.state.permutation_index += 1;
+            self.state.increment_generation();
+            self.state.chromosome.replace(chromosome);
+            self.fitness
+                .call_for_state_chromosome(&self.genotype, &mut self.state, &self.config);
+            self.state.update_best_chromosome_and_report(
+                &self.genotype,
+                &self.config,
+                &mut self.reporter,
+            );
+            self.reporter
+                .on_generation_complete(&self.genotype, &self.state, &self.config);
+        }
+        number_of_permutations < chunk_size
     }
-    fn call_parallel(&mut self) {
+    /// Returns true when all permutations of the current scale are iterated over
+    fn call_parallel(&mut self) -> bool {
+        let chunk_size = self.config.chunk_size.unwrap_or(usize::MAX);
+        let mut number_of_permutations = 0;
         rayon::scope(|s| {
             let thread_genotype = self.genotype.clone();
             let thread_best_chromosome = self.state.best_chromosome.clone();
+            let permutation_index = self.state.permutation_index;
             let fitness = self.fitness.clone();
             let fitness_cache = self.config.fitness_cache();
+            // the context at the start, as the chromosomes are not evaluated in order
+            let context = FitnessContext::from_state(&self.state);
+            let deadline = self.state.deadline;
             let (sender, receiver) = sync_channel(1000);
 
             s.spawn(move |_| {
                 thread_genotype
                     .chromosome_permutations_into_iter(thread_best_chromosome.as_ref())
+                    .skip(permutation_index)
+                    .take(chunk_size)
+                    // stop handing out permutations on the deadline, but finish the ones in
+                    // progress, so the evaluated permutations remain in sequential order
+                    .take_while(|_| deadline.map_or(true, |deadline| Instant::now() < deadline))
                     .par_bridge()
                     .for_each_with((sender, fitness), |(sender, fitness), mut chromosome| {
                         let now = Instant::now();
-                        fitness.call_for_chromosome(
-                            &mut chromosome,
-                            &thread_genotype,
-                            fitness_cache,
-                        );
+                        context.scope(|| {
+                            fitness.call_for_chromosome(
+                                &mut chromosome,
+                                &thread_genotype,
+                                fitness_cache,
+                            )
+                        });
                         sender.send((chromosome, now.elapsed())).unwrap();
                     });
             });
 
             receiver.iter().for_each(|(chromosome, fitness_duration)| {
+                number_of_permutations += 1;
+                self.state.permutation_index += 1;
                 self.state.increment_generation();
                 self.state.chromosome.replace(chromosome);
                 self.state.update_best_chromosome_and_report(
@@ -277,10 +434,148 @@ impl<G: PermutateGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
                 );
                 self.state
                     .add_duration(StrategyAction::Fitness, fitness_duration);
+                self.state.add_fitness_evaluations(1);
                 self.reporter
                     .on_generation_complete(&self.genotype, &self.state, &self.config);
             });
         });
+        !self.update_stopped_early() && number_of_permutations < chunk_size
+    }
+    /// Fully parallel, each rayon thread reduces its own best chromosome (ties resolved by
+    /// permutation index, like the sequential order). Only the overall best is reported.
+    /// The deadline is checked by the rayon threads before each permutation. With a
+    /// termination_predicate, the permutations are evaluated in windows of a
+    /// permutation_chunk_size per thread, checking the termination_predicate in between.
+    /// Returns true when all permutations of the current scale are iterated over
+    fn call_parallel_chunked(&mut self, permutation_chunk_size: usize) -> bool {
+        let chunk_size = self.config.chunk_size.unwrap_or(usize::MAX);
+        let window_size = if self.termination_predicate.is_some() {
+            permutation_chunk_size
+                .saturating_mul(rayon::current_num_threads())
+                .max(1)
+        } else {
+            chunk_size
+        };
+        let mut number_of_permutations = 0;
+        loop {
+            if self.update_stopped_early() {
+                return false;
+            }
+            let size = window_size.min(chunk_size - number_of_permutations);
+            let window_permutations = self.call_parallel_window(permutation_chunk_size, size);
+            number_of_permutations += window_permutations;
+            if self.state.deadline_reached {
+                return false;
+            } else if window_permutations < size {
+                return true;
+            } else if number_of_permutations >= chunk_size {
+                return false;
+            }
+        }
+    }
+    /// Returns the number of permutations iterated over, less than the window_size when all
+    /// permutations of the current scale are iterated over or when the deadline is reached.
+    /// On the deadline, the permutations from the first skipped one onwards are iterated over
+    /// again when resumed (also the ones some threads still evaluated)
+    fn call_parallel_window(&mut self, permutation_chunk_size: usize, window_size: usize) -> usize {
+        let now = Instant::now();
+        let start_index = self.state.permutation_index;
+        let start_generation = self.state.current_generation;
+        let fitness_ordering = self.config.fitness_ordering;
+        let replace_on_equal_fitness = self.config.replace_on_equal_fitness;
+        let fitness_cache = self.config.fitness_cache();
+        let start_context = FitnessContext::from_state(&self.state);
+        let deadline = self.state.deadline;
+        let clock = &self.clock;
+        let genotype = &self.genotype;
+
+        let (number_of_evaluations, best, first_skipped_index) = genotype
+            .chromosome_permutations_par_iter(
+                self.state.best_chromosome.as_ref(),
+                start_index..start_index.saturating_add(window_size),
+                permutation_chunk_size,
+            )
+            .map_with(self.fitness.clone(), |fitness, (index, mut chromosome)| {
+                if deadline.is_some_and(|deadline| clock.now() >= deadline) {
+                    return (0, None, Some(index));
+                }
+                let context = FitnessContext {
+                    generation: start_generation + index - start_index + 1,
+                    ..start_context
+                };
+                context.scope(|| {
+                    fitness.call_for_chromosome(&mut chromosome, genotype, fitness_cache)
+                });
+                (1, Some((index, chromosome)), None)
+            })
+            .reduce(
+                || (0, None, None),
+                |(count_a, a, skipped_a), (count_b, b, skipped_b)| {
+                    let best = match (a, b) {
+                        (Some(a), Some(b)) => Some(Self::better_permutation(
+                            a,
+                            b,
+                            fitness_ordering,
+                            replace_on_equal_fitness,
+                        )),
+                        (a, b) => a.or(b),
+                    };
+                    let skipped = match (skipped_a, skipped_b) {
+                        (Some(skipped_a), Some(skipped_b)) => Some(skipped_a.min(skipped_b)),
+                        (skipped_a, skipped_b) => skipped_a.or(skipped_b),
+                    };
+                    (count_a + count_b, best, skipped)
+                },
+            );
+        self.state
+            .add_duration(StrategyAction::Fitness, now.elapsed());
+        self.state.add_fitness_evaluations(number_of_evaluations);
+        let number_of_permutations = match first_skipped_index {
+            Some(first_skipped_index) => {
+                self.state.deadline_reached = true;
+                first_skipped_index - start_index
+            }
+            None => number_of_evaluations,
+        };
+
+        if let Some((index, chromosome)) = best {
+            // report the best chromosome at the generation it would have been found sequentially
+            // (or at the end of the window, when evaluated after a skipped one)
+            self.state.current_generation =
+                start_generation + (index - start_index + 1).min(number_of_permutations);
+            self.state.chromosome.replace(chromosome);
+            self.state.update_best_chromosome_and_report(
+                &self.genotype,
+                &self.config,
+                &mut self.reporter,
+            );
+        }
+        self.state.permutation_index += number_of_permutations;
+        self.state.current_generation = start_generation + number_of_permutations;
+        self.state.scale_generation += number_of_permutations;
+        self.reporter
+            .on_generation_complete(&self.genotype, &self.state, &self.config);
+        number_of_permutations
+    }
+    /// The best of two evaluated permutations, as the sequential order would have kept it
+    fn better_permutation(
+        a: (usize, Chromosome<G::Allele>),
+        b: (usize, Chromosome<G::Allele>),
+        fitness_ordering: FitnessOrdering,
+        replace_on_equal_fitness: bool,
+    ) -> (usize, Chromosome<G::Allele>) {
+        let (first, last) = if a.0 < b.0 { (a, b) } else { (b, a) };
+        let first_fitness_score = first.1.fitness_score();
+        let last_fitness_score = last.1.fitness_score();
+        if fitness_ordering.is_better(last_fitness_score, first_fitness_score)
+            || (replace_on_equal_fitness
+                && last_fitness_score.is_some()
+                && last_fitness_score == first_fitness_score)
+        {
+            last
+        } else {
+            first
+        }
     }
 }
 
@@ -294,6 +589,12 @@ impl StrategyConfig for PermutateConfig {
     fn replace_on_equal_fitness(&self) -> bool {
         self.replace_on_equal_fitness
     }
+    fn max_duration(&self) -> Option<Duration> {
+        self.max_duration
+    }
+    fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
     fn variant(&self) -> StrategyVariant {
         StrategyVariant::Permutate(self.variant)
     }
@@ -346,6 +647,9 @@ impl<G: PermutateGenotype> StrategyState<G> for PermutateState<G> {
     fn population_cardinality(&self) -> Option<usize> {
         None
     }
+    fn selection_intensity(&self) -> Option<f32> {
+        None
+    }
     fn durations(&self) -> &HashMap<StrategyAction, Duration> {
         &self.durations
     }
@@ -355,9 +659,21 @@ impl<G: PermutateGenotype> StrategyState<G> for PermutateState<G> {
     fn total_duration(&self) -> Duration {
         self.durations.values().sum()
     }
+    fn fitness_evaluations(&self) -> usize {
+        self.fitness_evaluations
+    }
+    fn add_fitness_evaluations(&mut self, number_of_evaluations: usize) {
+        self.fitness_evaluations += number_of_evaluations;
+    }
+    fn termination_reason(&self) -> Option<StrategyTerminationReason> {
+        self.termination_reason
+    }
     fn best_genes(&self) -> Option<Genes<G::Allele>> {
         self.best_chromosome.as_ref().map(|c| c.genes().clone())
     }
+    fn best_chromosome_as_ref(&self) -> Option<&Chromosome<G::Allele>> {
+        self.best_chromosome.as_ref()
+    }
 }
 
 impl<G: PermutateGenotype> PermutateState<G> {
@@ -384,17 +700,27 @@ impl<G: PermutateGenotype> PermutateState<G> {
                 (true, false) => {
                     self.best_chromosome = Some(chromosome.clone());
                     reporter.on_new_best_chromosome_equal_fitness(genotype, self, config);
-                    self.increment_stale_generations()
+                    self.increment_stale_generations();
+                    reporter.on_stale_generation(genotype, self, config);
+                }
+                _ => {
+                    self.increment_stale_generations();
+                    reporter.on_stale_generation(genotype, self, config);
                 }
-                _ => self.increment_stale_generations(),
             }
             self.add_duration(StrategyAction::UpdateBestChromosome, now.elapsed());
         }
     }
-    fn scale(&mut self, genotype: &mut G, _config: &PermutateConfig) {
+    fn scale<SR: StrategyReporter<Genotype = G>>(
+        &mut self,
+        genotype: &mut G,
+        config: &PermutateConfig,
+        reporter: &mut SR,
+    ) {
         if genotype.increment_scale_index() {
             self.reset_scale_generation();
             self.reset_stale_generations();
+            reporter.on_scale_down(genotype, self, config);
         }
     }
 }
@@ -405,33 +731,28 @@ impl<G: PermutateGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
     type Error = TryFromPermutateBuilderError;
 
     fn try_from(builder: PermutateBuilder<G, F, SR>) -> Result<Self, Self::Error> {
-        if builder.genotype.is_none() {
-            Err(TryFromPermutateBuilderError(
-                "Permutate requires a PermutateGenotype",
-            ))
-        } else if builder.fitness.is_none() {
-            Err(TryFromPermutateBuilderError("Permutate requires a Fitness"))
-        } else if builder
-            .genotype
-            .as_ref()
-            .map(|o| !o.allows_permutation())
-            .unwrap()
-        {
-            Err(TryFromPermutateBuilderError(
-                "The Genotype's mutation_type does not allow permutation",
-            ))
+        if let Some(error) = builder.validation_errors().into_iter().next() {
+            Err(error)
         } else {
             let genotype = builder.genotype.unwrap();
-            let state = PermutateState::new(&genotype);
+            let mut state = PermutateState::new(&genotype);
+            // the generation counter continues from the resumed permutation
+            state.permutation_index = builder.start_permutation_index;
+            state.current_generation = builder.start_permutation_index;
 
             Ok(Self {
                 genotype,
                 fitness: builder.fitness.unwrap(),
-
+                termination_predicate: builder.termination_predicate,
+                clock: builder.clock,
                 config: PermutateConfig {
                     fitness_ordering: builder.fitness_ordering,
                     par_fitness: builder.par_fitness,
+                    permutation_chunk_size: builder.permutation_chunk_size,
                     replace_on_equal_fitness: builder.replace_on_equal_fitness,
+                    chunk_size: builder.chunk_size,
+                    max_duration: builder.max_duration,
+                    deadline: builder.deadline,
                     ..Default::default()
                 },
                 state,
@@ -447,7 +768,11 @@ impl Default for PermutateConfig {
             variant: Default::default(),
             fitness_ordering: FitnessOrdering::Maximize,
             par_fitness: false,
+            permutation_chunk_size: None,
             replace_on_equal_fitness: false,
+            chunk_size: None,
+            max_duration: None,
+            deadline: None,
         }
     }
 }
@@ -461,6 +786,7 @@ impl<G: PermutateGenotype> PermutateState<G> {
     pub fn new(genotype: &G) -> Self {
         Self {
             current_iteration: 0,
+            iterations_run: 0,
             current_generation: 0,
             stale_generations: 0,
             scale_generation: 0,
@@ -469,6 +795,12 @@ impl<G: PermutateGenotype> PermutateState<G> {
             chromosome: None,
             population: Population::new_empty(genotype.chromosome_recycling()),
             durations: HashMap::new(),
+            fitness_evaluations: 0,
+            deadline: None,
+            deadline_reached: false,
+            termination_predicate_met: false,
+            termination_reason: None,
+            permutation_index: 0,
             best_chromosome: None,
         }
     }
@@ -492,7 +824,15 @@ impl fmt::Display for PermutateConfig {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "permutate_config:")?;
         writeln!(f, "  fitness_ordering: {:?}", self.fitness_ordering)?;
-        writeln!(f, "  par_fitness: {:?}", self.par_fitness)
+        writeln!(f, "  par_fitness: {:?}", self.par_fitness)?;
+        writeln!(
+            f,
+            "  permutation_chunk_size: {:?}",
+            self.permutation_chunk_size
+        )?;
+        writeln!(f, "  chunk_size: {:?}", self.chunk_size)?;
+        writeln!(f, "  max_duration: {:?}", self.max_duration)?;
+        writeln!(f, "  deadline: {:?}", self.deadline)
     }
 }
 
@@ -501,6 +841,8 @@ impl<G: PermutateGenotype> fmt::Display for PermutateState<G> {
         writeln!(f, "permutate_state:")?;
         writeln!(f, "  current iteration: -")?;
         writeln!(f, "  current generation: {:?}", self.current_generation)?;
+        writeln!(f, "  permutation index: {:?}", self.permutation_index)?;
+        writeln!(f, "  fitness evaluations: {:?}", self.fitness_evaluations)?;
         writeln!(f, "  best fitness score: {:?}", self.best_fitness_score())
     }
 }
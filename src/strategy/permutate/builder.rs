@@ -1,8 +1,13 @@
 use super::Permutate;
 pub use crate::errors::TryFromStrategyBuilderError as TryFromBuilderError;
+use crate::errors::{BuilderError, BuilderValidation};
 use crate::fitness::{Fitness, FitnessOrdering};
 use crate::genotype::PermutateGenotype;
-use crate::strategy::{Strategy, StrategyReporter, StrategyReporterNoop};
+use crate::strategy::{
+    Strategy, StrategyClock, StrategyReporter, StrategyReporterNoop, StrategyState,
+    StrategyTerminationPredicate,
+};
+use std::time::{Duration, Instant};
 
 /// The builder for an Permutate struct.
 #[derive(Clone, Debug)]
@@ -15,7 +20,14 @@ pub struct Builder<
     pub fitness: Option<F>,
     pub fitness_ordering: FitnessOrdering,
     pub par_fitness: bool,
+    pub permutation_chunk_size: Option<usize>,
     pub replace_on_equal_fitness: bool,
+    pub chunk_size: Option<usize>,
+    pub start_permutation_index: usize,
+    pub max_duration: Option<Duration>,
+    pub deadline: Option<Instant>,
+    pub termination_predicate: Option<StrategyTerminationPredicate<G>>,
+    pub clock: StrategyClock,
     pub reporter: SR,
 }
 
@@ -27,7 +39,14 @@ impl<G: PermutateGenotype, F: Fitness<Genotype = G>> Default
             genotype: None,
             fitness_ordering: FitnessOrdering::Maximize,
             par_fitness: false,
+            permutation_chunk_size: None,
             replace_on_equal_fitness: false,
+            chunk_size: None,
+            start_permutation_index: 0,
+            max_duration: None,
+            deadline: None,
+            termination_predicate: None,
+            clock: StrategyClock::default(),
             fitness: None,
             reporter: StrategyReporterNoop::new(),
         }
@@ -45,6 +64,58 @@ impl<G: PermutateGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
     pub fn build(self) -> Result<Permutate<G, F, SR>, TryFromBuilderError> {
         self.try_into()
     }
+
+    /// Dry-run validation of the full configuration, without building. Returns all errors at
+    /// once, where `build()` and `call()` only return the first one. The warnings are returned
+    /// separately, as they don't fail the build, but are likely misconfigurations.
+    pub fn validate(&self) -> BuilderValidation {
+        BuilderValidation {
+            errors: self
+                .validation_errors()
+                .into_iter()
+                .map(|error| error.0.to_string())
+                .collect(),
+            warnings: self.validation_warnings(),
+        }
+    }
+
+    pub(crate) fn validation_errors(&self) -> Vec<TryFromBuilderError> {
+        let mut errors = vec![];
+        if self.genotype.is_none() {
+            errors.push(TryFromBuilderError(
+                "Permutate requires a PermutateGenotype",
+                BuilderError::MissingGenotype,
+            ));
+        }
+        if self.fitness.is_none() {
+            errors.push(TryFromBuilderError(
+                "Permutate requires a Fitness",
+                BuilderError::MissingFitness,
+            ));
+        }
+        if self
+            .genotype
+            .as_ref()
+            .is_some_and(|genotype| !genotype.allows_permutation())
+        {
+            errors.push(TryFromBuilderError(
+                "The Genotype's mutation_type does not allow permutation",
+                BuilderError::IncompatibleMutationType,
+            ));
+        }
+        errors
+    }
+
+    pub(crate) fn validation_warnings(&self) -> Vec<String> {
+        let mut warnings = vec![];
+        if self.permutation_chunk_size.is_some() && !self.par_fitness {
+            warnings.push(
+                "The permutation_chunk_size is only used with par_fitness, so it is ignored"
+                    .to_string(),
+            );
+        }
+        warnings
+    }
     pub fn with_genotype(mut self, genotype: G) -> Self {
         self.genotype = Some(genotype);
         self
@@ -57,10 +128,68 @@ impl<G: PermutateGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
         self.par_fitness = par_fitness;
         self
     }
+    /// Switch to the fully parallel permutation mode (only used with `with_par_fitness(true)`),
+    /// where the permutations are handed to the rayon threads in chunks of
+    /// permutation_chunk_size, each thread keeping its own best chromosome. This scales roughly
+    /// linearly with the cores for cheap fitness functions, as no chromosome passes through the
+    /// main thread. The resulting best chromosome is the same as sequentially, but the per
+    /// chromosome reporting (`on_generation_complete`, stale generations) is only done once per
+    /// scale (or chunk_size, or window with a termination_predicate)
+    pub fn with_permutation_chunk_size(mut self, permutation_chunk_size: usize) -> Self {
+        self.permutation_chunk_size = Some(permutation_chunk_size);
+        self
+    }
     pub fn with_replace_on_equal_fitness(mut self, replace_on_equal_fitness: bool) -> Self {
         self.replace_on_equal_fitness = replace_on_equal_fitness;
         self
     }
+    /// Only iterate over at most chunk_size permutations per `call()`, so a long running
+    /// permutation can be split up. Each next `call()` on the same [Permutate] resumes where the
+    /// previous one stopped, see [Permutate::permutation_index] and [Permutate::progress]
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+    /// Skip the first start_permutation_index permutations, to resume from a persisted
+    /// [Permutate::permutation_index] of a previous (chunked) run. The best chromosome of the
+    /// previous run is not restored, so persist and compare it externally. Only meaningful for
+    /// the first scale of a scaled genotype
+    pub fn with_start_permutation_index(mut self, start_permutation_index: usize) -> Self {
+        self.start_permutation_index = start_permutation_index;
+        self
+    }
+    /// Stop when the given duration since the start of the `call()` has passed (checked per
+    /// permutation, also by the rayon threads when fully parallel). The run can be resumed like a
+    /// chunked run by the next `call()`, which gets a fresh max_duration
+    pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+    /// Stop when the given point in time has passed, checked like the max_duration
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+    /// Stop when the predicate on the state returns true, checked like the max_duration (but
+    /// only after the full scale when parallel, or per window of permutation_chunk_size
+    /// permutations per thread when fully parallel), e.g. for external conditions. The run can be resumed like a chunked run by the next `call()`, see
+    /// [StrategyTerminationPredicate]
+    pub fn with_termination_predicate<P>(mut self, termination_predicate: P) -> Self
+    where
+        P: Fn(&dyn StrategyState<G>) -> bool + Send + Sync + 'static,
+    {
+        self.termination_predicate = Some(StrategyTerminationPredicate::new(termination_predicate));
+        self
+    }
+    /// The source of the current time for the max_duration and deadline, defaults to the system
+    /// clock, see [StrategyClock]
+    pub fn with_clock<N>(mut self, now: N) -> Self
+    where
+        N: Fn() -> Instant + Send + Sync + 'static,
+    {
+        self.clock = StrategyClock::new(now);
+        self
+    }
     pub fn with_fitness(mut self, fitness: F) -> Self {
         self.fitness = Some(fitness);
         self
@@ -73,7 +202,14 @@ impl<G: PermutateGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
             genotype: self.genotype,
             fitness_ordering: self.fitness_ordering,
             par_fitness: self.par_fitness,
+            permutation_chunk_size: self.permutation_chunk_size,
             replace_on_equal_fitness: self.replace_on_equal_fitness,
+            chunk_size: self.chunk_size,
+            start_permutation_index: self.start_permutation_index,
+            max_duration: self.max_duration,
+            deadline: self.deadline,
+            termination_predicate: self.termination_predicate,
+            clock: self.clock,
             fitness: self.fitness,
             reporter,
         }
@@ -87,4 +223,17 @@ impl<G: PermutateGenotype, F: Fitness<Genotype = G>, SR: StrategyReporter<Genoty
         permutate.call();
         Ok(permutate)
     }
+    /// Call a clone of the builder with the fitness_ordering overridden for this run only, so the
+    /// same configured builder can be reused for both orderings. All ordering dependent logic of
+    /// the strategy (e.g. best chromosome tracking and target_fitness_score) reads the overridden
+    /// ordering. The fitness itself is not overridden, so a fitness with its own ordering (e.g.
+    /// [FitnessDuplicatePenalty](crate::fitness::FitnessDuplicatePenalty), the non-finite policy
+    /// of [SumGenes](crate::fitness::placeholders::SumGenes) or the always minimizing
+    /// [TourDistance](crate::fitness::placeholders::TourDistance)) must match the passed ordering.
+    pub fn call_with_ordering(
+        &self,
+        fitness_ordering: FitnessOrdering,
+    ) -> Result<Permutate<G, F, SR>, TryFromBuilderError> {
+        self.clone().with_fitness_ordering(fitness_ordering).call()
+    }
 }
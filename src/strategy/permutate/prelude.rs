@@ -1,27 +1,33 @@
 #[doc(no_inline)]
 pub use crate::chromosome::{Chromosome, GenesHash};
 #[doc(no_inline)]
+pub use crate::errors::{BuilderError, BuilderValidation};
+#[doc(no_inline)]
 pub use crate::fitness::{
-    Fitness, FitnessChromosome, FitnessGenes, FitnessGenotype, FitnessOrdering, FitnessPopulation,
-    FitnessValue,
+    Fitness, FitnessAllele, FitnessChromosome, FitnessDuplicatePenalty, FitnessGenes,
+    FitnessGenotype, FitnessMultiObjective, FitnessNonFinitePolicy, FitnessOrdering,
+    FitnessPopulation, FitnessValue, FitnessWeightedObjectives, MultiObjectiveFitness,
 };
 #[doc(no_inline)]
 pub use crate::genotype::{
-    Allele, BinaryGenotype, Genotype, GenotypeBuilder, ListGenotype, MultiListGenotype,
-    MultiRangeGenotype, MultiUniqueGenotype, MutationType, PermutateGenotype, RangeAllele,
-    RangeGenotype, TryFromGenotypeBuilderError, UniqueGenotype,
+    Allele, BinaryGenotype, Genotype, GenotypeBuilder, GrayCodeGenotype, ListGenotype,
+    MaskedValueGenotype, MultiListGenotype, MultiRangeGenotype, MultiUniqueGenotype, MutationType,
+    OneHotGenotype, PermutateGenotype, PermutationOrder, RangeAllele, RangeGenotype,
+    TryFromGenotypeBuilderError, UniqueGenotype,
 };
 #[doc(no_inline)]
 pub use crate::impl_allele;
 #[doc(no_inline)]
 pub use crate::strategy::permutate::{
-    Permutate, PermutateBuilder, PermutateConfig, PermutateReporterDuration, PermutateReporterNoop,
-    PermutateReporterSimple, PermutateState, PermutateVariant, TryFromPermutateBuilderError,
+    Permutate, PermutateBuilder, PermutateConfig, PermutateReporterCsv, PermutateReporterDuration,
+    PermutateReporterJson, PermutateReporterNoop, PermutateReporterSimple, PermutateState,
+    PermutateVariant, TryFromPermutateBuilderError,
 };
 #[doc(no_inline)]
 pub use crate::strategy::{
-    Strategy, StrategyBuilder, StrategyConfig, StrategyReporter, StrategyReporterDuration,
-    StrategyReporterNoop, StrategyReporterSimple, StrategyState, TryFromStrategyBuilderError,
-    STRATEGY_ACTIONS,
+    Strategy, StrategyBuilder, StrategyClock, StrategyConfig, StrategyReporter,
+    StrategyReporterCsv, StrategyReporterDuration, StrategyReporterJson, StrategyReporterNoop,
+    StrategyReporterSimple, StrategyResult, StrategyState, StrategyTerminationPredicate,
+    StrategyTerminationReason, TryFromStrategyBuilderError, STRATEGY_ACTIONS,
 };
 pub use num::BigUint;
@@ -1,5 +1,7 @@
 //! Reporters directed at Permutate process specific data
+use crate::fitness::FitnessValue;
 use crate::genotype::PermutateGenotype;
+use crate::strategy::reporter::{finish_line, show_genes_on_improvement, ReportedGenes};
 use crate::strategy::{StrategyConfig, StrategyReporter, StrategyState, STRATEGY_ACTIONS};
 use num::{BigUint, ToPrimitive};
 use std::fmt::Arguments;
@@ -14,6 +16,9 @@ pub struct Simple<G: PermutateGenotype> {
     pub period: usize,
     pub show_genes: bool,
     pub show_equal_fitness: bool,
+    pub show_genes_min_improvement: Option<FitnessValue>,
+    pub show_genes_truncate_size: Option<usize>,
+    last_shown_genes_fitness_score: Option<FitnessValue>,
     _phantom: PhantomData<G>,
 }
 impl<G: PermutateGenotype> Default for Simple<G> {
@@ -23,6 +28,9 @@ impl<G: PermutateGenotype> Default for Simple<G> {
             period: 1,
             show_genes: false,
             show_equal_fitness: false,
+            show_genes_min_improvement: None,
+            show_genes_truncate_size: None,
+            last_shown_genes_fitness_score: None,
             _phantom: PhantomData,
         }
     }
@@ -55,6 +63,17 @@ impl<G: PermutateGenotype> Simple<G> {
             ..Default::default()
         }
     }
+    /// Only show the genes when the fitness score changed at least min_improvement since the
+    /// last shown genes (the first new best always shows the genes). Requires show_genes.
+    pub fn with_show_genes_min_improvement(mut self, min_improvement: FitnessValue) -> Self {
+        self.show_genes_min_improvement = Some(min_improvement);
+        self
+    }
+    /// Only show the first and last truncate_size genes, for large genotypes. Requires show_genes.
+    pub fn with_show_genes_truncate_size(mut self, truncate_size: usize) -> Self {
+        self.show_genes_truncate_size = Some(truncate_size);
+        self
+    }
     fn writeln(&mut self, args: Arguments<'_>) {
         if let Some(buffer) = self.buffer.as_mut() {
             buffer.write_fmt(args).unwrap_or(());
@@ -64,6 +83,13 @@ impl<G: PermutateGenotype> Simple<G> {
             println!()
         }
     }
+    fn show_genes_on_improvement(&mut self, fitness_score: Option<FitnessValue>) -> bool {
+        show_genes_on_improvement(
+            &mut self.last_shown_genes_fitness_score,
+            fitness_score,
+            self.show_genes_min_improvement,
+        )
+    }
 }
 impl<G: PermutateGenotype> StrategyReporter for Simple<G> {
     type Genotype = G;
@@ -79,6 +105,7 @@ impl<G: PermutateGenotype> StrategyReporter for Simple<G> {
         _state: &S,
         config: &C,
     ) {
+        self.last_shown_genes_fitness_score = None;
         let number_of_seed_genes = genotype.seed_genes_list().len();
         if number_of_seed_genes > 0 {
             self.writeln(format_args!(
@@ -96,6 +123,16 @@ impl<G: PermutateGenotype> StrategyReporter for Simple<G> {
         }
     }
 
+    fn on_finish<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        config: &C,
+    ) {
+        if let Some(finish_line) = finish_line(state, config) {
+            self.writeln(format_args!("{}", finish_line));
+        }
+    }
     fn on_exit<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
         &mut self,
         _genotype: &Self::Genotype,
@@ -145,13 +182,19 @@ impl<G: PermutateGenotype> StrategyReporter for Simple<G> {
         state: &S,
         _config: &C,
     ) {
+        let show_genes =
+            self.show_genes && self.show_genes_on_improvement(state.best_fitness_score());
+        let truncate_size = self.show_genes_truncate_size;
         self.writeln(format_args!(
             "new best - generation: {}, fitness_score: {:?}, scale_index: {:?}, genes: {:?}",
             state.current_generation(),
             state.best_fitness_score(),
             genotype.current_scale_index(),
-            if self.show_genes {
-                Some(state.best_genes())
+            if show_genes {
+                Some(state.best_genes().map(|genes| ReportedGenes {
+                    genes,
+                    truncate_size,
+                }))
             } else {
                 None
             },
@@ -165,13 +208,19 @@ impl<G: PermutateGenotype> StrategyReporter for Simple<G> {
         _config: &C,
     ) {
         if self.show_equal_fitness {
+            let show_genes =
+                self.show_genes && self.show_genes_on_improvement(state.best_fitness_score());
+            let truncate_size = self.show_genes_truncate_size;
             self.writeln(format_args!(
                 "equal best - generation: {}, fitness_score: {:?}, scale_index: {:?}, genes: {:?}",
                 state.current_generation(),
                 state.best_fitness_score(),
                 genotype.current_scale_index(),
-                if self.show_genes {
-                    Some(state.best_genes())
+                if show_genes {
+                    Some(state.best_genes().map(|genes| ReportedGenes {
+                        genes,
+                        truncate_size,
+                    }))
                 } else {
                     None
                 },
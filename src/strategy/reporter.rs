@@ -1,16 +1,74 @@
 //! Generic strategy reporters:
 //! * [Duration], only reports duration, non-strategy specific
 //! * [Noop], silences reporting, non-strategy specific
+//! * [Csv] and [Json], write per generation metrics for offline analysis, non-strategy specific
 //! * [Simple], prefer to use strategy specific implementations:
 //!     * [EvolveReporterSimple](crate::strategy::evolve::EvolveReporterSimple)
 //!     * [PermutateReporterSimple](crate::strategy::permutate::PermutateReporterSimple)
 //!     * [HillClimbReporterSimple](crate::strategy::hill_climb::HillClimbReporterSimple)
 //!
+use crate::fitness::FitnessValue;
 use crate::genotype::Genotype;
 use crate::strategy::{StrategyConfig, StrategyReporter, StrategyState, STRATEGY_ACTIONS};
-use std::fmt::Arguments;
+use std::fmt::{self, Arguments};
 use std::io::Write;
 use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+/// Genes for reporting, debug formatted as the first and last truncate_size genes (with an
+/// ellipsis in between) when longer than twice the truncate_size
+pub(crate) struct ReportedGenes<T> {
+    pub genes: Vec<T>,
+    pub truncate_size: Option<usize>,
+}
+impl<T: fmt::Debug> fmt::Debug for ReportedGenes<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.truncate_size {
+            Some(size) if self.genes.len() > 2 * size => f
+                .debug_list()
+                .entries(&self.genes[..size])
+                .entry(&format_args!("..."))
+                .entries(&self.genes[self.genes.len() - size..])
+                .finish(),
+            _ => self.genes.fmt(f),
+        }
+    }
+}
+
+/// Whether to show the genes for the fitness_score, given the fitness score of the last shown
+/// genes and an optional minimal improvement (absolute difference). Stores the fitness_score as
+/// last shown if so.
+pub(crate) fn show_genes_on_improvement(
+    last_shown_fitness_score: &mut Option<FitnessValue>,
+    fitness_score: Option<FitnessValue>,
+    min_improvement: Option<FitnessValue>,
+) -> bool {
+    let show = match (min_improvement, *last_shown_fitness_score, fitness_score) {
+        (Some(min_improvement), Some(last_shown), Some(current)) => {
+            (current - last_shown).abs() >= min_improvement
+        }
+        _ => true,
+    };
+    if show {
+        *last_shown_fitness_score = fitness_score;
+    }
+    show
+}
+
+/// The finish line with the termination reason of the state, None when the run loop didn't
+/// finish (e.g. a chunked [Permutate](crate::strategy::permutate::Permutate))
+pub(crate) fn finish_line<G: Genotype, S: StrategyState<G>, C: StrategyConfig>(
+    state: &S,
+    config: &C,
+) -> Option<String> {
+    state.termination_reason().map(|termination_reason| {
+        format!(
+            "finish - {}, termination reason: {:?}",
+            config.variant(),
+            termination_reason
+        )
+    })
+}
 
 /// The noop reporter, silences reporting
 #[derive(Clone)]
@@ -115,6 +173,9 @@ pub struct Simple<G: Genotype> {
     pub period: usize,
     pub show_genes: bool,
     pub show_equal_fitness: bool,
+    pub show_genes_min_improvement: Option<FitnessValue>,
+    pub show_genes_truncate_size: Option<usize>,
+    last_shown_genes_fitness_score: Option<FitnessValue>,
     _phantom: PhantomData<G>,
 }
 impl<G: Genotype> Default for Simple<G> {
@@ -124,6 +185,9 @@ impl<G: Genotype> Default for Simple<G> {
             period: 1,
             show_genes: false,
             show_equal_fitness: false,
+            show_genes_min_improvement: None,
+            show_genes_truncate_size: None,
+            last_shown_genes_fitness_score: None,
             _phantom: PhantomData,
         }
     }
@@ -156,6 +220,17 @@ impl<G: Genotype> Simple<G> {
             ..Default::default()
         }
     }
+    /// Only show the genes when the fitness score changed at least min_improvement since the
+    /// last shown genes (the first new best always shows the genes). Requires show_genes.
+    pub fn with_show_genes_min_improvement(mut self, min_improvement: FitnessValue) -> Self {
+        self.show_genes_min_improvement = Some(min_improvement);
+        self
+    }
+    /// Only show the first and last truncate_size genes, for large genotypes. Requires show_genes.
+    pub fn with_show_genes_truncate_size(mut self, truncate_size: usize) -> Self {
+        self.show_genes_truncate_size = Some(truncate_size);
+        self
+    }
     fn writeln(&mut self, args: Arguments<'_>) {
         if let Some(buffer) = self.buffer.as_mut() {
             buffer.write_fmt(args).unwrap_or(());
@@ -165,6 +240,13 @@ impl<G: Genotype> Simple<G> {
             println!()
         }
     }
+    fn show_genes_on_improvement(&mut self, fitness_score: Option<FitnessValue>) -> bool {
+        show_genes_on_improvement(
+            &mut self.last_shown_genes_fitness_score,
+            fitness_score,
+            self.show_genes_min_improvement,
+        )
+    }
 }
 impl<G: Genotype> StrategyReporter for Simple<G> {
     type Genotype = G;
@@ -180,6 +262,7 @@ impl<G: Genotype> StrategyReporter for Simple<G> {
         state: &S,
         config: &C,
     ) {
+        self.last_shown_genes_fitness_score = None;
         let number_of_seed_genes = genotype.seed_genes_list().len();
         if number_of_seed_genes > 0 {
             self.writeln(format_args!(
@@ -196,6 +279,16 @@ impl<G: Genotype> StrategyReporter for Simple<G> {
             ));
         }
     }
+    fn on_finish<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        config: &C,
+    ) {
+        if let Some(finish_line) = finish_line(state, config) {
+            self.writeln(format_args!("{}", finish_line));
+        }
+    }
     fn on_exit<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
         &mut self,
         _genotype: &Self::Genotype,
@@ -242,13 +335,19 @@ impl<G: Genotype> StrategyReporter for Simple<G> {
         state: &S,
         _config: &C,
     ) {
+        let show_genes =
+            self.show_genes && self.show_genes_on_improvement(state.best_fitness_score());
+        let truncate_size = self.show_genes_truncate_size;
         self.writeln(format_args!(
             "new best - generation: {}, fitness_score: {:?}, scale_index: {:?}, genes: {:?}",
             state.current_generation(),
             state.best_fitness_score(),
             genotype.current_scale_index(),
-            if self.show_genes {
-                Some(state.best_genes())
+            if show_genes {
+                Some(state.best_genes().map(|genes| ReportedGenes {
+                    genes,
+                    truncate_size,
+                }))
             } else {
                 None
             },
@@ -262,13 +361,19 @@ impl<G: Genotype> StrategyReporter for Simple<G> {
         _config: &C,
     ) {
         if self.show_equal_fitness {
+            let show_genes =
+                self.show_genes && self.show_genes_on_improvement(state.best_fitness_score());
+            let truncate_size = self.show_genes_truncate_size;
             self.writeln(format_args!(
                 "equal best - generation: {}, fitness_score: {:?}, scale_index: {:?}, genes: {:?}",
                 state.current_generation(),
                 state.best_fitness_score(),
                 genotype.current_scale_index(),
-                if self.show_genes {
-                    Some(state.best_genes())
+                if show_genes {
+                    Some(state.best_genes().map(|genes| ReportedGenes {
+                        genes,
+                        truncate_size,
+                    }))
                 } else {
                     None
                 },
@@ -276,3 +381,282 @@ impl<G: Genotype> StrategyReporter for Simple<G> {
         }
     }
 }
+
+/// The writer of the [Csv] and [Json] reporters, shared between the clones of the reporter
+type SharedWriter = Arc<Mutex<dyn Write + Send>>;
+
+/// The metric names of the [Csv] and [Json] reporters, the durations are cumulative in seconds
+/// and ordered as [STRATEGY_ACTIONS]
+pub const METRICS_COLUMNS: [&str; 19] = [
+    "iteration",
+    "generation",
+    "stale_generations",
+    "best_generation",
+    "best_fitness_score",
+    "mean_fitness_score",
+    "population_size",
+    "population_cardinality",
+    "selection_intensity",
+    "scale_index",
+    "fitness_evaluations",
+    "duration_setup_and_cleanup",
+    "duration_extension",
+    "duration_select",
+    "duration_crossover",
+    "duration_mutate",
+    "duration_fitness",
+    "duration_update_best_chromosome",
+    "duration_other",
+];
+
+/// The metric values in the order of [METRICS_COLUMNS], None when not available (e.g. no
+/// population_cardinality for HillClimb)
+fn metrics_values<G: Genotype, S: StrategyState<G>>(
+    genotype: &G,
+    state: &S,
+) -> Vec<Option<String>> {
+    let population = state.population_as_ref();
+    let mean_fitness_score = Some(population.fitness_score_mean()).filter(|v| v.is_finite());
+    let mut values = vec![
+        Some(state.current_iteration().to_string()),
+        Some(state.current_generation().to_string()),
+        Some(state.stale_generations().to_string()),
+        Some(state.best_generation().to_string()),
+        state.best_fitness_score().map(|v| v.to_string()),
+        mean_fitness_score.map(|v| v.to_string()),
+        Some(population.size().to_string()),
+        state.population_cardinality().map(|v| v.to_string()),
+        state
+            .selection_intensity()
+            .filter(|v| v.is_finite())
+            .map(|v| v.to_string()),
+        genotype.current_scale_index().map(|v| v.to_string()),
+        Some(state.fitness_evaluations().to_string()),
+    ];
+    values.extend(STRATEGY_ACTIONS.iter().map(|action| {
+        Some(
+            state
+                .durations()
+                .get(action)
+                .map_or(0.0, |duration| duration.as_secs_f64())
+                .to_string(),
+        )
+    }));
+    values
+}
+
+/// Write a line to the shared writer if set, otherwise to the buffer if set, otherwise to stdout
+fn write_metrics_line(buffer: &mut Option<Vec<u8>>, writer: &Option<SharedWriter>, line: &str) {
+    if let Some(writer) = writer.as_ref() {
+        if let Ok(mut writer) = writer.lock() {
+            writeln!(writer, "{}", line).unwrap_or(());
+        }
+    } else if let Some(buffer) = buffer.as_mut() {
+        writeln!(buffer, "{}", line).unwrap_or(());
+    } else {
+        println!("{}", line);
+    }
+}
+
+fn flush_metrics_writer(writer: &Option<SharedWriter>) {
+    if let Some(writer) = writer.as_ref() {
+        if let Ok(mut writer) = writer.lock() {
+            writer.flush().unwrap_or(());
+        }
+    }
+}
+
+/// A CSV metrics reporter generic over Genotype, for analysis of the runs in e.g. pandas.
+/// A row with the [METRICS_COLUMNS] is written every period generations. Missing values are
+/// left empty.
+///
+/// Writes to stdout, an internal buffer (see [StrategyReporter::flush]) or any
+/// [Write](std::io::Write) (e.g. a [File](std::fs::File)). The writer is shared between the
+/// clones of the reporter, so `call_repeatedly()` and `call_speciated()` write all runs to the
+/// same sink, distinguished by the iteration column. The header is written once, when the
+/// writer is provided or otherwise on entering the first iteration.
+///
+/// Example output:
+///
+/// ```"not rust",ignore
+/// iteration,generation,stale_generations,best_generation,best_fitness_score,mean_fitness_score,...
+/// 0,1,0,1,63,51.2,100,94,0.83,,200,0.000012,0,0.000105,0.000154,0.000032,0.000041,0.000011,0
+/// ```
+#[derive(Clone)]
+pub struct Csv<G: Genotype> {
+    pub buffer: Option<Vec<u8>>,
+    pub period: usize,
+    writer: Option<SharedWriter>,
+    header_pending: bool,
+    _phantom: PhantomData<G>,
+}
+impl<G: Genotype> Default for Csv<G> {
+    fn default() -> Self {
+        Self {
+            buffer: None,
+            period: 1,
+            writer: None,
+            header_pending: true,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<G: Genotype> Csv<G> {
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            ..Default::default()
+        }
+    }
+    pub fn new_with_buffer(period: usize) -> Self {
+        Self {
+            buffer: Some(Vec::new()),
+            period,
+            ..Default::default()
+        }
+    }
+    /// Write to the provided writer (e.g. a `BufWriter<File>`), which is flushed on exit
+    pub fn new_with_writer<W: Write + Send + 'static>(period: usize, writer: W) -> Self {
+        let mut reporter = Self {
+            period,
+            writer: Some(Arc::new(Mutex::new(writer))),
+            ..Default::default()
+        };
+        reporter.write_header();
+        reporter
+    }
+    fn write_header(&mut self) {
+        self.header_pending = false;
+        write_metrics_line(&mut self.buffer, &self.writer, &METRICS_COLUMNS.join(","));
+    }
+}
+impl<G: Genotype> StrategyReporter for Csv<G> {
+    type Genotype = G;
+
+    fn flush(&mut self, output: &mut Vec<u8>) {
+        if let Some(buffer) = self.buffer.as_mut() {
+            output.append(buffer);
+        }
+    }
+    fn on_enter<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        _config: &C,
+    ) {
+        if self.header_pending && state.current_iteration() == 0 {
+            self.write_header();
+        }
+    }
+    fn on_exit<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        _state: &S,
+        _config: &C,
+    ) {
+        flush_metrics_writer(&self.writer);
+    }
+    fn on_generation_complete<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        genotype: &Self::Genotype,
+        state: &S,
+        _config: &C,
+    ) {
+        if state.current_generation() % self.period == 0 {
+            let line = metrics_values(genotype, state)
+                .into_iter()
+                .map(|value| value.unwrap_or_default())
+                .collect::<Vec<_>>()
+                .join(",");
+            write_metrics_line(&mut self.buffer, &self.writer, &line);
+        }
+    }
+}
+
+/// A JSON Lines metrics reporter generic over Genotype, for analysis of the runs in e.g. pandas
+/// (`read_json(path, lines=True)`). An object with the [METRICS_COLUMNS] is written every period
+/// generations. Missing values are null.
+///
+/// Writes to stdout, an internal buffer (see [StrategyReporter::flush]) or any
+/// [Write](std::io::Write), shared between the clones of the reporter like [Csv].
+///
+/// Example output:
+///
+/// ```"not rust",ignore
+/// {"iteration":0,"generation":1,"stale_generations":0,"best_generation":1,"best_fitness_score":63,...}
+/// ```
+#[derive(Clone)]
+pub struct Json<G: Genotype> {
+    pub buffer: Option<Vec<u8>>,
+    pub period: usize,
+    writer: Option<SharedWriter>,
+    _phantom: PhantomData<G>,
+}
+impl<G: Genotype> Default for Json<G> {
+    fn default() -> Self {
+        Self {
+            buffer: None,
+            period: 1,
+            writer: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<G: Genotype> Json<G> {
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            ..Default::default()
+        }
+    }
+    pub fn new_with_buffer(period: usize) -> Self {
+        Self {
+            buffer: Some(Vec::new()),
+            period,
+            ..Default::default()
+        }
+    }
+    /// Write to the provided writer (e.g. a `BufWriter<File>`), which is flushed on exit
+    pub fn new_with_writer<W: Write + Send + 'static>(period: usize, writer: W) -> Self {
+        Self {
+            period,
+            writer: Some(Arc::new(Mutex::new(writer))),
+            ..Default::default()
+        }
+    }
+}
+impl<G: Genotype> StrategyReporter for Json<G> {
+    type Genotype = G;
+
+    fn flush(&mut self, output: &mut Vec<u8>) {
+        if let Some(buffer) = self.buffer.as_mut() {
+            output.append(buffer);
+        }
+    }
+    fn on_exit<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        _state: &S,
+        _config: &C,
+    ) {
+        flush_metrics_writer(&self.writer);
+    }
+    fn on_generation_complete<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        genotype: &Self::Genotype,
+        state: &S,
+        _config: &C,
+    ) {
+        if state.current_generation() % self.period == 0 {
+            let fields = METRICS_COLUMNS
+                .iter()
+                .zip(metrics_values(genotype, state))
+                .map(|(column, value)| {
+                    format!("\"{}\":{}", column, value.as_deref().unwrap_or("null"))
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            write_metrics_line(&mut self.buffer, &self.writer, &format!("{{{}}}", fields));
+        }
+    }
+}
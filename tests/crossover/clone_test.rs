@@ -3,7 +3,7 @@ use crate::support::*;
 use genetic_algorithm::crossover::{Crossover, CrossoverClone};
 use genetic_algorithm::genotype::{BinaryGenotype, Genotype};
 use genetic_algorithm::population::Population;
-use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState, EvolveVariant};
 use genetic_algorithm::strategy::StrategyReporterNoop;
 
 #[test]
@@ -40,3 +40,39 @@ fn standard() {
         ]
     )
 }
+
+#[test]
+fn steady_state_generation_gap() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+
+    let population: Population<bool> = build::population_with_age(vec![
+        (vec![true, true, true], 1),
+        (vec![false, false, false], 2),
+        (vec![true, true, true], 1),
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let config = EvolveConfig {
+        variant: EvolveVariant::SteadyState,
+        generation_gap: Some(1),
+        target_population_size: 3,
+        ..Default::default()
+    };
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    CrossoverClone::new(0.5).call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert_eq!(
+        inspect::population_with_age(&state.population),
+        vec![
+            (vec![true, true, true], 1),
+            (vec![false, false, false], 2),
+            (vec![true, true, true], 1),
+            (vec![true, true, true], 0),
+        ]
+    )
+}
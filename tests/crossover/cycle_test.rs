@@ -0,0 +1,19 @@
+use super::assert_genes_remain_permutations;
+use genetic_algorithm::crossover::CrossoverCycle;
+use genetic_algorithm::genotype::UniqueGenotype;
+
+#[test]
+fn cycle_crossover() {
+    let father = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    let mother = vec![8, 5, 2, 1, 3, 6, 4, 7];
+    // cycles: positions [0, 7, 6, 3], [1, 4, 2] and [5]
+    assert_eq!(
+        UniqueGenotype::<usize>::cycle_crossover(&father, &mother),
+        (vec![1, 5, 2, 4, 3, 6, 7, 8], vec![8, 2, 3, 1, 5, 6, 4, 7])
+    );
+}
+
+#[test]
+fn genes_remain_permutations() {
+    assert_genes_remain_permutations(CrossoverCycle::new(1.0, 1.0));
+}
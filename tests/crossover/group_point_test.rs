@@ -0,0 +1,93 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::crossover::{Crossover, CrossoverGroupPoint};
+use genetic_algorithm::genotype::{BinaryGenotype, Genotype};
+use genetic_algorithm::population::Population;
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::StrategyReporterNoop;
+
+#[test]
+fn standard() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(9)
+        .build()
+        .unwrap();
+
+    let population: Population<bool> = build::population_with_age(vec![
+        (vec![true; 9], 1),
+        (vec![false; 9], 1),
+        (vec![true; 9], 1),
+        (vec![false; 9], 1),
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let config = EvolveConfig {
+        target_population_size: 4,
+        ..Default::default()
+    };
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    // boundaries 0 and 12 are ignored, so both remaining boundaries are always cut
+    CrossoverGroupPoint::new(0.5, 1.0, vec![6, 3, 0, 12], 4).call(
+        &genotype,
+        &mut state,
+        &config,
+        &mut reporter,
+        &mut rng,
+    );
+
+    assert_eq!(
+        inspect::population_with_age(&state.population),
+        vec![
+            (vec![true; 9], 1),
+            (vec![false; 9], 1),
+            (vec![true; 9], 1),
+            (vec![false; 9], 1),
+            (
+                vec![true, true, true, false, false, false, true, true, true],
+                0
+            ),
+            (
+                vec![false, false, false, true, true, true, false, false, false],
+                0
+            ),
+        ]
+    )
+}
+
+#[test]
+fn groups_are_never_split() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let boundaries = vec![2, 5, 7];
+    let groups = [0..2, 2..5, 5..7, 7..10];
+
+    let config = EvolveConfig {
+        target_population_size: 20,
+        ..Default::default()
+    };
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut crossover = CrossoverGroupPoint::new(1.0, 1.0, boundaries, 2);
+    for _ in 0..10 {
+        let mut state = EvolveState::new(&genotype);
+        state.population = build::population(
+            (0..20)
+                .map(|index| vec![index % 2 == 0; 10])
+                .collect::<Vec<_>>(),
+        );
+        crossover.call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+        let children = inspect::population(&state.population).split_off(20);
+        assert_eq!(children.len(), 20);
+        for child in children {
+            for group in groups.iter().cloned() {
+                let genes = &child[group];
+                assert!(genes.iter().all(|gene| *gene == genes[0]));
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::crossover::{Crossover, CrossoverMasked};
+use genetic_algorithm::genotype::{BinaryGenotype, Genotype};
+use genetic_algorithm::population::Population;
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::StrategyReporterNoop;
+
+#[test]
+fn standard() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(6)
+        .build()
+        .unwrap();
+
+    let population: Population<bool> = build::population_with_age(vec![
+        (vec![true; 6], 1),
+        (vec![false; 6], 1),
+        (vec![true; 6], 1),
+        (vec![false; 6], 1),
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let config = EvolveConfig {
+        target_population_size: 4,
+        ..Default::default()
+    };
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    CrossoverMasked::new_with_probabilities(0.5, 1.0, vec![1.0, 1.0, 0.0, 0.0, 1.0, 0.0]).call(
+        &genotype,
+        &mut state,
+        &config,
+        &mut reporter,
+        &mut rng,
+    );
+
+    assert_eq!(
+        inspect::population_with_age(&state.population),
+        vec![
+            (vec![true; 6], 1),
+            (vec![false; 6], 1),
+            (vec![true; 6], 1),
+            (vec![false; 6], 1),
+            (vec![false, false, true, true, false, true], 0),
+            (vec![true, true, false, false, true, false], 0),
+        ]
+    )
+}
+
+#[test]
+fn unmasked_genes_are_never_exchanged() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let mut mask = vec![false; 4];
+    mask.extend(vec![true; 6]);
+
+    let config = EvolveConfig {
+        target_population_size: 20,
+        ..Default::default()
+    };
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut crossover = CrossoverMasked::new(1.0, 1.0, mask);
+    let mut number_of_exchanged_genes = 0;
+    for _ in 0..10 {
+        let mut state = EvolveState::new(&genotype);
+        state.population = build::population(
+            (0..20)
+                .map(|index| vec![index % 2 == 0; 10])
+                .collect::<Vec<_>>(),
+        );
+        crossover.call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+        let children = inspect::population(&state.population).split_off(20);
+        assert_eq!(children.len(), 20);
+        for child in children {
+            // the structural prefix is kept intact
+            assert!(child[0..4].iter().all(|gene| *gene == child[0]));
+            number_of_exchanged_genes +=
+                child[4..].iter().filter(|gene| **gene != child[0]).count();
+        }
+    }
+    assert!(number_of_exchanged_genes > 0);
+}
+
+#[test]
+fn offspring_rate() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(6)
+        .build()
+        .unwrap();
+
+    let population: Population<bool> = build::population_with_age(vec![
+        (vec![true; 6], 1),
+        (vec![false; 6], 1),
+        (vec![true; 6], 1),
+        (vec![false; 6], 1),
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let config = EvolveConfig {
+        target_population_size: 4,
+        offspring_rate: Some(2.5),
+        ..Default::default()
+    };
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    CrossoverMasked::new_with_probabilities(0.5, 1.0, vec![1.0, 1.0, 0.0, 0.0, 1.0, 0.0]).call(
+        &genotype,
+        &mut state,
+        &config,
+        &mut reporter,
+        &mut rng,
+    );
+
+    assert_eq!(
+        inspect::population_with_age(&state.population),
+        vec![
+            (vec![true; 6], 1),
+            (vec![false; 6], 1),
+            (vec![true; 6], 1),
+            (vec![false; 6], 1),
+            (vec![false, false, true, true, false, true], 0),
+            (vec![true, true, false, false, true, false], 0),
+            (vec![false, false, true, true, false, true], 0),
+            (vec![true, true, false, false, true, false], 0),
+            (vec![true; 6], 0),
+        ]
+    )
+}
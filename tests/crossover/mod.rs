@@ -1,7 +1,60 @@
 pub mod clone_test;
+pub mod cycle_test;
+pub mod group_point_test;
+pub mod masked_test;
 pub mod multi_gene_test;
 pub mod multi_point_test;
+pub mod multi_unique_order_test;
+pub mod noop_test;
+pub mod ordered_test;
+pub mod partially_mapped_test;
 pub mod rejuvenate_test;
 pub mod single_gene_test;
 pub mod single_point_test;
 pub mod uniform_test;
+
+use crate::support::*;
+use genetic_algorithm::crossover::Crossover;
+use genetic_algorithm::genotype::UniqueGenotype;
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::StrategyReporterNoop;
+
+/// Repeatedly cross the children of permutations of `0..8` and assert the genes remain
+/// permutations
+pub fn assert_genes_remain_permutations<C: Crossover<Genotype = UniqueGenotype<usize>>>(
+    mut crossover: C,
+) {
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list((0..8).collect())
+        .build()
+        .unwrap();
+
+    let parents = vec![
+        vec![0, 1, 2, 3, 4, 5, 6, 7],
+        vec![7, 6, 5, 4, 3, 2, 1, 0],
+        vec![2, 0, 4, 1, 5, 3, 7, 6],
+        vec![3, 5, 1, 4, 0, 7, 2, 6],
+    ];
+    let population: Population<usize> = build::population(parents);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let config = EvolveConfig {
+        target_population_size: 4,
+        ..Default::default()
+    };
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    for _ in 0..10 {
+        crossover.call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+        // continue with the children only
+        state.population.chromosomes.drain(..4);
+    }
+
+    assert_eq!(state.population.size(), 4);
+    state.population.chromosomes.iter().for_each(|chromosome| {
+        let mut genes = chromosome.genes.clone();
+        genes.sort_unstable();
+        assert_eq!(genes, (0..8).collect::<Vec<usize>>());
+    });
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::crossover::{Crossover, CrossoverMultiUniqueOrder};
+use genetic_algorithm::genotype::{Genotype, MultiUniqueGenotype};
+use genetic_algorithm::population::Population;
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::StrategyReporterNoop;
+
+#[test]
+fn order_crossover() {
+    let mut genes = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    let donor = vec![8, 6, 4, 2, 7, 5, 3, 1];
+    CrossoverMultiUniqueOrder::<usize>::order_crossover(&mut genes, &donor, 2, 5);
+    // keep [3, 4, 5], fill from position 5 onwards (wrapping) with donor order from position 5
+    // onwards (wrapping): 5 (skip), 3 (skip), 1, 8, 6, 4 (skip), 2, 7
+    assert_eq!(genes, vec![2, 7, 3, 4, 5, 1, 8, 6]);
+}
+
+#[test]
+fn sets_remain_permutations() {
+    let allele_lists = vec![vec![0, 1, 2, 3, 4, 5], vec![10, 11, 12, 13], vec![20]];
+    let genotype = MultiUniqueGenotype::builder()
+        .with_allele_lists(allele_lists.clone())
+        .build()
+        .unwrap();
+
+    let parents = vec![
+        vec![0, 1, 2, 3, 4, 5, 10, 11, 12, 13, 20],
+        vec![5, 4, 3, 2, 1, 0, 13, 12, 11, 10, 20],
+        vec![2, 0, 4, 1, 5, 3, 12, 10, 13, 11, 20],
+        vec![3, 5, 1, 4, 0, 2, 11, 13, 10, 12, 20],
+    ];
+    let population: Population<usize> = build::population(parents.clone());
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let config = EvolveConfig {
+        target_population_size: 4,
+        ..Default::default()
+    };
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut crossover = CrossoverMultiUniqueOrder::new(1.0, 1.0);
+    for _ in 0..10 {
+        crossover.call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+        // continue with the children only
+        state.population.chromosomes.drain(..4);
+    }
+
+    assert_eq!(state.population.size(), 4);
+    state.population.chromosomes.iter().for_each(|chromosome| {
+        let mut offset = 0;
+        allele_lists.iter().for_each(|allele_list| {
+            let mut set = chromosome.genes[offset..offset + allele_list.len()].to_vec();
+            set.sort_unstable();
+            assert_eq!(&set, allele_list);
+            offset += allele_list.len();
+        });
+    });
+
+    // the orderings did actually mix
+    assert!(state
+        .population
+        .chromosomes
+        .iter()
+        .any(|chromosome| !parents.contains(&chromosome.genes)));
+}
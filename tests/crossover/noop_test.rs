@@ -0,0 +1,43 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::crossover::{Crossover, CrossoverNoop};
+use genetic_algorithm::genotype::{Genotype, UniqueGenotype};
+use genetic_algorithm::population::Population;
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::StrategyReporterNoop;
+
+#[test]
+fn standard() {
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list(vec![0, 1, 2])
+        .build()
+        .unwrap();
+
+    let population: Population<usize> = build::population_with_age(vec![
+        (vec![0, 1, 2], 1),
+        (vec![2, 1, 0], 2),
+        (vec![1, 0, 2], 1),
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let config = EvolveConfig {
+        target_population_size: 3,
+        ..Default::default()
+    };
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    CrossoverNoop::new().call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert_eq!(
+        inspect::population_with_age(&state.population),
+        vec![
+            (vec![0, 1, 2], 1),
+            (vec![2, 1, 0], 2),
+            (vec![1, 0, 2], 1),
+            (vec![0, 1, 2], 0),
+            (vec![2, 1, 0], 0),
+            (vec![1, 0, 2], 0),
+        ]
+    )
+}
@@ -0,0 +1,20 @@
+use super::assert_genes_remain_permutations;
+use genetic_algorithm::crossover::CrossoverOrdered;
+use genetic_algorithm::genotype::UniqueGenotype;
+
+#[test]
+fn order_crossover() {
+    let genes = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    let donor = vec![8, 6, 4, 2, 7, 5, 3, 1];
+    // keep [3, 4, 5], fill from position 5 onwards (wrapping) with donor order from position 5
+    // onwards (wrapping): 5 (skip), 3 (skip), 1, 8, 6, 4 (skip), 2, 7
+    assert_eq!(
+        UniqueGenotype::<usize>::order_crossover(&genes, &donor, 2, 5),
+        vec![2, 7, 3, 4, 5, 1, 8, 6]
+    );
+}
+
+#[test]
+fn genes_remain_permutations() {
+    assert_genes_remain_permutations(CrossoverOrdered::new(1.0, 1.0));
+}
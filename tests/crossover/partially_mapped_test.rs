@@ -0,0 +1,19 @@
+use super::assert_genes_remain_permutations;
+use genetic_algorithm::crossover::CrossoverPartiallyMapped;
+use genetic_algorithm::genotype::UniqueGenotype;
+
+#[test]
+fn partially_mapped_crossover() {
+    let genes = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let donor = vec![9, 3, 7, 8, 2, 6, 5, 1, 4];
+    // keep [4, 5, 6, 7], take the rest from the donor: 9, 3, 7 (-> 5 -> 2), 1, 4 (-> 8)
+    assert_eq!(
+        UniqueGenotype::<usize>::partially_mapped_crossover(&genes, &donor, 3, 7),
+        vec![9, 3, 2, 4, 5, 6, 7, 1, 8]
+    );
+}
+
+#[test]
+fn genes_remain_permutations() {
+    assert_genes_remain_permutations(CrossoverPartiallyMapped::new(1.0, 1.0));
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::strategy::evolve::prelude::*;
+
+#[derive(Clone)]
+struct ExtensionEventRecorder {
+    events: Vec<(String, Option<usize>)>,
+}
+impl StrategyReporter for ExtensionEventRecorder {
+    type Genotype = BinaryGenotype;
+
+    fn on_extension_event<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        event: ExtensionEvent,
+        _genotype: &Self::Genotype,
+        _state: &S,
+        _config: &C,
+    ) {
+        self.events.push((event.name, event.cardinality_threshold));
+    }
+}
+
+fn build_state(genotype: &BinaryGenotype) -> EvolveState<BinaryGenotype> {
+    let population: Population<bool> = build::population_with_fitness_scores(vec![
+        (vec![true, true, true], Some(0)),
+        (vec![true, true, false], Some(1)),
+        (vec![true, false, false], Some(2)),
+        (vec![true, true, true], Some(0)),
+        (vec![true, true, false], Some(1)),
+        (vec![true, false, false], Some(2)),
+        (vec![true, true, true], Some(0)),
+        (vec![true, true, false], Some(1)),
+    ]);
+    let mut state = EvolveState::new(genotype);
+    assert_eq!(population.genes_cardinality(), Some(3));
+    state.population_cardinality = population.genes_cardinality();
+    state.population = population;
+    state
+}
+
+#[test]
+fn calls_in_order() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut state = build_state(&genotype);
+
+    let config = EvolveConfig::new();
+    let mut reporter = ExtensionEventRecorder { events: vec![] };
+    let mut rng = SmallRng::seed_from_u64(0);
+    ExtensionChain::new(vec![
+        ExtensionMassExtinction::new(3, 0.5, 0.0).into(),
+        ExtensionMassExtinction::new(2, 0.5, 0.0).into(),
+    ])
+    .with_extension(ExtensionMassExtinction::new(4, 0.5, 0.0))
+    .call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert_eq!(
+        reporter.events,
+        vec![
+            ("MassExtinction".to_string(), Some(3)),
+            ("MassExtinction".to_string(), Some(4)),
+        ]
+    );
+    assert_eq!(state.population.size(), 2);
+}
+
+#[test]
+fn empty_noop() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut state = build_state(&genotype);
+
+    let config = EvolveConfig::new();
+    let mut reporter = ExtensionEventRecorder { events: vec![] };
+    let mut rng = SmallRng::seed_from_u64(0);
+    ExtensionChain::new(vec![]).call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert!(reporter.events.is_empty());
+    assert_eq!(state.population.size(), 8);
+}
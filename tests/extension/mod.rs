@@ -1,4 +1,6 @@
+pub mod chain_test;
 pub mod mass_deduplication_test;
 pub mod mass_degeneration_test;
 pub mod mass_extinction_test;
 pub mod mass_genesis_test;
+pub mod scale_reset_test;
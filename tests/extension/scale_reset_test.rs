@@ -0,0 +1,100 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::fitness::placeholders::SumGenes;
+use genetic_algorithm::strategy::evolve::prelude::*;
+
+#[test]
+fn not_scaled_noop() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+
+    let population: Population<bool> = build::population_with_fitness_scores(vec![
+        (vec![true, true, true], Some(3)),
+        (vec![true, true, true], Some(3)),
+    ]);
+    let mut state = EvolveState::new(&genotype);
+    state.population_cardinality = Some(1);
+    state.population = population;
+
+    let config = EvolveConfig {
+        target_population_size: 2,
+        ..Default::default()
+    };
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut extension = ExtensionScaleReset::new(2, 1);
+    extension.call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert!(!state.scale_index_reset_requested);
+    assert_eq!(extension.number_of_resets, 0);
+}
+
+#[derive(Clone)]
+struct ScaleIndexRecorder {
+    scale_indices: Vec<Option<usize>>,
+    number_of_scale_resets: usize,
+}
+impl StrategyReporter for ScaleIndexRecorder {
+    type Genotype = RangeGenotype<f32>;
+
+    fn on_generation_complete<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        genotype: &Self::Genotype,
+        _state: &S,
+        _config: &C,
+    ) {
+        self.scale_indices.push(genotype.current_scale_index());
+    }
+    fn on_extension_event<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        event: ExtensionEvent,
+        _genotype: &Self::Genotype,
+        _state: &S,
+        _config: &C,
+    ) {
+        if event.name == "ScaleReset" {
+            self.number_of_scale_resets += 1;
+        }
+    }
+}
+
+#[test]
+fn resets_scale_index_mid_run() {
+    let genotype = RangeGenotype::<f32>::builder()
+        .with_genes_size(10)
+        .with_allele_range(0.0..=1.0)
+        .with_mutation_type(MutationType::StepScaled(vec![0.1, 0.01, 0.001]))
+        .build()
+        .unwrap();
+
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_stale_generations(5)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(SumGenes::new_with_precision(1e-3))
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        // always triggers when zoomed in, but only once
+        .with_extension(ExtensionScaleReset::new(usize::MAX, 1))
+        .with_reporter(ScaleIndexRecorder {
+            scale_indices: vec![],
+            number_of_scale_resets: 0,
+        })
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    assert_eq!(evolve.reporter.number_of_scale_resets, 1);
+    // zoomed in to scale index 1, reset to 0, then zoomed in again to the last scale index
+    let scale_indices = &evolve.reporter.scale_indices;
+    let reset_position = scale_indices
+        .windows(2)
+        .position(|w| w == [Some(1), Some(0)])
+        .unwrap();
+    assert!(scale_indices[..reset_position].contains(&Some(0)));
+    assert!(scale_indices[reset_position..].contains(&Some(2)));
+    assert_eq!(evolve.genotype.current_scale_index(), Some(2));
+}
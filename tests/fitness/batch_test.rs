@@ -0,0 +1,186 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::fitness::cache::Cache;
+use genetic_algorithm::fitness::{
+    Fitness, FitnessAllele, FitnessChromosome, FitnessContext, FitnessGenotype, FitnessValue,
+};
+use genetic_algorithm::strategy::evolve::prelude::*;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug)]
+struct BatchCountTrue {
+    batch_size: usize,
+    batch_sizes: Arc<Mutex<Vec<usize>>>,
+}
+impl BatchCountTrue {
+    fn new(batch_size: usize) -> Self {
+        Self {
+            batch_size,
+            batch_sizes: Arc::new(Mutex::new(vec![])),
+        }
+    }
+}
+impl Fitness for BatchCountTrue {
+    type Genotype = BinaryGenotype;
+    fn calculate_for_chromosome(
+        &mut self,
+        _chromosome: &FitnessChromosome<Self>,
+        _genotype: &FitnessGenotype<Self>,
+    ) -> Option<FitnessValue> {
+        panic!("no per chromosome fallback when batched");
+    }
+    fn batch_size(&self) -> Option<usize> {
+        Some(self.batch_size)
+    }
+    fn calculate_for_batch(
+        &mut self,
+        genes_matrix: &[FitnessAllele<Self>],
+        genes_size: usize,
+        _genotype: &FitnessGenotype<Self>,
+        _context: &FitnessContext,
+    ) -> Vec<Option<FitnessValue>> {
+        self.batch_sizes
+            .lock()
+            .unwrap()
+            .push(genes_matrix.len() / genes_size);
+        genes_matrix
+            .chunks(genes_size)
+            .map(|genes| Some(genes.iter().filter(|&value| *value).count() as FitnessValue))
+            .collect()
+    }
+}
+
+#[test]
+fn call_for_population() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut population: Population<bool> = build::population(vec![
+        vec![true, true, true],
+        vec![true, true, false],
+        vec![true, false, false],
+        vec![false, false, false],
+        vec![true, true, true],
+        vec![true, true, false],
+        vec![true, false, false],
+        vec![false, false, false],
+        vec![true, false, true],
+        vec![false, true, false],
+    ]);
+    population.chromosomes[0].set_fitness_score(Some(10));
+
+    let mut fitness = BatchCountTrue::new(4);
+    fitness.call_for_population(&mut population, &genotype, None, None);
+
+    assert_eq!(*fitness.batch_sizes.lock().unwrap(), vec![4, 4, 1]);
+    assert_eq!(
+        inspect::population_with_fitness_scores(&population)
+            .into_iter()
+            .map(|(_, fitness_score)| fitness_score)
+            .collect::<Vec<_>>(),
+        vec![
+            Some(10),
+            Some(2),
+            Some(1),
+            Some(0),
+            Some(3),
+            Some(2),
+            Some(1),
+            Some(0),
+            Some(2),
+            Some(1)
+        ]
+    );
+}
+
+#[test]
+fn call_for_population_with_cache() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .with_genes_hashing(true)
+        .build()
+        .unwrap();
+    let mut population: Population<bool> = build::population(vec![
+        vec![true, true, true],
+        vec![true, true, false],
+        vec![true, false, false],
+    ]);
+    let cache = Cache::try_new(10).unwrap();
+    cache.write(population.chromosomes[1].genes_hash().unwrap(), 20);
+
+    let mut fitness = BatchCountTrue::new(4);
+    fitness.call_for_population(&mut population, &genotype, None, Some(&cache));
+
+    assert_eq!(*fitness.batch_sizes.lock().unwrap(), vec![2]);
+    assert_eq!(
+        inspect::population_with_fitness_scores(&population)
+            .into_iter()
+            .map(|(_, fitness_score)| fitness_score)
+            .collect::<Vec<_>>(),
+        vec![Some(3), Some(20), Some(1)]
+    );
+    assert_eq!(
+        cache.read(population.chromosomes[0].genes_hash().unwrap()),
+        Some(3)
+    );
+}
+
+#[test]
+fn call_evolve() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(20)
+        .build()
+        .unwrap();
+    let fitness = BatchCountTrue::new(16);
+    let batch_sizes = fitness.batch_sizes.clone();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(50)
+        .with_target_fitness_score(20)
+        .with_max_stale_generations(100)
+        .with_par_fitness(true)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(fitness)
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    assert_eq!(evolve.best_fitness_score(), Some(20));
+    let batch_sizes = batch_sizes.lock().unwrap();
+    assert_eq!(batch_sizes[0..4], [16, 16, 16, 2]);
+    assert!(batch_sizes.iter().all(|batch_size| *batch_size <= 16));
+}
+
+#[test]
+fn call_for_population_through_wrapper() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut population: Population<bool> = build::population(vec![
+        vec![true, true, true],
+        vec![true, true, false],
+        vec![true, false, false],
+        vec![false, false, false],
+        vec![true, false, true],
+    ]);
+
+    let batch_count_true = BatchCountTrue::new(2);
+    let batch_sizes = batch_count_true.batch_sizes.clone();
+    let mut fitness = FitnessDuplicatePenalty::new(batch_count_true, 10, FitnessOrdering::Maximize);
+    assert_eq!(fitness.batch_size(), Some(2));
+    fitness.call_for_population(&mut population, &genotype, None, None);
+
+    // batched through the wrapper, without falling back to calculate_for_chromosome
+    assert_eq!(*batch_sizes.lock().unwrap(), vec![2, 2, 1]);
+    assert_eq!(
+        inspect::population_with_fitness_scores(&population)
+            .into_iter()
+            .map(|(_, fitness_score)| fitness_score)
+            .collect::<Vec<_>>(),
+        vec![Some(-17), Some(-8), Some(-9), Some(-20), Some(-8)]
+    );
+}
@@ -19,6 +19,11 @@ fn standard() {
     assert_eq!(cache.read(3), None);
     assert_eq!(cache.read(3), None);
     assert_eq!(cache.hit_miss_stats(), (3, 2, 1.5));
+
+    // contains doesn't count
+    assert!(cache.contains(1));
+    assert!(!cache.contains(3));
+    assert_eq!(cache.hit_miss_stats(), (3, 2, 1.5));
 }
 
 #[test]
@@ -0,0 +1,144 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::fitness::{
+    Fitness, FitnessCache, FitnessChromosome, FitnessContext, FitnessGenotype, FitnessValue,
+};
+use genetic_algorithm::strategy::evolve::prelude::*;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug, Default)]
+struct AnnealedCountTrue {
+    contexts: Arc<Mutex<Vec<FitnessContext>>>,
+}
+impl Fitness for AnnealedCountTrue {
+    type Genotype = BinaryGenotype;
+    fn calculate_for_chromosome(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        genotype: &FitnessGenotype<Self>,
+    ) -> Option<FitnessValue> {
+        self.calculate_for_chromosome_with_context(chromosome, genotype, &FitnessContext::default())
+    }
+    fn calculate_for_chromosome_with_context(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        _genotype: &FitnessGenotype<Self>,
+        context: &FitnessContext,
+    ) -> Option<FitnessValue> {
+        self.contexts.lock().unwrap().push(*context);
+        let count = chromosome.genes.iter().filter(|&value| *value).count();
+        Some((count * (context.generation + 1)) as FitnessValue)
+    }
+}
+
+#[test]
+fn call_for_population_default_context() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut population: Population<bool> = build::population(vec![vec![true, false, true]; 2]);
+    let mut fitness = AnnealedCountTrue::default();
+    fitness.call_for_population(&mut population, &genotype, None, None);
+
+    assert_eq!(
+        inspect::population_with_fitness_scores(&population),
+        vec![
+            (vec![true, false, true], Some(2)),
+            (vec![true, false, true], Some(2)),
+        ]
+    );
+    assert_eq!(
+        *fitness.contexts.lock().unwrap(),
+        vec![FitnessContext::default(); 2]
+    );
+}
+
+#[test]
+fn call_evolve_context() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let fitness = AnnealedCountTrue::default();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_generations(5)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(fitness.clone())
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectElite::new(0.5, 0.02))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    let contexts = fitness.contexts.lock().unwrap();
+    let mut generations: Vec<usize> = contexts.iter().map(|context| context.generation).collect();
+    generations.dedup();
+    assert_eq!(generations, vec![0, 1, 2, 3, 4, 5]);
+    // the initial population is evaluated before any best chromosome or cardinality is known
+    assert!(contexts
+        .iter()
+        .filter(|context| context.generation == 0)
+        .all(|context| context.best_fitness_score.is_none()));
+    assert!(contexts
+        .iter()
+        .filter(|context| context.generation > 0)
+        .all(|context| context.best_fitness_score.is_some()
+            && context.population_cardinality.is_some()));
+    assert_eq!(evolve.generations_run(), 5);
+}
+
+#[derive(Clone, Debug, Default)]
+struct CallCountingCountTrue {
+    inner: AnnealedCountTrue,
+    calls: Arc<Mutex<usize>>,
+}
+impl Fitness for CallCountingCountTrue {
+    type Genotype = BinaryGenotype;
+    fn call_for_chromosome(
+        &mut self,
+        chromosome: &mut FitnessChromosome<Self>,
+        genotype: &FitnessGenotype<Self>,
+        cache: Option<&FitnessCache>,
+    ) {
+        *self.calls.lock().unwrap() += 1;
+        self.inner.call_for_chromosome(chromosome, genotype, cache);
+    }
+    fn calculate_for_chromosome(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        genotype: &FitnessGenotype<Self>,
+    ) -> Option<FitnessValue> {
+        self.inner.calculate_for_chromosome(chromosome, genotype)
+    }
+}
+
+#[test]
+fn call_evolve_call_for_chromosome_override() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let fitness = CallCountingCountTrue::default();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_generations(5)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(fitness.clone())
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectElite::new(0.5, 0.02))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    // the strategies call the overridden call_for_chromosome, which still receives the context
+    let calls = *fitness.calls.lock().unwrap();
+    let contexts = fitness.inner.contexts.lock().unwrap();
+    assert!(calls > 0);
+    assert_eq!(contexts.len(), calls);
+    assert_eq!(contexts.last().map(|context| context.generation), Some(5));
+    assert_eq!(evolve.generations_run(), 5);
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::fitness::placeholders::{SumGenes, Zero};
+use genetic_algorithm::fitness::{Fitness, FitnessDuplicatePenalty, FitnessOrdering};
+
+#[test]
+fn duplicates_count() {
+    let fitness = FitnessDuplicatePenalty::new(
+        Zero::<ListGenotype<usize>>::new(),
+        10,
+        FitnessOrdering::Maximize,
+    );
+    assert_eq!(
+        fitness.duplicates_count(&build::chromosome(vec![0, 1, 2, 3])),
+        0
+    );
+    assert_eq!(
+        fitness.duplicates_count(&build::chromosome(vec![0, 1, 1, 3])),
+        1
+    );
+    assert_eq!(
+        fitness.duplicates_count(&build::chromosome(vec![1, 1, 2, 2])),
+        2
+    );
+    assert_eq!(
+        fitness.duplicates_count(&build::chromosome(vec![1, 1, 1, 2])),
+        2
+    );
+}
+
+#[test]
+fn maximize() {
+    let genotype = ListGenotype::<i32>::builder()
+        .with_genes_size(4)
+        .with_allele_list((0..4).collect())
+        .build()
+        .unwrap();
+    let mut fitness = FitnessDuplicatePenalty::new(SumGenes::new(), 10, FitnessOrdering::Maximize);
+
+    let unique = build::chromosome(vec![0, 1, 2, 3]);
+    let duplicates = build::chromosome(vec![0, 0, 3, 3]);
+    assert_eq!(
+        fitness.calculate_for_chromosome(&unique, &genotype),
+        Some(6)
+    );
+    assert_eq!(
+        fitness.calculate_for_chromosome(&duplicates, &genotype),
+        Some(6 - 2 * 10)
+    );
+}
+
+#[test]
+fn minimize() {
+    let genotype = ListGenotype::<i32>::builder()
+        .with_genes_size(4)
+        .with_allele_list((0..4).collect())
+        .build()
+        .unwrap();
+    let mut fitness = FitnessDuplicatePenalty::new(SumGenes::new(), 10, FitnessOrdering::Minimize);
+
+    let unique = build::chromosome(vec![0, 1, 2, 3]);
+    let duplicates = build::chromosome(vec![0, 0, 3, 3]);
+    assert_eq!(
+        fitness.calculate_for_chromosome(&unique, &genotype),
+        Some(6)
+    );
+    assert_eq!(
+        fitness.calculate_for_chromosome(&duplicates, &genotype),
+        Some(6 + 2 * 10)
+    );
+}
@@ -1,2 +1,10 @@
+pub mod batch_test;
 pub mod cache_test;
+pub mod context_test;
+pub mod duplicate_penalty_test;
+pub mod nonfinite_policy_test;
+pub mod ordering_test;
 pub mod placeholders_test;
+pub mod progress_test;
+pub mod resampling_test;
+pub mod weighted_objectives_test;
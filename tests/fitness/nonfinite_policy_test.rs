@@ -0,0 +1,129 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::fitness::placeholders::SumGenes;
+use genetic_algorithm::strategy::evolve::prelude::*;
+
+#[test]
+fn to_fitness_value() {
+    let policy = FitnessNonFinitePolicy::TreatAsWorst;
+    assert_eq!(
+        policy.to_fitness_value(1.9, FitnessOrdering::Maximize),
+        Some(1)
+    );
+    assert_eq!(
+        policy.to_fitness_value(f64::NAN, FitnessOrdering::Maximize),
+        Some(FitnessValue::MIN)
+    );
+    assert_eq!(
+        policy.to_fitness_value(f64::INFINITY, FitnessOrdering::Maximize),
+        Some(FitnessValue::MIN)
+    );
+    assert_eq!(
+        policy.to_fitness_value(f64::NEG_INFINITY, FitnessOrdering::Minimize),
+        Some(FitnessValue::MAX)
+    );
+
+    let policy = FitnessNonFinitePolicy::Skip;
+    assert_eq!(
+        policy.to_fitness_value(-1.9, FitnessOrdering::Minimize),
+        Some(-1)
+    );
+    assert_eq!(
+        policy.to_fitness_value(f64::NAN, FitnessOrdering::Maximize),
+        None
+    );
+    assert_eq!(
+        policy.to_fitness_value(f64::INFINITY, FitnessOrdering::Minimize),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "non-finite fitness value: NaN")]
+fn to_fitness_value_error() {
+    FitnessNonFinitePolicy::Error.to_fitness_value(f64::NAN, FitnessOrdering::Maximize);
+}
+
+#[test]
+fn sum_genes() {
+    let genotype = RangeGenotype::<f64>::builder()
+        .with_genes_size(3)
+        .with_allele_range(0.0..=1.0)
+        .build()
+        .unwrap();
+    let finite = build::chromosome(vec![0.0, 1.0, 1.0]);
+    let nan = build::chromosome(vec![0.0, 1.0, f64::NAN]);
+    let infinite = build::chromosome(vec![0.0, 1.0, f64::INFINITY]);
+
+    let mut fitness = SumGenes::new();
+    assert_eq!(
+        fitness.calculate_for_chromosome(&finite, &genotype),
+        Some(2)
+    );
+    assert_eq!(
+        fitness.calculate_for_chromosome(&nan, &genotype),
+        Some(FitnessValue::MIN)
+    );
+    assert_eq!(
+        fitness.calculate_for_chromosome(&infinite, &genotype),
+        Some(FitnessValue::MIN)
+    );
+
+    let mut fitness = SumGenes::new().with_fitness_ordering(FitnessOrdering::Minimize);
+    assert_eq!(
+        fitness.calculate_for_chromosome(&infinite, &genotype),
+        Some(FitnessValue::MAX)
+    );
+
+    let mut fitness = SumGenes::new().with_nonfinite_fitness(FitnessNonFinitePolicy::Skip);
+    assert_eq!(
+        fitness.calculate_for_chromosome(&finite, &genotype),
+        Some(2)
+    );
+    assert_eq!(fitness.calculate_for_chromosome(&nan, &genotype), None);
+}
+
+/// Sum of the genes, but NaN when the first gene is above 0.5
+#[derive(Clone, Debug)]
+struct SumGenesNanAboveHalf;
+impl Fitness for SumGenesNanAboveHalf {
+    type Genotype = RangeGenotype<f64>;
+    fn calculate_for_chromosome(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        _genotype: &FitnessGenotype<Self>,
+    ) -> Option<FitnessValue> {
+        let value = if chromosome.genes[0] > 0.5 {
+            f64::NAN
+        } else {
+            chromosome.genes.iter().sum::<f64>() * 100.0
+        };
+        FitnessNonFinitePolicy::TreatAsWorst.to_fitness_value(value, FitnessOrdering::Maximize)
+    }
+}
+
+#[test]
+fn call_evolve_nan_never_becomes_best() {
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(5)
+        .with_allele_range(0.0..=1.0)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(SumGenesNanAboveHalf)
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectElite::new(0.5, 0.02))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    let best_genes = evolve.best_genes().unwrap();
+    assert!(best_genes[0] <= 0.5);
+    let best_fitness_score = evolve.best_fitness_score().unwrap();
+    assert!(best_fitness_score > FitnessValue::MIN);
+    assert!(best_fitness_score <= 450);
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+use genetic_algorithm::fitness::FitnessOrdering;
+
+#[test]
+fn is_better_maximize() {
+    let ordering = FitnessOrdering::Maximize;
+    assert!(ordering.is_better(Some(2), Some(1)));
+    assert!(!ordering.is_better(Some(1), Some(2)));
+    assert!(!ordering.is_better(Some(1), Some(1)));
+    assert!(ordering.is_better(Some(-5), None));
+    assert!(!ordering.is_better(None, Some(-5)));
+    assert!(!ordering.is_better(None, None));
+}
+
+#[test]
+fn is_better_minimize() {
+    let ordering = FitnessOrdering::Minimize;
+    assert!(ordering.is_better(Some(1), Some(2)));
+    assert!(!ordering.is_better(Some(2), Some(1)));
+    assert!(!ordering.is_better(Some(1), Some(1)));
+    assert!(ordering.is_better(Some(5), None));
+    assert!(!ordering.is_better(None, Some(5)));
+    assert!(!ordering.is_better(None, None));
+}
+
+#[test]
+fn better_of_maximize() {
+    let ordering = FitnessOrdering::Maximize;
+    assert_eq!(ordering.better_of(Some(2), Some(1)), Some(2));
+    assert_eq!(ordering.better_of(Some(1), Some(2)), Some(2));
+    assert_eq!(ordering.better_of(Some(1), Some(1)), Some(1));
+    assert_eq!(ordering.better_of(Some(-5), None), Some(-5));
+    assert_eq!(ordering.better_of(None, Some(-5)), Some(-5));
+    assert_eq!(ordering.better_of(None, None), None);
+}
+
+#[test]
+fn better_of_minimize() {
+    let ordering = FitnessOrdering::Minimize;
+    assert_eq!(ordering.better_of(Some(2), Some(1)), Some(1));
+    assert_eq!(ordering.better_of(Some(1), Some(2)), Some(1));
+    assert_eq!(ordering.better_of(Some(1), Some(1)), Some(1));
+    assert_eq!(ordering.better_of(Some(5), None), Some(5));
+    assert_eq!(ordering.better_of(None, Some(5)), Some(5));
+    assert_eq!(ordering.better_of(None, None), None);
+}
+
+#[test]
+fn dominates() {
+    let ordering = FitnessOrdering::Maximize;
+    assert!(ordering.dominates(&[2, 3], &[1, 3]));
+    assert!(!ordering.dominates(&[1, 3], &[2, 3]));
+    assert!(!ordering.dominates(&[2, 3], &[2, 3]));
+    assert!(!ordering.dominates(&[2, 1], &[1, 2]));
+    assert!(!ordering.dominates(&[2, 3], &[1]));
+
+    let ordering = FitnessOrdering::Minimize;
+    assert!(ordering.dominates(&[1, 3], &[2, 3]));
+    assert!(!ordering.dominates(&[2, 3], &[1, 3]));
+    assert!(!ordering.dominates(&[2, 3], &[2, 3]));
+    assert!(!ordering.dominates(&[2, 1], &[1, 2]));
+}
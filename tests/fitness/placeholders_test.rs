@@ -2,9 +2,10 @@
 use crate::support::*;
 use genetic_algorithm::chromosome::Chromosome;
 use genetic_algorithm::fitness::placeholders::{
-    CountTrue, CountTrueWithSleep, Countdown, CountdownNoisy, SumGenes, Zero,
+    CountTrue, CountTrueWithSleep, Countdown, CountdownNoisy, SumGenes, TourDistance, Zero,
 };
 use genetic_algorithm::fitness::Fitness;
+use genetic_algorithm::genotype::UniqueGenotype;
 
 #[test]
 fn binary_genotype() {
@@ -223,3 +224,42 @@ fn range_genotype_f64() {
         Some(7199)
     );
 }
+
+#[test]
+fn unique_genotype_tour_distance() {
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list(vec![0, 1, 2, 3])
+        .build()
+        .unwrap();
+    // 4 cities on the corners of a 3x4 rectangle, the perimeter is the optimal tour (14.0)
+    let distance_matrix = vec![
+        vec![0.0, 3.0, 5.0, 4.0],
+        vec![3.0, 0.0, 4.0, 5.0],
+        vec![5.0, 4.0, 0.0, 3.0],
+        vec![4.0, 5.0, 3.0, 0.0],
+    ];
+    let mut fitness = TourDistance::new(distance_matrix.clone());
+
+    let chromosome: Chromosome<usize> = build::chromosome(vec![0, 1, 2, 3]);
+    assert_eq!(
+        fitness.calculate_for_chromosome(&chromosome, &genotype),
+        Some(14)
+    );
+    let chromosome: Chromosome<usize> = build::chromosome(vec![2, 1, 0, 3]);
+    assert_eq!(
+        fitness.calculate_for_chromosome(&chromosome, &genotype),
+        Some(14)
+    );
+    let chromosome: Chromosome<usize> = build::chromosome(vec![0, 2, 1, 3]);
+    assert_eq!(
+        fitness.calculate_for_chromosome(&chromosome, &genotype),
+        Some(18)
+    );
+
+    let mut fitness = TourDistance::new_with_precision(distance_matrix, 0.5);
+    let chromosome: Chromosome<usize> = build::chromosome(vec![0, 1, 2, 3]);
+    assert_eq!(
+        fitness.calculate_for_chromosome(&chromosome, &genotype),
+        Some(28)
+    );
+}
@@ -0,0 +1,203 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::fitness::placeholders::CountTrue;
+use genetic_algorithm::fitness::{Fitness, FitnessChromosome, FitnessGenotype, FitnessValue};
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::ThreadId;
+use thread_local::ThreadLocal;
+
+#[derive(Clone, Debug, Default)]
+struct ThreadRecorder {
+    thread_ids: Arc<Mutex<HashSet<ThreadId>>>,
+}
+impl Fitness for ThreadRecorder {
+    type Genotype = BinaryGenotype;
+    fn calculate_for_chromosome(
+        &mut self,
+        _chromosome: &FitnessChromosome<Self>,
+        _genotype: &FitnessGenotype<Self>,
+    ) -> Option<FitnessValue> {
+        self.thread_ids
+            .lock()
+            .unwrap()
+            .insert(std::thread::current().id());
+        Some(0)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct ChunkRecorder {
+    thread_ids: Arc<Mutex<HashMap<Vec<bool>, ThreadId>>>,
+}
+impl Fitness for ChunkRecorder {
+    type Genotype = BinaryGenotype;
+    fn calculate_for_chromosome(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        _genotype: &FitnessGenotype<Self>,
+    ) -> Option<FitnessValue> {
+        self.thread_ids
+            .lock()
+            .unwrap()
+            .insert(chromosome.genes.clone(), std::thread::current().id());
+        Some(0)
+    }
+}
+
+#[test]
+fn progress_single_threaded() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut population: Population<bool> = build::population(vec![vec![true, false, true]; 10]);
+
+    let calls = AtomicUsize::new(0);
+    let max_progress = AtomicUsize::new(0);
+    let progress = |count: usize| {
+        calls.fetch_add(1, Ordering::Relaxed);
+        max_progress.fetch_max(count, Ordering::Relaxed);
+    };
+    CountTrue.call_for_population_with_progress(
+        &mut population,
+        &genotype,
+        None,
+        None,
+        None,
+        Some(&progress),
+        None,
+    );
+
+    assert_eq!(calls.load(Ordering::Relaxed), 10);
+    assert_eq!(max_progress.load(Ordering::Relaxed), 10);
+    assert!(population
+        .chromosomes
+        .iter()
+        .all(|c| c.fitness_score() == Some(2)));
+}
+
+#[test]
+fn progress_multi_threaded() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut population: Population<bool> = build::population(vec![vec![true, false, true]; 100]);
+
+    let calls = AtomicUsize::new(0);
+    let max_progress = AtomicUsize::new(0);
+    let progress = |count: usize| {
+        calls.fetch_add(1, Ordering::Relaxed);
+        max_progress.fetch_max(count, Ordering::Relaxed);
+    };
+    let thread_local = ThreadLocal::new();
+    CountTrue.call_for_population_with_progress(
+        &mut population,
+        &genotype,
+        Some(&thread_local),
+        None,
+        Some(10),
+        Some(&progress),
+        None,
+    );
+
+    assert_eq!(calls.load(Ordering::Relaxed), 100);
+    assert_eq!(max_progress.load(Ordering::Relaxed), 100);
+    assert!(population
+        .chromosomes
+        .iter()
+        .all(|c| c.fitness_score() == Some(2)));
+}
+
+#[test]
+fn chunk_size_multi_threaded() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut population: Population<bool> = build::population(vec![vec![true, false, true]; 100]);
+
+    // a single chunk for the whole population is never split over threads
+    let mut fitness = ThreadRecorder::default();
+    let thread_local: ThreadLocal<RefCell<ThreadRecorder>> = ThreadLocal::new();
+    fitness.call_for_population_with_progress(
+        &mut population,
+        &genotype,
+        Some(&thread_local),
+        None,
+        Some(100),
+        None,
+        None,
+    );
+
+    assert_eq!(fitness.thread_ids.lock().unwrap().len(), 1);
+    assert!(population
+        .chromosomes
+        .iter()
+        .all(|c| c.fitness_score() == Some(0)));
+}
+
+#[test]
+fn chunk_size_consecutive_chromosomes() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(7)
+        .build()
+        .unwrap();
+    // unique genes per chromosome, the bits of its index
+    let genes_list: Vec<Vec<bool>> = (0..100)
+        .map(|index: usize| (0..7).map(|bit| index >> bit & 1 == 1).collect())
+        .collect();
+    let mut population: Population<bool> = build::population(genes_list.clone());
+
+    // each chunk of 10 consecutive chromosomes is calculated by a single thread
+    let mut fitness = ChunkRecorder::default();
+    let thread_local: ThreadLocal<RefCell<ChunkRecorder>> = ThreadLocal::new();
+    fitness.call_for_population_with_progress(
+        &mut population,
+        &genotype,
+        Some(&thread_local),
+        None,
+        Some(10),
+        None,
+        None,
+    );
+
+    let thread_ids = fitness.thread_ids.lock().unwrap();
+    assert_eq!(thread_ids.len(), 100);
+    genes_list.chunks(10).for_each(|chunk| {
+        let chunk_thread_ids: HashSet<&ThreadId> =
+            chunk.iter().map(|genes| &thread_ids[genes]).collect();
+        assert_eq!(chunk_thread_ids.len(), 1);
+    });
+}
+
+#[test]
+fn chunk_size_from_config() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut state = EvolveState::new(&genotype);
+    state.population = build::population(vec![vec![true, false, true]; 100]);
+    let config = EvolveConfig {
+        par_fitness: true,
+        fitness_chunk_size: Some(100),
+        ..Default::default()
+    };
+
+    // the configured chunk size covers the whole population, so it is never split over threads
+    let mut fitness = ThreadRecorder::default();
+    let thread_local: ThreadLocal<RefCell<ThreadRecorder>> = ThreadLocal::new();
+    fitness.call_for_state_population(&genotype, &mut state, &config, Some(&thread_local));
+
+    assert_eq!(fitness.thread_ids.lock().unwrap().len(), 1);
+    assert!(state
+        .population
+        .chromosomes
+        .iter()
+        .all(|c| c.fitness_score() == Some(0)));
+}
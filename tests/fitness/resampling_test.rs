@@ -0,0 +1,150 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::fitness::{
+    Fitness, FitnessChromosome, FitnessContext, FitnessGenotype, FitnessResampling,
+    FitnessResamplingAggregation, FitnessValue,
+};
+use genetic_algorithm::strategy::evolve::prelude::*;
+
+/// Deterministic noise, cycling through the offsets -1, 0 and +4 per call
+#[derive(Clone, Debug, Default)]
+struct NoisyCountTrue {
+    calls: usize,
+}
+impl Fitness for NoisyCountTrue {
+    type Genotype = BinaryGenotype;
+    fn calculate_for_chromosome(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        _genotype: &FitnessGenotype<Self>,
+    ) -> Option<FitnessValue> {
+        let noise = [-1, 0, 4][self.calls % 3];
+        self.calls += 1;
+        let count = chromosome.genes.iter().filter(|&value| *value).count();
+        Some(count as FitnessValue + noise)
+    }
+}
+
+#[test]
+fn aggregate_mean() {
+    let resampling = FitnessResampling::new(4);
+    assert_eq!(
+        resampling.aggregate(&[Some(1), Some(2), Some(3), Some(6)]),
+        Some(3)
+    );
+    assert_eq!(resampling.aggregate(&[Some(-1), Some(-2)]), Some(-1));
+    assert_eq!(
+        resampling.aggregate(&[Some(1), None, Some(3), None]),
+        Some(2)
+    );
+    assert_eq!(resampling.aggregate(&[None, None]), None);
+    assert_eq!(resampling.aggregate(&[]), None);
+}
+
+#[test]
+fn aggregate_median() {
+    let mut resampling = FitnessResampling::new(4);
+    resampling.aggregation = FitnessResamplingAggregation::Median;
+    assert_eq!(resampling.aggregate(&[Some(6), Some(1), Some(3)]), Some(3));
+    assert_eq!(
+        resampling.aggregate(&[Some(1), Some(2), Some(4), Some(100)]),
+        Some(3)
+    );
+    assert_eq!(
+        resampling.aggregate(&[Some(100), None, Some(2), Some(1)]),
+        Some(2)
+    );
+    assert_eq!(resampling.aggregate(&[None]), None);
+}
+
+#[test]
+fn call_for_chromosome() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut fitness = NoisyCountTrue::default();
+    let mut chromosome = build::chromosome(vec![true, false, true]);
+
+    let mut resampling = FitnessResampling::new(3);
+    resampling.call_for_chromosome(
+        &mut fitness,
+        &mut chromosome,
+        &genotype,
+        &FitnessContext::default(),
+    );
+    assert_eq!(chromosome.fitness_score(), Some(3));
+    assert_eq!(fitness.calls, 3);
+
+    resampling.aggregation = FitnessResamplingAggregation::Median;
+    resampling.call_for_chromosome(
+        &mut fitness,
+        &mut chromosome,
+        &genotype,
+        &FitnessContext::default(),
+    );
+    assert_eq!(chromosome.fitness_score(), Some(2));
+    assert_eq!(fitness.calls, 6);
+}
+
+#[test]
+fn call_for_state_population() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut fitness = NoisyCountTrue::default();
+    let mut state = EvolveState::new(&genotype);
+    state.population = build::population_with_fitness_scores(vec![
+        (vec![false, false, false], None),
+        (vec![true, false, false], Some(10)),
+        (vec![true, true, false], None),
+        (vec![true, true, true], None),
+    ]);
+    let config = EvolveConfig::new();
+
+    let mut resampling = FitnessResampling::new(3);
+    resampling.aggregation = FitnessResamplingAggregation::Median;
+    resampling.call_for_state_population(&mut fitness, &genotype, &mut state, &config, None);
+
+    assert_eq!(
+        inspect::population_with_fitness_scores(&state.population),
+        vec![
+            (vec![false, false, false], Some(0)),
+            (vec![true, false, false], Some(10)),
+            (vec![true, true, false], Some(2)),
+            (vec![true, true, true], Some(3)),
+        ]
+    );
+    assert_eq!(state.fitness_evaluations, 9);
+    assert_eq!(fitness.calls, 9);
+}
+
+#[test]
+fn call_evolve_median() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_target_fitness_score(9)
+        .with_max_stale_generations(100)
+        // order independent, stored on the builder until build
+        .with_fitness_resampling_aggregation(FitnessResamplingAggregation::Median)
+        .with_fitness_resampling_best_interval(5)
+        .with_fitness_resampling(3)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(NoisyCountTrue::default())
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    // the median of the three samples is noise free
+    assert_eq!(evolve.best_fitness_score(), Some(9));
+    assert_eq!(evolve.state.fitness_evaluations % 3, 0);
+}
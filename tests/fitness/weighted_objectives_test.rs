@@ -0,0 +1,132 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::fitness::{Fitness, FitnessValue, FitnessWeightedObjectives};
+
+type Objective = Box<dyn Fn(&Chromosome<i32>) -> FitnessValue + Send + Sync>;
+
+fn sum_genes(chromosome: &Chromosome<i32>) -> FitnessValue {
+    chromosome.genes.iter().sum::<i32>() as FitnessValue
+}
+fn max_gene(chromosome: &Chromosome<i32>) -> FitnessValue {
+    chromosome.genes.iter().copied().max().unwrap_or(0) as FitnessValue
+}
+
+#[test]
+fn weighted_sum() {
+    let genotype = ListGenotype::<i32>::builder()
+        .with_genes_size(4)
+        .with_allele_list((0..10).collect())
+        .build()
+        .unwrap();
+    let sum_genes: Objective = Box::new(sum_genes);
+    let max_gene: Objective = Box::new(max_gene);
+    let mut fitness = FitnessWeightedObjectives::<ListGenotype<i32>>::new(vec![
+        (sum_genes, 2.0),
+        (max_gene, -0.5),
+    ]);
+
+    let chromosome = build::chromosome(vec![1, 2, 3, 9]);
+    assert_eq!(fitness.weighted_sum(&chromosome), 15.0 * 2.0 - 9.0 * 0.5);
+    assert_eq!(
+        fitness.calculate_for_chromosome(&chromosome, &genotype),
+        Some(26) // 25.5 rounded
+    );
+
+    let chromosome = build::chromosome(vec![0, 0, 0, 4]);
+    assert_eq!(
+        fitness.calculate_for_chromosome(&chromosome, &genotype),
+        Some(4 * 2 - 2)
+    );
+}
+
+#[test]
+fn with_objective() {
+    let genotype = ListGenotype::<i32>::builder()
+        .with_genes_size(4)
+        .with_allele_list((0..10).collect())
+        .build()
+        .unwrap();
+    let mut fitness = FitnessWeightedObjectives::<ListGenotype<i32>>::new(vec![])
+        .with_objective(sum_genes, 1.0)
+        .with_objective(|c| c.genes.len() as FitnessValue, 10.0);
+
+    let chromosome = build::chromosome(vec![1, 2, 3, 4]);
+    assert_eq!(
+        fitness.calculate_for_chromosome(&chromosome, &genotype),
+        Some(10 + 40)
+    );
+    assert_eq!(fitness.clone().objectives.len(), 2);
+}
+
+#[test]
+fn with_normalized_objective() {
+    let genotype = ListGenotype::<i32>::builder()
+        .with_genes_size(2)
+        .with_allele_list((0..10).collect())
+        .build()
+        .unwrap();
+    let first_best = build::chromosome(vec![9, 0]);
+    let second_best = build::chromosome(vec![0, 9]);
+
+    // without normalization the second objective (a million times larger) dominates
+    let mut raw_fitness = FitnessWeightedObjectives::<ListGenotype<i32>>::new(vec![])
+        .with_objective(|c| c.genes[0] as FitnessValue, 1.0)
+        .with_objective(|c| c.genes[1] as FitnessValue * 1_000_000, 1.0);
+    assert_eq!(
+        raw_fitness.calculate_for_chromosome(&first_best, &genotype),
+        Some(9)
+    );
+    assert_eq!(
+        raw_fitness.calculate_for_chromosome(&second_best, &genotype),
+        Some(9_000_000)
+    );
+
+    // with normalization both objectives contribute equally
+    let mut normalized_fitness = FitnessWeightedObjectives::<ListGenotype<i32>>::new(vec![])
+        .with_normalized_objective(|c| c.genes[0] as FitnessValue, 1.0, (0, 9))
+        .with_normalized_objective(
+            |c| c.genes[1] as FitnessValue * 1_000_000,
+            1.0,
+            (0, 9_000_000),
+        );
+    assert_eq!(
+        normalized_fitness.objective_bounds,
+        vec![Some((0, 9)), Some((0, 9_000_000))]
+    );
+    assert_eq!(
+        normalized_fitness.calculate_for_chromosome(&first_best, &genotype),
+        Some(1000)
+    );
+    assert_eq!(
+        normalized_fitness.calculate_for_chromosome(&second_best, &genotype),
+        Some(1000)
+    );
+    assert_eq!(
+        normalized_fitness.calculate_for_chromosome(&build::chromosome(vec![3, 6]), &genotype),
+        Some(1000)
+    );
+    // fixed bounds, so the score doesn't depend on the earlier calculations
+    assert_eq!(
+        normalized_fitness.calculate_for_chromosome(&first_best, &genotype),
+        Some(1000)
+    );
+    assert_eq!(
+        normalized_fitness
+            .clone()
+            .calculate_for_chromosome(&build::chromosome(vec![0, 0]), &genotype),
+        Some(0)
+    );
+
+    // mixed with a plain objective, outside values clamped to the bounds
+    let mut mixed_fitness = FitnessWeightedObjectives::<ListGenotype<i32>>::new(vec![])
+        .with_objective(|c| c.genes[0] as FitnessValue, 1.0)
+        .with_normalized_objective(|c| c.genes[1] as FitnessValue, 1.0, (0, 4));
+    assert_eq!(
+        mixed_fitness.calculate_for_chromosome(&build::chromosome(vec![3, 2]), &genotype),
+        Some(3 + 500)
+    );
+    assert_eq!(
+        mixed_fitness.calculate_for_chromosome(&build::chromosome(vec![3, 9]), &genotype),
+        Some(3 + 1000)
+    );
+}
@@ -1,7 +1,8 @@
 #[cfg(test)]
 use crate::support::*;
 use genetic_algorithm::genotype::{
-    BinaryGenotype, EvolveGenotype, Genotype, HillClimbGenotype, PermutateGenotype,
+    BinaryGenotype, EvolveGenotype, Genotype, GenotypeDistance, HillClimbGenotype,
+    PermutateGenotype, TryFromGenotypeBuilderError,
 };
 
 #[test]
@@ -78,6 +79,56 @@ fn mutate_chromosome_genes_without_duplicates() {
         vec![true, true, false, false, false, true, true, false, false, true]
     );
 }
+#[test]
+fn mutate_chromosome_genes_with_genes_mutation_weights() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .with_genes_mutation_weights(vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 3.0])
+        .build()
+        .unwrap();
+
+    assert!((0..100).all(|_| genotype.sample_gene_index(&mut rng) >= 8));
+    assert!(genotype
+        .sample_gene_indices(100, true, &mut rng)
+        .iter()
+        .all(|index| *index >= 8));
+
+    // only the two positive weighted genes can mutate, also when asking for more
+    let mut chromosome = build::chromosome(vec![true; 10]);
+    genotype.mutate_chromosome_genes(5, false, &mut chromosome, &mut rng);
+    assert_eq!(
+        inspect::chromosome(&chromosome),
+        vec![true, true, true, true, true, true, true, true, false, false]
+    );
+
+    // the heavier weighted last gene mutates about three times as often
+    let mut counts = [0; 10];
+    genotype
+        .sample_gene_indices(4000, true, &mut rng)
+        .into_iter()
+        .for_each(|index| counts[index] += 1);
+    assert!(counts[9] > 2 * counts[8]);
+}
+#[test]
+fn build_invalid_genes_mutation_weights() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .with_genes_mutation_weights(vec![1.0, 1.0])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "BinaryGenotype requires non-negative genes_mutation_weights of genes_size length with a positive sum"
+        ))
+    );
+
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .with_genes_mutation_weights(vec![0.0, 0.0, 0.0])
+        .build();
+    assert!(genotype.is_err());
+}
 
 #[test]
 fn crossover_chromosome_pair_single_gene() {
@@ -559,3 +610,39 @@ fn calculate_genes_hash() {
     // Different genes should have different hash
     assert_ne!(hash_1, hash_3);
 }
+
+#[test]
+fn genes_iter_and_slice() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+
+    let chromosome = genotype.chromosome_constructor_random(&mut rng);
+    assert_eq!(
+        genotype
+            .genes_iter(&chromosome)
+            .copied()
+            .collect::<Vec<_>>(),
+        chromosome.genes
+    );
+    assert_eq!(genotype.genes_slice(&chromosome), &chromosome.genes[..]);
+}
+
+#[test]
+fn genes_distance() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(4)
+        .build()
+        .unwrap();
+
+    let genes_a = vec![true, true, false, false];
+    let genes_b = vec![true, false, true, false];
+    assert_eq!(genotype.genes_distance(&genes_a, &genes_a), 0.0);
+    assert_eq!(genotype.genes_distance(&genes_a, &genes_b), 2.0);
+    assert_eq!(
+        genotype.chromosome_distance(&build::chromosome(genes_a), &build::chromosome(genes_b)),
+        2.0
+    );
+}
@@ -0,0 +1,238 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::genotype::{
+    EvolveGenotype, Genotype, GrayCodeGenotype, HillClimbGenotype, PermutateGenotype,
+    TryFromGenotypeBuilderError,
+};
+
+fn assert_in_ranges(values: &[i64], integer_ranges: &[std::ops::RangeInclusive<i64>]) {
+    assert_eq!(values.len(), integer_ranges.len());
+    values
+        .iter()
+        .zip(integer_ranges)
+        .for_each(|(value, range)| assert!(range.contains(value), "{:?}", values));
+}
+
+#[test]
+fn build_invalid() {
+    let genotype = GrayCodeGenotype::builder().build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "GrayCodeGenotype requires integer_ranges"
+        ))
+    );
+
+    let genotype = GrayCodeGenotype::builder()
+        .with_integer_ranges(vec![])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "GrayCodeGenotype requires non-empty integer_ranges"
+        ))
+    );
+
+    let genotype = GrayCodeGenotype::builder()
+        .with_integer_ranges(vec![0..=3, 5..=5])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "GrayCodeGenotype requires integer_ranges with start < end"
+        ))
+    );
+
+    let genotype = GrayCodeGenotype::builder()
+        .with_integer_ranges(vec![0..=7, -10..=10])
+        .with_seed_genes_list(vec![vec![true; 8], vec![true; 7]])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "GrayCodeGenotype requires seed_genes_list of genes_size length"
+        ))
+    );
+}
+
+#[test]
+fn encode_decode() {
+    let genotype = GrayCodeGenotype::builder()
+        .with_integer_ranges(vec![0..=7, -10..=10])
+        .build()
+        .unwrap();
+    assert_eq!(genotype.bit_sizes, vec![3, 5]);
+    assert_eq!(genotype.genes_size(), 8);
+
+    assert_eq!(
+        genotype.encode(&[0, -10]),
+        vec![false, false, false, false, false, false, false, false]
+    );
+    assert_eq!(
+        genotype.encode(&[2, -6]),
+        vec![false, true, true, false, false, true, true, false]
+    );
+    // clamped to range
+    assert_eq!(genotype.decode(&genotype.encode(&[9, 20])), vec![7, 10]);
+    // bit strings beyond the range end wrap around to the range start
+    assert_eq!(
+        genotype.decode(&[true, false, false, true, true, true, true, true]),
+        vec![7, -10]
+    );
+
+    for value in 0..=7 {
+        for other in -10..=10 {
+            assert_eq!(
+                genotype.decode(&genotype.encode(&[value, other])),
+                vec![value, other]
+            );
+        }
+    }
+    // no hamming cliffs, adjacent values differ in a single bit
+    for value in -10..10 {
+        let genes = genotype.encode(&[0, value]);
+        let next_genes = genotype.encode(&[0, value + 1]);
+        let differences = genes
+            .iter()
+            .zip(next_genes.iter())
+            .filter(|(a, b)| a != b)
+            .count();
+        assert_eq!(differences, 1, "{} {:?} {:?}", value, genes, next_genes);
+    }
+}
+
+#[test]
+fn decode_distribution() {
+    let genotype = GrayCodeGenotype::builder()
+        .with_integer_ranges(vec![0..=16])
+        .build()
+        .unwrap();
+    assert_eq!(genotype.genes_size(), 5);
+
+    // all 32 bit strings, each value is decoded from one or two of them
+    let mut counts = vec![0; 17];
+    for code in 0..32u32 {
+        let genes: Vec<bool> = (0..5).rev().map(|bit| (code >> bit) & 1 == 1).collect();
+        let values = genotype.decode(&genes);
+        assert!((0..=16).contains(&values[0]), "{:?}", values);
+        counts[values[0] as usize] += 1;
+    }
+    assert_eq!(
+        counts,
+        vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1]
+    );
+}
+
+#[test]
+fn random_genes_factory() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let integer_ranges = vec![0..=7, -10..=10, 100..=102];
+    let genotype = GrayCodeGenotype::builder()
+        .with_integer_ranges(integer_ranges.clone())
+        .build()
+        .unwrap();
+    assert_eq!(genotype.genes_size(), 10);
+
+    for _ in 0..20 {
+        let genes = genotype.random_genes_factory(&mut rng);
+        assert_eq!(genes.len(), 10);
+        assert_in_ranges(&genotype.decode(&genes), &integer_ranges);
+    }
+}
+
+#[test]
+fn mutate_chromosome_flips_genes() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = GrayCodeGenotype::builder()
+        .with_integer_ranges(vec![0..=7, -10..=10])
+        .build()
+        .unwrap();
+
+    let mut chromosome = build::chromosome(genotype.encode(&[3, 0]));
+    genotype.mutate_chromosome_genes_at(&[2], &mut chromosome, &mut rng);
+    assert_eq!(genotype.decode(&chromosome.genes), vec![2, 0]);
+    genotype.mutate_chromosome_genes_at(&[7], &mut chromosome, &mut rng);
+    assert_eq!(genotype.decode(&chromosome.genes), vec![2, 1]);
+
+    for _ in 0..20 {
+        let before = chromosome.genes.clone();
+        genotype.mutate_chromosome_genes(1, true, &mut chromosome, &mut rng);
+        let differences = before
+            .iter()
+            .zip(chromosome.genes.iter())
+            .filter(|(a, b)| a != b)
+            .count();
+        assert_eq!(differences, 1);
+    }
+}
+
+#[test]
+fn crossover_chromosome_keeps_ranges() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let integer_ranges = vec![0..=7, -10..=10];
+    let genotype = GrayCodeGenotype::builder()
+        .with_integer_ranges(integer_ranges.clone())
+        .build()
+        .unwrap();
+
+    let mut father = build::chromosome(genotype.encode(&[0, -10]));
+    let mut mother = build::chromosome(genotype.encode(&[5, 10]));
+    for _ in 0..50 {
+        genotype.crossover_chromosome_genes(2, true, &mut father, &mut mother, &mut rng);
+        assert_in_ranges(&genotype.decode(&father.genes), &integer_ranges);
+        assert_in_ranges(&genotype.decode(&mother.genes), &integer_ranges);
+        genotype.crossover_chromosome_points(2, false, &mut father, &mut mother, &mut rng);
+        assert_in_ranges(&genotype.decode(&father.genes), &integer_ranges);
+        assert_in_ranges(&genotype.decode(&mother.genes), &integer_ranges);
+    }
+}
+
+#[test]
+fn neighbouring_population() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = GrayCodeGenotype::builder()
+        .with_integer_ranges(vec![0..=3, 0..=2])
+        .build()
+        .unwrap();
+
+    let chromosome = build::chromosome(genotype.encode(&[1, 1]));
+    assert_eq!(genotype.neighbouring_population_size(), BigUint::from(4u32));
+
+    let mut population = Population::new(vec![], true);
+    genotype.fill_neighbouring_population(&chromosome, &mut population, &mut rng);
+    let values: Vec<Vec<i64>> = population
+        .chromosomes
+        .iter()
+        .map(|c| genotype.decode(&c.genes))
+        .collect();
+    assert_eq!(values, vec![vec![2, 1], vec![0, 1], vec![1, 2], vec![1, 0]]);
+}
+
+#[test]
+fn chromosome_permutations() {
+    let integer_ranges = vec![0..=2, -1..=1];
+    let genotype = GrayCodeGenotype::builder()
+        .with_integer_ranges(integer_ranges.clone())
+        .build()
+        .unwrap();
+
+    assert_eq!(genotype.chromosome_permutations_size(), BigUint::from(9u32));
+    let values: Vec<Vec<i64>> = genotype
+        .chromosome_permutations_into_iter(None)
+        .map(|c| genotype.decode(&c.genes))
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            vec![0, -1],
+            vec![0, 0],
+            vec![0, 1],
+            vec![1, -1],
+            vec![1, 0],
+            vec![1, 1],
+            vec![2, -1],
+            vec![2, 0],
+            vec![2, 1],
+        ]
+    );
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::genotype::{
+    Genotype, HillClimbGenotype, LatinSquareGenotype, TryFromGenotypeBuilderError,
+};
+
+#[test]
+fn build_invalid() {
+    let genotype = LatinSquareGenotype::builder().build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "LatinSquareGenotype requires a latin_square_size"
+        ))
+    );
+
+    let genotype = LatinSquareGenotype::builder()
+        .with_latin_square_size(1)
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "LatinSquareGenotype requires a latin_square_size > 1"
+        ))
+    );
+}
+
+#[test]
+fn is_latin_square() {
+    let genotype = LatinSquareGenotype::builder()
+        .with_latin_square_size(3)
+        .build()
+        .unwrap();
+    assert!(genotype.is_latin_square(&[0, 1, 2, 1, 2, 0, 2, 0, 1]));
+    assert!(!genotype.is_latin_square(&[0, 1, 2, 1, 2, 0, 1, 2, 0]));
+    assert!(!genotype.is_latin_square(&[0, 1, 2, 0, 1, 2, 0, 1, 2]));
+    assert!(!genotype.is_latin_square(&[0, 1, 2, 1, 2, 0]));
+}
+
+#[test]
+fn random_genes_factory() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = LatinSquareGenotype::builder()
+        .with_latin_square_size(5)
+        .build()
+        .unwrap();
+    assert_eq!(genotype.genes_size(), 25);
+
+    for _ in 0..10 {
+        assert!(genotype.is_latin_square(&genotype.random_genes_factory(&mut rng)));
+    }
+}
+
+#[test]
+fn mutate_chromosome_keeps_latin_square() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = LatinSquareGenotype::builder()
+        .with_latin_square_size(5)
+        .build()
+        .unwrap();
+
+    let mut chromosome = Chromosome::new(genotype.random_genes_factory(&mut rng));
+    for _ in 0..100 {
+        let before = chromosome.genes.clone();
+        genotype.mutate_chromosome_genes(1, true, &mut chromosome, &mut rng);
+        assert!(genotype.is_latin_square(&chromosome.genes));
+        assert_ne!(before, chromosome.genes);
+    }
+    for _ in 0..100 {
+        genotype.mutate_chromosome_genes(3, false, &mut chromosome, &mut rng);
+        assert!(genotype.is_latin_square(&chromosome.genes));
+    }
+    for _ in 0..100 {
+        let indices = genotype.sample_gene_indices(2, false, &mut rng);
+        genotype.mutate_chromosome_genes_at(&indices, &mut chromosome, &mut rng);
+        assert!(genotype.is_latin_square(&chromosome.genes));
+    }
+}
+
+#[test]
+fn mutate_chromosome_genes_at_changes_gene() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = LatinSquareGenotype::builder()
+        .with_latin_square_size(5)
+        .build()
+        .unwrap();
+
+    let mut chromosome = Chromosome::new(genotype.random_genes_factory(&mut rng));
+    for index in 0..genotype.genes_size() {
+        let before = chromosome.genes.clone();
+        genotype.mutate_chromosome_genes_at(&[index], &mut chromosome, &mut rng);
+        assert!(genotype.is_latin_square(&chromosome.genes));
+        assert_ne!(before[index], chromosome.genes[index]);
+    }
+}
+
+#[test]
+fn neighbouring_population() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = LatinSquareGenotype::builder()
+        .with_latin_square_size(4)
+        .build()
+        .unwrap();
+    assert_eq!(
+        genotype.neighbouring_population_size(),
+        BigUint::from(30_u32)
+    );
+
+    // the cyclic square, only the rows at distance 2 have two cycles (of two columns each)
+    let chromosome = build::chromosome(vec![0, 1, 2, 3, 1, 2, 3, 0, 2, 3, 0, 1, 3, 0, 1, 2]);
+    let mut population = Population::new(vec![], true);
+    genotype.fill_neighbouring_population(&chromosome, &mut population, &mut rng);
+    assert_eq!(population.size(), 18 + 4);
+    population.chromosomes.iter().for_each(|neighbour| {
+        assert!(genotype.is_latin_square(&neighbour.genes));
+        assert_ne!(neighbour.genes, chromosome.genes);
+    });
+}
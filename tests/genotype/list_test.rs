@@ -1,7 +1,7 @@
 #[cfg(test)]
 use crate::support::*;
 use genetic_algorithm::genotype::{
-    EvolveGenotype, Genotype, HillClimbGenotype, ListGenotype, PermutateGenotype,
+    EvolveGenotype, Genotype, GenotypeDistance, HillClimbGenotype, ListGenotype, PermutateGenotype,
 };
 
 #[test]
@@ -244,3 +244,58 @@ fn integer_calculate_genes_hash() {
     // the sign on does not matter (-0 == 0)
     assert_eq!(hash_1, hash_3);
 }
+
+#[test]
+fn genes_iter_and_slice() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = ListGenotype::builder()
+        .with_genes_size(10)
+        .with_allele_list(vec![5, 2, 3, 4])
+        .build()
+        .unwrap();
+
+    let chromosome = genotype.chromosome_constructor_random(&mut rng);
+    assert_eq!(
+        genotype
+            .genes_iter(&chromosome)
+            .copied()
+            .collect::<Vec<_>>(),
+        chromosome.genes
+    );
+    assert_eq!(genotype.genes_slice(&chromosome), &chromosome.genes[..]);
+}
+
+#[test]
+fn allele_list_fn() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = ListGenotype::builder()
+        .with_genes_size(100)
+        .with_allele_list_fn(10, |index| index * index)
+        .build()
+        .unwrap();
+    let allele_list: Vec<usize> = (0..10).map(|index| index * index).collect();
+    assert_eq!(genotype.allele_list, allele_list);
+
+    let mut chromosome = Chromosome::new(genotype.random_genes_factory(&mut rng));
+    assert!(chromosome
+        .genes
+        .iter()
+        .all(|gene| allele_list.contains(gene)));
+    genotype.mutate_chromosome_genes(50, true, &mut chromosome, &mut rng);
+    assert!(chromosome
+        .genes
+        .iter()
+        .all(|gene| allele_list.contains(gene)));
+}
+
+#[test]
+fn genes_distance() {
+    let genotype = ListGenotype::builder()
+        .with_genes_size(4)
+        .with_allele_list((0..5).collect())
+        .build()
+        .unwrap();
+
+    assert_eq!(genotype.genes_distance(&[0, 1, 2, 3], &[0, 1, 2, 3]), 0.0);
+    assert_eq!(genotype.genes_distance(&[0, 1, 2, 3], &[4, 1, 2, 0]), 2.0);
+}
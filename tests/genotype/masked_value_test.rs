@@ -0,0 +1,144 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::genotype::{
+    Genotype, HillClimbGenotype, MaskedValueGenotype, PermutateGenotype,
+    TryFromGenotypeBuilderError,
+};
+
+#[test]
+fn build_invalid() {
+    let genotype = MaskedValueGenotype::<usize>::builder()
+        .with_genes_size(5)
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "MaskedValueGenotype requires a value_list"
+        ))
+    );
+
+    let genotype = MaskedValueGenotype::builder()
+        .with_value_list(vec![1, 2, 3])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "MaskedValueGenotype requires a genes_size > 0"
+        ))
+    );
+}
+
+#[test]
+fn random_genes_factory() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = MaskedValueGenotype::builder()
+        .with_genes_size(100)
+        .with_value_list(vec![5, 2, 3, 4])
+        .build()
+        .unwrap();
+    assert_eq!(genotype.value_list, vec![5, 2, 3, 4]);
+
+    let genes = genotype.random_genes_factory(&mut rng);
+    assert_eq!(genes.len(), 100);
+    assert!(genes.iter().any(|(enabled, _)| *enabled));
+    assert!(genes.iter().any(|(enabled, _)| !*enabled));
+    assert!(genes
+        .iter()
+        .all(|(_, value)| genotype.value_list.contains(value)));
+    assert_eq!(
+        genotype.enabled_values(&genes).count(),
+        genes.iter().filter(|(enabled, _)| *enabled).count()
+    );
+}
+
+#[test]
+fn mutate_chromosome_disabled_values_unchanged() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = MaskedValueGenotype::builder()
+        .with_genes_size(10)
+        .with_value_list(vec![0, 1, 2, 3])
+        .build()
+        .unwrap();
+
+    // mutating a disabled gene only enables it, the other genes stay untouched
+    let mut chromosome = build::chromosome(vec![(false, 0); 10]);
+    genotype.mutate_chromosome_genes_at(&[0, 1, 2], &mut chromosome, &mut rng);
+    assert_eq!(
+        inspect::chromosome(&chromosome),
+        vec![
+            (true, 0),
+            (true, 0),
+            (true, 0),
+            (false, 0),
+            (false, 0),
+            (false, 0),
+            (false, 0),
+            (false, 0),
+            (false, 0),
+            (false, 0),
+        ]
+    );
+
+    // the values of the disabled genes never change, the enabled genes get new values
+    let mut values_mutated = false;
+    for _ in 0..100 {
+        genotype.mutate_chromosome_genes_at(&[0, 1, 2], &mut chromosome, &mut rng);
+        let genes = inspect::chromosome(&chromosome);
+        assert!(genes[3..].iter().all(|gene| *gene == (false, 0)));
+        values_mutated |= genes[0..3].iter().any(|(_, value)| *value != 0);
+    }
+    assert!(values_mutated);
+}
+
+#[test]
+fn neighbouring_population() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = MaskedValueGenotype::builder()
+        .with_genes_size(2)
+        .with_value_list(vec![0, 1, 2])
+        .build()
+        .unwrap();
+    assert_eq!(genotype.neighbouring_population_size(), BigUint::from(6u32));
+
+    let chromosome = build::chromosome(vec![(true, 1), (false, 0)]);
+    let mut population = Population::new(vec![], true);
+    genotype.fill_neighbouring_population(&chromosome, &mut population, &mut rng);
+    assert_eq!(
+        inspect::population(&population),
+        vec![
+            vec![(false, 1), (false, 0)],
+            vec![(true, 0), (false, 0)],
+            vec![(true, 2), (false, 0)],
+            vec![(true, 1), (true, 0)],
+        ]
+    );
+}
+
+#[test]
+fn chromosome_permutations() {
+    let genotype = MaskedValueGenotype::builder()
+        .with_genes_size(2)
+        .with_value_list(vec![1, 2])
+        .build()
+        .unwrap();
+
+    assert_eq!(genotype.chromosome_permutations_size(), BigUint::from(9u32));
+    assert_eq!(
+        inspect::chromosomes(
+            &genotype
+                .chromosome_permutations_into_iter(None)
+                .collect::<Vec<_>>()
+        ),
+        vec![
+            vec![(false, 1), (false, 1)],
+            vec![(false, 1), (true, 1)],
+            vec![(false, 1), (true, 2)],
+            vec![(true, 1), (false, 1)],
+            vec![(true, 1), (true, 1)],
+            vec![(true, 1), (true, 2)],
+            vec![(true, 2), (false, 1)],
+            vec![(true, 2), (true, 1)],
+            vec![(true, 2), (true, 2)],
+        ]
+    );
+}
@@ -1,7 +1,12 @@
 pub mod binary_test;
+pub mod gray_code_test;
+pub mod latin_square_test;
 pub mod list_test;
+pub mod masked_value_test;
 pub mod multi_list_test;
 pub mod multi_range_test;
 pub mod multi_unique_test;
+pub mod one_hot_test;
 pub mod range_test;
+pub mod simplex_test;
 pub mod unique_test;
@@ -2,6 +2,7 @@
 use crate::support::*;
 use genetic_algorithm::genotype::{
     EvolveGenotype, Genotype, HillClimbGenotype, MultiListGenotype, PermutateGenotype,
+    PermutationOrder,
 };
 
 #[test]
@@ -226,6 +227,55 @@ fn chromosome_permutations_genes_size_4() {
     );
 }
 
+#[test]
+fn chromosome_permutations_shuffled() {
+    let genotype = MultiListGenotype::builder()
+        .with_allele_lists(vec![vec![0], vec![0, 1], vec![0, 1, 2], vec![0, 1, 2, 3]])
+        .with_permutation_order(PermutationOrder::Shuffled(0))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        genotype.chromosome_permutations_size(),
+        BigUint::from(24u32)
+    );
+    let shuffled = inspect::chromosomes(
+        genotype
+            .chromosome_permutations_into_iter(None)
+            .collect::<Vec<_>>()
+            .as_slice(),
+    );
+    // deterministic, for resuming
+    assert_eq!(
+        inspect::chromosomes(
+            genotype
+                .chromosome_permutations_into_iter(None)
+                .collect::<Vec<_>>()
+                .as_slice()
+        ),
+        shuffled
+    );
+
+    let nested_genotype = MultiListGenotype::builder()
+        .with_allele_lists(vec![vec![0], vec![0, 1], vec![0, 1, 2], vec![0, 1, 2, 3]])
+        .build()
+        .unwrap();
+    let nested = inspect::chromosomes(
+        nested_genotype
+            .chromosome_permutations_into_iter(None)
+            .collect::<Vec<_>>()
+            .as_slice(),
+    );
+    assert_ne!(shuffled, nested);
+    // the first half already covers both values of the second gene
+    assert!(shuffled[0..12].iter().any(|genes| genes[1] == 0));
+    assert!(shuffled[0..12].iter().any(|genes| genes[1] == 1));
+
+    let mut sorted = shuffled.clone();
+    sorted.sort();
+    assert_eq!(sorted, nested);
+}
+
 #[test]
 fn chromosome_permutations_genes_size_huge() {
     let genotype = MultiListGenotype::builder()
@@ -260,3 +310,24 @@ fn chromosome_permutations_genes_size_huge() {
         vec![vec![0; 10]]
     )
 }
+
+#[test]
+fn allele_lists_fn() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = MultiListGenotype::builder()
+        .with_allele_lists_fn(4, |index| (0..=index).map(|value| value * 10).collect())
+        .build()
+        .unwrap();
+    assert_eq!(genotype.genes_size(), 4);
+    assert_eq!(
+        genotype.allele_lists,
+        vec![vec![0], vec![0, 10], vec![0, 10, 20], vec![0, 10, 20, 30]]
+    );
+
+    let chromosome = Chromosome::new(genotype.random_genes_factory(&mut rng));
+    assert!(chromosome
+        .genes
+        .iter()
+        .enumerate()
+        .all(|(index, gene)| genotype.allele_lists[index].contains(gene)));
+}
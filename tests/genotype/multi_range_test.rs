@@ -2,7 +2,7 @@
 use crate::support::*;
 use genetic_algorithm::genotype::{
     EvolveGenotype, Genotype, HillClimbGenotype, MultiRangeGenotype, MutationType,
-    PermutateGenotype,
+    PermutateGenotype, TryFromGenotypeBuilderError,
 };
 
 #[test]
@@ -137,6 +137,64 @@ fn float_mutate_chromosome_single_range_scaled() {
     ));
 }
 #[test]
+fn float_mutate_chromosome_single_range_scaled_per_gene_units() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut genotype = MultiRangeGenotype::<f32>::builder()
+        .with_allele_ranges(vec![0.0..=1.0, 0.0..=10000.0])
+        .with_mutation_types(vec![
+            MutationType::RangeScaled(vec![0.1, 0.001]),
+            MutationType::RangeScaled(vec![1000.0, 10.0]),
+        ])
+        .build()
+        .unwrap();
+
+    let mut chromosome = Chromosome::new(genotype.random_genes_factory(&mut rng));
+    assert!(genotype.increment_scale_index());
+    for _ in 0..10 {
+        let before = chromosome.genes.clone();
+        genotype.mutate_chromosome_genes(2, false, &mut chromosome, &mut rng);
+        assert!((chromosome.genes[0] - before[0]).abs() <= 0.001 + 1e-6);
+        assert!((chromosome.genes[1] - before[1]).abs() <= 10.0 + 1e-3);
+    }
+}
+#[test]
+fn build_invalid_mutation_types() {
+    let genotype = MultiRangeGenotype::builder()
+        .with_allele_ranges(vec![0.0..=1.0, 0.0..=10000.0])
+        .with_mutation_types(vec![MutationType::RangeScaled(vec![0.1, 0.001])])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "MultiRangeGenotype requires mutation_types of the allele_ranges length"
+        ))
+    );
+
+    let genotype = MultiRangeGenotype::builder()
+        .with_allele_ranges(vec![0.0..=1.0, 0.0..=10000.0, 0.0..=1.0])
+        .with_mutation_types(vec![
+            MutationType::RangeScaled(vec![0.1, 0.001]),
+            MutationType::StepScaled(vec![1000.0, 100.0, 10.0]),
+            MutationType::Discrete,
+        ])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError("MultiRangeGenotype requires the same non-zero number of scales for all scaled mutation_types"))
+    );
+
+    let genotype = MultiRangeGenotype::builder()
+        .with_allele_ranges(vec![0.0..=1.0, 0.0..=10.0])
+        .with_mutation_types(vec![MutationType::Random, MutationType::Gaussian(0.0)])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "MultiRangeGenotype requires Gaussian sigmas > 0"
+        ))
+    );
+}
+#[test]
 fn float_mutate_chromosome_single_step() {
     let mut rng = SmallRng::seed_from_u64(0);
     let genotype = MultiRangeGenotype::builder()
@@ -1053,3 +1111,130 @@ fn integer_neighbouring_population_3_discrete() {
         ]
     );
 }
+
+#[test]
+fn float_mutate_chromosome_single_gaussian_per_gene() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = MultiRangeGenotype::<f32>::builder()
+        .with_allele_ranges(vec![-100.0..=100.0, -100.0..=100.0])
+        .with_mutation_types(vec![
+            MutationType::Gaussian(0.1),
+            MutationType::Gaussian(10.0),
+        ])
+        .build()
+        .unwrap();
+
+    (0..100).for_each(|_| {
+        let mut chromosome = Chromosome::new(vec![0.0, 0.0]);
+        genotype.mutate_chromosome_genes_at(&[0, 1], &mut chromosome, &mut rng);
+        // at most 10 sigma per gene
+        assert!(chromosome.genes[0] != 0.0 && chromosome.genes[0].abs() <= 1.0);
+        assert!(chromosome.genes[1] != 0.0 && chromosome.genes[1].abs() <= 100.0);
+    });
+}
+
+#[test]
+fn integer_allele_steps() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = MultiRangeGenotype::builder()
+        .with_allele_ranges(vec![0..=100, 0..=10])
+        .with_allele_steps(vec![Some(5), None])
+        .with_mutation_types(vec![MutationType::Step(1), MutationType::Step(1)])
+        .build()
+        .unwrap();
+
+    for _ in 0..10 {
+        let chromosome = Chromosome::new(genotype.random_genes_factory(&mut rng));
+        assert!((0..=100).contains(&chromosome.genes[0]) && chromosome.genes[0] % 5 == 0);
+        assert!((0..=10).contains(&chromosome.genes[1]));
+    }
+
+    // a mutation smaller than the allele_step moves to the neighbouring grid value
+    for _ in 0..10 {
+        let mut chromosome = Chromosome::new(vec![50, 5]);
+        genotype.mutate_chromosome_genes_at(&[0, 1], &mut chromosome, &mut rng);
+        assert!(chromosome.genes[0] == 45 || chromosome.genes[0] == 55);
+        assert!(chromosome.genes[1] == 4 || chromosome.genes[1] == 6);
+    }
+
+    assert_eq!(genotype.snap_to_allele_step(0, 52), 50);
+    assert_eq!(genotype.snap_to_allele_step(0, 53), 55);
+    assert_eq!(genotype.snap_to_allele_step(0, 101), 100);
+    assert_eq!(genotype.snap_to_allele_step(1, 7), 7);
+
+    let mut population = Population::new(vec![], true);
+    let chromosome = Chromosome::new(vec![50, 7]);
+    genotype.fill_neighbouring_population(&chromosome, &mut population, &mut rng);
+    assert_eq!(
+        inspect::population(&population),
+        vec![vec![45, 7], vec![55, 7], vec![50, 6], vec![50, 8]]
+    );
+}
+
+#[test]
+fn integer_allele_steps_seed_genes_list() {
+    let genotype = MultiRangeGenotype::builder()
+        .with_allele_ranges(vec![0..=100, 0..=10])
+        .with_allele_steps(vec![Some(5), None])
+        .with_seed_genes_list(vec![vec![52, 7], vec![98, 3]])
+        .build()
+        .unwrap();
+
+    assert_eq!(genotype.seed_genes_list(), &vec![vec![50, 7], vec![100, 3]]);
+}
+
+#[test]
+fn integer_chromosome_permutations_allele_steps() {
+    let genotype = MultiRangeGenotype::builder()
+        .with_allele_ranges(vec![0..=12, 0..=2])
+        .with_allele_steps(vec![Some(5), None])
+        .with_mutation_types(vec![MutationType::Random, MutationType::Discrete])
+        .build()
+        .unwrap();
+
+    assert!(genotype.allows_permutation());
+    assert_eq!(genotype.chromosome_permutations_size(), BigUint::from(9u32));
+    assert_eq!(
+        inspect::chromosomes(
+            &genotype
+                .chromosome_permutations_into_iter(None)
+                .collect::<Vec<_>>()
+        ),
+        vec![
+            vec![0, 0],
+            vec![0, 1],
+            vec![0, 2],
+            vec![5, 0],
+            vec![5, 1],
+            vec![5, 2],
+            vec![10, 0],
+            vec![10, 1],
+            vec![10, 2],
+        ]
+    );
+}
+
+#[test]
+fn build_invalid_allele_steps() {
+    let genotype = MultiRangeGenotype::builder()
+        .with_allele_ranges(vec![0..=100, 0..=10])
+        .with_allele_steps(vec![Some(5)])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "MultiRangeGenotype requires allele_steps of the allele_ranges length"
+        ))
+    );
+
+    let genotype = MultiRangeGenotype::builder()
+        .with_allele_ranges(vec![0..=100, 0..=10])
+        .with_allele_steps(vec![Some(5), Some(0)])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "MultiRangeGenotype requires allele_steps > 0"
+        ))
+    );
+}
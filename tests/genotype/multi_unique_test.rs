@@ -2,6 +2,7 @@
 use crate::support::*;
 use genetic_algorithm::genotype::{
     EvolveGenotype, Genotype, HillClimbGenotype, MultiUniqueGenotype, PermutateGenotype,
+    PermutationOrder,
 };
 
 #[test]
@@ -59,6 +60,33 @@ fn mutate_chromosome_single() {
         BigUint::from(288u32)
     );
 }
+#[test]
+fn mutate_chromosome_genes_at_moves_gene_within_set() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = MultiUniqueGenotype::builder()
+        .with_allele_lists(vec![vec![0, 1], vec![4, 5, 6, 7], vec![9]])
+        .build()
+        .unwrap();
+
+    let mut chromosome = build::chromosome(vec![0, 1, 4, 5, 6, 7, 9]);
+    for _ in 0..100 {
+        let index = genotype.sample_gene_index(&mut rng).min(5);
+        let before = chromosome.genes.clone();
+        genotype.mutate_chromosome_genes_at(&[index], &mut chromosome, &mut rng);
+        assert_ne!(before[index], chromosome.genes[index]);
+        let mut first_set = chromosome.genes[0..2].to_vec();
+        first_set.sort();
+        assert_eq!(first_set, vec![0, 1]);
+        let mut second_set = chromosome.genes[2..6].to_vec();
+        second_set.sort();
+        assert_eq!(second_set, vec![4, 5, 6, 7]);
+    }
+
+    // a set of a single gene never moves
+    genotype.mutate_chromosome_genes_at(&[6], &mut chromosome, &mut rng);
+    assert_eq!(chromosome.genes[6], 9);
+}
+
 #[test]
 fn mutate_chromosome_genes_with_duplicates() {
     let mut rng = SmallRng::seed_from_u64(0);
@@ -292,6 +320,60 @@ fn chromosome_permutations_genes_size_4() {
     );
 }
 
+#[test]
+fn chromosome_permutations_shuffled() {
+    let genotype = MultiUniqueGenotype::builder()
+        .with_allele_lists(vec![vec![0, 1], vec![2, 3, 4]])
+        .with_permutation_order(PermutationOrder::Shuffled(0))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        genotype.chromosome_permutations_size(),
+        BigUint::from(12u32)
+    );
+    let shuffled = inspect::chromosomes(
+        genotype
+            .chromosome_permutations_into_iter(None)
+            .collect::<Vec<_>>()
+            .as_slice(),
+    );
+    // deterministic, for resuming
+    assert_eq!(
+        inspect::chromosomes(
+            genotype
+                .chromosome_permutations_into_iter(None)
+                .collect::<Vec<_>>()
+                .as_slice()
+        ),
+        shuffled
+    );
+
+    let nested_genotype = MultiUniqueGenotype::builder()
+        .with_allele_lists(vec![vec![0, 1], vec![2, 3, 4]])
+        .build()
+        .unwrap();
+    let nested = inspect::chromosomes(
+        nested_genotype
+            .chromosome_permutations_into_iter(None)
+            .collect::<Vec<_>>()
+            .as_slice(),
+    );
+    assert_eq!(
+        nested[0..3],
+        [
+            vec![0, 1, 2, 3, 4],
+            vec![0, 1, 2, 4, 3],
+            vec![0, 1, 3, 2, 4]
+        ]
+    );
+    assert_ne!(shuffled, nested);
+
+    let mut sorted = shuffled.clone();
+    sorted.sort();
+    assert_eq!(sorted, nested);
+}
+
 #[test]
 fn chromosome_permutations_genes_size_huge() {
     let genotype = MultiUniqueGenotype::builder()
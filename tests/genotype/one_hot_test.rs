@@ -0,0 +1,179 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::genotype::{
+    EvolveGenotype, Genotype, HillClimbGenotype, OneHotGenotype, PermutateGenotype,
+    TryFromGenotypeBuilderError,
+};
+
+fn assert_one_hot(genes: &[bool], group_sizes: &[usize]) {
+    assert_eq!(genes.len(), group_sizes.iter().sum::<usize>());
+    let mut offset = 0;
+    for group_size in group_sizes {
+        assert_eq!(
+            genes[offset..offset + group_size]
+                .iter()
+                .filter(|v| **v)
+                .count(),
+            1,
+            "{:?}",
+            genes
+        );
+        offset += group_size;
+    }
+}
+
+#[test]
+fn build_invalid() {
+    let genotype = OneHotGenotype::builder().build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "OneHotGenotype requires a group_sizes"
+        ))
+    );
+
+    let genotype = OneHotGenotype::builder()
+        .with_group_sizes(vec![3, 1, 2])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "OneHotGenotype requires all group_sizes > 1"
+        ))
+    );
+
+    for seed_genes in [
+        vec![true, false, false, false, false],
+        vec![true, false, true, false, true],
+        vec![true, false, false, true],
+    ] {
+        let genotype = OneHotGenotype::builder()
+            .with_group_sizes(vec![3, 2])
+            .with_seed_genes_list(vec![vec![false, true, false, true, false], seed_genes])
+            .build();
+        assert_eq!(
+            genotype.err(),
+            Some(TryFromGenotypeBuilderError(
+                "OneHotGenotype requires seed_genes of genes_size length with exactly one active gene per group"
+            ))
+        );
+    }
+}
+
+#[test]
+fn random_genes_factory() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let group_sizes = vec![3, 2, 4];
+    let genotype = OneHotGenotype::builder()
+        .with_group_sizes(group_sizes.clone())
+        .build()
+        .unwrap();
+    assert_eq!(genotype.genes_size(), 9);
+
+    for _ in 0..10 {
+        assert_one_hot(&genotype.random_genes_factory(&mut rng), &group_sizes);
+    }
+}
+
+#[test]
+fn mutate_chromosome_keeps_one_hot() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let group_sizes = vec![3, 2, 4];
+    let genotype = OneHotGenotype::builder()
+        .with_group_sizes(group_sizes.clone())
+        .build()
+        .unwrap();
+
+    let mut chromosome = Chromosome::new(genotype.random_genes_factory(&mut rng));
+    for _ in 0..100 {
+        let before = chromosome.genes.clone();
+        genotype.mutate_chromosome_genes(1, true, &mut chromosome, &mut rng);
+        assert_one_hot(&chromosome.genes, &group_sizes);
+        assert_ne!(before, chromosome.genes);
+    }
+    for _ in 0..100 {
+        genotype.mutate_chromosome_genes(2, false, &mut chromosome, &mut rng);
+        assert_one_hot(&chromosome.genes, &group_sizes);
+    }
+    for index in 0..genotype.genes_size() {
+        genotype.mutate_chromosome_genes_at(&[index], &mut chromosome, &mut rng);
+        assert_one_hot(&chromosome.genes, &group_sizes);
+    }
+}
+
+#[test]
+fn crossover_chromosome_keeps_one_hot() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let group_sizes = vec![3, 2, 4];
+    let genotype = OneHotGenotype::builder()
+        .with_group_sizes(group_sizes.clone())
+        .build()
+        .unwrap();
+
+    let mut father: Chromosome<bool> = build::chromosome(vec![
+        true, false, false, true, false, true, false, false, false,
+    ]);
+    let mut mother: Chromosome<bool> = build::chromosome(vec![
+        false, false, true, false, true, false, false, false, true,
+    ]);
+    for _ in 0..50 {
+        genotype.crossover_chromosome_genes(2, true, &mut father, &mut mother, &mut rng);
+        assert_one_hot(&father.genes, &group_sizes);
+        assert_one_hot(&mother.genes, &group_sizes);
+        genotype.crossover_chromosome_genes(2, false, &mut father, &mut mother, &mut rng);
+        assert_one_hot(&father.genes, &group_sizes);
+        assert_one_hot(&mother.genes, &group_sizes);
+        genotype.crossover_chromosome_points(1, true, &mut father, &mut mother, &mut rng);
+        assert_one_hot(&father.genes, &group_sizes);
+        assert_one_hot(&mother.genes, &group_sizes);
+        genotype.crossover_chromosome_points(2, false, &mut father, &mut mother, &mut rng);
+        assert_one_hot(&father.genes, &group_sizes);
+        assert_one_hot(&mother.genes, &group_sizes);
+    }
+}
+
+#[test]
+fn neighbouring_population() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let group_sizes = vec![3, 2, 4];
+    let genotype = OneHotGenotype::builder()
+        .with_group_sizes(group_sizes.clone())
+        .build()
+        .unwrap();
+
+    let chromosome: Chromosome<bool> = build::chromosome(vec![
+        true, false, false, true, false, true, false, false, false,
+    ]);
+    assert_eq!(genotype.neighbouring_population_size(), BigUint::from(6u32));
+
+    let mut population = Population::new(vec![], true);
+    genotype.fill_neighbouring_population(&chromosome, &mut population, &mut rng);
+    assert_eq!(
+        inspect::population(&population),
+        vec![
+            vec![false, true, false, true, false, true, false, false, false],
+            vec![false, false, true, true, false, true, false, false, false],
+            vec![true, false, false, false, true, true, false, false, false],
+            vec![true, false, false, true, false, false, true, false, false],
+            vec![true, false, false, true, false, false, false, true, false],
+            vec![true, false, false, true, false, false, false, false, true],
+        ]
+    );
+}
+
+#[test]
+fn chromosome_permutations() {
+    let group_sizes = vec![3, 2];
+    let genotype = OneHotGenotype::builder()
+        .with_group_sizes(group_sizes.clone())
+        .build()
+        .unwrap();
+
+    assert_eq!(genotype.chromosome_permutations_size(), BigUint::from(6u32));
+    let chromosomes: Vec<Chromosome<bool>> =
+        genotype.chromosome_permutations_into_iter(None).collect();
+    assert_eq!(chromosomes.len(), 6);
+    chromosomes
+        .iter()
+        .for_each(|c| assert_one_hot(&c.genes, &group_sizes));
+}
@@ -1,7 +1,10 @@
 #[cfg(test)]
 use crate::support::*;
+use genetic_algorithm::fitness::placeholders::SumGenes;
+use genetic_algorithm::fitness::{Fitness, FitnessOrdering};
 use genetic_algorithm::genotype::{
-    EvolveGenotype, Genotype, HillClimbGenotype, MutationType, PermutateGenotype, RangeGenotype,
+    EvolveGenotype, Genotype, GenotypeDistance, HillClimbGenotype, MutationType, PermutateGenotype,
+    RangeGenotype, TryFromGenotypeBuilderError,
 };
 
 #[test]
@@ -338,6 +341,21 @@ fn mutate_chromosome_genes_random_without_duplicates() {
         0.001
     ));
 }
+#[test]
+fn mutate_chromosome_genes_with_genes_mutation_weights() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(10)
+        .with_allele_range(1.0..=2.0)
+        .with_genes_mutation_weights(vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0])
+        .build()
+        .unwrap();
+
+    let mut chromosome = build::chromosome(vec![0.0; 10]);
+    (0..10).for_each(|_| genotype.mutate_chromosome_genes(2, true, &mut chromosome, &mut rng));
+    assert!(chromosome.genes[0..7].iter().all(|gene| *gene == 0.0));
+    assert!(chromosome.genes[7..].iter().any(|gene| *gene >= 1.0));
+}
 
 #[test]
 fn crossover_chromosome_pair_single_gene() {
@@ -1206,6 +1224,47 @@ fn float_calculate_genes_hash() {
     assert_ne!(hash_1, hash_3);
 }
 
+#[test]
+fn float_calculate_genes_hash_with_genes_key_precision() {
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(2)
+        .with_allele_range(-1.0..=1.0)
+        .with_genes_key_precision(3)
+        .build()
+        .unwrap();
+
+    let chromosome_1: Chromosome<f32> = build::chromosome_without_genes_hash(vec![0.12341, 0.5]);
+    let chromosome_2: Chromosome<f32> = build::chromosome_without_genes_hash(vec![0.12344, 0.5]);
+    let chromosome_3: Chromosome<f32> = build::chromosome_without_genes_hash(vec![0.12361, 0.5]);
+    let chromosome_4: Chromosome<f32> = build::chromosome_without_genes_hash(vec![0.0001, 0.5]);
+    let chromosome_5: Chromosome<f32> = build::chromosome_without_genes_hash(vec![-0.0001, 0.5]);
+
+    // within precision should have same hash
+    assert_eq!(
+        genotype.calculate_genes_hash(&chromosome_1),
+        genotype.calculate_genes_hash(&chromosome_2)
+    );
+    assert_ne!(
+        genotype.calculate_genes_hash(&chromosome_1),
+        genotype.calculate_genes_hash(&chromosome_3)
+    );
+    // the sign on a rounded zero does not matter
+    assert_eq!(
+        genotype.calculate_genes_hash(&chromosome_4),
+        genotype.calculate_genes_hash(&chromosome_5)
+    );
+
+    // without precision the raw genes are hashed
+    assert_ne!(chromosome_1.calculate_hash(), chromosome_2.calculate_hash());
+
+    let mut chromosome = chromosome_2.clone();
+    genotype.reset_chromosome_metadata(&mut chromosome);
+    assert_eq!(
+        chromosome.genes_hash(),
+        Some(genotype.calculate_genes_hash(&chromosome_1))
+    );
+}
+
 #[test]
 fn integer_calculate_genes_hash() {
     let chromosome_1: Chromosome<i32> =
@@ -1231,3 +1290,312 @@ fn integer_calculate_genes_hash() {
     // the sign on does not matter
     assert_eq!(hash_1, hash_3);
 }
+
+#[test]
+fn genes_iter_and_slice() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(10)
+        .with_allele_range(0.0..=1.0)
+        .build()
+        .unwrap();
+
+    let chromosome = genotype.chromosome_constructor_random(&mut rng);
+    assert_eq!(
+        genotype
+            .genes_iter(&chromosome)
+            .copied()
+            .collect::<Vec<_>>(),
+        chromosome.genes
+    );
+    assert_eq!(genotype.genes_slice(&chromosome), &chromosome.genes[..]);
+}
+
+#[test]
+fn float_neighbouring_population_include_self_neighbour() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(2)
+        .with_allele_range(0.0..=1.0)
+        .with_mutation_type(MutationType::Step(0.1))
+        .with_include_self_neighbour(true)
+        .build()
+        .unwrap();
+
+    // already optimal, so all real neighbours are worse
+    let chromosome: Chromosome<f32> = build::chromosome(vec![1.0, 1.0]);
+
+    assert_eq!(genotype.neighbouring_population_size(), BigUint::from(5u32));
+    let mut population = Population::new(vec![], true);
+    genotype.fill_neighbouring_population(&chromosome, &mut population, &mut rng);
+    assert!(relative_population_eq(
+        inspect::population(&population),
+        vec![vec![1.0, 1.0], vec![0.9, 1.0], vec![1.0, 0.9]],
+        0.001,
+    ));
+
+    SumGenes::new_with_precision(1e-3).call_for_population(&mut population, &genotype, None, None);
+    assert_eq!(
+        population.best_chromosome_index(FitnessOrdering::Maximize),
+        Some(0)
+    );
+}
+
+#[test]
+fn float_mutate_chromosome_single_gaussian() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = RangeGenotype::<f32>::builder()
+        .with_genes_size(1)
+        .with_allele_range(-100.0..=100.0)
+        .with_mutation_type(MutationType::Gaussian(1.0))
+        .build()
+        .unwrap();
+
+    let number_of_mutations = 10_000;
+    let within_one_sigma_count = (0..number_of_mutations)
+        .filter(|_| {
+            let mut chromosome = Chromosome::new(vec![0.0]);
+            genotype.mutate_chromosome_genes(1, true, &mut chromosome, &mut rng);
+            assert_ne!(chromosome.genes[0], 0.0);
+            chromosome.genes[0].abs() <= 1.0
+        })
+        .count();
+
+    // about 68% of a normal distribution is within one sigma
+    let within_one_sigma_rate = within_one_sigma_count as f32 / number_of_mutations as f32;
+    assert!(relative_chromosome_eq(
+        vec![within_one_sigma_rate],
+        vec![0.683],
+        0.03
+    ));
+}
+
+#[test]
+fn float_neighbouring_population_2_gaussian_scaled() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut genotype = RangeGenotype::builder()
+        .with_genes_size(2)
+        .with_allele_range(0.0..=1.0)
+        .with_mutation_type(MutationType::GaussianScaled(vec![1.0, 0.01]))
+        .build()
+        .unwrap();
+
+    assert_eq!(genotype.max_scale_index(), Some(1));
+    assert_eq!(genotype.current_scale_index(), Some(0));
+    genotype.increment_scale_index();
+    assert_eq!(genotype.current_scale_index(), Some(1));
+
+    let chromosome = Chromosome::new(vec![0.5, 0.5]);
+    assert_eq!(genotype.neighbouring_population_size(), BigUint::from(4u32));
+    let mut population = Population::new(vec![], true);
+    genotype.fill_neighbouring_population(&chromosome, &mut population, &mut rng);
+
+    let genes = inspect::population(&population);
+    assert_eq!(genes.len(), 4);
+    // down and up for each gene, within 10 sigma of the current scale
+    assert!(genes[0][0] < 0.5 && genes[0][0] > 0.4 && genes[0][1] == 0.5);
+    assert!(genes[1][0] > 0.5 && genes[1][0] < 0.6 && genes[1][1] == 0.5);
+    assert!(genes[2][1] < 0.5 && genes[2][1] > 0.4 && genes[2][0] == 0.5);
+    assert!(genes[3][1] > 0.5 && genes[3][1] < 0.6 && genes[3][0] == 0.5);
+}
+
+#[test]
+fn integer_mutate_chromosome_single_gaussian() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(1)
+        .with_allele_range(0..=100)
+        .with_mutation_type(MutationType::Gaussian(2))
+        .build()
+        .unwrap();
+
+    (0..100).for_each(|_| {
+        let mut chromosome = Chromosome::new(vec![50]);
+        genotype.mutate_chromosome_genes(1, true, &mut chromosome, &mut rng);
+        // at least the smallest increment, at most 10 sigma
+        assert_ne!(chromosome.genes[0], 50);
+        assert!(chromosome.genes[0] >= 30 && chromosome.genes[0] <= 70);
+    });
+}
+
+#[test]
+fn build_invalid_gaussian_sigma() {
+    for mutation_type in [
+        MutationType::Gaussian(0.0),
+        MutationType::Gaussian(-1.0),
+        MutationType::Gaussian(f32::NAN),
+        MutationType::GaussianScaled(vec![1.0, 0.0]),
+    ] {
+        let genotype = RangeGenotype::builder()
+            .with_genes_size(1)
+            .with_allele_range(0.0..=1.0)
+            .with_mutation_type(mutation_type)
+            .build();
+        assert_eq!(
+            genotype.err(),
+            Some(TryFromGenotypeBuilderError(
+                "RangeGenotype requires Gaussian sigmas > 0"
+            ))
+        );
+    }
+}
+
+#[test]
+fn genes_distance() {
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(3)
+        .with_allele_range(0.0..=10.0)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        genotype.genes_distance(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]),
+        0.0
+    );
+    assert_eq!(
+        genotype.genes_distance(&[0.0, 3.0, 1.0], &[4.0, 0.0, 1.0]),
+        5.0
+    );
+}
+
+#[test]
+fn genes_distance_unsigned() {
+    let genotype = RangeGenotype::<u8>::builder()
+        .with_genes_size(2)
+        .with_allele_range(0..=10)
+        .build()
+        .unwrap();
+
+    assert_eq!(genotype.genes_distance(&[0, 3], &[4, 0]), 5.0);
+    assert_eq!(genotype.genes_distance(&[4, 0], &[0, 3]), 5.0);
+}
+
+#[test]
+fn integer_allele_step() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(10)
+        .with_allele_range(0..=100)
+        .with_allele_step(5)
+        .with_mutation_type(MutationType::Step(1))
+        .build()
+        .unwrap();
+
+    for _ in 0..10 {
+        let chromosome = Chromosome::new(genotype.random_genes_factory(&mut rng));
+        assert!(chromosome
+            .genes
+            .iter()
+            .all(|gene| (0..=100).contains(gene) && gene % 5 == 0));
+    }
+
+    // a mutation smaller than the allele_step moves to the neighbouring grid value
+    let mut chromosome = Chromosome::new(vec![50; 10]);
+    genotype.mutate_chromosome_genes(10, false, &mut chromosome, &mut rng);
+    assert!(chromosome
+        .genes
+        .iter()
+        .all(|gene| *gene == 45 || *gene == 55));
+
+    assert_eq!(genotype.snap_to_allele_step(52), 50);
+    assert_eq!(genotype.snap_to_allele_step(53), 55);
+    assert_eq!(genotype.snap_to_allele_step(101), 100);
+}
+
+#[test]
+fn integer_allele_step_seed_genes_list() {
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(2)
+        .with_allele_range(0..=100)
+        .with_allele_step(5)
+        .with_seed_genes_list(vec![vec![52, 53], vec![0, 101]])
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        genotype.seed_genes_list(),
+        &vec![vec![50, 55], vec![0, 100]]
+    );
+}
+
+#[test]
+fn integer_neighbouring_population_allele_step() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(2)
+        .with_allele_range(0..=100)
+        .with_allele_step(5)
+        .with_mutation_type(MutationType::Step(1))
+        .build()
+        .unwrap();
+
+    let mut population = Population::new(vec![], true);
+    let chromosome = Chromosome::new(vec![50, 100]);
+    genotype.fill_neighbouring_population(&chromosome, &mut population, &mut rng);
+    assert_eq!(
+        inspect::population(&population),
+        vec![vec![45, 100], vec![55, 100], vec![50, 95]]
+    );
+}
+
+#[test]
+fn integer_chromosome_permutations_allele_step() {
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(2)
+        .with_allele_range(0..=12)
+        .with_allele_step(5)
+        .build()
+        .unwrap();
+
+    assert!(genotype.allows_permutation());
+    assert_eq!(genotype.chromosome_permutations_size(), BigUint::from(9u32));
+    assert_eq!(
+        inspect::chromosomes(
+            &genotype
+                .chromosome_permutations_into_iter(None)
+                .collect::<Vec<_>>()
+        ),
+        vec![
+            vec![0, 0],
+            vec![0, 5],
+            vec![0, 10],
+            vec![5, 0],
+            vec![5, 5],
+            vec![5, 10],
+            vec![10, 0],
+            vec![10, 5],
+            vec![10, 10],
+        ]
+    );
+}
+
+#[test]
+fn float_allele_step() {
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(1)
+        .with_allele_range(0.0..=1.0)
+        .with_allele_step(0.25)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        genotype.permutable_gene_values_allele_step(0.25),
+        vec![0.0, 0.25, 0.5, 0.75, 1.0]
+    );
+    assert_eq!(genotype.snap_to_allele_step(0.3), 0.25);
+    assert_eq!(genotype.snap_to_allele_step(0.9), 1.0);
+    assert_eq!(genotype.snap_to_allele_step(-1.0), 0.0);
+    assert_eq!(genotype.snap_to_allele_step(2.0), 1.0);
+
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(1)
+        .with_allele_range(0.0..=1.0)
+        .with_allele_step(0.0)
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "RangeGenotype requires an allele_step > 0"
+        ))
+    );
+}
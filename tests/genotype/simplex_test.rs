@@ -0,0 +1,189 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::genotype::{
+    EvolveGenotype, Genotype, HillClimbGenotype, MutationType, SimplexGenotype,
+    TryFromGenotypeBuilderError,
+};
+
+fn genes_sum(genes: &[f64]) -> f64 {
+    genes.iter().sum()
+}
+
+#[test]
+fn build_invalid() {
+    let genotype = SimplexGenotype::<f64>::builder().with_genes_size(1).build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "SimplexGenotype requires a genes_size > 1"
+        ))
+    );
+
+    let genotype = SimplexGenotype::<f64>::builder()
+        .with_genes_size(5)
+        .with_mutation_type(MutationType::Step(0.1))
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "SimplexGenotype only supports MutationType::Random and MutationType::Range"
+        ))
+    );
+
+    let genotype = SimplexGenotype::<f64>::builder()
+        .with_genes_size(3)
+        .with_seed_genes_list(vec![vec![0.2, 0.3, 0.5], vec![0.2, 0.3, 0.6]])
+        .build();
+    assert_eq!(
+        genotype.err(),
+        Some(TryFromGenotypeBuilderError(
+            "SimplexGenotype requires non-negative seed_genes of genes_size length, which sum up to the allele_sum"
+        ))
+    );
+
+    let genotype = SimplexGenotype::<f64>::builder()
+        .with_genes_size(3)
+        .with_allele_sum(2.0)
+        .with_seed_genes_list(vec![vec![0.4, 0.6, 1.0]])
+        .build();
+    assert!(genotype.is_ok());
+}
+
+#[test]
+fn random_genes_factory() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = SimplexGenotype::<f64>::builder()
+        .with_genes_size(10)
+        .with_allele_sum(2.0)
+        .build()
+        .unwrap();
+
+    for _ in 0..10 {
+        let genes = genotype.random_genes_factory(&mut rng);
+        assert_eq!(genes.len(), 10);
+        assert!(genes.iter().all(|v| *v >= 0.0));
+        assert!(relative_eq!(genes_sum(&genes), 2.0, epsilon = 1e-9));
+    }
+}
+
+#[test]
+fn mutate_chromosome_preserves_sum_random() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = SimplexGenotype::<f64>::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+
+    let mut chromosome = Chromosome::new(genotype.random_genes_factory(&mut rng));
+    for _ in 0..100 {
+        let before = chromosome.genes.clone();
+        genotype.mutate_chromosome_genes(3, true, &mut chromosome, &mut rng);
+        assert!(chromosome.genes.iter().all(|v| *v >= 0.0));
+        assert!(relative_eq!(
+            genes_sum(&chromosome.genes),
+            1.0,
+            epsilon = 1e-9
+        ));
+        assert_ne!(before, chromosome.genes);
+    }
+    for _ in 0..100 {
+        genotype.mutate_chromosome_genes(3, false, &mut chromosome, &mut rng);
+        assert!(chromosome.genes.iter().all(|v| *v >= 0.0));
+        assert!(relative_eq!(
+            genes_sum(&chromosome.genes),
+            1.0,
+            epsilon = 1e-9
+        ));
+    }
+}
+
+#[test]
+fn mutate_chromosome_preserves_sum_range() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = SimplexGenotype::<f64>::builder()
+        .with_genes_size(5)
+        .with_allele_sum(100.0)
+        .with_mutation_type(MutationType::Range(1.0))
+        .build()
+        .unwrap();
+
+    let mut chromosome = build::chromosome(vec![20.0, 20.0, 20.0, 20.0, 20.0]);
+    for _ in 0..100 {
+        let before = chromosome.genes.clone();
+        genotype.mutate_chromosome_genes(1, true, &mut chromosome, &mut rng);
+        assert!(relative_eq!(
+            genes_sum(&chromosome.genes),
+            100.0,
+            epsilon = 1e-9
+        ));
+        // a single transfer changes two genes by at most the bandwidth
+        assert!(before
+            .iter()
+            .zip(chromosome.genes.iter())
+            .all(|(a, b)| (a - b).abs() <= 1.0 + 1e-9));
+    }
+}
+
+#[test]
+fn crossover_chromosome_genes_preserves_sum() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = SimplexGenotype::<f64>::builder()
+        .with_genes_size(4)
+        .build()
+        .unwrap();
+
+    let mut father = build::chromosome(vec![0.7, 0.1, 0.1, 0.1]);
+    let mut mother = build::chromosome(vec![0.1, 0.1, 0.1, 0.7]);
+    genotype.crossover_chromosome_genes(2, false, &mut father, &mut mother, &mut rng);
+    assert!(relative_eq!(genes_sum(&father.genes), 1.0, epsilon = 1e-9));
+    assert!(relative_eq!(genes_sum(&mother.genes), 1.0, epsilon = 1e-9));
+
+    for _ in 0..100 {
+        let mut father = Chromosome::new(genotype.random_genes_factory(&mut rng));
+        let mut mother = Chromosome::new(genotype.random_genes_factory(&mut rng));
+        genotype.crossover_chromosome_genes(2, true, &mut father, &mut mother, &mut rng);
+        assert!(father.genes.iter().all(|v| *v >= 0.0));
+        assert!(relative_eq!(genes_sum(&father.genes), 1.0, epsilon = 1e-9));
+        assert!(relative_eq!(genes_sum(&mother.genes), 1.0, epsilon = 1e-9));
+    }
+}
+
+#[test]
+fn crossover_chromosome_points_preserves_sum() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = SimplexGenotype::<f64>::builder()
+        .with_genes_size(10)
+        .with_allele_sum(3.0)
+        .build()
+        .unwrap();
+
+    for _ in 0..100 {
+        let mut father = Chromosome::new(genotype.random_genes_factory(&mut rng));
+        let mut mother = Chromosome::new(genotype.random_genes_factory(&mut rng));
+        genotype.crossover_chromosome_points(3, false, &mut father, &mut mother, &mut rng);
+        assert!(relative_eq!(genes_sum(&father.genes), 3.0, epsilon = 1e-9));
+        assert!(relative_eq!(genes_sum(&mother.genes), 3.0, epsilon = 1e-9));
+    }
+}
+
+#[test]
+fn neighbouring_population() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = SimplexGenotype::<f64>::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+
+    let chromosome = build::chromosome(vec![0.5, 0.5, 0.0]);
+    let mut population = Population::new(vec![], true);
+    genotype.fill_neighbouring_population(&chromosome, &mut population, &mut rng);
+
+    assert_eq!(genotype.neighbouring_population_size(), BigUint::from(6u32));
+    // the empty gene is no donor
+    assert_eq!(population.size(), 4);
+    assert!(population.chromosomes.iter().all(|c| relative_eq!(
+        genes_sum(&c.genes),
+        1.0,
+        epsilon = 1e-9
+    )));
+}
@@ -1,7 +1,8 @@
 #[cfg(test)]
 use crate::support::*;
 use genetic_algorithm::genotype::{
-    EvolveGenotype, Genotype, HillClimbGenotype, PermutateGenotype, UniqueGenotype,
+    EvolveGenotype, Genotype, GenotypeDistance, HillClimbGenotype, PermutateGenotype,
+    UniqueGenotype,
 };
 
 #[test]
@@ -68,6 +69,25 @@ fn mutate_chromosome_genes_without_duplicates() {
         vec![3, 2, 1, 4, 7, 8, 5, 6, 9]
     );
 }
+#[test]
+fn mutate_chromosome_genes_at_moves_gene() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list(vec![1, 2, 3, 4, 5])
+        .build()
+        .unwrap();
+
+    let mut chromosome = build::chromosome(vec![1, 2, 3, 4, 5]);
+    for _ in 0..100 {
+        let index = genotype.sample_gene_index(&mut rng);
+        let before = chromosome.genes.clone();
+        genotype.mutate_chromosome_genes_at(&[index], &mut chromosome, &mut rng);
+        assert_ne!(before[index], chromosome.genes[index]);
+        let mut genes = chromosome.genes.clone();
+        genes.sort();
+        assert_eq!(genes, vec![1, 2, 3, 4, 5]);
+    }
+}
 
 #[test]
 #[should_panic]
@@ -214,3 +234,31 @@ fn neighbouring_population_4() {
         ]
     );
 }
+
+#[test]
+fn genes_distance() {
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list((0..5).collect())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        genotype.genes_distance(&[0, 1, 2, 3, 4], &[0, 1, 2, 3, 4]),
+        0.0
+    );
+    // single swap
+    assert_eq!(
+        genotype.genes_distance(&[0, 1, 2, 3, 4], &[1, 0, 2, 3, 4]),
+        1.0
+    );
+    // a cycle of 3 takes 2 swaps, plus one more swap
+    assert_eq!(
+        genotype.genes_distance(&[0, 1, 2, 3, 4], &[1, 2, 0, 4, 3]),
+        3.0
+    );
+    // a cycle of 5 takes 4 swaps
+    assert_eq!(
+        genotype.genes_distance(&[0, 1, 2, 3, 4], &[4, 0, 1, 2, 3]),
+        4.0
+    );
+}
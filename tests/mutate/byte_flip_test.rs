@@ -0,0 +1,75 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::genotype::{Genotype, ListGenotype};
+use genetic_algorithm::mutate::{Mutate, MutateByteFlip};
+use genetic_algorithm::population::Population;
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::StrategyReporterNoop;
+
+#[test]
+fn list_genotype_u16() {
+    let genotype = ListGenotype::<u16>::builder()
+        .with_genes_size(3)
+        .with_allele_list(vec![0, 1, 2, 3])
+        .build()
+        .unwrap();
+
+    let population: Population<u16> = build::population(vec![
+        vec![0, 0, 0],
+        vec![0, 0, 0],
+        vec![0, 0, 0],
+        vec![0, 0, 0],
+        vec![0, 0, 0],
+        vec![0, 0, 0],
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let config = EvolveConfig::new();
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    MutateByteFlip::new(1.0).call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    // exactly one bit flipped in exactly one gene of each chromosome
+    for genes in inspect::population(&state.population) {
+        let changed_genes: Vec<u16> = genes.into_iter().filter(|gene| *gene != 0).collect();
+        assert_eq!(changed_genes.len(), 1);
+        assert_eq!(changed_genes[0].count_ones(), 1);
+    }
+    assert!(state
+        .population
+        .chromosomes
+        .iter()
+        .all(|c| c.fitness_score().is_none()));
+}
+
+#[test]
+fn flip_bit_changes_single_bit() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    for value in [0_u16, 1, 1234, u16::MAX] {
+        let mut gene = value;
+        MutateByteFlip::<ListGenotype<u16>>::flip_bit(&mut gene, &mut rng);
+        assert_eq!((gene ^ value).count_ones(), 1);
+    }
+}
+
+#[test]
+fn zero_probability() {
+    let genotype = ListGenotype::<u16>::builder()
+        .with_genes_size(3)
+        .with_allele_list(vec![0, 1, 2, 3])
+        .build()
+        .unwrap();
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = build::population(vec![vec![1, 2, 3], vec![3, 2, 1]]);
+    let config = EvolveConfig::new();
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    MutateByteFlip::new(0.0).call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert_eq!(
+        inspect::population(&state.population),
+        vec![vec![1, 2, 3], vec![3, 2, 1]]
+    );
+}
@@ -1,5 +1,7 @@
+pub mod byte_flip_test;
 pub mod multi_gene_dynamic_test;
 pub mod multi_gene_range_test;
+pub mod multi_gene_stratified_test;
 pub mod multi_gene_test;
 pub mod single_gene_dynamic_test;
 pub mod single_gene_test;
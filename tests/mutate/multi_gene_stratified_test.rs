@@ -0,0 +1,70 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::genotype::{BinaryGenotype, Genotype};
+use genetic_algorithm::mutate::{Mutate, MutateMultiGeneStratified};
+use genetic_algorithm::population::Population;
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::StrategyReporterNoop;
+
+#[test]
+fn binary_genotype_covers_all_genes() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+
+    let population: Population<bool> = build::population(vec![vec![true; 10]; 4]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let config = EvolveConfig::new();
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut mutate = MutateMultiGeneStratified::new(2, 1.0);
+
+    let mut mutated_genes = vec![false; 10];
+    for _ in 0..2 {
+        let before = inspect::population(&state.population);
+        mutate.call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+        let after = inspect::population(&state.population);
+        for (genes_before, genes_after) in before.iter().zip(after.iter()) {
+            let changed: Vec<usize> = (0..10)
+                .filter(|index| genes_before[*index] != genes_after[*index])
+                .collect();
+            assert_eq!(changed.len(), 2);
+            changed
+                .iter()
+                .for_each(|index| mutated_genes[*index] = true);
+        }
+    }
+
+    assert_eq!(mutated_genes, vec![true; 10]);
+}
+
+#[test]
+fn binary_genotype_number_of_mutations_capped_by_genes_size() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+
+    let population: Population<bool> = build::population(vec![vec![true; 3]; 4]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let config = EvolveConfig::new();
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    MutateMultiGeneStratified::new(5, 1.0).call(
+        &genotype,
+        &mut state,
+        &config,
+        &mut reporter,
+        &mut rng,
+    );
+
+    assert_eq!(
+        inspect::population(&state.population),
+        vec![vec![false; 3]; 4]
+    );
+}
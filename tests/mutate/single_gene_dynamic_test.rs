@@ -66,3 +66,35 @@ fn binary_genotype() {
         ]
     );
 }
+
+#[test]
+fn binary_genotype_mutation_floor() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+
+    let population: Population<bool> = build::population(vec![vec![true, true, true]; 10]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let config = EvolveConfig::new();
+    let mut reporter = StrategyReporterNoop::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut mutate = MutateSingleGeneDynamic::new(0.1, 5).with_mutation_floor(0.25);
+    assert_eq!(mutate.mutation_probability, 0.25);
+
+    mutate.mutation_probability = 1.0;
+    state.population_cardinality = Some(10);
+    for _ in 0..20 {
+        mutate.call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+        assert!(mutate.mutation_probability >= 0.25);
+    }
+    assert_eq!(mutate.mutation_probability, 0.25);
+
+    // a floor out of probability range is clamped
+    let mutate = MutateSingleGeneDynamic::<BinaryGenotype>::new(0.1, 5).with_mutation_floor(1.5);
+    assert_eq!(mutate.mutation_probability, 1.0);
+    let mutate = MutateSingleGeneDynamic::<BinaryGenotype>::new(0.1, 5).with_mutation_floor(-0.5);
+    assert_eq!(mutate.mutation_probability, 0.0);
+}
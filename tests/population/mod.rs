@@ -1,9 +1,11 @@
 use crate::support::build;
 use approx::assert_relative_eq;
+use genetic_algorithm::chromosome::Chromosome;
 use genetic_algorithm::fitness::placeholders::CountTrue;
 use genetic_algorithm::fitness::{Fitness, FitnessOrdering};
 use genetic_algorithm::genotype::{BinaryGenotype, Genotype};
 use genetic_algorithm::population::Population;
+use rayon::prelude::*;
 
 #[test]
 fn fitness_score_stddev() {
@@ -418,6 +420,48 @@ fn genes_cardinality() {
     assert_eq!(population.genes_cardinality(), Some(5));
 }
 
+#[test]
+fn unique_chromosome_count_and_deduplicate() {
+    let mut population: Population<bool> = build::population_without_genes_hash(vec![
+        vec![false, false, false],
+        vec![false, false, true],
+        vec![false, true, true],
+        vec![true, true, true],
+        vec![true, true, false],
+        vec![false, false, false],
+        vec![false, false, true],
+        vec![false, true, true],
+    ]);
+
+    assert_eq!(population.unique_chromosome_count(), None);
+    // hashed on demand
+    let mut population_without_genes_hash = population.clone();
+    assert_eq!(population_without_genes_hash.deduplicate(), 3);
+    assert_eq!(population_without_genes_hash.size(), 5);
+
+    population.chromosomes.iter_mut().for_each(|chromosome| {
+        chromosome.reset_metadata(true);
+    });
+
+    assert_eq!(population.unique_chromosome_count(), Some(5));
+    assert_eq!(population.deduplicate(), 3);
+    assert_eq!(population.unique_chromosome_count(), Some(5));
+    assert_eq!(
+        population
+            .chromosomes
+            .iter()
+            .map(|c| c.genes.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            vec![false, false, false],
+            vec![false, false, true],
+            vec![false, true, true],
+            vec![true, true, true],
+            vec![true, true, false],
+        ]
+    );
+}
+
 #[test]
 fn parents_and_offspring_size() {
     let population: Population<bool> = build::population_with_age(vec![
@@ -433,3 +477,210 @@ fn parents_and_offspring_size() {
 
     assert_eq!(population.parents_and_offspring_size(), (5, 3));
 }
+
+#[test]
+fn par_iter_fitness_score_sum() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut population: Population<bool> = build::population(vec![
+        vec![false, false, false],
+        vec![false, false, true],
+        vec![false, true, true],
+        vec![true, true, true],
+        vec![true, true, false],
+        vec![true, false, false],
+    ]);
+    CountTrue.call_for_population(&mut population, &genotype, None, None);
+
+    let serial_sum: isize = population
+        .chromosomes
+        .iter()
+        .filter_map(|c| c.fitness_score())
+        .sum();
+    let parallel_sum: isize = population
+        .par_iter()
+        .filter_map(|c| c.fitness_score())
+        .sum();
+    assert_eq!(serial_sum, 9);
+    assert_eq!(parallel_sum, serial_sum);
+
+    population.par_iter_mut().for_each(|c| {
+        c.genes.iter_mut().for_each(|gene| *gene = true);
+        c.reset_metadata(false);
+    });
+    CountTrue.call_for_population(&mut population, &genotype, None, None);
+    let parallel_sum: isize = population
+        .par_iter()
+        .filter_map(|c| c.fitness_score())
+        .sum();
+    assert_eq!(parallel_sum, 18);
+}
+
+#[test]
+fn into_sorted_vec() {
+    let population: Population<bool> = build::population_with_fitness_scores(vec![
+        (vec![false, true, true], Some(2)),
+        (vec![true, true, false], None),
+        (vec![false, false, false], Some(0)),
+        (vec![true, true, true], Some(3)),
+        (vec![false, false, true], Some(1)),
+    ]);
+
+    let chromosomes = population
+        .clone()
+        .into_sorted_vec(FitnessOrdering::Maximize);
+    assert_eq!(
+        chromosomes
+            .iter()
+            .map(|c| c.fitness_score())
+            .collect::<Vec<_>>(),
+        vec![Some(3), Some(2), Some(1), Some(0), None]
+    );
+    assert_eq!(chromosomes[0].genes, vec![true, true, true]);
+
+    let chromosomes: Vec<Chromosome<bool>> = population.into_sorted_vec(FitnessOrdering::Minimize);
+    assert_eq!(
+        chromosomes
+            .iter()
+            .map(|c| c.fitness_score())
+            .collect::<Vec<_>>(),
+        vec![Some(0), Some(1), Some(2), Some(3), None]
+    );
+    assert_eq!(chromosomes[4].genes, vec![true, true, false]);
+}
+
+#[test]
+fn non_dominated_fronts_and_crowding_distances() {
+    let mut population: Population<bool> = build::population(vec![
+        vec![false, false, false],
+        vec![false, false, true],
+        vec![false, true, false],
+        vec![false, true, true],
+        vec![true, false, false],
+        vec![true, true, true],
+    ]);
+    let objectives: Vec<Vec<isize>> = vec![
+        vec![1, 5],
+        vec![2, 2],
+        vec![3, 3],
+        vec![5, 1],
+        vec![1, 1],
+        vec![],
+    ];
+    population
+        .chromosomes
+        .iter_mut()
+        .zip(objectives)
+        .for_each(|(chromosome, objectives)| chromosome.objectives = objectives);
+
+    let fronts = population.non_dominated_fronts(FitnessOrdering::Maximize);
+    assert_eq!(fronts, vec![vec![0, 2, 3], vec![1], vec![4], vec![5]]);
+    assert_eq!(
+        population.pareto_front_indices(FitnessOrdering::Maximize),
+        vec![0, 2, 3]
+    );
+
+    let fronts = population.non_dominated_fronts(FitnessOrdering::Minimize);
+    assert_eq!(fronts, vec![vec![4], vec![0, 1, 3], vec![2], vec![5]]);
+
+    let distances = population.crowding_distances(&[0, 2, 3]);
+    assert_eq!(distances, vec![f64::INFINITY, 2.0, f64::INFINITY]);
+}
+
+#[test]
+fn pareto_front_indices_deduplicates_without_genes_hash() {
+    let mut population: Population<bool> = Population::new(
+        vec![
+            Chromosome::new(vec![false, false, true]),
+            Chromosome::new(vec![false, true, false]),
+            Chromosome::new(vec![false, false, true]),
+        ],
+        false,
+    );
+    population
+        .chromosomes
+        .iter_mut()
+        .zip(vec![vec![1, 5], vec![5, 1], vec![1, 5]])
+        .for_each(|(chromosome, objectives)| chromosome.objectives = objectives);
+
+    assert!(population
+        .chromosomes
+        .iter()
+        .all(|chromosome| chromosome.genes_hash().is_none()));
+    assert_eq!(
+        population.non_dominated_fronts(FitnessOrdering::Maximize)[0],
+        vec![0, 1, 2]
+    );
+    assert_eq!(
+        population.pareto_front_indices(FitnessOrdering::Maximize),
+        vec![0, 1]
+    );
+}
+
+#[test]
+fn fitness_score_statistics() {
+    let population: Population<bool> = build::population_with_fitness_scores(vec![
+        (vec![false, false, false], Some(0)),
+        (vec![false, false, true], Some(1)),
+        (vec![false, true, true], Some(2)),
+        (vec![true, true, true], Some(3)),
+        (vec![true, true, true], Some(4)),
+        (vec![true, true, false], None),
+    ]);
+
+    assert_eq!(population.fitness_score_min(), Some(0));
+    assert_eq!(population.fitness_score_max(), Some(4));
+    assert_eq!(population.fitness_score_percentile(0.0), Some(0.0));
+    assert_eq!(population.fitness_score_percentile(0.25), Some(1.0));
+    assert_eq!(population.fitness_score_percentile(0.5), Some(2.0));
+    assert_eq!(population.fitness_score_percentile(1.0), Some(4.0));
+    assert_relative_eq!(
+        population.fitness_score_percentile(0.1).unwrap(),
+        0.4,
+        epsilon = 0.001
+    );
+    assert_eq!(population.fitness_score_histogram(2), vec![2, 3]);
+    assert_eq!(population.fitness_score_histogram(4), vec![1, 1, 1, 2]);
+    assert_eq!(population.fitness_score_histogram(0), vec![] as Vec<usize>);
+
+    let population: Population<bool> =
+        build::population_with_fitness_scores(vec![(vec![true], None)]);
+    assert_eq!(population.fitness_score_min(), None);
+    assert_eq!(population.fitness_score_percentile(0.5), None);
+    assert_eq!(population.fitness_score_histogram(2), vec![] as Vec<usize>);
+}
+
+#[test]
+fn genes_entropy() {
+    let population = build::population(vec![
+        vec![true, false, true],
+        vec![true, true, true],
+        vec![true, false, true],
+        vec![true, true, false],
+    ]);
+    let genes_entropy = population.genes_entropy();
+    assert_eq!(genes_entropy.len(), 3);
+    assert_relative_eq!(genes_entropy[0], 0.0, epsilon = 0.001);
+    assert_relative_eq!(genes_entropy[1], 1.0, epsilon = 0.001);
+    assert_relative_eq!(genes_entropy[2], 0.811, epsilon = 0.001);
+
+    let population: Population<bool> = build::population(vec![]);
+    assert_eq!(population.genes_entropy(), vec![] as Vec<f64>);
+}
+
+#[test]
+fn genes_variance() {
+    let population = build::population(vec![
+        vec![1.0, 0.5, 2.0],
+        vec![3.0, 0.5, 2.0],
+        vec![1.0, 0.5, 5.0],
+        vec![3.0, 0.5, 5.0],
+    ]);
+    let genes_variance = population.genes_variance();
+    assert_eq!(genes_variance.len(), 3);
+    assert_relative_eq!(genes_variance[0], 1.0, epsilon = 0.001);
+    assert_relative_eq!(genes_variance[1], 0.0, epsilon = 0.001);
+    assert_relative_eq!(genes_variance[2], 2.25, epsilon = 0.001);
+}
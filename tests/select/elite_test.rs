@@ -4,7 +4,7 @@ use genetic_algorithm::fitness::placeholders::CountTrue;
 use genetic_algorithm::fitness::{Fitness, FitnessOrdering};
 use genetic_algorithm::genotype::{BinaryGenotype, Genotype};
 use genetic_algorithm::population::Population;
-use genetic_algorithm::select::{Select, SelectElite};
+use genetic_algorithm::select::{Select, SelectElite, SelectTournament};
 use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
 use genetic_algorithm::strategy::StrategyReporterNoop;
 
@@ -199,3 +199,98 @@ fn extreme_elitism_rates() {
         ]
     );
 }
+
+#[test]
+fn selection_intensity_versus_weak_tournament() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(1)
+        .build()
+        .unwrap();
+    // fitness scores 0..8, four times each
+    let population: Population<bool> = build::population_with_fitness_scores(
+        (0..32).map(|index| (vec![true], Some(index % 8))).collect(),
+    );
+    let mut reporter = StrategyReporterNoop::<BinaryGenotype>::new();
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Maximize,
+        target_population_size: 8,
+        ..Default::default()
+    };
+
+    let mut elite_state = EvolveState::new(&genotype);
+    elite_state.population = population.clone();
+    let mut rng = SmallRng::seed_from_u64(0);
+    SelectElite::new(1.0, 0.0).call(
+        &genotype,
+        &mut elite_state,
+        &config,
+        &mut reporter,
+        &mut rng,
+    );
+
+    let mut tournament_state = EvolveState::new(&genotype);
+    tournament_state.population = population;
+    let mut rng = SmallRng::seed_from_u64(0);
+    SelectTournament::new(1.0, 0.0, 1).call(
+        &genotype,
+        &mut tournament_state,
+        &config,
+        &mut reporter,
+        &mut rng,
+    );
+
+    // elite keeps the four 7s and four 6s: (6.5 - 3.5) / 2.29
+    let elite_intensity = elite_state.selection_intensity.unwrap();
+    assert!(elite_intensity > 1.28 && elite_intensity < 1.32);
+    let tournament_intensity = tournament_state.selection_intensity.unwrap();
+    assert!(elite_intensity > tournament_intensity);
+}
+
+#[test]
+fn maximize_with_deduplication() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .with_genes_hashing(true)
+        .build()
+        .unwrap();
+    let population: Population<bool> = build::population(vec![
+        vec![true, true, true],
+        vec![true, true, true],
+        vec![false, true, true],
+        vec![false, true, true],
+        vec![true, false, true],
+        vec![true, true, false],
+        vec![false, false, true],
+        vec![false, false, false],
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let mut reporter = StrategyReporterNoop::<BinaryGenotype>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    CountTrue.call_for_population(&mut state.population, &genotype, None, None);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Maximize,
+        target_population_size: 5,
+        ..Default::default()
+    };
+    SelectElite::new(0.5, 0.02).with_deduplication(true).call(
+        &genotype,
+        &mut state,
+        &config,
+        &mut reporter,
+        &mut rng,
+    );
+
+    assert_eq!(state.population.size(), 5);
+    assert_eq!(state.population.unique_chromosome_count(), Some(5));
+    assert_eq!(
+        state
+            .population
+            .chromosomes
+            .iter()
+            .filter_map(|c| c.fitness_score())
+            .sum::<isize>(),
+        3 + 2 + 2 + 2 + 1
+    );
+}
@@ -1,4 +1,8 @@
 pub mod elite_test;
+pub mod nsga2_test;
+pub mod percentile_test;
+pub mod proportionate_test;
+pub mod sharing_test;
 pub mod tournament_test;
 
 mod select_test {
@@ -0,0 +1,96 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::fitness::FitnessOrdering;
+use genetic_algorithm::genotype::BinaryGenotype;
+use genetic_algorithm::population::Population;
+use genetic_algorithm::select::{Select, SelectNsga2};
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::StrategyReporterNoop;
+
+fn population_with_objectives(data: Vec<(Vec<bool>, Vec<isize>)>) -> Population<bool> {
+    let mut population = build::population(data.iter().map(|(genes, _)| genes.clone()).collect());
+    population
+        .chromosomes
+        .iter_mut()
+        .zip(data)
+        .for_each(|(chromosome, (_, objectives))| {
+            chromosome.set_fitness_score(Some(objectives.iter().sum()));
+            chromosome.objectives = objectives;
+        });
+    population
+}
+
+#[test]
+fn maximize() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let population = population_with_objectives(vec![
+        (vec![false, false, false], vec![1, 5]),
+        (vec![false, false, true], vec![2, 2]),
+        (vec![false, true, false], vec![3, 3]),
+        (vec![false, true, true], vec![5, 1]),
+        (vec![true, false, false], vec![1, 1]),
+        (vec![true, true, true], vec![]),
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let mut reporter = StrategyReporterNoop::<BinaryGenotype>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Maximize,
+        target_population_size: 4,
+        ..Default::default()
+    };
+    SelectNsga2::new().call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert_eq!(
+        inspect::population(&state.population),
+        vec![
+            vec![false, false, false],
+            vec![false, true, true],
+            vec![false, true, false],
+            vec![false, false, true],
+        ]
+    );
+}
+
+#[test]
+fn minimize() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let population = population_with_objectives(vec![
+        (vec![false, false, false], vec![1, 5]),
+        (vec![false, false, true], vec![2, 2]),
+        (vec![false, true, false], vec![3, 3]),
+        (vec![false, true, true], vec![5, 1]),
+        (vec![true, false, false], vec![1, 1]),
+        (vec![true, true, true], vec![]),
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let mut reporter = StrategyReporterNoop::<BinaryGenotype>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Minimize,
+        target_population_size: 5,
+        ..Default::default()
+    };
+    SelectNsga2::new().call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert_eq!(
+        inspect::population(&state.population),
+        vec![
+            vec![true, false, false],
+            vec![false, false, false],
+            vec![false, true, true],
+            vec![false, false, true],
+            vec![false, true, false],
+        ]
+    );
+}
@@ -0,0 +1,202 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::crossover::CrossoverUniform;
+use genetic_algorithm::fitness::placeholders::CountTrue;
+use genetic_algorithm::fitness::{Fitness, FitnessOrdering};
+use genetic_algorithm::genotype::{BinaryGenotype, Genotype};
+use genetic_algorithm::mutate::MutateSingleGene;
+use genetic_algorithm::population::Population;
+use genetic_algorithm::select::{Select, SelectPercentile};
+use genetic_algorithm::strategy::evolve::{Evolve, EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::{Strategy, StrategyReporterNoop};
+
+#[test]
+fn maximize_better_half() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let population: Population<bool> = build::population_with_fitness_scores(vec![
+        (vec![false, false, false], Some(0)),
+        (vec![false, false, true], Some(5)),
+        (vec![false, true, false], Some(2)),
+        (vec![false, true, true], Some(7)),
+        (vec![true, false, false], Some(4)),
+        (vec![true, false, true], Some(1)),
+        (vec![true, true, false], Some(6)),
+        (vec![true, true, true], Some(3)),
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let mut reporter = StrategyReporterNoop::<BinaryGenotype>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Maximize,
+        target_population_size: 8,
+        ..Default::default()
+    };
+    SelectPercentile::new(0.5).call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert_eq!(
+        inspect::population_with_fitness_scores(&state.population),
+        vec![
+            (vec![false, true, true], Some(7)),
+            (vec![true, true, false], Some(6)),
+            (vec![false, false, true], Some(5)),
+            (vec![true, false, false], Some(4)),
+            // refilled up to the target_population_size
+            (vec![false, true, true], Some(7)),
+            (vec![true, true, false], Some(6)),
+            (vec![false, false, true], Some(5)),
+            (vec![true, false, false], Some(4)),
+        ]
+    );
+}
+
+#[test]
+fn minimize_better_half() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let population: Population<bool> = build::population_with_fitness_scores(vec![
+        (vec![false, false, false], Some(0)),
+        (vec![false, false, true], Some(5)),
+        (vec![false, true, false], Some(2)),
+        (vec![false, true, true], Some(7)),
+        (vec![true, false, false], Some(4)),
+        (vec![true, false, true], Some(1)),
+        (vec![true, true, false], Some(6)),
+        (vec![true, true, true], Some(3)),
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let mut reporter = StrategyReporterNoop::<BinaryGenotype>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Minimize,
+        target_population_size: 8,
+        ..Default::default()
+    };
+    SelectPercentile::new(0.5).call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert_eq!(
+        inspect::population_with_fitness_scores(&state.population),
+        vec![
+            (vec![false, false, false], Some(0)),
+            (vec![true, false, true], Some(1)),
+            (vec![false, true, false], Some(2)),
+            (vec![true, true, true], Some(3)),
+            // refilled up to the target_population_size
+            (vec![false, false, false], Some(0)),
+            (vec![true, false, true], Some(1)),
+            (vec![false, true, false], Some(2)),
+            (vec![true, true, true], Some(3)),
+        ]
+    );
+}
+
+#[test]
+fn fitness_ordering_with_none_fitness() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let population: Population<bool> = build::population_with_fitness_scores(vec![
+        (vec![false, false, false], None),
+        (vec![false, false, true], Some(1)),
+        (vec![false, true, true], Some(2)),
+        (vec![true, true, true], None),
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let mut reporter = StrategyReporterNoop::<BinaryGenotype>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Minimize,
+        target_population_size: 4,
+        ..Default::default()
+    };
+    SelectPercentile::new(0.5).call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert_eq!(
+        inspect::population_with_fitness_scores(&state.population),
+        vec![
+            (vec![false, false, true], Some(1)),
+            (vec![false, true, true], Some(2)),
+            (vec![false, false, true], Some(1)),
+            (vec![false, true, true], Some(2)),
+        ]
+    );
+}
+
+#[test]
+fn capped_by_target_population_size() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let population: Population<bool> = build::population(vec![
+        vec![false, false, false],
+        vec![false, false, true],
+        vec![false, true, true],
+        vec![true, true, true],
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let mut reporter = StrategyReporterNoop::<BinaryGenotype>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    CountTrue.call_for_population(&mut state.population, &genotype, None, None);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Maximize,
+        target_population_size: 1,
+        ..Default::default()
+    };
+    SelectPercentile::new(1.0).call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert_eq!(
+        inspect::population(&state.population),
+        vec![vec![true, true, true]]
+    );
+}
+
+#[test]
+fn interpolated_selection_size() {
+    let select = SelectPercentile::<BinaryGenotype>::new(0.25);
+    let mut rng = SmallRng::seed_from_u64(0);
+    let sizes: Vec<usize> = (0..1000)
+        .map(|_| select.selection_size(10, &mut rng))
+        .collect();
+    assert!(sizes.iter().all(|size| *size == 2 || *size == 3));
+    let count_3 = sizes.iter().filter(|size| **size == 3).count();
+    assert!(count_3 > 400 && count_3 < 600);
+
+    assert_eq!(select.selection_size(8, &mut rng), 2);
+}
+
+#[test]
+fn population_size_stable_over_generations() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(20)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_generations(30)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectPercentile::new(0.25))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    assert_eq!(evolve.generations_run(), 30);
+    // the selected target_population_size plus the offspring of the last generation
+    assert!(evolve.final_population().len() > 20);
+}
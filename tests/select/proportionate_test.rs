@@ -0,0 +1,115 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::fitness::FitnessOrdering;
+use genetic_algorithm::genotype::{BinaryGenotype, Genotype};
+use genetic_algorithm::population::Population;
+use genetic_algorithm::select::{Select, SelectProportionate, SelectProportionateScaling};
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::StrategyReporterNoop;
+
+fn population() -> Population<bool> {
+    build::population_with_fitness_scores(vec![
+        (vec![false, false, false], Some(-3)),
+        (vec![false, false, true], Some(2)),
+        (vec![false, true, false], Some(-1)),
+        (vec![false, true, true], Some(7)),
+        (vec![true, false, false], None),
+        (vec![true, false, true], Some(0)),
+        (vec![true, true, false], Some(5)),
+        (vec![true, true, true], Some(2)),
+    ])
+}
+
+#[test]
+fn weights_windowed() {
+    let population = population();
+    let select = SelectProportionate::<BinaryGenotype>::new_roulette_wheel(0.5, 0.0);
+    assert_eq!(
+        select.weights(&population.chromosomes, FitnessOrdering::Maximize),
+        vec![1.0, 6.0, 3.0, 11.0, 0.0, 4.0, 9.0, 6.0]
+    );
+    assert_eq!(
+        select.weights(&population.chromosomes, FitnessOrdering::Minimize),
+        vec![11.0, 6.0, 9.0, 1.0, 0.0, 8.0, 3.0, 6.0]
+    );
+}
+
+#[test]
+fn weights_rank() {
+    let population = population();
+    let select = SelectProportionate::<BinaryGenotype>::new_stochastic_universal(0.5, 0.0)
+        .with_scaling(SelectProportionateScaling::Rank);
+    assert_eq!(
+        select.weights(&population.chromosomes, FitnessOrdering::Maximize),
+        vec![1.0, 4.0, 2.0, 7.0, 0.0, 3.0, 6.0, 5.0]
+    );
+    assert_eq!(
+        select.weights(&population.chromosomes, FitnessOrdering::Minimize),
+        vec![7.0, 3.0, 6.0, 1.0, 0.0, 5.0, 2.0, 4.0]
+    );
+}
+
+#[test]
+fn roulette_wheel_maximize() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population();
+    let mut reporter = StrategyReporterNoop::<BinaryGenotype>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Maximize,
+        target_population_size: 6,
+        ..Default::default()
+    };
+    SelectProportionate::new_roulette_wheel(0.5, 0.0).call(
+        &genotype,
+        &mut state,
+        &config,
+        &mut reporter,
+        &mut rng,
+    );
+
+    let fitness_scores: Vec<Option<isize>> = state
+        .population
+        .chromosomes
+        .iter()
+        .map(|c| c.fitness_score())
+        .collect();
+    assert_eq!(fitness_scores.len(), 6);
+    // zero weight, only selected in shortage
+    assert!(!fitness_scores.contains(&None));
+    assert_eq!(state.population.chromosomes.capacity(), 8);
+}
+
+#[test]
+fn stochastic_universal_minimize_selects_each_once() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population();
+    let mut reporter = StrategyReporterNoop::<BinaryGenotype>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Minimize,
+        target_population_size: 8,
+        ..Default::default()
+    };
+    SelectProportionate::new_stochastic_universal(0.5, 0.0).call(
+        &genotype,
+        &mut state,
+        &config,
+        &mut reporter,
+        &mut rng,
+    );
+
+    let mut genes = inspect::population(&state.population);
+    genes.sort();
+    assert_eq!(genes, inspect::population(&population()));
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+use crate::support::*;
+use genetic_algorithm::fitness::placeholders::CountTrue;
+use genetic_algorithm::fitness::{Fitness, FitnessOrdering};
+use genetic_algorithm::genotype::{BinaryGenotype, Genotype};
+use genetic_algorithm::population::Population;
+use genetic_algorithm::select::{Select, SelectSharing};
+use genetic_algorithm::strategy::evolve::{EvolveConfig, EvolveState};
+use genetic_algorithm::strategy::StrategyReporterNoop;
+
+#[test]
+fn niche_counts() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let population: Population<bool> = build::population(vec![
+        vec![true, true, true],
+        vec![true, true, true],
+        vec![false, true, true],
+        vec![false, false, false],
+    ]);
+
+    let select = SelectSharing::new(0.5, 0.0, 2.0);
+    assert_eq!(
+        select.niche_counts(&genotype, &population.chromosomes),
+        vec![2.5, 2.5, 2.0, 1.0]
+    );
+
+    let select = SelectSharing::new(0.5, 0.0, 2.0).with_sharing_alpha(2.0);
+    assert_eq!(
+        select.niche_counts(&genotype, &population.chromosomes),
+        vec![2.75, 2.75, 2.5, 1.0]
+    );
+}
+
+#[test]
+fn maximize() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let population: Population<bool> = build::population(vec![
+        vec![true, true, true],
+        vec![true, true, true],
+        vec![true, true, true],
+        vec![true, true, true],
+        vec![false, true, true],
+        vec![true, false, false],
+        vec![false, false, false],
+    ]);
+
+    let mut state = EvolveState::new(&genotype);
+    state.population = population;
+    let mut reporter = StrategyReporterNoop::<BinaryGenotype>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+    CountTrue.call_for_population(&mut state.population, &genotype, None, None);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Maximize,
+        target_population_size: 4,
+        ..Default::default()
+    };
+    // only the duplicates share within a radius of 1.0
+    SelectSharing::new(0.5, 0.0, 1.0).call(&genotype, &mut state, &config, &mut reporter, &mut rng);
+
+    assert_eq!(
+        inspect::population(&state.population),
+        vec![
+            vec![false, true, true],
+            vec![true, false, false],
+            vec![true, true, true],
+            vec![true, true, true],
+        ]
+    );
+}
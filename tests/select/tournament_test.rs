@@ -254,3 +254,94 @@ fn extreme_elitism_rates() {
         ]
     );
 }
+
+#[test]
+fn soft_winner_distribution() {
+    let mut population: Population<bool> = Population::new(vec![], true);
+    let mut rng = SmallRng::seed_from_u64(0);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Maximize,
+        ..Default::default()
+    };
+    let select = SelectTournament::<BinaryGenotype>::new_soft(0.5, 0.0, 3, 0.5);
+
+    let mut winner_counts = [0usize; 3];
+    let number_of_tournaments = 10_000;
+    for _ in 0..number_of_tournaments {
+        let mut chromosomes = build::population_with_fitness_scores(vec![
+            (vec![false, false, false], Some(0)),
+            (vec![true, true, true], Some(2)),
+            (vec![true, false, false], Some(1)),
+        ])
+        .chromosomes;
+        select.selection(&mut chromosomes, 1, &mut population, &config, &mut rng);
+        assert_eq!(chromosomes.len(), 1);
+        winner_counts[2 - chromosomes[0].fitness_score().unwrap() as usize] += 1;
+    }
+
+    // geometric: best p, second p(1-p), last takes the remaining (1-p)^2
+    let winner_rates: Vec<f32> = winner_counts
+        .iter()
+        .map(|count| *count as f32 / number_of_tournaments as f32)
+        .collect();
+    assert!(relative_chromosome_eq(
+        winner_rates,
+        vec![0.5, 0.25, 0.25],
+        0.03
+    ));
+}
+
+#[test]
+fn sample_without_replacement_full_tournament() {
+    let mut population: Population<bool> = Population::new(vec![], true);
+    let mut rng = SmallRng::seed_from_u64(0);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Maximize,
+        ..Default::default()
+    };
+    let select =
+        SelectTournament::<BinaryGenotype>::new(0.5, 0.0, 3).with_sample_with_replacement(false);
+
+    for _ in 0..100 {
+        let mut chromosomes = build::population_with_fitness_scores(vec![
+            (vec![false, false, false], Some(0)),
+            (vec![true, true, true], Some(2)),
+            (vec![true, false, false], Some(1)),
+        ])
+        .chromosomes;
+        select.selection(&mut chromosomes, 1, &mut population, &config, &mut rng);
+        assert_eq!(chromosomes.len(), 1);
+        assert_eq!(chromosomes[0].fitness_score(), Some(2));
+    }
+}
+
+#[test]
+fn soft_sample_with_replacement_winner_distribution() {
+    let mut population: Population<bool> = Population::new(vec![], true);
+    let mut rng = SmallRng::seed_from_u64(0);
+    let config = EvolveConfig {
+        fitness_ordering: FitnessOrdering::Maximize,
+        ..Default::default()
+    };
+    let select = SelectTournament::<BinaryGenotype>::new_soft(0.5, 0.0, 3, 1.0)
+        .with_sample_with_replacement(true);
+
+    let mut best_count = 0;
+    let number_of_tournaments = 10_000;
+    for _ in 0..number_of_tournaments {
+        let mut chromosomes = build::population_with_fitness_scores(vec![
+            (vec![false, false, false], Some(0)),
+            (vec![true, true, true], Some(2)),
+            (vec![true, false, false], Some(1)),
+        ])
+        .chromosomes;
+        select.selection(&mut chromosomes, 1, &mut population, &config, &mut rng);
+        if chromosomes[0].fitness_score() == Some(2) {
+            best_count += 1;
+        }
+    }
+
+    // the best always wins if sampled, which happens with 1 - (2/3)^3
+    let best_rate = best_count as f32 / number_of_tournaments as f32;
+    assert!(relative_chromosome_eq(vec![best_rate], vec![0.704], 0.03));
+}
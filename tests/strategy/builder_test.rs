@@ -25,10 +25,46 @@ fn build_invalid_missing_variant() {
     assert!(strategy.is_err());
     assert_eq!(
         strategy.err(),
-        Some(TryFromStrategyBuilderError("StrategyVariant is required"))
+        Some(TryFromStrategyBuilderError(
+            "StrategyVariant is required",
+            BuilderError::MissingVariant
+        ))
     );
 }
 
+#[test]
+fn validate_variant() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(5)
+        .build()
+        .unwrap();
+
+    let builder = StrategyBuilder::new()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_fitness(CountTrue)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4));
+
+    assert_eq!(
+        builder.validate().errors,
+        vec!["StrategyVariant is required".to_string()]
+    );
+    assert_eq!(
+        builder
+            .clone()
+            .with_variant(StrategyVariant::Evolve(EvolveVariant::Standard))
+            .validate()
+            .errors,
+        vec!["Evolve requires at least a max_stale_generations, max_generations, target_fitness_score, max_duration or deadline ending condition".to_string()]
+    );
+    assert!(builder
+        .with_variant(StrategyVariant::Permutate(PermutateVariant::Standard))
+        .validate()
+        .is_valid());
+}
+
 #[test]
 fn call_permutate() {
     let genotype = BinaryGenotype::builder()
@@ -1,7 +1,12 @@
 #[cfg(test)]
 use crate::support::*;
-use genetic_algorithm::fitness::placeholders::{CountTrue, SumGenes};
+use genetic_algorithm::fitness::placeholders::{CountTrue, SumGenes, TourDistance, Zero};
 use genetic_algorithm::strategy::evolve::prelude::*;
+use genetic_algorithm::strategy::hill_climb::{HillClimb, HillClimbVariant};
+use genetic_algorithm::strategy::reporter::METRICS_COLUMNS;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[test]
 fn build_invalid_missing_ending_condition() {
@@ -24,7 +29,105 @@ fn build_invalid_missing_ending_condition() {
     assert_eq!(
         evolve.err(),
         Some(TryFromEvolveBuilderError(
-            "Evolve requires at least a max_stale_generations, max_generations or target_fitness_score ending condition"
+            "Evolve requires at least a max_stale_generations, max_generations, target_fitness_score, max_duration or deadline ending condition",
+            BuilderError::MissingEndingCondition
+        ))
+    );
+}
+
+#[test]
+fn build_invalid_soft_tournament_winning_probability() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(100)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8));
+
+    for winning_probability in [-0.1, 1.5, f32::NAN] {
+        let evolve = builder
+            .clone()
+            .with_select(SelectTournament::new_soft(
+                0.5,
+                0.02,
+                4,
+                winning_probability,
+            ))
+            .build();
+        assert_eq!(
+            evolve.err(),
+            Some(TryFromEvolveBuilderError(
+                "Evolve requires a Select strategy with a winning_probability between 0.0 and 1.0",
+                BuilderError::InvalidParameter
+            ))
+        );
+    }
+    assert!(builder
+        .with_select(SelectTournament::new_soft(0.5, 0.02, 4, 0.8))
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn build_invalid_fitness_resampling() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(100)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4));
+
+    let evolve = builder.clone().with_fitness_resampling(0).build();
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires a fitness_resampling with samples > 0",
+            BuilderError::InvalidParameter
+        ))
+    );
+    let evolve = builder
+        .clone()
+        .with_fitness_resampling(3)
+        .with_fitness_resampling_best_interval(0)
+        .build();
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires a fitness_resampling with best_reevaluation_interval > 0",
+            BuilderError::InvalidParameter
+        ))
+    );
+    let evolve = builder
+        .clone()
+        .with_fitness_resampling_best_interval(5)
+        .build();
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires a fitness_resampling for the fitness_resampling_aggregation and fitness_resampling_best_interval",
+            BuilderError::InvalidParameter
+        ))
+    );
+    let evolve = builder
+        .with_fitness_resampling(3)
+        .with_fitness_cache(100)
+        .build();
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve does not support a fitness_cache with fitness_resampling",
+            BuilderError::InvalidParameter
         ))
     );
 }
@@ -51,14 +154,17 @@ fn build_invalid_require_crossover_indexes() {
     assert_eq!(
         evolve.err(),
         Some(TryFromEvolveBuilderError(
-            "The provided Crossover strategy requires crossover_indexes, which the provided EvolveGenotype does not provide"
+            "The provided Crossover strategy requires crossover_indexes, which the provided EvolveGenotype does not provide",
+            BuilderError::IncompatibleCrossover {
+                requires: "crossover_indexes"
+            }
         ))
     );
 }
 #[test]
-fn build_invalid_require_crossover_points() {
-    let genotype = UniqueGenotype::builder()
-        .with_allele_list((0..10).collect())
+fn build_invalid_require_crossover_ordered() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
         .build()
         .unwrap();
     let evolve = Evolve::builder()
@@ -66,26 +172,27 @@ fn build_invalid_require_crossover_points() {
         .with_target_population_size(100)
         .with_max_stale_generations(20)
         .with_mutate(MutateSingleGene::new(0.1))
-        .with_fitness(SumGenes::new())
-        .with_crossover(CrossoverSinglePoint::new(0.7, 0.8))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverOrdered::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionNoop::new())
-        // .with_reporter(StrategyReporterNoop::new())
         .build();
 
     assert!(evolve.is_err());
     assert_eq!(
         evolve.err(),
         Some(TryFromEvolveBuilderError(
-            "The provided Crossover strategy requires crossover_points, which the provided EvolveGenotype does not provide"
+            "The provided Crossover strategy requires crossover_ordered, which the provided EvolveGenotype does not provide",
+            BuilderError::IncompatibleCrossover {
+                requires: "crossover_ordered"
+            }
         ))
     );
 }
 
 #[test]
-fn call_binary_max_stale_generations_maximize() {
-    let genotype = BinaryGenotype::builder()
-        .with_genes_size(10)
+fn call_unique_crossover_ordered() {
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list((0..10).collect())
         .build()
         .unwrap();
     let evolve = Evolve::builder()
@@ -93,54 +200,130 @@ fn call_binary_max_stale_generations_maximize() {
         .with_target_population_size(100)
         .with_max_stale_generations(20)
         .with_mutate(MutateSingleGene::new(0.1))
-        .with_fitness(CountTrue)
-        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverPartiallyMapped::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionNoop::new())
-        .with_reporter(StrategyReporterNoop::new())
         .with_rng_seed_from_u64(0)
         .call()
         .unwrap();
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(10));
+    let mut best_genes = evolve.best_genes().unwrap();
+    best_genes.sort_unstable();
+    assert_eq!(best_genes, (0..10).collect::<Vec<i32>>());
+    assert_eq!(evolve.best_fitness_score(), Some(45));
+}
+
+#[test]
+fn build_invalid_error_kinds() {
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list((0..10).collect())
+        .build()
+        .unwrap();
+    let builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4));
+
+    let error = builder.clone().build().err().unwrap();
     assert_eq!(
-        evolve.best_genes().unwrap(),
-        vec![true, true, true, true, true, true, true, true, true, true]
+        error.kind(),
+        BuilderError::IncompatibleCrossover {
+            requires: "crossover_indexes"
+        }
+    );
+    // the message is kept as Display
+    assert_eq!(error.to_string(), error.0);
+
+    let builder = Evolve::builder()
+        .with_genotype(builder.genotype.unwrap())
+        .with_target_population_size(100)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverClone::new(0.7))
+        .with_select(SelectTournament::new(0.5, 0.02, 4));
+
+    let error = builder
+        .clone()
+        .with_max_generations(10)
+        .with_generation_gap(0)
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(error.kind(), BuilderError::InvalidParameter);
+    assert_eq!(error.0, "Evolve requires a generation_gap > 0");
+
+    let error = builder.build().err().unwrap();
+    assert_eq!(error.kind(), BuilderError::MissingEndingCondition);
+    let boxed: Box<dyn std::error::Error> = Box::new(error);
+    assert_eq!(
+        boxed.to_string(),
+        "Evolve requires at least a max_stale_generations, max_generations, target_fitness_score, max_duration or deadline ending condition"
     );
 }
 
 #[test]
-fn call_binary_max_stale_generations_minimize() {
-    let genotype = BinaryGenotype::builder()
-        .with_genes_size(10)
+fn build_invalid_require_crossover_points() {
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list((0..10).collect())
         .build()
         .unwrap();
     let evolve = Evolve::builder()
         .with_genotype(genotype)
         .with_target_population_size(100)
-        .with_fitness_ordering(FitnessOrdering::Minimize)
         .with_max_stale_generations(20)
         .with_mutate(MutateSingleGene::new(0.1))
-        .with_fitness(CountTrue)
-        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverSinglePoint::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
         .with_extension(ExtensionNoop::new())
         // .with_reporter(StrategyReporterNoop::new())
-        .with_rng_seed_from_u64_option(Some(0))
-        .call()
+        .build();
+
+    assert!(evolve.is_err());
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "The provided Crossover strategy requires crossover_points, which the provided EvolveGenotype does not provide",
+            BuilderError::IncompatibleCrossover {
+                requires: "crossover_points"
+            }
+        ))
+    );
+}
+
+#[test]
+fn build_invalid_unique_with_crossover_uniform() {
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list((0..10).collect())
+        .build()
         .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .build();
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(0));
+    assert!(evolve.is_err());
     assert_eq!(
-        evolve.best_genes().unwrap(),
-        vec![false, false, false, false, false, false, false, false, false, false]
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "The provided Crossover strategy requires crossover_indexes, which the provided EvolveGenotype does not provide",
+            BuilderError::IncompatibleCrossover {
+                requires: "crossover_indexes"
+            }
+        ))
     );
 }
 
 #[test]
-fn call_binary_max_generations_maximize() {
+fn build_invalid_crossover_mask_size() {
     let genotype = BinaryGenotype::builder()
         .with_genes_size(10)
         .build()
@@ -148,392 +331,2457 @@ fn call_binary_max_generations_maximize() {
     let evolve = Evolve::builder()
         .with_genotype(genotype)
         .with_target_population_size(100)
-        .with_max_generations(50)
+        .with_max_stale_generations(20)
         .with_mutate(MutateSingleGene::new(0.1))
         .with_fitness(CountTrue)
-        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_crossover(CrossoverMasked::new(0.7, 0.8, vec![true; 9]))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionNoop::new())
-        .with_reporter(StrategyReporterNoop::new())
-        .with_rng_seed_from_u64(0)
-        .call()
+        .build();
+
+    assert!(evolve.is_err());
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "The provided Crossover strategy's mask size does not match the genes_size of the provided EvolveGenotype",
+            BuilderError::IncompatibleCrossover {
+                requires: "mask_size"
+            }
+        ))
+    );
+}
+
+#[test]
+fn build_invalid_simplex_with_crossover_group_point() {
+    let genotype = SimplexGenotype::<f64>::builder()
+        .with_genes_size(6)
+        .build()
         .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new_with_precision(1e-3))
+        .with_crossover(CrossoverGroupPoint::new(0.7, 0.8, vec![2, 4], 1))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .build();
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(10));
+    assert!(evolve.is_err());
     assert_eq!(
-        evolve.best_genes().unwrap(),
-        vec![true, true, true, true, true, true, true, true, true, true]
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "The provided Crossover strategy requires raw_gene_swap, which the provided EvolveGenotype does not provide",
+            BuilderError::IncompatibleCrossover {
+                requires: "raw_gene_swap"
+            }
+        ))
     );
 }
 
 #[test]
-fn call_binary_max_stale_generations_and_valid_fitness_score_maximize() {
-    let genotype = BinaryGenotype::builder()
-        .with_genes_size(100)
+fn build_invalid_one_hot_with_crossover_masked() {
+    let genotype = OneHotGenotype::builder()
+        .with_group_sizes(vec![2, 3])
         .build()
         .unwrap();
     let evolve = Evolve::builder()
         .with_genotype(genotype)
-        .with_target_population_size(20)
-        .with_max_stale_generations(2)
-        .with_valid_fitness_score(75)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
         .with_mutate(MutateSingleGene::new(0.1))
-        .with_fitness(CountTrue)
-        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_fitness(Zero::new())
+        .with_crossover(CrossoverMasked::new(0.7, 0.8, vec![true; 5]))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        // .with_extension(ExtensionNoop::new())
-        .with_reporter(StrategyReporterNoop::new())
-        .with_rng_seed_from_u64(0)
-        .call()
+        .build();
+
+    assert!(evolve.is_err());
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "The provided Crossover strategy requires raw_gene_swap, which the provided EvolveGenotype does not provide",
+            BuilderError::IncompatibleCrossover {
+                requires: "raw_gene_swap"
+            }
+        ))
+    );
+}
+
+#[test]
+fn build_invalid_latin_square_with_crossover_uniform() {
+    let genotype = LatinSquareGenotype::builder()
+        .with_latin_square_size(4)
+        .build()
         .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(Zero::new())
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .build();
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(75));
+    assert!(evolve.is_err());
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "The provided Crossover strategy requires crossover_indexes, which the provided EvolveGenotype does not provide",
+            BuilderError::IncompatibleCrossover {
+                requires: "crossover_indexes"
+            }
+        ))
+    );
 }
 
 #[test]
-fn call_binary_max_stale_generations_and_valid_fitness_score_minimize() {
-    let genotype = BinaryGenotype::builder()
-        .with_genes_size(100)
+fn build_valid_multi_unique_with_crossover_single_point() {
+    let genotype = MultiUniqueGenotype::builder()
+        .with_allele_lists(vec![(0..5).collect(), (5..10).collect()])
         .build()
         .unwrap();
     let evolve = Evolve::builder()
         .with_genotype(genotype)
-        .with_target_population_size(20)
-        .with_fitness_ordering(FitnessOrdering::Minimize)
-        .with_max_stale_generations(2)
-        .with_valid_fitness_score(25)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
         .with_mutate(MutateSingleGene::new(0.1))
-        .with_fitness(CountTrue)
-        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverSinglePoint::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionNoop::new())
-        // .with_reporter(StrategyReporterNoop::new())
-        .with_rng_seed_from_u64(0)
-        .call()
+        .build();
+
+    assert!(evolve.is_ok());
+}
+
+#[test]
+fn validate_reports_all_problems() {
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list((0..10).collect())
+        .build()
         .unwrap();
+    let builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4));
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(25));
+    assert_eq!(
+        builder.validate(),
+        BuilderValidation {
+            errors: vec![
+                "The provided Crossover strategy requires crossover_indexes, which the provided EvolveGenotype does not provide".to_string(),
+                "Evolve requires at least a max_stale_generations, max_generations, target_fitness_score, max_duration or deadline ending condition".to_string(),
+            ],
+            warnings: vec![],
+        }
+    );
+    let validation = builder.with_max_stale_generations(20).validate();
+    assert!(!validation.is_valid());
+    assert_eq!(
+        validation.errors,
+        vec![
+            "The provided Crossover strategy requires crossover_indexes, which the provided EvolveGenotype does not provide".to_string(),
+        ]
+    );
 }
 
 #[test]
-fn call_binary_target_fitness_score_maximize() {
+fn validate_valid() {
     let genotype = BinaryGenotype::builder()
         .with_genes_size(10)
         .build()
         .unwrap();
-    let evolve = Evolve::builder()
+    let builder = Evolve::builder()
         .with_genotype(genotype)
         .with_target_population_size(100)
-        .with_target_fitness_score(9)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4));
+
+    assert_eq!(builder.validate(), BuilderValidation::default());
+}
+
+#[test]
+fn validate_warning_number_of_crossovers_exceeds_genes_size() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverMultiPoint::new(0.7, 0.8, 1000, true))
+        .with_select(SelectTournament::new(0.5, 0.02, 4));
+
+    let validation = builder.validate();
+    assert!(validation.is_valid());
+    assert_eq!(
+        validation.warnings,
+        vec![
+            "The provided Crossover strategy's number_of_crossovers (1000) exceeds the genes_size (10) and is silently clamped, derive it from the genes_size instead".to_string(),
+        ]
+    );
+    // only a warning, the build succeeds
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn validate_warning_likely_misconfigurations() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(4)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.0))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverUniform::new(0.0, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 8));
+
+    let validation = builder.validate();
+    assert!(validation.is_valid());
+    assert_eq!(
+        validation.warnings,
+        vec![
+            "The provided Crossover strategy's selection_rate is zero, so no offspring are produced".to_string(),
+            "The provided Mutate strategy's mutation_probability is zero, so the offspring are never mutated".to_string(),
+            "The target_population_size (4) is smaller than the provided Select strategy's tournament_size (8), so the tournaments are clamped to the population size".to_string(),
+        ]
+    );
+    // only warnings, the build succeeds
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn validate_warning_select_ignored_by_variant() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_variant(EvolveVariant::SteadyState)
+        .with_target_population_size(4)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 8));
+
+    let validation = builder.validate();
+    assert!(validation.is_valid());
+    assert_eq!(
+        validation.warnings,
+        vec![
+            "The provided Select strategy is ignored, as the replacement of the Generational and SteadyState EvolveVariant takes its place".to_string(),
+        ]
+    );
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn call_binary_max_stale_generations_maximize() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionNoop::new())
+        .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(10));
+    assert_eq!(
+        evolve.best_genes().unwrap(),
+        vec![true, true, true, true, true, true, true, true, true, true]
+    );
+}
+
+#[test]
+fn call_unique_mutation_only() {
+    // 8 cities on a circle, the optimal tour visits them in (rotated or reversed) order
+    let distance_matrix: Vec<Vec<f64>> = (0..8)
+        .map(|i: i32| {
+            (0..8)
+                .map(|j: i32| ((i - j).abs()).min(8 - (i - j).abs()) as f64)
+                .collect()
+        })
+        .collect();
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list((0..8).collect())
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_fitness_ordering(FitnessOrdering::Minimize)
+        .with_max_stale_generations(100)
+        .with_target_fitness_score(8)
+        .with_mutate(MutateSingleGene::new(0.5))
+        .with_fitness(TourDistance::new(distance_matrix))
+        .with_crossover(CrossoverNoop::new())
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        // .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(8));
+}
+
+#[derive(Clone, Debug)]
+struct FrontAndBackTradeOff;
+impl MultiObjectiveFitness for FrontAndBackTradeOff {
+    type Genotype = BinaryGenotype;
+    fn calculate_objectives_for_chromosome(
+        &mut self,
+        chromosome: &Chromosome<bool>,
+        _genotype: &Self::Genotype,
+    ) -> Option<Vec<FitnessValue>> {
+        let front_true_count = chromosome.genes[0..4].iter().filter(|&v| *v).count();
+        let back_false_count = chromosome.genes[2..6].iter().filter(|&v| !*v).count();
+        Some(vec![
+            front_true_count as FitnessValue,
+            back_false_count as FitnessValue,
+        ])
+    }
+}
+
+#[test]
+fn call_binary_multi_objective() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(6)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_generations(50)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(FitnessMultiObjective::new(FrontAndBackTradeOff))
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectNsga2::new())
+        // .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    let pareto_front = evolve.pareto_front();
+    assert!(!pareto_front.is_empty());
+    pareto_front.iter().for_each(|chromosome| {
+        assert_eq!(chromosome.genes[0..2], [true, true]);
+        assert_eq!(chromosome.genes[4..6], [false, false]);
+        assert_eq!(chromosome.objectives().iter().sum::<FitnessValue>(), 6);
+        pareto_front.iter().for_each(|other| {
+            assert!(
+                !FitnessOrdering::Maximize.dominates(other.objectives(), chromosome.objectives())
+            );
+        });
+    });
+    assert_eq!(evolve.best_fitness_score(), Some(6));
+}
+
+#[test]
+fn call_binary_max_stale_generations_minimize() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_fitness_ordering(FitnessOrdering::Minimize)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionNoop::new())
+        // .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64_option(Some(0))
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(0));
+    assert_eq!(
+        evolve.best_genes().unwrap(),
+        vec![false, false, false, false, false, false, false, false, false, false]
+    );
+}
+
+#[test]
+fn call_binary_max_generations_maximize() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_generations(50)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionNoop::new())
+        .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(10));
+    assert_eq!(
+        evolve.best_genes().unwrap(),
+        vec![true, true, true, true, true, true, true, true, true, true]
+    );
+}
+
+#[test]
+fn call_binary_max_stale_generations_and_valid_fitness_score_maximize() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_stale_generations(2)
+        .with_valid_fitness_score(75)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        // .with_extension(ExtensionNoop::new())
+        .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(75));
+}
+
+#[test]
+fn call_binary_max_stale_generations_and_valid_fitness_score_minimize() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_fitness_ordering(FitnessOrdering::Minimize)
+        .with_max_stale_generations(2)
+        .with_valid_fitness_score(25)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionNoop::new())
+        // .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(25));
+}
+
+#[test]
+fn call_binary_target_fitness_score_maximize() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_target_fitness_score(9)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionNoop::new())
+        .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(9));
+    assert_eq!(
+        evolve.best_genes().unwrap(),
+        vec![true, true, true, false, true, true, true, true, true, true]
+    );
+}
+
+#[test]
+fn call_binary_target_fitness_score_minimize() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_fitness_ordering(FitnessOrdering::Minimize)
+        .with_target_fitness_score(0)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionNoop::new())
+        // .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(0));
+    assert_eq!(
+        evolve.best_genes().unwrap(),
+        vec![false, false, false, false, false, false, false, false, false, false]
+    );
+}
+
+#[test]
+fn call_binary_mass_degeneration() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_target_fitness_score(10)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionMassDegeneration::new(10, 10, 0.02))
+        .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(10));
+}
+
+#[test]
+fn call_binary_mass_extinction() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_target_fitness_score(10)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionMassExtinction::new(10, 0.1, 0.02))
+        // .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(10));
+}
+
+#[test]
+fn call_binary_mass_genesis() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_target_fitness_score(10)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionMassGenesis::new(10))
+        .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(10));
+}
+
+#[test]
+fn call_range_f32() {
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(10)
+        .with_allele_range(0.0..=1.0)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new_with_precision(1e-3))
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        // .with_extension(ExtensionNoop::new())
+        .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(9880));
+    assert!(relative_chromosome_eq(
+        evolve.best_genes().unwrap(),
+        vec![0.998, 0.993, 0.979, 0.992, 0.982, 0.999, 0.987, 0.972, 0.979, 0.995],
+        0.001
+    ));
+}
+
+#[test]
+fn call_range_usize() {
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(10)
+        .with_allele_range(0..=9)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        // .with_extension(ExtensionNoop::new())
+        .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(89));
+    assert_eq!(
+        evolve.best_genes().unwrap(),
+        vec![9, 9, 9, 8, 9, 9, 9, 9, 9, 9]
+    );
+}
+
+#[test]
+fn call_range_isize() {
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(10)
+        .with_allele_range(0..=9)
+        .with_mutation_type(MutationType::Range(1))
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        // .with_extension(ExtensionNoop::new())
+        .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(90));
+    assert_eq!(
+        evolve.best_genes().unwrap(),
+        vec![9, 9, 9, 9, 9, 9, 9, 9, 9, 9]
+    );
+}
+
+#[test]
+fn call_list() {
+    let genotype = ListGenotype::builder()
+        .with_genes_size(10)
+        .with_allele_list((0..4).collect())
+        .build()
+        .unwrap();
+
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionNoop::new())
+        // .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(30));
+    assert_eq!(
+        evolve.best_genes().unwrap(),
+        vec![3, 3, 3, 3, 3, 3, 3, 3, 3, 3]
+    );
+}
+
+#[test]
+fn call_multi_list() {
+    let genotype = MultiListGenotype::builder()
+        .with_allele_lists(vec![
+            vec![0, 1, 2, 3, 4],
+            vec![0, 1],
+            vec![0],
+            vec![0, 1, 2, 3],
+        ])
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionNoop::new())
+        .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(8));
+    assert_eq!(evolve.best_genes().unwrap(), vec![4, 1, 0, 3]);
+}
+
+#[test]
+fn call_par_fitness() {
+    let genotype = ListGenotype::builder()
+        .with_genes_size(10)
+        .with_allele_list((0..4).collect())
+        .build()
+        .unwrap();
+
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new())
+        .with_par_fitness(true)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionNoop::new())
+        // .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(30));
+    assert_eq!(
+        evolve.best_genes().unwrap(),
+        vec![3, 3, 3, 3, 3, 3, 3, 3, 3, 3]
+    );
+}
+
+#[test]
+fn population_factory_binary() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(4)
+        .build()
+        .unwrap();
+    let mut evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(8)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_extension(ExtensionNoop::new())
+        .with_reporter(StrategyReporterNoop::new())
+        .with_rng_seed_from_u64(0)
+        .build()
+        .unwrap();
+
+    evolve.setup(None);
+    assert_eq!(
+        inspect::population(&evolve.state.population),
+        vec![
+            vec![false, false, true, false],
+            vec![true, true, true, false],
+            vec![false, true, false, true],
+            vec![true, false, true, false],
+            vec![false, false, true, true],
+            vec![true, false, false, true],
+            vec![false, true, true, false],
+            vec![true, false, true, false]
+        ]
+    )
+}
+
+#[test]
+fn call_binary_evaluate_initial_population() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_target_fitness_score(3)
+        .with_evaluate_initial_population(true)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    println!("{:#?}", evolve.best_genes());
+    assert_eq!(evolve.best_fitness_score(), Some(3));
+    assert_eq!(evolve.best_generation(), 0);
+    assert_eq!(evolve.state.current_generation, 0);
+    assert_eq!(evolve.best_genes().unwrap(), vec![true, true, true]);
+}
+
+#[test]
+fn setup_binary_without_evaluate_initial_population() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(3)
+        .build()
+        .unwrap();
+    let mut evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(8)
+        .with_target_fitness_score(3)
+        .with_evaluate_initial_population(false)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .build()
+        .unwrap();
+
+    evolve.setup(None);
+    assert_eq!(evolve.best_fitness_score(), None);
+    assert!(evolve
+        .state
+        .population
+        .chromosomes
+        .iter()
+        .all(|c| c.fitness_score().is_none()));
+}
+
+#[test]
+fn call_binary_generations_and_iterations_run() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve_builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_generations(15)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0);
+
+    let evolve = evolve_builder.clone().build().unwrap();
+    assert_eq!(evolve.generations_run(), 0);
+    assert_eq!(evolve.iterations_run(), 0);
+
+    let evolve = evolve_builder.clone().call().unwrap();
+    assert_eq!(evolve.generations_run(), 15);
+    assert_eq!(evolve.iterations_run(), 1);
+
+    // the best run counts all completed runs, the others only themselves
+    let (best_evolve, other_evolves) = evolve_builder.call_repeatedly(3).unwrap();
+    assert_eq!(best_evolve.generations_run(), 15);
+    assert_eq!(best_evolve.iterations_run(), 3);
+    other_evolves.iter().for_each(|e| {
+        assert_eq!(e.generations_run(), 15);
+        assert_eq!(e.iterations_run(), 1);
+    });
+}
+
+#[test]
+fn call_speciated_derived_seed_reproduces_species() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve_builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_generations(15)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0);
+    assert_ne!(
+        evolve_builder.derived_seed(0),
+        evolve_builder.derived_seed(1)
+    );
+    assert_ne!(
+        evolve_builder.derived_seed(1),
+        evolve_builder.derived_seed(2)
+    );
+    assert_ne!(evolve_builder.derived_seed(0), 0);
+
+    let (_final_evolve, species_evolves) = evolve_builder.clone().call_speciated(3).unwrap();
+    assert_eq!(species_evolves.len(), 3);
+    species_evolves
+        .iter()
+        .enumerate()
+        .for_each(|(index, species_evolve)| {
+            let reproduced_evolve = evolve_builder
+                .clone()
+                .with_rng_seed_from_u64(evolve_builder.derived_seed(index))
+                .call()
+                .unwrap();
+            assert_eq!(species_evolve.best_genes(), reproduced_evolve.best_genes());
+            assert_eq!(
+                species_evolve.best_fitness_score(),
+                reproduced_evolve.best_fitness_score()
+            );
+            assert_eq!(
+                species_evolve.best_generation(),
+                reproduced_evolve.best_generation()
+            );
+        });
+}
+
+/// Deceptive trap: per block of 4 genes, all true scores 4, otherwise the fewer true the better
+#[derive(Clone, Debug)]
+struct TrapBlocks;
+impl Fitness for TrapBlocks {
+    type Genotype = BinaryGenotype;
+    fn calculate_for_chromosome(
+        &mut self,
+        chromosome: &FitnessChromosome<Self>,
+        _genotype: &Self::Genotype,
+    ) -> Option<FitnessValue> {
+        Some(
+            chromosome
+                .genes
+                .chunks(4)
+                .map(|block| match block.iter().filter(|&value| *value).count() {
+                    4 => 4,
+                    count => 3 - count as FitnessValue,
+                })
+                .sum(),
+        )
+    }
+}
+
+#[test]
+fn call_par_speciated_with_speciation_merge_interval() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(20)
+        .build()
+        .unwrap();
+    let (final_evolve, species_evolves) = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_stale_generations(10)
+        .with_speciation_merge_interval(5)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(TrapBlocks)
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .call_par_speciated(4)
+        .unwrap();
+
+    assert_eq!(species_evolves.len(), 4);
+    // the epochs end without overall improvement, so the lagging species were all reseeded with
+    // the overall best and caught up, where isolated species would each keep their own best
+    let best_fitness_score = species_evolves[0].best_fitness_score();
+    assert!(best_fitness_score.is_some());
+    species_evolves.iter().for_each(|species_evolve| {
+        assert_eq!(species_evolve.best_fitness_score(), best_fitness_score);
+    });
+    assert!(final_evolve.best_fitness_score() >= best_fitness_score);
+}
+
+#[test]
+fn call_speciated_with_speciation_merge_interval_and_max_generations() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(20)
+        .build()
+        .unwrap();
+    let (_final_evolve, species_evolves) = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_generations(12)
+        .with_speciation_merge_interval(5)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(TrapBlocks)
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call_speciated(3)
+        .unwrap();
+
+    assert_eq!(species_evolves.len(), 3);
+    // the max_generations is summed over the epochs, so at most 5 + 5 + 2 generations
+    species_evolves.iter().for_each(|species_evolve| {
+        assert!(species_evolve.generations_run() <= 5);
+    });
+}
+
+#[test]
+fn migration_topology_source_indices() {
+    let topology = EvolveMigrationTopology::Ring;
+    assert_eq!(topology.source_indices(0, 4), vec![3]);
+    assert_eq!(topology.source_indices(2, 4), vec![1]);
+    assert_eq!(topology.source_indices(0, 1), vec![] as Vec<usize>);
+
+    let topology = EvolveMigrationTopology::FullyConnected;
+    assert_eq!(topology.source_indices(0, 4), vec![1, 2, 3]);
+    assert_eq!(topology.source_indices(2, 4), vec![0, 1, 3]);
+    assert_eq!(topology.source_indices(0, 1), vec![] as Vec<usize>);
+}
+
+#[test]
+fn call_par_islands_ring() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(20)
+        .build()
+        .unwrap();
+    let (best_evolve, island_evolves) = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_stale_generations(10)
+        .with_migration(EvolveMigrationTopology::Ring, 5)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(TrapBlocks)
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .call_par_islands(4)
+        .unwrap();
+
+    assert_eq!(island_evolves.len(), 3);
+    assert!(best_evolve.best_fitness_score().is_some());
+    island_evolves.iter().for_each(|island_evolve| {
+        assert!(island_evolve.best_fitness_score() <= best_evolve.best_fitness_score());
+    });
+}
+
+#[test]
+fn call_islands_fully_connected_with_max_generations() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(20)
+        .build()
+        .unwrap();
+    let (best_evolve, island_evolves) = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_generations(12)
+        .with_migration(EvolveMigrationTopology::FullyConnected, 5)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(TrapBlocks)
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call_islands(3)
+        .unwrap();
+
+    assert_eq!(island_evolves.len(), 2);
+    // the max_generations is summed over the epochs, so the last epoch only runs 2 generations
+    assert!(best_evolve.generations_run() <= 2);
+    island_evolves.iter().for_each(|island_evolve| {
+        assert!(island_evolve.generations_run() <= 2);
+    });
+}
+
+#[test]
+fn call_islands_with_max_duration() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(20)
+        .build()
+        .unwrap();
+    let start = Instant::now();
+    let (best_evolve, island_evolves) = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(20)
+        .with_max_duration(Duration::from_millis(50))
+        .with_target_fitness_score(21)
+        .with_migration(EvolveMigrationTopology::Ring, 2)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverUniform::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call_islands(3)
+        .unwrap();
+
+    // the max_duration is shared by all epochs, the unreachable target_fitness_score would
+    // otherwise keep starting new epochs
+    assert!(start.elapsed() < Duration::from_secs(10));
+    assert_eq!(island_evolves.len(), 2);
+    assert!(best_evolve.best_fitness_score().is_some());
+}
+
+#[derive(Clone)]
+struct ReplacementRecorder {
+    generations: Vec<(usize, usize, usize, bool)>,
+}
+impl StrategyReporter for ReplacementRecorder {
+    type Genotype = BinaryGenotype;
+
+    fn on_generation_complete<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        _config: &C,
+    ) {
+        let population = state.population_as_ref();
+        let (parents_size, offspring_size) = population.parents_and_offspring_size();
+        let fittest_first = population
+            .chromosomes
+            .windows(2)
+            .all(|w| w[0].fitness_score() >= w[1].fitness_score());
+        self.generations.push((
+            population.size(),
+            parents_size,
+            offspring_size,
+            fittest_first,
+        ));
+    }
+}
+
+#[test]
+fn call_binary_variant_generational_and_steady_state() {
+    let builder = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(100)
+                .build()
+                .unwrap(),
+        )
+        .with_target_population_size(100)
+        .with_max_generations(10)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_reporter(ReplacementRecorder {
+            generations: vec![],
+        })
+        .with_rng_seed_from_u64(0);
+
+    // all 70 offspring replace parents, the best 30 parents fill the population
+    let generational = builder
+        .clone()
+        .with_variant(EvolveVariant::Generational)
+        .call()
+        .unwrap();
+    assert_eq!(generational.reporter.generations.len(), 10);
+    assert!(generational.reporter.generations.iter().all(
+        |(size, parents_size, offspring_size, _)| (*size, *parents_size, *offspring_size)
+            == (100, 30, 70)
+    ));
+
+    // offspring only replace the worst members, so less offspring survive
+    let steady_state = builder
+        .clone()
+        .with_variant(EvolveVariant::SteadyState)
+        .call()
+        .unwrap();
+    assert_eq!(steady_state.reporter.generations.len(), 10);
+    assert!(steady_state
+        .reporter
+        .generations
+        .iter()
+        .all(|(size, _, _, fittest_first)| *size == 100 && *fittest_first));
+    assert!(steady_state
+        .reporter
+        .generations
+        .iter()
+        .any(|(_, _, offspring_size, _)| *offspring_size < 70));
+    assert_ne!(
+        generational.reporter.generations,
+        steady_state.reporter.generations
+    );
+
+    // the select strategy handles the replacement in the next generation
+    let standard = builder
+        .with_variant(EvolveVariant::Standard)
+        .call()
+        .unwrap();
+    assert!(standard
+        .reporter
+        .generations
+        .iter()
+        .all(|(size, _, offspring_size, _)| *size == 170 && *offspring_size == 70));
+}
+
+#[test]
+fn call_binary_variant_selects_once() {
+    // only 4 unique chromosomes, so a deduplicating select would shrink the population
+    let builder = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(2)
+                .build()
+                .unwrap(),
+        )
+        .with_target_population_size(20)
+        .with_max_generations(5)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectElite::new(0.5, 0.02).with_deduplication(true))
+        .with_reporter(ReplacementRecorder {
+            generations: vec![],
+        })
+        .with_rng_seed_from_u64(0);
+
+    // the replacement takes the place of the select strategy
+    for variant in [EvolveVariant::Generational, EvolveVariant::SteadyState] {
+        let evolve = builder.clone().with_variant(variant).call().unwrap();
+        assert_eq!(evolve.reporter.generations.len(), 5);
+        assert!(evolve
+            .reporter
+            .generations
+            .iter()
+            .all(|(size, _, _, _)| *size == 20));
+    }
+
+    let standard = builder.call().unwrap();
+    assert!(standard
+        .reporter
+        .generations
+        .iter()
+        .all(|(size, _, _, _)| *size < 20));
+}
+
+#[test]
+fn call_binary_variant_steady_state_with_generation_gap() {
+    let evolve = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(100)
+                .build()
+                .unwrap(),
+        )
+        .with_variant(EvolveVariant::SteadyState)
+        .with_generation_gap(5)
+        .with_target_population_size(100)
+        .with_max_generations(10)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_reporter(ReplacementRecorder {
+            generations: vec![],
+        })
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    assert_eq!(evolve.reporter.generations.len(), 10);
+    assert!(evolve.reporter.generations.iter().all(
+        |(size, _, offspring_size, fittest_first)| *size == 100
+            && *offspring_size <= 5
+            && *fittest_first
+    ));
+    // the initial population plus at most 5 offspring per generation
+    assert!(evolve.state.fitness_evaluations <= 100 + 10 * 5);
+}
+
+#[test]
+fn build_invalid_generation_gap() {
+    let evolve = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(10)
+                .build()
+                .unwrap(),
+        )
+        .with_variant(EvolveVariant::SteadyState)
+        .with_generation_gap(0)
+        .with_target_population_size(100)
+        .with_max_generations(10)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .build();
+
+    assert!(evolve.is_err());
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires a generation_gap > 0",
+            BuilderError::InvalidParameter
+        ))
+    );
+}
+
+#[derive(Clone)]
+struct PopulationBestRecorder {
+    best_fitness_scores: Vec<Option<FitnessValue>>,
+}
+impl StrategyReporter for PopulationBestRecorder {
+    type Genotype = BinaryGenotype;
+
+    fn on_generation_complete<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        _config: &C,
+    ) {
+        self.best_fitness_scores.push(
+            state
+                .population_as_ref()
+                .chromosomes
+                .iter()
+                .filter_map(|c| c.fitness_score())
+                .max(),
+        );
+    }
+}
+
+#[test]
+fn call_binary_with_elitism_rate_never_regresses() {
+    let evolve = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(100)
+                .build()
+                .unwrap(),
+        )
+        // all parents are replaced by heavily mutated offspring
+        .with_variant(EvolveVariant::Generational)
+        .with_elitism_rate(0.02)
+        .with_target_population_size(100)
+        .with_max_generations(50)
+        .with_mutate(MutateMultiGene::new(10, 1.0))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverUniform::new(1.0, 1.0))
+        .with_select(SelectTournament::new(1.0, 0.0, 2))
+        .with_reporter(PopulationBestRecorder {
+            best_fitness_scores: vec![],
+        })
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    let best_fitness_scores = &evolve.reporter.best_fitness_scores;
+    assert_eq!(best_fitness_scores.len(), 50);
+    assert!(best_fitness_scores.windows(2).all(|w| w[1] >= w[0]));
+    assert_eq!(
+        *best_fitness_scores.last().unwrap(),
+        evolve.best_fitness_score()
+    );
+}
+
+#[test]
+fn build_invalid_elitism_rate() {
+    let evolve = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(10)
+                .build()
+                .unwrap(),
+        )
+        .with_elitism_rate(1.5)
+        .with_target_population_size(100)
+        .with_max_generations(10)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .build();
+
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires an elitism_rate between 0.0 and 1.0",
+            BuilderError::InvalidParameter
+        ))
+    );
+}
+
+#[test]
+fn build_invalid_offspring_rate() {
+    let evolve = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(10)
+                .build()
+                .unwrap(),
+        )
+        .with_offspring_rate(0.0)
+        .with_target_population_size(100)
+        .with_max_generations(10)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .build();
+
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires an offspring_rate > 0.0",
+            BuilderError::InvalidParameter
+        ))
+    );
+}
+
+#[test]
+fn call_binary_with_offspring_rate() {
+    let evolve = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(100)
+                .build()
+                .unwrap(),
+        )
+        .with_offspring_rate(3.0)
+        .with_target_population_size(10)
+        .with_target_fitness_score(100)
+        .with_max_stale_generations(1000)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverUniform::new(0.8, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    assert_eq!(evolve.best_fitness_score(), Some(100));
+    assert!(evolve.state.population.size() <= 10);
+}
+
+#[test]
+fn build_invalid_age_layers() {
+    let builder = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(10)
+                .build()
+                .unwrap(),
+        )
+        .with_target_population_size(100)
+        .with_max_generations(10)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4));
+
+    assert_eq!(
+        builder.clone().with_age_layers(1, 5).build().err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires age_layers with a number_of_layers > 1 and an age_gap > 0",
+            BuilderError::InvalidParameter
+        ))
+    );
+    assert_eq!(
+        builder.clone().with_age_layers(60, 5).build().err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires a target_population_size of at least 2 per age layer",
+            BuilderError::InvalidTargetPopulationSize
+        ))
+    );
+    assert_eq!(
+        builder
+            .with_age_layers(4, 5)
+            .with_variant(EvolveVariant::Generational)
+            .build()
+            .err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve age_layers require the EvolveVariant::Standard",
+            BuilderError::InvalidParameter
+        ))
+    );
+}
+
+#[test]
+fn age_layers_config() {
+    let config = EvolveConfig {
+        target_population_size: 42,
+        age_layers: Some((4, 5)),
+        ..Default::default()
+    };
+    assert_eq!(
+        (0..25)
+            .step_by(4)
+            .map(|lineage_age| config.age_layer_index(lineage_age))
+            .collect::<Vec<_>>(),
+        vec![0, 0, 1, 2, 3, 3, 3]
+    );
+    assert_eq!(
+        (0..4)
+            .map(|layer_index| config.age_layer_size(layer_index))
+            .collect::<Vec<_>>(),
+        vec![10, 10, 10, 12]
+    );
+
+    let config = EvolveConfig {
+        target_population_size: 42,
+        ..Default::default()
+    };
+    assert_eq!(config.age_layer_index(100), 0);
+    assert_eq!(config.age_layer_size(0), 42);
+}
+
+#[test]
+fn call_binary_with_age_layers() {
+    let evolve = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(100)
+                .build()
+                .unwrap(),
+        )
+        .with_age_layers(4, 5)
+        .with_target_population_size(40)
+        .with_target_fitness_score(100)
+        .with_max_stale_generations(1000)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverUniform::new(0.8, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    assert_eq!(evolve.best_fitness_score(), Some(100));
+}
+
+#[test]
+fn call_binary_with_age_layers_initial_population() {
+    let evolve = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(100)
+                .build()
+                .unwrap(),
+        )
+        .with_age_layers(4, 5)
+        .with_target_population_size(40)
+        .with_max_generations(1)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverNoop::new())
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    // all in the youngest layer, not cut down to a layer share (10) and no injection at the
+    // start. Doubled by the offspring of the CrossoverNoop
+    assert_eq!(evolve.final_population().len(), 80);
+}
+
+#[test]
+fn call_binary_with_seed_genes_from_hill_climb() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    let hill_climb = HillClimb::builder()
+        .with_genotype(genotype.clone())
+        .with_variant(HillClimbVariant::Stochastic)
+        .with_max_generations(50)
+        .with_fitness(CountTrue)
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+    let hill_climb_best_genes = hill_climb.best_genes().unwrap();
+
+    // order independent, the seed genes are stored on the builder until build
+    let builder = Evolve::builder()
+        .with_seed_genes_from(&hill_climb)
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_generations(0)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0);
+    assert_eq!(builder.seed_genes, vec![hill_climb_best_genes.clone()]);
+    assert!(builder
+        .genotype
+        .as_ref()
+        .unwrap()
+        .seed_genes_list()
+        .is_empty());
+
+    // the seeded hill climb best takes a single place in the initial population
+    let mut evolve = builder.clone().build().unwrap();
+    evolve.setup(None);
+    assert_eq!(evolve.state.population.size(), 100);
+    assert_eq!(
+        evolve
+            .state
+            .population
+            .chromosomes
+            .iter()
+            .filter(|chromosome| chromosome.genes == hill_climb_best_genes)
+            .count(),
+        1
+    );
+
+    let evolve = builder.call().unwrap();
+    assert_eq!(evolve.best_generation(), 0);
+    assert_eq!(evolve.best_genes(), Some(hill_climb_best_genes));
+    assert_eq!(evolve.best_fitness_score(), hill_climb.best_fitness_score());
+
+    let other_hill_climb = HillClimb::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(10)
+                .build()
+                .unwrap(),
+        )
+        .with_max_generations(5)
+        .with_fitness(CountTrue)
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(100)
+                .build()
+                .unwrap(),
+        )
+        .with_seed_genes_from(&other_hill_climb)
+        .with_target_population_size(100)
+        .with_max_generations(0)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .build();
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires seed_genes_from genes with the genotype's genes_size",
+            BuilderError::IncompatibleSeedGenes
+        ))
+    );
+}
+
+#[test]
+fn call_binary_with_ordering() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0);
+
+    let evolve = builder
+        .call_with_ordering(FitnessOrdering::Maximize)
+        .unwrap();
+    assert_eq!(evolve.best_fitness_score(), Some(10));
+    assert_eq!(evolve.best_genes(), Some(vec![true; 10]));
+
+    let evolve = builder
+        .call_with_ordering(FitnessOrdering::Minimize)
+        .unwrap();
+    assert_eq!(evolve.best_fitness_score(), Some(0));
+    assert_eq!(evolve.best_genes(), Some(vec![false; 10]));
+
+    // the builder itself is untouched
+    assert!(matches!(
+        builder.fitness_ordering,
+        FitnessOrdering::Maximize
+    ));
+}
+
+#[test]
+fn call_binary_stop_after() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    let builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0);
+
+    // the stale check is ignored before generation 50, and applied after
+    let evolve = builder
+        .clone()
+        .with_max_stale_generations(1)
+        .with_stop_after(50, true)
+        .call()
+        .unwrap();
+    assert!(evolve.state.current_generation >= 50);
+    assert!(evolve.state.stale_generations >= 1);
+
+    // stop at generation 20, while not stale
+    let evolve = builder
+        .with_max_stale_generations(1000)
+        .with_stop_after(20, false)
+        .call()
+        .unwrap();
+    assert_eq!(evolve.state.current_generation, 20);
+    assert!(evolve.state.stale_generations < 1000);
+}
+
+#[test]
+fn call_binary_max_duration() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    let builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0);
+
+    // the only ending condition, a hard stop regardless of the valid_fitness_score
+    let evolve = builder
+        .clone()
+        .with_max_duration(Duration::ZERO)
+        .with_valid_fitness_score(100)
+        .call()
+        .unwrap();
+    assert_eq!(evolve.generations_run(), 0);
+    assert_eq!(
+        evolve.termination_reason(),
+        Some(StrategyTerminationReason::MaxDuration)
+    );
+
+    // stops within the time budget, before the unreachable target_fitness_score
+    let evolve = builder
+        .clone()
+        .with_max_stale_generations(usize::MAX)
+        .with_target_fitness_score(101)
+        .with_max_duration(Duration::from_millis(20))
+        .call()
+        .unwrap();
+    assert!(evolve.generations_run() > 0);
+    assert!(evolve.state.total_duration() >= Duration::from_millis(20));
+    assert_eq!(
+        evolve.termination_reason(),
+        Some(StrategyTerminationReason::MaxDuration)
+    );
+
+    // other ending conditions are reported first
+    let evolve = builder
+        .with_max_generations(5)
+        .with_deadline(Instant::now() + Duration::from_secs(60))
+        .call()
+        .unwrap();
+    assert_eq!(evolve.generations_run(), 5);
+    assert_eq!(
+        evolve.termination_reason(),
+        Some(StrategyTerminationReason::MaxGenerations)
+    );
+}
+
+#[test]
+fn call_binary_max_duration_with_mock_clock() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    // advances a second on each reading, regardless of the wall-clock
+    let start = Instant::now();
+    let ticks = Arc::new(AtomicU64::new(0));
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(usize::MAX)
+        .with_target_fitness_score(101)
+        .with_max_duration(Duration::from_secs(10))
+        .with_clock(move || start + Duration::from_secs(ticks.fetch_add(1, Ordering::SeqCst)))
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    // the readings for the deadline and before the first generation take the first two seconds,
+    // after that a second per generation
+    assert_eq!(evolve.generations_run(), 9);
+    assert_eq!(
+        evolve.termination_reason(),
+        Some(StrategyTerminationReason::MaxDuration)
+    );
+}
+
+#[test]
+fn call_binary_termination_predicate() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(usize::MAX)
+        .with_target_fitness_score(101)
+        .with_termination_predicate(|state| state.current_generation() >= 5)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    assert_eq!(evolve.generations_run(), 5);
+    assert_eq!(
+        evolve.termination_reason(),
+        Some(StrategyTerminationReason::TerminationPredicate)
+    );
+}
+
+#[test]
+fn call_binary_should_terminate() {
+    #[derive(Clone, Debug)]
+    struct CountTrueWithCertificate;
+    impl Fitness for CountTrueWithCertificate {
+        type Genotype = BinaryGenotype;
+        fn calculate_for_chromosome(
+            &mut self,
+            chromosome: &FitnessChromosome<Self>,
+            _genotype: &FitnessGenotype<Self>,
+        ) -> Option<FitnessValue> {
+            Some(chromosome.genes.iter().filter(|&value| *value).count() as FitnessValue)
+        }
+        fn should_terminate(&self, best: &FitnessChromosome<Self>) -> bool {
+            best.genes.iter().all(|value| *value)
+        }
+    }
+
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(20)
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(1000)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrueWithCertificate)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    assert_eq!(evolve.best_fitness_score(), Some(20));
+    assert_eq!(
+        evolve.state.current_generation,
+        evolve.state.best_generation
+    );
+    assert_eq!(evolve.state.stale_generations, 0);
+}
+
+#[test]
+fn call_list_par_fitness_chunk_size() {
+    let genotype = ListGenotype::builder()
+        .with_genes_size(10)
+        .with_allele_list((0..4).collect())
+        .build()
+        .unwrap();
+    let builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new())
+        .with_par_fitness(true)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0);
+
+    let evolve = builder.clone().call().unwrap();
+    assert_eq!(evolve.config.fitness_chunk_size, None);
+    for chunk_size in [1, 7, 1000] {
+        let chunked_evolve = builder
+            .clone()
+            .with_fitness_chunk_size(chunk_size)
+            .call()
+            .unwrap();
+        assert_eq!(chunked_evolve.config.fitness_chunk_size, Some(chunk_size));
+        assert_eq!(chunked_evolve.best_genes(), evolve.best_genes());
+        assert_eq!(
+            chunked_evolve.state.current_generation,
+            evolve.state.current_generation
+        );
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct BestGenesPointerRecorder {
+    records: Vec<(usize, usize)>,
+}
+impl StrategyReporter for BestGenesPointerRecorder {
+    type Genotype = BinaryGenotype;
+
+    fn on_generation_complete<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        _config: &C,
+    ) {
+        let best_chromosome = state.best_chromosome_as_ref().unwrap();
+        self.records.push((
+            state.stale_generations(),
+            best_chromosome.genes.as_ptr() as usize,
+        ));
+    }
+}
+
+#[test]
+fn call_binary_result() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let evolve_builder = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(100)
+        .with_max_stale_generations(20)
+        .with_target_fitness_score(10)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(CountTrue)
+        .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
+        .with_select(SelectTournament::new(0.5, 0.02, 4))
+        .with_rng_seed_from_u64(0);
+
+    let evolve = evolve_builder.clone().build().unwrap();
+    assert_eq!(evolve.termination_reason(), None);
+    assert_eq!(evolve.result().fitness_evaluations, 0);
+
+    let evolve = evolve_builder.call().unwrap();
+    let result = evolve.result();
+    assert_eq!(result.best_fitness_score, Some(10));
+    assert_eq!(result.best_fitness_score, evolve.best_fitness_score());
+    assert_eq!(
+        result.best_chromosome.as_ref().map(|c| c.genes.clone()),
+        evolve.best_genes()
+    );
+    assert_eq!(result.best_generation, evolve.best_generation());
+    assert_eq!(result.generations_run, evolve.generations_run());
+    // at least the initial population
+    assert!(result.fitness_evaluations >= 100);
+    assert_eq!(result.fitness_evaluations, evolve.state.fitness_evaluations);
+    assert!(result.durations.contains_key(&StrategyAction::Fitness));
+    assert_eq!(result.total_duration, evolve.state.total_duration());
+    assert_eq!(
+        result.termination_reason,
+        Some(StrategyTerminationReason::TargetFitnessScore)
+    );
+
+    let evolve = Evolve::builder()
+        .with_genotype(
+            BinaryGenotype::builder()
+                .with_genes_size(100)
+                .build()
+                .unwrap(),
+        )
+        .with_target_population_size(10)
+        .with_max_generations(3)
         .with_mutate(MutateSingleGene::new(0.1))
         .with_fitness(CountTrue)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionNoop::new())
-        .with_reporter(StrategyReporterNoop::new())
         .with_rng_seed_from_u64(0)
         .call()
         .unwrap();
-
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(9));
+    let result = evolve.result();
+    assert_eq!(result.generations_run, 3);
     assert_eq!(
-        evolve.best_genes().unwrap(),
-        vec![true, true, true, false, true, true, true, true, true, true]
+        result.termination_reason,
+        Some(StrategyTerminationReason::MaxGenerations)
     );
 }
 
 #[test]
-fn call_binary_target_fitness_score_minimize() {
+fn call_binary_fitness_evaluations_exclude_cache_hits() {
     let genotype = BinaryGenotype::builder()
-        .with_genes_size(10)
+        .with_genes_size(4)
         .build()
         .unwrap();
     let evolve = Evolve::builder()
         .with_genotype(genotype)
         .with_target_population_size(100)
-        .with_fitness_ordering(FitnessOrdering::Minimize)
-        .with_target_fitness_score(0)
+        .with_max_generations(5)
         .with_mutate(MutateSingleGene::new(0.1))
         .with_fitness(CountTrue)
+        .with_fitness_cache(100)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionNoop::new())
-        // .with_reporter(StrategyReporterNoop::new())
         .with_rng_seed_from_u64(0)
         .call()
         .unwrap();
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(0));
-    assert_eq!(
-        evolve.best_genes().unwrap(),
-        vec![false, false, false, false, false, false, false, false, false, false]
-    );
+    // only 16 distinct genes, so most chromosomes are read from the cache
+    let (hits, misses, _) = evolve
+        .config
+        .fitness_cache
+        .as_ref()
+        .unwrap()
+        .hit_miss_stats();
+    assert!(hits > 0);
+    assert_eq!(evolve.state.fitness_evaluations, misses);
 }
 
 #[test]
-fn call_binary_mass_degeneration() {
+fn call_binary_termination_reason_on_finish() {
     let genotype = BinaryGenotype::builder()
         .with_genes_size(10)
         .build()
         .unwrap();
-    let evolve = Evolve::builder()
+    let mut evolve = Evolve::builder()
         .with_genotype(genotype)
         .with_target_population_size(100)
+        .with_max_stale_generations(20)
         .with_target_fitness_score(10)
         .with_mutate(MutateSingleGene::new(0.1))
         .with_fitness(CountTrue)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionMassDegeneration::new(10, 10, 0.02))
-        .with_reporter(StrategyReporterNoop::new())
+        .with_reporter(EvolveReporterSimple::new_with_buffer(100))
         .with_rng_seed_from_u64(0)
         .call()
         .unwrap();
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(10));
+    assert_eq!(
+        evolve.state.termination_reason(),
+        Some(StrategyTerminationReason::TargetFitnessScore)
+    );
+    assert_eq!(
+        evolve.state.termination_reason(),
+        evolve.termination_reason()
+    );
+
+    let mut buffer: Vec<u8> = vec![];
+    evolve.flush_reporter(&mut buffer);
+    assert!(String::from_utf8(buffer)
+        .unwrap()
+        .lines()
+        .any(|line| line == "finish - evolve, termination reason: TargetFitnessScore"));
 }
 
 #[test]
-fn call_binary_mass_extinction() {
+fn call_binary_local_search() {
     let genotype = BinaryGenotype::builder()
         .with_genes_size(10)
         .build()
         .unwrap();
-    let evolve = Evolve::builder()
+    let builder = Evolve::builder()
         .with_genotype(genotype)
-        .with_target_population_size(100)
-        .with_target_fitness_score(10)
+        .with_target_population_size(10)
+        .with_max_generations(1)
         .with_mutate(MutateSingleGene::new(0.1))
         .with_fitness(CountTrue)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionMassExtinction::new(10, 0.1, 0.02))
-        // .with_reporter(StrategyReporterNoop::new())
-        .with_rng_seed_from_u64(0)
+        .with_rng_seed_from_u64(0);
+
+    let evolve = builder.clone().call().unwrap();
+    let fitness_evaluations = evolve.state.fitness_evaluations;
+
+    // each steepest ascent step flips one more gene to true
+    let evolve = builder
+        .clone()
+        .with_local_search(HillClimbVariant::SteepestAscent, 10)
         .call()
         .unwrap();
-
-    println!("{:#?}", evolve.best_genes());
     assert_eq!(evolve.best_fitness_score(), Some(10));
+    assert!(evolve.state.fitness_evaluations > fitness_evaluations);
+
+    let evolve = builder
+        .clone()
+        .with_local_search(HillClimbVariant::Stochastic, 5)
+        .with_local_search_top_k(1)
+        .call()
+        .unwrap();
+    assert_eq!(evolve.state.fitness_evaluations, fitness_evaluations + 5);
+
+    // order independent, the top_k is stored on the builder until build
+    let evolve = builder
+        .clone()
+        .with_local_search_top_k(1)
+        .with_local_search(HillClimbVariant::Stochastic, 5)
+        .call()
+        .unwrap();
+    assert_eq!(evolve.state.fitness_evaluations, fitness_evaluations + 5);
+
+    let evolve = builder.clone().with_local_search_top_k(1).build();
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires a local_search for the local_search_top_k",
+            BuilderError::InvalidParameter
+        ))
+    );
+
+    let evolve = builder
+        .with_local_search(HillClimbVariant::Stochastic, 0)
+        .build();
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires a local_search with steps > 0",
+            BuilderError::InvalidParameter
+        ))
+    );
 }
 
 #[test]
-fn call_binary_mass_genesis() {
+fn call_binary_population_snapshot() {
+    let path = std::env::temp_dir().join("genetic_algorithm_population_snapshot_test.bin");
     let genotype = BinaryGenotype::builder()
         .with_genes_size(10)
         .build()
         .unwrap();
-    let evolve = Evolve::builder()
+    let _evolve = Evolve::builder()
         .with_genotype(genotype)
-        .with_target_population_size(100)
-        .with_target_fitness_score(10)
+        .with_target_population_size(20)
+        .with_max_generations(5)
         .with_mutate(MutateSingleGene::new(0.1))
         .with_fitness(CountTrue)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionMassGenesis::new(10))
-        .with_reporter(StrategyReporterNoop::new())
+        .with_population_snapshot(2, std::fs::File::create(&path).unwrap())
         .with_rng_seed_from_u64(0)
         .call()
         .unwrap();
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(10));
+    let bytes = std::fs::read(&path).unwrap();
+    let read_u64 =
+        |offset: usize| u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+    let mut offset = 0;
+    let mut generations = vec![];
+    while offset < bytes.len() {
+        assert_eq!(
+            bytes[offset..offset + 4],
+            EvolvePopulationSnapshot::<BinaryGenotype>::MAGIC
+        );
+        let (generation, population_size, genes_size, allele_size) = (
+            read_u64(offset + 12),
+            read_u64(offset + 20) as usize,
+            read_u64(offset + 28) as usize,
+            read_u64(offset + 36) as usize,
+        );
+        assert_eq!((genes_size, allele_size), (10, 1));
+        assert!(population_size > 0);
+        generations.push(generation);
+        offset += 44;
+        for _ in 0..population_size {
+            let fitness_score =
+                i64::from_le_bytes(bytes[offset + 1..offset + 9].try_into().unwrap());
+            let genes = &bytes[offset + 9..offset + 9 + genes_size];
+            if bytes[offset] == 1 {
+                assert_eq!(
+                    fitness_score,
+                    genes.iter().filter(|gene| **gene == 1).count() as i64
+                );
+            }
+            offset += 9 + genes_size * allele_size;
+        }
+    }
+    assert_eq!(generations, vec![2, 4]);
+    std::fs::remove_file(&path).unwrap();
 }
 
 #[test]
-fn call_range_f32() {
-    let genotype = RangeGenotype::builder()
+fn call_binary_reporter_csv() {
+    let genotype = BinaryGenotype::builder()
         .with_genes_size(10)
-        .with_allele_range(0.0..=1.0)
         .build()
         .unwrap();
-    let evolve = Evolve::builder()
+    let mut evolve = Evolve::builder()
         .with_genotype(genotype)
-        .with_target_population_size(100)
-        .with_max_stale_generations(20)
+        .with_target_population_size(20)
+        .with_max_generations(5)
         .with_mutate(MutateSingleGene::new(0.1))
-        .with_fitness(SumGenes::new_with_precision(1e-3))
+        .with_fitness(CountTrue)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        // .with_extension(ExtensionNoop::new())
-        .with_reporter(StrategyReporterNoop::new())
+        .with_reporter(EvolveReporterCsv::new_with_buffer(1))
         .with_rng_seed_from_u64(0)
         .call()
         .unwrap();
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(9880));
-    assert!(relative_chromosome_eq(
-        evolve.best_genes().unwrap(),
-        vec![0.998, 0.993, 0.979, 0.992, 0.982, 0.999, 0.987, 0.972, 0.979, 0.995],
-        0.001
-    ));
+    let mut buffer: Vec<u8> = vec![];
+    evolve.flush_reporter(&mut buffer);
+    let output = String::from_utf8(buffer).unwrap();
+    let mut lines = output.lines();
+    assert_eq!(lines.next(), Some(METRICS_COLUMNS.join(",").as_str()));
+    let rows: Vec<&str> = lines.collect();
+    assert!(!rows.is_empty());
+    assert!(rows
+        .iter()
+        .all(|row| row.starts_with("0,") && row.split(',').count() == METRICS_COLUMNS.len()));
 }
 
 #[test]
-fn call_range_usize() {
-    let genotype = RangeGenotype::builder()
+fn call_binary_reporter_json() {
+    let genotype = BinaryGenotype::builder()
         .with_genes_size(10)
-        .with_allele_range(0..=9)
         .build()
         .unwrap();
-    let evolve = Evolve::builder()
+    let mut evolve = Evolve::builder()
         .with_genotype(genotype)
-        .with_target_population_size(100)
-        .with_max_stale_generations(20)
+        .with_target_population_size(20)
+        .with_max_generations(5)
         .with_mutate(MutateSingleGene::new(0.1))
-        .with_fitness(SumGenes::new())
+        .with_fitness(CountTrue)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        // .with_extension(ExtensionNoop::new())
-        .with_reporter(StrategyReporterNoop::new())
+        .with_reporter(EvolveReporterJson::new_with_buffer(1))
         .with_rng_seed_from_u64(0)
         .call()
         .unwrap();
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(89));
-    assert_eq!(
-        evolve.best_genes().unwrap(),
-        vec![9, 9, 9, 8, 9, 9, 9, 9, 9, 9]
-    );
+    let mut buffer: Vec<u8> = vec![];
+    evolve.flush_reporter(&mut buffer);
+    let output = String::from_utf8(buffer).unwrap();
+    assert!(output.lines().count() > 0);
+    assert!(output
+        .lines()
+        .all(|line| line.starts_with("{\"iteration\":0,\"generation\":")
+            && line.ends_with('}')
+            && line.contains("\"duration_fitness\":")));
 }
 
 #[test]
-fn call_range_isize() {
-    let genotype = RangeGenotype::builder()
-        .with_genes_size(10)
-        .with_allele_range(0..=9)
-        .with_mutation_type(MutationType::Range(1))
+fn call_binary_best_chromosome_ref() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
         .build()
         .unwrap();
     let evolve = Evolve::builder()
         .with_genotype(genotype)
         .with_target_population_size(100)
-        .with_max_stale_generations(20)
+        .with_max_stale_generations(10)
         .with_mutate(MutateSingleGene::new(0.1))
-        .with_fitness(SumGenes::new())
+        .with_fitness(CountTrue)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        // .with_extension(ExtensionNoop::new())
-        .with_reporter(StrategyReporterNoop::new())
+        .with_reporter(BestGenesPointerRecorder::default())
         .with_rng_seed_from_u64(0)
         .call()
         .unwrap();
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(90));
+    let best_chromosome_ref = evolve.best_chromosome_ref().unwrap();
     assert_eq!(
-        evolve.best_genes().unwrap(),
-        vec![9, 9, 9, 9, 9, 9, 9, 9, 9, 9]
+        best_chromosome_ref.genes,
+        evolve.best_chromosome().unwrap().genes
     );
+    assert_eq!(
+        best_chromosome_ref.fitness_score(),
+        evolve.best_fitness_score()
+    );
+
+    // the reference is not a copy, so it reflects the updates of the state
+    let records = &evolve.reporter.records;
+    assert_eq!(
+        best_chromosome_ref.genes.as_ptr() as usize,
+        records.last().unwrap().1
+    );
+    assert!(std::ptr::eq(
+        best_chromosome_ref,
+        evolve.state.best_chromosome.as_ref().unwrap()
+    ));
+
+    // the best chromosome is not reallocated during the non-improving generations
+    assert!(records
+        .iter()
+        .any(|(stale_generations, _)| *stale_generations > 0));
+    records.windows(2).for_each(|w| {
+        if w[1].0 > 0 {
+            assert_eq!(w[0].1, w[1].1);
+        }
+    });
+}
+
+#[derive(Clone, Debug, Default)]
+struct InitialPopulationRecorder {
+    genes_list: Vec<Vec<bool>>,
+}
+impl StrategyReporter for InitialPopulationRecorder {
+    type Genotype = BinaryGenotype;
+
+    fn on_start<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        _config: &C,
+    ) {
+        self.genes_list = inspect::population(state.population_as_ref());
+    }
 }
 
 #[test]
-fn call_list() {
-    let genotype = ListGenotype::builder()
-        .with_genes_size(10)
-        .with_allele_list((0..4).collect())
+fn call_binary_seed_population_from() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
         .build()
         .unwrap();
-
     let evolve = Evolve::builder()
-        .with_genotype(genotype)
+        .with_genotype(genotype.clone())
         .with_target_population_size(100)
-        .with_max_stale_generations(20)
-        .with_mutate(MutateSingleGene::new(0.1))
-        .with_fitness(SumGenes::new())
+        .with_max_stale_generations(10)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(CountTrue)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionNoop::new())
-        // .with_reporter(StrategyReporterNoop::new())
         .with_rng_seed_from_u64(0)
         .call()
         .unwrap();
+    let final_population = evolve.final_population();
+    assert!(!final_population.is_empty());
+    assert!(evolve.state.population.chromosomes.is_empty());
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(30));
-    assert_eq!(
-        evolve.best_genes().unwrap(),
-        vec![3, 3, 3, 3, 3, 3, 3, 3, 3, 3]
-    );
-}
-
-#[test]
-fn call_multi_list() {
-    let genotype = MultiListGenotype::builder()
-        .with_allele_lists(vec![
-            vec![0, 1, 2, 3, 4],
-            vec![0, 1],
-            vec![0],
-            vec![0, 1, 2, 3],
-        ])
-        .build()
-        .unwrap();
-    let evolve = Evolve::builder()
+    // continue with a lower mutation probability
+    let continued_evolve = Evolve::builder()
         .with_genotype(genotype)
         .with_target_population_size(100)
-        .with_max_stale_generations(20)
-        .with_mutate(MutateSingleGene::new(0.1))
-        .with_fitness(SumGenes::new())
+        .with_max_stale_generations(10)
+        .with_mutate(MutateSingleGene::new(0.05))
+        .with_fitness(CountTrue)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionNoop::new())
-        .with_reporter(StrategyReporterNoop::new())
-        .with_rng_seed_from_u64(0)
+        .with_seed_population_from(&evolve)
+        .with_reporter(InitialPopulationRecorder::default())
+        .with_rng_seed_from_u64(1)
         .call()
         .unwrap();
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(8));
-    assert_eq!(evolve.best_genes().unwrap(), vec![4, 1, 0, 3]);
+    assert_eq!(continued_evolve.reporter.genes_list, final_population);
 }
 
 #[test]
-fn call_par_fitness() {
-    let genotype = ListGenotype::builder()
-        .with_genes_size(10)
-        .with_allele_list((0..4).collect())
+fn call_binary_resume_from_checkpoint() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
         .build()
         .unwrap();
-
-    let evolve = Evolve::builder()
+    let builder = Evolve::builder()
         .with_genotype(genotype)
         .with_target_population_size(100)
-        .with_max_stale_generations(20)
-        .with_mutate(MutateSingleGene::new(0.1))
-        .with_fitness(SumGenes::new())
-        .with_par_fitness(true)
+        .with_mutate(MutateSingleGene::new(0.2))
+        .with_fitness(CountTrue)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
-        .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionNoop::new())
-        // .with_reporter(StrategyReporterNoop::new())
+        .with_select(SelectTournament::new(0.5, 0.02, 4));
+    let mut evolve = builder
+        .clone()
+        .with_max_generations(10)
         .with_rng_seed_from_u64(0)
         .call()
         .unwrap();
+    let checkpoint = evolve.checkpoint();
+    assert_eq!(checkpoint.current_generation, 10);
+    assert_eq!(checkpoint.population, evolve.final_population());
+    assert_eq!(
+        checkpoint.best_chromosome.as_ref().unwrap().fitness_score(),
+        evolve.best_fitness_score()
+    );
+
+    // the ending conditions count from the start of the original run
+    let resumed_evolve = builder
+        .clone()
+        .with_max_generations(20)
+        .with_checkpoint(checkpoint.clone())
+        .call()
+        .unwrap();
+    assert_eq!(resumed_evolve.state.current_generation, 20);
+    assert!(resumed_evolve.best_fitness_score() >= evolve.best_fitness_score());
 
-    println!("{:#?}", evolve.best_genes());
-    assert_eq!(evolve.best_fitness_score(), Some(30));
+    // resuming the same checkpoint is reproducible
+    let resumed_again_evolve = builder
+        .with_max_generations(20)
+        .with_checkpoint(checkpoint)
+        .call()
+        .unwrap();
     assert_eq!(
-        evolve.best_genes().unwrap(),
-        vec![3, 3, 3, 3, 3, 3, 3, 3, 3, 3]
+        resumed_again_evolve.best_genes(),
+        resumed_evolve.best_genes()
     );
 }
 
 #[test]
-fn population_factory_binary() {
+fn setup_binary_seed_population_reuses_fitness_scores() {
     let genotype = BinaryGenotype::builder()
         .with_genes_size(4)
         .build()
         .unwrap();
-    let mut evolve = Evolve::builder()
+    let mut evaluated = Chromosome::new(vec![false, false, false, false]);
+    // not the CountTrue fitness, to detect recalculation
+    evaluated.set_fitness_score(Some(10));
+    let population = Population::new(
+        vec![
+            evaluated,
+            Chromosome::new(vec![true, true, false, false]),
+            Chromosome::new(vec![true, false, false, false]),
+        ],
+        false,
+    );
+    let builder = Evolve::builder()
         .with_genotype(genotype)
         .with_target_population_size(8)
         .with_max_stale_generations(20)
@@ -541,24 +2789,89 @@ fn population_factory_binary() {
         .with_fitness(CountTrue)
         .with_crossover(CrossoverSingleGene::new(0.7, 0.8))
         .with_select(SelectTournament::new(0.5, 0.02, 4))
-        .with_extension(ExtensionNoop::new())
-        .with_reporter(StrategyReporterNoop::new())
-        .with_rng_seed_from_u64(0)
+        .with_rng_seed_from_u64(0);
+
+    let mut evolve = builder
+        .clone()
+        .with_seed_population(population)
         .build()
         .unwrap();
-
     evolve.setup(None);
     assert_eq!(
-        inspect::population(&evolve.state.population),
+        inspect::population_with_fitness_scores(&evolve.state.population),
         vec![
-            vec![false, false, true, false],
-            vec![true, true, true, false],
-            vec![false, true, false, true],
-            vec![true, false, true, false],
-            vec![false, false, true, true],
-            vec![true, false, false, true],
-            vec![false, true, true, false],
-            vec![true, false, true, false]
+            (vec![false, false, false, false], Some(10)),
+            (vec![true, true, false, false], Some(2)),
+            (vec![true, false, false, false], Some(1)),
         ]
-    )
+    );
+    assert_eq!(evolve.state.fitness_evaluations, 2);
+    assert_eq!(evolve.best_fitness_score(), Some(10));
+
+    let invalid_population = Population::new(vec![Chromosome::new(vec![true, true])], false);
+    let evolve = builder.with_seed_population(invalid_population).build();
+    assert_eq!(
+        evolve.err(),
+        Some(TryFromEvolveBuilderError(
+            "Evolve requires seed_population chromosomes with the genotype's genes_size",
+            BuilderError::IncompatibleSeedGenes
+        ))
+    );
+}
+
+#[derive(Clone, Debug, Default)]
+struct FinalPopulationRecorder {
+    offspring_genes_list: Vec<Vec<i32>>,
+    parent_genes_list: Vec<Vec<i32>>,
+}
+impl StrategyReporter for FinalPopulationRecorder {
+    type Genotype = UniqueGenotype<i32>;
+
+    fn on_finish<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        _config: &C,
+    ) {
+        let (offspring, parents): (Vec<_>, Vec<_>) = state
+            .population_as_ref()
+            .chromosomes
+            .iter()
+            .partition(|c| c.is_offspring());
+        self.offspring_genes_list = offspring.into_iter().map(|c| c.genes.clone()).collect();
+        self.parent_genes_list = parents.into_iter().map(|c| c.genes.clone()).collect();
+    }
+}
+
+#[test]
+fn call_unique_unique_population() {
+    let genotype = UniqueGenotype::builder()
+        .with_allele_list((0..4).collect())
+        .build()
+        .unwrap();
+    let evolve = Evolve::builder()
+        .with_genotype(genotype)
+        .with_target_population_size(12)
+        .with_max_generations(20)
+        .with_unique_population(true)
+        .with_mutate(MutateSingleGene::new(0.1))
+        .with_fitness(SumGenes::new())
+        .with_crossover(CrossoverClone::new(0.7))
+        .with_select(SelectElite::new(0.5, 0.02))
+        .with_reporter(FinalPopulationRecorder::default())
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    // recorded on finish, as the population is drained on cleanup
+    let mut offspring_genes = evolve.reporter.offspring_genes_list.clone();
+    assert!(!offspring_genes.is_empty());
+    let parent_genes = &evolve.reporter.parent_genes_list;
+    assert!(offspring_genes
+        .iter()
+        .all(|genes| !parent_genes.contains(genes)));
+    let offspring_size = offspring_genes.len();
+    offspring_genes.sort();
+    offspring_genes.dedup();
+    assert_eq!(offspring_genes.len(), offspring_size);
 }
@@ -3,6 +3,8 @@ use crate::support::*;
 use genetic_algorithm::fitness::placeholders::{CountTrue, SumGenes};
 use genetic_algorithm::genotype::HillClimbGenotype;
 use genetic_algorithm::strategy::hill_climb::prelude::*;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 #[test]
 fn build_invalid_missing_ending_condition() {
@@ -23,11 +25,69 @@ fn build_invalid_missing_ending_condition() {
     assert_eq!(
         hill_climb.err(),
         Some(TryFromHillClimbBuilderError(
-            "HillClimb requires at least a max_stale_generations, max_generations or target_fitness_score ending condition"
+            "HillClimb requires at least a max_stale_generations, max_generations, target_fitness_score, max_duration or deadline ending condition",
+            BuilderError::MissingEndingCondition
         ))
     );
 }
 
+#[test]
+fn build_invalid_neighbour_sample_size() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+
+    let hill_climb = HillClimb::builder()
+        .with_genotype(genotype)
+        .with_variant(HillClimbVariant::SteepestAscent)
+        .with_neighbour_sample_size(0)
+        .with_max_generations(5)
+        .with_fitness(CountTrue)
+        .build();
+
+    assert_eq!(
+        hill_climb.err(),
+        Some(TryFromHillClimbBuilderError(
+            "HillClimb requires a neighbour_sample_size > 0",
+            BuilderError::InvalidParameter
+        ))
+    );
+}
+
+#[test]
+fn validate_reports_all_problems() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let builder = HillClimb::builder()
+        .with_genotype(genotype)
+        .with_neighbour_sample_size(0)
+        .with_fitness(CountTrue);
+
+    assert_eq!(
+        builder.validate(),
+        BuilderValidation {
+            errors: vec![
+                "HillClimb requires at least a max_stale_generations, max_generations, target_fitness_score, max_duration or deadline ending condition".to_string(),
+                "HillClimb requires a neighbour_sample_size > 0".to_string(),
+            ],
+            warnings: vec![
+                "The neighbour_sample_size is only used by the HillClimbVariant::SteepestAscent, so it is ignored".to_string(),
+            ],
+        }
+    );
+    assert_eq!(
+        builder
+            .with_variant(HillClimbVariant::SteepestAscent)
+            .with_neighbour_sample_size(3)
+            .with_max_generations(5)
+            .validate(),
+        BuilderValidation::default()
+    );
+}
+
 #[test]
 fn call_range_max_stale_generations_maximize() {
     let genotype = RangeGenotype::builder()
@@ -278,3 +338,449 @@ fn call_binary_steepest_ascent() {
     println!("{:#?}", hill_climb.best_genes());
     assert_eq!(hill_climb.best_fitness_score(), Some(0));
 }
+
+#[test]
+fn call_binary_seeded_runs_are_identical() {
+    for variant in [
+        HillClimbVariant::Stochastic,
+        HillClimbVariant::SteepestAscent,
+    ] {
+        let genotype = BinaryGenotype::builder()
+            .with_genes_size(100)
+            .build()
+            .unwrap();
+        let builder = HillClimb::builder()
+            .with_genotype(genotype)
+            .with_variant(variant)
+            .with_max_stale_generations(1000)
+            .with_fitness(CountTrue)
+            .with_rng_seed_from_u64(0);
+
+        let first = builder.clone().call().unwrap();
+        let second = builder.call().unwrap();
+
+        assert_eq!(first.best_genes(), second.best_genes());
+        assert_eq!(first.best_fitness_score(), second.best_fitness_score());
+        assert_eq!(first.best_generation(), second.best_generation());
+        assert_eq!(first.best_fitness_score(), Some(100));
+    }
+}
+
+#[test]
+fn call_range_seeded_runs_match_baseline() {
+    // golden values of the implementation before the working buffer refactor
+    for (fitness_ordering, target_fitness_score, best_fitness_score, best_genes) in [
+        (
+            FitnessOrdering::Maximize,
+            8000,
+            8008,
+            vec![
+                0.567, 0.651, 1.0, 0.696, 1.0, 1.0, 0.785, 0.899, 0.490, 0.918,
+            ],
+        ),
+        (
+            FitnessOrdering::Minimize,
+            1000,
+            972,
+            vec![0.0, 0.0, 0.395, 0.0, 0.364, 0.0, 0.0, 0.0, 0.0, 0.211],
+        ),
+    ] {
+        let genotype = RangeGenotype::builder()
+            .with_genes_size(10)
+            .with_allele_range(0.0..=1.0)
+            .with_mutation_type(MutationType::Range(0.1))
+            .build()
+            .unwrap();
+        let hill_climb = HillClimb::builder()
+            .with_genotype(genotype)
+            .with_variant(HillClimbVariant::Stochastic)
+            .with_fitness_ordering(fitness_ordering)
+            .with_target_fitness_score(target_fitness_score)
+            .with_fitness(SumGenes::new_with_precision(1e-3))
+            .with_reporter(StrategyReporterNoop::new())
+            .with_rng_seed_from_u64(0)
+            .call()
+            .unwrap();
+
+        assert_eq!(hill_climb.best_fitness_score(), Some(best_fitness_score));
+        assert!(relative_chromosome_eq(
+            hill_climb.best_genes().unwrap(),
+            best_genes,
+            0.001
+        ));
+    }
+}
+
+#[test]
+fn call_binary_stochastic_with_threshold_acceptance() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(20)
+        .with_seed_genes_list(vec![vec![false; 20]])
+        .build()
+        .unwrap();
+
+    // accept worsenings of at most one for the first 50 calls, only improvements or equal after
+    let accepted_moves = Arc::new(Mutex::new(vec![]));
+    let recorded_moves = accepted_moves.clone();
+    let mut calls = 0;
+    let hill_climb = HillClimb::builder()
+        .with_genotype(genotype)
+        .with_variant(HillClimbVariant::Stochastic)
+        .with_target_fitness_score(20)
+        .with_max_generations(10_000)
+        .with_fitness(CountTrue)
+        .with_acceptance(move |contending, current| {
+            calls += 1;
+            let threshold = if calls <= 50 { 1 } else { 0 };
+            let accept = match (contending, current) {
+                (Some(contending), Some(current)) => contending >= current - threshold,
+                (Some(_), None) => true,
+                (None, _) => false,
+            };
+            if accept {
+                recorded_moves.lock().unwrap().push((contending, current));
+            }
+            accept
+        })
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    let accepted_moves = accepted_moves.lock().unwrap();
+    let first_worsening = accepted_moves
+        .iter()
+        .position(|(contending, current)| contending < current)
+        .unwrap();
+    let (worse, _) = accepted_moves[first_worsening];
+    assert!(accepted_moves
+        .iter()
+        .skip(first_worsening)
+        .any(|(contending, _)| *contending > worse));
+    assert_eq!(hill_climb.best_fitness_score(), Some(20));
+}
+
+#[test]
+fn call_binary_reporter_show_genes_min_improvement_and_truncate_size() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(20)
+        .with_seed_genes_list(vec![vec![false; 20]])
+        .build()
+        .unwrap();
+    let mut hill_climb = HillClimb::builder()
+        .with_genotype(genotype)
+        .with_variant(HillClimbVariant::SteepestAscent)
+        .with_target_fitness_score(20)
+        .with_fitness(CountTrue)
+        .with_reporter(
+            HillClimbReporterSimple::new_with_flags(100, true, true, false)
+                .with_show_genes_min_improvement(5)
+                .with_show_genes_truncate_size(2),
+        )
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    let mut buffer: Vec<u8> = vec![];
+    hill_climb.flush_reporter(&mut buffer);
+    let output = String::from_utf8(buffer).unwrap();
+    let new_best_lines: Vec<&str> = output
+        .lines()
+        .filter(|line| line.starts_with("new best"))
+        .collect();
+
+    // each generation improves by one, genes are only shown every 5 improvements
+    assert_eq!(new_best_lines.len(), 21);
+    let shown_genes_lines: Vec<&str> = new_best_lines
+        .iter()
+        .filter(|line| !line.ends_with("genes: None"))
+        .copied()
+        .collect();
+    assert_eq!(shown_genes_lines.len(), 5);
+    for (line, fitness_score) in shown_genes_lines.iter().zip([0, 5, 10, 15, 20]) {
+        assert!(line.contains(&format!("fitness_score: Some({}),", fitness_score)));
+        assert!(line.contains(", ..., "));
+    }
+    assert!(shown_genes_lines[4].ends_with("genes: Some(Some([true, true, ..., true, true]))"));
+    assert_eq!(hill_climb.best_fitness_score(), Some(20));
+}
+
+#[derive(Clone)]
+struct NeighbourRecorder {
+    generations: Vec<(usize, Option<FitnessValue>, Option<FitnessValue>)>,
+}
+impl StrategyReporter for NeighbourRecorder {
+    type Genotype = BinaryGenotype;
+
+    fn on_generation_complete<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        _config: &C,
+    ) {
+        let population = state.population_as_ref();
+        self.generations.push((
+            population.size(),
+            population
+                .chromosomes
+                .iter()
+                .filter_map(|c| c.fitness_score())
+                .max(),
+            state.best_fitness_score(),
+        ));
+    }
+}
+
+#[test]
+fn call_binary_steepest_ascent_neighbour_sample_size() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(20)
+        .with_seed_genes_list(vec![vec![false; 20]])
+        .build()
+        .unwrap();
+    let hill_climb = HillClimb::builder()
+        .with_genotype(genotype)
+        .with_variant(HillClimbVariant::SteepestAscent)
+        .with_neighbour_sample_size(3)
+        .with_max_generations(5)
+        .with_fitness(CountTrue)
+        .with_reporter(NeighbourRecorder {
+            generations: vec![],
+        })
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    // each neighbour flips a single gene to true, so the best neighbour always improves by one
+    assert_eq!(
+        hill_climb.reporter.generations,
+        vec![
+            (3, Some(1), Some(1)),
+            (3, Some(2), Some(2)),
+            (3, Some(3), Some(3)),
+            (3, Some(4), Some(4)),
+            (3, Some(5), Some(5)),
+        ]
+    );
+    assert_eq!(hill_climb.best_fitness_score(), Some(5));
+}
+
+#[test]
+fn call_binary_generations_and_iterations_run() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    let hill_climb_builder = HillClimb::builder()
+        .with_genotype(genotype)
+        .with_variant(HillClimbVariant::SteepestAscent)
+        .with_max_generations(10)
+        .with_fitness(CountTrue)
+        .with_rng_seed_from_u64(0);
+
+    let hill_climb = hill_climb_builder.clone().build().unwrap();
+    assert_eq!(hill_climb.iterations_run(), 0);
+
+    let hill_climb = hill_climb_builder.clone().call().unwrap();
+    assert_eq!(hill_climb.generations_run(), 10);
+    assert_eq!(hill_climb.iterations_run(), 1);
+
+    let (best_hill_climb, _) = hill_climb_builder.call_repeatedly(3).unwrap();
+    assert_eq!(best_hill_climb.iterations_run(), 3);
+}
+
+#[test]
+fn call_binary_stochastic_with_ordering() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    let builder = HillClimb::builder()
+        .with_genotype(genotype)
+        .with_variant(HillClimbVariant::Stochastic)
+        .with_max_stale_generations(1000)
+        .with_fitness(CountTrue)
+        .with_rng_seed_from_u64(0);
+
+    let hill_climb = builder
+        .call_with_ordering(FitnessOrdering::Maximize)
+        .unwrap();
+    assert_eq!(hill_climb.best_fitness_score(), Some(100));
+
+    let hill_climb = builder
+        .call_with_ordering(FitnessOrdering::Minimize)
+        .unwrap();
+    assert_eq!(hill_climb.best_fitness_score(), Some(0));
+}
+
+#[derive(Clone)]
+struct AcceptingMovesCounter {
+    number_of_accepting_moves: usize,
+}
+impl StrategyReporter for AcceptingMovesCounter {
+    type Genotype = BinaryGenotype;
+
+    fn on_new_best_chromosome<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        _config: &C,
+    ) {
+        // skip the initial chromosome in setup
+        if state.current_generation() > 0 {
+            self.number_of_accepting_moves += 1;
+        }
+    }
+}
+
+#[test]
+fn call_binary_stochastic_record_trajectory() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    let builder = HillClimb::builder()
+        .with_genotype(genotype)
+        .with_variant(HillClimbVariant::Stochastic)
+        .with_max_stale_generations(100)
+        .with_fitness(CountTrue)
+        .with_reporter(AcceptingMovesCounter {
+            number_of_accepting_moves: 0,
+        })
+        .with_rng_seed_from_u64(0);
+
+    let hill_climb = builder.clone().call().unwrap();
+    assert!(hill_climb.trajectory().is_empty());
+
+    let hill_climb = builder.clone().with_record_trajectory(true).call().unwrap();
+    let trajectory = hill_climb.trajectory();
+    assert!(!trajectory.is_empty());
+    assert_eq!(
+        trajectory.len(),
+        hill_climb.reporter.number_of_accepting_moves
+    );
+    assert!(trajectory.windows(2).all(|w| w[0].genes != w[1].genes));
+    assert_eq!(
+        trajectory.last().unwrap().fitness_score(),
+        hill_climb.best_fitness_score()
+    );
+
+    let hill_climb = builder
+        .with_record_trajectory(true)
+        .with_trajectory_limit(3)
+        .call()
+        .unwrap();
+    assert_eq!(hill_climb.trajectory().len(), 3);
+}
+
+#[derive(Clone)]
+struct StaleAndScaleCounter {
+    number_of_stale_generations: usize,
+    scale_down_generations: Vec<usize>,
+}
+impl StrategyReporter for StaleAndScaleCounter {
+    type Genotype = RangeGenotype<f32>;
+
+    fn on_stale_generation<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        _state: &S,
+        _config: &C,
+    ) {
+        self.number_of_stale_generations += 1;
+    }
+    fn on_scale_down<S: StrategyState<Self::Genotype>, C: StrategyConfig>(
+        &mut self,
+        _genotype: &Self::Genotype,
+        state: &S,
+        _config: &C,
+    ) {
+        assert_eq!(state.stale_generations(), 0);
+        self.scale_down_generations.push(state.current_generation());
+    }
+}
+
+#[test]
+fn call_range_reporter_stale_generation_and_scale_down() {
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(10)
+        .with_allele_range(0.0..=1.0)
+        .with_mutation_type(MutationType::StepScaled(vec![0.1, 0.01, 0.001]))
+        .build()
+        .unwrap();
+    let hill_climb = HillClimb::builder()
+        .with_genotype(genotype)
+        .with_max_stale_generations(100)
+        .with_fitness(SumGenes::new_with_precision(1e-5))
+        .with_reporter(StaleAndScaleCounter {
+            number_of_stale_generations: 0,
+            scale_down_generations: vec![],
+        })
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    // two scale downs for three scales, each after max_stale_generations in the previous scale
+    assert_eq!(hill_climb.reporter.scale_down_generations.len(), 2);
+    assert!(hill_climb.reporter.number_of_stale_generations >= 300);
+}
+
+#[test]
+fn call_binary_deadline() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    let builder = HillClimb::builder()
+        .with_genotype(genotype)
+        .with_variant(HillClimbVariant::Stochastic)
+        .with_fitness(CountTrue)
+        .with_rng_seed_from_u64(0);
+
+    // already passed
+    let hill_climb = builder
+        .clone()
+        .with_deadline(Instant::now())
+        .call()
+        .unwrap();
+    assert_eq!(hill_climb.generations_run(), 0);
+    assert_eq!(
+        hill_climb.termination_reason(),
+        Some(StrategyTerminationReason::MaxDuration)
+    );
+
+    let hill_climb = builder
+        .with_max_stale_generations(usize::MAX)
+        .with_target_fitness_score(101)
+        .with_max_duration(Duration::from_millis(20))
+        .call()
+        .unwrap();
+    assert!(hill_climb.generations_run() > 0);
+    assert_eq!(
+        hill_climb.termination_reason(),
+        Some(StrategyTerminationReason::MaxDuration)
+    );
+}
+
+#[test]
+fn call_binary_termination_predicate() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(100)
+        .build()
+        .unwrap();
+    let hill_climb = HillClimb::builder()
+        .with_genotype(genotype)
+        .with_variant(HillClimbVariant::Stochastic)
+        .with_max_stale_generations(usize::MAX)
+        .with_target_fitness_score(101)
+        .with_termination_predicate(|state| state.current_generation() >= 50)
+        .with_fitness(CountTrue)
+        .with_rng_seed_from_u64(0)
+        .call()
+        .unwrap();
+
+    assert_eq!(hill_climb.generations_run(), 50);
+    assert_eq!(
+        hill_climb.termination_reason(),
+        Some(StrategyTerminationReason::TerminationPredicate)
+    );
+}
@@ -2,3 +2,4 @@ pub mod builder_test;
 pub mod evolve_test;
 pub mod hill_climb_test;
 pub mod permutate_test;
+pub mod prelude_test;
@@ -2,10 +2,38 @@
 use crate::support::*;
 use genetic_algorithm::fitness::placeholders::{CountTrue, SumGenes};
 use genetic_algorithm::strategy::permutate::prelude::*;
+use std::time::Duration;
 
 //#[test]
 //build_invalid cannot be tested because invalid doesn't even have a type
 
+#[test]
+fn validate_warning_permutation_chunk_size_without_par_fitness() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(5)
+        .build()
+        .unwrap();
+    let builder = Permutate::builder()
+        .with_genotype(genotype)
+        .with_fitness(CountTrue)
+        .with_permutation_chunk_size(100);
+
+    assert_eq!(
+        builder.validate(),
+        BuilderValidation {
+            errors: vec![],
+            warnings: vec![
+                "The permutation_chunk_size is only used with par_fitness, so it is ignored"
+                    .to_string()
+            ],
+        }
+    );
+    assert_eq!(
+        builder.with_par_fitness(true).validate(),
+        BuilderValidation::default()
+    );
+}
+
 #[test]
 fn call_binary_maximize() {
     let genotype = BinaryGenotype::builder()
@@ -28,6 +56,33 @@ fn call_binary_maximize() {
     );
 }
 
+#[test]
+fn call_binary_result() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(5)
+        .build()
+        .unwrap();
+
+    let permutate = Permutate::builder()
+        .with_genotype(genotype)
+        .with_fitness(CountTrue)
+        .call()
+        .unwrap();
+
+    let result = permutate.result();
+    assert_eq!(result.best_fitness_score, Some(5));
+    assert_eq!(
+        result.best_chromosome.map(|c| c.genes),
+        Some(vec![true, true, true, true, true])
+    );
+    // all 32 permutations, plus the first one once more in setup
+    assert_eq!(result.fitness_evaluations, 33);
+    assert_eq!(
+        result.termination_reason,
+        Some(StrategyTerminationReason::Exhausted)
+    );
+}
+
 #[test]
 fn call_binary_minimize() {
     let genotype = BinaryGenotype::builder()
@@ -159,7 +214,8 @@ fn call_range_f32_random_invalid() {
     assert_eq!(
         permutate.err(),
         Some(TryFromPermutateBuilderError(
-            "The Genotype's mutation_type does not allow permutation"
+            "The Genotype's mutation_type does not allow permutation",
+            BuilderError::IncompatibleMutationType
         ))
     );
 }
@@ -184,3 +240,295 @@ fn call_par_fitness() {
     assert_eq!(permutate.best_fitness_score(), Some(45));
     assert_eq!(permutate.best_genes().unwrap(), vec![9, 9, 9, 9, 9]);
 }
+
+#[test]
+fn call_par_fitness_fully_parallel() {
+    let genotype = ListGenotype::builder()
+        .with_genes_size(5)
+        .with_allele_list((0..10).collect())
+        .build()
+        .unwrap();
+    let builder = Permutate::builder()
+        .with_genotype(genotype)
+        .with_fitness(SumGenes::new())
+        .with_reporter(StrategyReporterNoop::new());
+
+    let sequential = builder.clone().call().unwrap();
+    let permutate = builder
+        .with_par_fitness(true)
+        .with_permutation_chunk_size(1000)
+        .call()
+        .unwrap();
+
+    assert_eq!(permutate.best_fitness_score(), Some(45));
+    assert_eq!(permutate.best_genes().unwrap(), vec![9, 9, 9, 9, 9]);
+    assert_eq!(permutate.best_generation(), sequential.best_generation());
+    assert_eq!(permutate.generations_run(), 100_000);
+    assert_eq!(permutate.generations_run(), sequential.generations_run());
+}
+
+#[test]
+fn call_range_f32_scaled_fully_parallel() {
+    let genotype = RangeGenotype::builder()
+        .with_genes_size(4)
+        .with_allele_range(0.0..=1.0)
+        .with_mutation_type(MutationType::StepScaled(vec![0.1, 0.01, 0.001]))
+        .build()
+        .unwrap();
+
+    let mut permutate = Permutate::builder()
+        .with_genotype(genotype)
+        .with_fitness(SumGenes::new_with_precision(1e-3))
+        .with_par_fitness(true)
+        .with_permutation_chunk_size(100)
+        .with_chunk_size(1000)
+        .with_reporter(StrategyReporterNoop::new())
+        .build()
+        .unwrap();
+
+    let mut number_of_calls = 0;
+    while permutate.termination_reason().is_none() {
+        permutate.call();
+        number_of_calls += 1;
+    }
+
+    println!("{:#?}", permutate.best_genes());
+    assert!(number_of_calls > 1);
+    assert_eq!(permutate.best_fitness_score(), Some(4000));
+    assert!(relative_chromosome_eq(
+        permutate.best_genes().unwrap(),
+        vec![1.0, 1.0, 1.0, 1.0],
+        0.001
+    ));
+}
+
+#[test]
+fn call_binary_generations_and_iterations_run() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(5)
+        .build()
+        .unwrap();
+
+    let permutate = Permutate::builder()
+        .with_genotype(genotype)
+        .with_fitness(CountTrue)
+        .call()
+        .unwrap();
+
+    // one generation per permutation
+    assert_eq!(permutate.generations_run(), 32);
+    assert_eq!(permutate.iterations_run(), 1);
+}
+
+#[test]
+fn par_iter_chromosomes_matches_serial_sum() {
+    use rayon::prelude::*;
+
+    let genotype = ListGenotype::builder()
+        .with_genes_size(4)
+        .with_allele_list((0..5).collect())
+        .build()
+        .unwrap();
+
+    let permutate = Permutate::builder()
+        .with_genotype(genotype.clone())
+        .with_fitness(SumGenes::new())
+        .build()
+        .unwrap();
+
+    let serial_sum: FitnessValue = genotype
+        .chromosome_permutations_into_iter(None)
+        .map(|chromosome| {
+            SumGenes::<ListGenotype<i32>>::new()
+                .calculate_for_chromosome(&chromosome, &genotype)
+                .unwrap()
+        })
+        .sum();
+
+    let parallel_sum: FitnessValue = permutate
+        .par_iter_chromosomes()
+        .map_with(
+            SumGenes::<ListGenotype<i32>>::new(),
+            |fitness, (_index, chromosome)| {
+                fitness
+                    .calculate_for_chromosome(&chromosome, &permutate.genotype)
+                    .unwrap()
+            },
+        )
+        .sum();
+
+    // 625 permutations, each allele value occurs 125 times per gene
+    assert_eq!(serial_sum, 5000);
+    assert_eq!(parallel_sum, serial_sum);
+
+    let mut indices: Vec<usize> = permutate
+        .par_iter_chromosomes()
+        .map(|(index, _chromosome)| index)
+        .collect();
+    indices.sort_unstable();
+    assert_eq!(indices, (0..625).collect::<Vec<_>>());
+}
+
+#[test]
+fn call_binary_chunked() {
+    use num::rational::Ratio;
+    use num::BigUint;
+
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(5)
+        .build()
+        .unwrap();
+
+    let mut permutate = Permutate::builder()
+        .with_genotype(genotype)
+        .with_fitness(CountTrue)
+        .with_chunk_size(10)
+        .build()
+        .unwrap();
+
+    let mut progress = vec![];
+    while permutate.termination_reason().is_none() {
+        permutate.call();
+        progress.push(permutate.progress());
+    }
+
+    // 32 permutations, the fourth chunk is partial and exhausts the scale
+    assert_eq!(
+        progress,
+        vec![
+            Ratio::new(BigUint::from(10u8), BigUint::from(32u8)),
+            Ratio::new(BigUint::from(20u8), BigUint::from(32u8)),
+            Ratio::new(BigUint::from(30u8), BigUint::from(32u8)),
+            Ratio::new(BigUint::from(1u8), BigUint::from(1u8)),
+        ]
+    );
+    assert_eq!(permutate.generations_run(), 32);
+    assert_eq!(permutate.permutation_index(), 0);
+    assert_eq!(permutate.best_fitness_score(), Some(5));
+    assert_eq!(
+        permutate.best_genes().unwrap(),
+        vec![true, true, true, true, true]
+    );
+}
+
+#[test]
+fn call_binary_resume_from_start_permutation_index() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(5)
+        .build()
+        .unwrap();
+
+    let mut permutate = Permutate::builder()
+        .with_genotype(genotype)
+        .with_fitness(CountTrue)
+        .with_chunk_size(10)
+        .with_start_permutation_index(20)
+        .build()
+        .unwrap();
+
+    permutate.call();
+    assert_eq!(permutate.permutation_index(), 30);
+    assert_eq!(permutate.generations_run(), 30);
+    assert_eq!(permutate.termination_reason(), None);
+
+    permutate.call();
+    assert_eq!(permutate.generations_run(), 32);
+    assert_eq!(
+        permutate.termination_reason(),
+        Some(StrategyTerminationReason::Exhausted)
+    );
+    assert_eq!(permutate.best_fitness_score(), Some(5));
+}
+
+#[test]
+fn call_binary_max_duration_resume() {
+    for (par_fitness, permutation_chunk_size) in [(false, None), (true, None), (true, Some(4))] {
+        let genotype = BinaryGenotype::builder()
+            .with_genes_size(5)
+            .build()
+            .unwrap();
+
+        let mut builder = Permutate::builder()
+            .with_genotype(genotype)
+            .with_fitness(CountTrue)
+            .with_par_fitness(par_fitness)
+            .with_max_duration(Duration::ZERO);
+        builder.permutation_chunk_size = permutation_chunk_size;
+        let mut permutate = builder.build().unwrap();
+
+        // stopped before the first permutation, not exhausted
+        permutate.call();
+        assert_eq!(permutate.permutation_index(), 0);
+        assert_eq!(permutate.generations_run(), 0);
+        assert_eq!(
+            permutate.termination_reason(),
+            Some(StrategyTerminationReason::MaxDuration)
+        );
+
+        permutate.config.max_duration = None;
+        permutate.call();
+        assert_eq!(permutate.generations_run(), 32);
+        assert_eq!(
+            permutate.termination_reason(),
+            Some(StrategyTerminationReason::Exhausted)
+        );
+        assert_eq!(permutate.best_fitness_score(), Some(5));
+    }
+}
+
+#[test]
+fn call_binary_termination_predicate() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(5)
+        .build()
+        .unwrap();
+    let permutate = Permutate::builder()
+        .with_genotype(genotype)
+        .with_fitness(CountTrue)
+        .with_termination_predicate(|state| state.current_generation() >= 10)
+        .call()
+        .unwrap();
+
+    // stopped early, not exhausted
+    assert_eq!(permutate.permutation_index(), 10);
+    assert_eq!(permutate.generations_run(), 10);
+    assert_eq!(
+        permutate.termination_reason(),
+        Some(StrategyTerminationReason::TerminationPredicate)
+    );
+}
+
+#[test]
+fn call_binary_termination_predicate_fully_parallel() {
+    let genotype = BinaryGenotype::builder()
+        .with_genes_size(10)
+        .build()
+        .unwrap();
+    let mut permutate = Permutate::builder()
+        .with_genotype(genotype)
+        .with_fitness(CountTrue)
+        .with_par_fitness(true)
+        .with_permutation_chunk_size(1)
+        .with_termination_predicate(|state| state.current_generation() >= 10)
+        .build()
+        .unwrap();
+
+    // stopped after the first window reaching the generation, not exhausted
+    permutate.call();
+    assert!(permutate.generations_run() >= 10);
+    assert!(permutate.generations_run() < 1024);
+    assert_eq!(permutate.permutation_index(), permutate.generations_run());
+    assert_eq!(
+        permutate.termination_reason(),
+        Some(StrategyTerminationReason::TerminationPredicate)
+    );
+
+    permutate.termination_predicate = None;
+    permutate.call();
+    assert_eq!(permutate.generations_run(), 1024);
+    assert_eq!(
+        permutate.termination_reason(),
+        Some(StrategyTerminationReason::Exhausted)
+    );
+    assert_eq!(permutate.best_fitness_score(), Some(10));
+}
@@ -0,0 +1,12 @@
+#[cfg(test)]
+use genetic_algorithm::genotype::BinaryGenotype;
+use genetic_algorithm::strategy::evolve::prelude::operators::*;
+
+#[test]
+fn operators_glob_import() {
+    let _select: SelectWrapper<BinaryGenotype> = SelectTournament::new(0.5, 0.02, 4).into();
+    let _crossover: CrossoverWrapper<BinaryGenotype> =
+        CrossoverGroupPoint::new(0.7, 0.8, vec![2], 1).into();
+    let _mutate: MutateWrapper<BinaryGenotype> = MutateSingleGene::new(0.2).into();
+    let _extension: ExtensionWrapper<BinaryGenotype> = ExtensionScaleReset::new(2, 2).into();
+}